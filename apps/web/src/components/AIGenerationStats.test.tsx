@@ -33,6 +33,7 @@ function makeStats(overrides = {}) {
     totalOutputTokens: 30000,
     cacheReadTokens: 10000,
     cacheCreationTokens: 5000,
+    thinkingOutputTokens: 12000,
     tokensByModel: [
       { model: 'claude-opus-4-5-20251101', inputTokens: 30000, outputTokens: 20000 },
       { model: 'claude-sonnet-4-20250514', inputTokens: 20000, outputTokens: 10000 },
@@ -175,6 +176,28 @@ describe('AIGenerationStats', () => {
       expect(screen.getByText('Claude Opus 4.5')).toBeInTheDocument()
       expect(screen.getByText('Claude Sonnet 4')).toBeInTheDocument()
     })
+
+    it('should show the thinking share of output tokens', () => {
+      mockUseAIGenerationStats.mockReturnValue({
+        data: makeStats(),
+        isLoading: false,
+        error: null,
+      })
+      render(<AIGenerationStats />)
+
+      expect(screen.getByText(/Thinking: 40% of output tokens/)).toBeInTheDocument()
+    })
+
+    it('should hide the thinking share when there are no output tokens', () => {
+      mockUseAIGenerationStats.mockReturnValue({
+        data: makeStats({ totalOutputTokens: 0, thinkingOutputTokens: 0 }),
+        isLoading: false,
+        error: null,
+      })
+      render(<AIGenerationStats />)
+
+      expect(screen.queryByText(/Thinking:/)).not.toBeInTheDocument()
+    })
   })
 
   describe('token usage by project', () => {
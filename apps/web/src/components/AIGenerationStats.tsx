@@ -23,7 +23,7 @@ interface AIGenerationStatsProps {
  *
  * Displays:
  * 1. Metric cards: Lines Generated, Files Edited, Tokens Used
- * 2. Token usage by model (progress bars)
+ * 2. Token usage by model (progress bars) with the thinking share of output
  * 3. Top projects by token usage (progress bars)
  */
 export function AIGenerationStats({ timeRange, project, branch }: AIGenerationStatsProps) {
@@ -66,6 +66,8 @@ export function AIGenerationStats({ timeRange, project, branch }: AIGenerationSt
     (sum, p) => sum + p.inputTokens + p.outputTokens,
     0,
   )
+  const thinkingRatio =
+    stats.totalOutputTokens > 0 ? stats.thinkingOutputTokens / stats.totalOutputTokens : null
 
   // Check if we have any meaningful data for the by-model / by-project breakdowns
   const hasModelData = stats.tokensByModel.length > 0
@@ -116,6 +118,12 @@ export function AIGenerationStats({ timeRange, project, branch }: AIGenerationSt
                 )
               })}
             </div>
+            {thinkingRatio !== null && (
+              <p className="mt-3 text-xs text-gray-500 dark:text-gray-400">
+                Thinking: {Math.round(thinkingRatio * 100)}% of output tokens (
+                {formatTokens(stats.thinkingOutputTokens)})
+              </p>
+            )}
           </div>
         )}
 
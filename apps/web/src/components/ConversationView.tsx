@@ -42,6 +42,7 @@ import { EmptyState, ErrorState, Skeleton } from './LoadingStates'
 import { SearchInput } from './SearchInput'
 import { SessionMetricsBar } from './SessionMetricsBar'
 import { ShareModal } from './ShareModal'
+import { TokenTimelineSparkline } from './TokenTimelineSparkline'
 import { ModelSelector } from './chat/ModelSelector'
 import { ConversationThread } from '@claude-view/shared/components/conversation/ConversationThread'
 import { ThinkingIndicator } from '@claude-view/shared/components/conversation/ThinkingIndicator'
@@ -436,6 +437,7 @@ export function ConversationView() {
           </Link>
           <span className="text-gray-300 dark:text-gray-600">|</span>
           <span className="font-medium text-gray-900 dark:text-gray-100">{projectName}</span>
+          {!isForeignSession && sessionId && <TokenTimelineSparkline sessionId={sessionId} />}
         </div>
 
         <div className="flex items-center gap-2">
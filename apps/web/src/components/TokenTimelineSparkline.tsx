@@ -0,0 +1,66 @@
+// src/components/TokenTimelineSparkline.tsx
+// Per-turn token usage for one session — fetches /api/sessions/:id/token-timeline
+
+import { useQuery } from '@tanstack/react-query'
+import { Area, AreaChart, Tooltip } from 'recharts'
+import { formatTokenCount } from '../lib/format-utils'
+import type { TurnTokens } from '../types/generated'
+
+interface TokenTimelineSparklineProps {
+  sessionId: string
+}
+
+function TimelineTooltip({
+  active,
+  payload,
+}: {
+  active?: boolean
+  payload?: Array<{ payload: TurnTokens }>
+}) {
+  if (!active || !payload?.length) return null
+  const point = payload[0].payload
+  return (
+    <div className="bg-white dark:bg-gray-800 border border-gray-200 dark:border-gray-700 rounded-md shadow-sm px-2 py-1 text-xs text-gray-700 dark:text-gray-300 tabular-nums">
+      Turn {point.index}: {formatTokenCount(point.tokens)}
+      <span className="text-gray-400"> / {formatTokenCount(point.cumulativeTokens)} total</span>
+    </div>
+  )
+}
+
+export function TokenTimelineSparkline({ sessionId }: TokenTimelineSparklineProps) {
+  const { data } = useQuery({
+    queryKey: ['session-token-timeline', sessionId],
+    queryFn: async () => {
+      const res = await fetch(`/api/sessions/${encodeURIComponent(sessionId)}/token-timeline`)
+      if (!res.ok) throw new Error('Failed to fetch token timeline')
+      return res.json() as Promise<TurnTokens[]>
+    },
+    staleTime: 5 * 60_000,
+  })
+
+  // A single turn has no shape worth drawing.
+  if (!data || data.length < 2) return null
+
+  return (
+    <div title="Tokens per turn" aria-label="Tokens per turn">
+      <AreaChart
+        width={120}
+        height={24}
+        data={data}
+        margin={{ top: 2, right: 0, bottom: 2, left: 0 }}
+      >
+        <Tooltip content={<TimelineTooltip />} cursor={false} />
+        <Area
+          type="monotone"
+          dataKey="tokens"
+          stroke="#3b82f6"
+          strokeWidth={1.5}
+          fill="#3b82f6"
+          fillOpacity={0.15}
+          dot={false}
+          isAnimationActive={false}
+        />
+      </AreaChart>
+    </div>
+  )
+}
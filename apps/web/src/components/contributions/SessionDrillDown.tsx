@@ -83,6 +83,14 @@ export function SessionDrillDown({
                   {data.workType}
                 </span>
               )}
+              {data.workTypeAlternates.map((alt) => (
+                <span
+                  key={alt}
+                  className="inline-block ml-1.5 px-2 py-1 text-xs font-medium rounded bg-gray-100 dark:bg-gray-800 text-gray-600 dark:text-gray-400 mb-2"
+                >
+                  {alt}
+                </span>
+              ))}
               <h2 className="text-lg font-semibold text-gray-900 dark:text-gray-100">
                 Session Details
               </h2>
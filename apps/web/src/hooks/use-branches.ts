@@ -4,12 +4,15 @@ import type { BranchesResponse } from '../types/generated/BranchesResponse'
 
 /**
  * Fetch distinct branches with session counts for a specific project.
+ * Branches git sync found deleted (gone locally and on every remote) are left out.
  *
  * @param projectId - The project identifier
  * @returns Promise resolving to BranchesResponse
  */
 async function fetchProjectBranches(projectId: string): Promise<BranchesResponse> {
-  const response = await fetch(`/api/projects/${encodeURIComponent(projectId)}/branches`)
+  const response = await fetch(
+    `/api/projects/${encodeURIComponent(projectId)}/branches?hideDeleted=true`,
+  )
   if (!response.ok) throw new Error('Failed to fetch project branches')
   return response.json()
 }
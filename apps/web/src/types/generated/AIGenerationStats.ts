@@ -14,6 +14,10 @@ export type AIGenerationStats = {
   totalOutputTokens: number
   cacheReadTokens: number
   cacheCreationTokens: number
+  /**
+   * Estimated share of `total_output_tokens` spent on thinking.
+   */
+  thinkingOutputTokens: number
   tokensByModel: Array<TokensByModel>
   tokensByProject: Array<TokensByProject>
   /**
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Runs of consecutive local days with at least one session.
+ */
+export type ActivityStreak = {
+  /**
+   * Active days in the run ending today (0 if today has no session).
+   */
+  currentDays: number
+  /**
+   * Longest run of active days ever recorded.
+   */
+  longestDays: number
+}
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A file or image attached to a user message (image/document content block).
+ */
+export type Attachment = {
+  /**
+   * File name from the block's title or source URL, else a placeholder
+   * derived from the media type (e.g. `image.png`).
+   */
+  name: string
+  mediaType: string
+}
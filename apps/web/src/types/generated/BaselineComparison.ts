@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One of the user's metrics placed against the bundled baseline.
+ */
+export type BaselineComparison = {
+  /**
+   * `"sessions_per_week"` or `"cache_hit_ratio"`.
+   */
+  metric: string
+  value: number
+  /**
+   * Share of the baseline at or below `value`, 0-100.
+   */
+  percentile: number
+}
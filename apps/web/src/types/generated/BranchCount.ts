@@ -3,4 +3,17 @@
 /**
  * Branch count for a project.
  */
-export type BranchCount = { branch: string | null; count: number }
+export type BranchCount = {
+  branch: string | null
+  count: number
+  /**
+   * Latest `last_message_at` (unix seconds) across the branch's sessions.
+   */
+  lastActivityAt: number
+  /**
+   * `"active"`, `"merged"`, `"remote"` (gone locally, still on a remote)
+   * or `"deleted"` as of the last git sync; `None` when never checked.
+   * Across worktrees the most alive status wins.
+   */
+  status: string | null
+}
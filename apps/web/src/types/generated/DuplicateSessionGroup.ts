@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Sessions sharing project, first-message time, and preview — usually the
+ * same conversation indexed twice (backup recovery, re-indexing).
+ */
+export type DuplicateSessionGroup = {
+  projectId: string
+  firstMessageAt: number
+  preview: string
+  /**
+   * Session IDs in the group, most recently active first.
+   */
+  sessionIds: Array<string>
+}
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A session ranked for error triage by `top_error_sessions`.
+ */
+export type ErrorSession = {
+  sessionId: string
+  projectId: string
+  projectDisplayName: string
+  preview: string
+  apiErrorCount: number
+  apiRetryCount: number
+  compactionCount: number
+  lastMessageAt: number
+}
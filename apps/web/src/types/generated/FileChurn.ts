@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A file edited more than once within sessions, for `files_most_reedited`.
+ */
+export type FileChurn = {
+  filePath: string
+  /**
+   * Edits beyond the first in each session, summed across sessions.
+   */
+  reeditCount: number
+  /**
+   * Sessions that edited the file more than once.
+   */
+  sessionCount: number
+  /**
+   * Up to five of those sessions, most recent first.
+   */
+  sessionIds: Array<string>
+}
@@ -3,4 +3,17 @@
 /**
  * Response for the health check endpoint.
  */
-export type HealthResponse = { status: string; version: string; uptime_secs: number }
+export type HealthResponse = {
+  status: string
+  version: string
+  uptime_secs: number
+  /**
+   * Whether the prompt search index is open. `false` before the index is
+   * built or after it failed to open, in which case search returns nothing.
+   */
+  search_available: boolean
+  /**
+   * Documents in the search index (0 when unavailable).
+   */
+  search_doc_count: number
+}
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One successful index run, from `index_metadata_history`.
+ */
+export type IndexRunHistoryEntry = {
+  /**
+   * Unix timestamp of the run's completion.
+   */
+  indexedAt: number
+  /**
+   * Run duration in milliseconds.
+   */
+  durationMs: number
+  /**
+   * Number of sessions indexed in the run.
+   */
+  sessionsIndexed: number
+  /**
+   * Number of projects indexed in the run.
+   */
+  projectsIndexed: number
+}
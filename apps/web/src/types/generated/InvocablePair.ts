@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Two invocables used in the same sessions, keyed as in
+ * `session_stats.invocation_counts` (e.g. `"Bash"`, `"Skill:commit"`).
+ */
+export type InvocablePair = {
+  first: string
+  second: string
+  /**
+   * Sessions that used both.
+   */
+  sessionCount: number
+}
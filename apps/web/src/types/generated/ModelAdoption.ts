@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ModelMonthTurns } from './ModelMonthTurns'
+
+/**
+ * When a model was first and last used, with assistant turns per month
+ * (for the model-adoption chart).
+ */
+export type ModelAdoption = {
+  modelId: string
+  family: string | null
+  /**
+   * `last_message_at` of the earliest session that used the model.
+   */
+  firstSeen: number
+  /**
+   * `last_message_at` of the latest session that used the model.
+   */
+  lastSeen: number
+  /**
+   * Months with usage, oldest first.
+   */
+  months: Array<ModelMonthTurns>
+}
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Full cost for one model, priced per token type including cache reads and
+ * cache writes (5m / 1h TTL split when recorded).
+ */
+export type ModelCost = {
+  model: string
+  inputTokens: number
+  outputTokens: number
+  cacheReadTokens: number
+  cacheCreationTokens: number
+  inputCostUsd: number
+  outputCostUsd: number
+  cacheReadCostUsd: number
+  cacheCreationCostUsd: number
+  /**
+   * What the cache reads would have cost at the uncached input rate, minus
+   * what they did cost.
+   */
+  cacheSavingsUsd: number
+  totalCostUsd: number
+  /**
+   * True when the model has no pricing entry; all costs are then 0.
+   */
+  hasUnpricedUsage: boolean
+}
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Assistant turns served by one model in one calendar month (UTC).
+ */
+export type ModelMonthTurns = {
+  /**
+   * `YYYY-MM`.
+   */
+  month: string
+  turnCount: number
+}
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One model used in a session, with how many assistant turns it served.
+ */
+export type ModelTurnCount = {
+  modelId: string
+  turnCount: number
+}
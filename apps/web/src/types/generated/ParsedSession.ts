@@ -1,8 +1,16 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Attachment } from './Attachment'
 import type { Message } from './Message'
 import type { SessionMetadata } from './SessionMetadata'
 
 /**
  * A parsed session with messages and metadata
  */
-export type ParsedSession = { messages: Array<Message>; metadata: SessionMetadata }
+export type ParsedSession = {
+  messages: Array<Message>
+  metadata: SessionMetadata
+  /**
+   * Distinct attachments across the session, in first-seen order.
+   */
+  attachments?: Array<Attachment>
+}
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One project's estimated cost for `project_cost_leaderboard`.
+ */
+export type ProjectCost = {
+  projectId: string
+  projectDisplayName: string
+  sessionCount: number
+  inputTokens: number
+  outputTokens: number
+  cacheReadTokens: number
+  cacheCreationTokens: number
+  /**
+   * Estimated cost in US cents, including cache reads and writes.
+   */
+  totalCostCents: number
+  /**
+   * True when some tokens used a model with no pricing entry; those
+   * tokens are counted but not costed.
+   */
+  hasUnpricedUsage: boolean
+}
@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HealthStatus } from './HealthStatus'
+
+/**
+ * Health rollup for one project (per-project system view).
+ */
+export type ProjectHealth = {
+  projectId: string
+  sessionsCount: number
+  lastActivityAt: number | null
+  /**
+   * Sessions with at least one API error.
+   */
+  errorSessionsCount: number
+  /**
+   * `error_sessions_count / sessions_count` (0 with no sessions).
+   */
+  errorRate: number
+  /**
+   * Share of sessions not yet classified (0 with no sessions).
+   */
+  unclassifiedRatio: number
+  /**
+   * Sessions that edited files but have no linked commit.
+   */
+  uncommittedSessionsCount: number
+  status: HealthStatus
+}
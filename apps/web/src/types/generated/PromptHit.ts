@@ -7,8 +7,10 @@ export type PromptHit = {
   promptId: string
   display: string
   /**
-   * HTML snippet with `<b>` tags around matched terms. Present when a
-   * free-text query was used; `None` otherwise (browse/filter-only mode).
+   * HTML snippet with `<b>` tags around matched terms when a free-text
+   * query was used. When only `model` or `skills` matched, the matching
+   * field as `model: <b>opus</b>` / `skill: <b>commit</b>`. `None`
+   * otherwise (browse/filter-only mode).
    */
   snippet: string | null
   /**
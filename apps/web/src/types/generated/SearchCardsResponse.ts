@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SearchSessionCard } from './SearchSessionCard'
+
+/**
+ * [`SearchResponse`] with each hit hydrated into a [`SearchSessionCard`].
+ */
+export type SearchCardsResponse = {
+  query: string
+  totalSessions: number
+  totalMatches: number
+  elapsedMs: number
+  cards: Array<SearchSessionCard>
+}
@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Checkpoint of a full deep index rebuild, from
+ * `search_index_rebuild_progress`.
+ */
+export type SearchIndexRebuildProgress = {
+  /**
+   * Unix timestamp the rebuild started.
+   */
+  startedAt: number
+  /**
+   * Last session the rebuild finished with, if any.
+   */
+  lastSessionId: string | null
+  /**
+   * Sessions processed so far, across restarts.
+   */
+  sessionsProcessed: number
+  /**
+   * Unix timestamp of the last checkpoint.
+   */
+  updatedAt: number
+  /**
+   * Unix timestamp the rebuild completed; None while in progress.
+   */
+  completedAt: number | null
+}
@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SearchTimings } from './SearchTimings'
 import type { SessionHit } from './SessionHit'
 
 /**
@@ -21,6 +22,10 @@ export type SearchResponse = {
    * Time spent executing the search, in milliseconds.
    */
   elapsedMs: number
+  /**
+   * Per-phase breakdown of `elapsed_ms`.
+   */
+  timings: SearchTimings
   /**
    * Session-grouped results, sorted by session recency descending.
    */
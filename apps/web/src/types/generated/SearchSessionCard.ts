@@ -0,0 +1,47 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MatchHit } from './MatchHit'
+import type { SessionInfo } from './SessionInfo'
+
+/**
+ * A search hit merged with its session's indexed metadata.
+ */
+export type SearchSessionCard = {
+  sessionId: string
+  project: string
+  branch: string | null
+  /**
+   * Unix timestamp (seconds) of the most recent match in this session.
+   */
+  modifiedAt: number
+  /**
+   * How many individual messages matched in this session.
+   */
+  matchCount: number
+  /**
+   * Search score: the best match's role boost (see
+   * [`crate::RoleBoosts`]), or 0 when no role boosts are set.
+   */
+  bestScore: number
+  /**
+   * The single best-scoring match (for collapsed view).
+   */
+  topMatch: MatchHit
+  /**
+   * All matches in this session (for expanded view).
+   */
+  matches: Array<MatchHit>
+  /**
+   * Every turn with a match, sorted and deduped (for a scrollbar
+   * mini-map). Unlike `matches`, never collapsed by snippet dedupe.
+   */
+  matchTurns: number[]
+  /**
+   * Which search engines contributed to this session's results.
+   * Session search currently returns ["grep"].
+   */
+  engines: Array<string>
+  /**
+   * `None` when the JSONL file matched but the session isn't indexed yet.
+   */
+  session: SessionInfo | null
+}
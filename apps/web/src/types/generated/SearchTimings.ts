@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Sub-timings of a search, in milliseconds. The phases run back to back,
+ * so together they never exceed `elapsed_ms`.
+ */
+export type SearchTimings = {
+  /**
+   * Query parsing: qualifier extraction and attachment filtering setup.
+   */
+  parseMs: number
+  /**
+   * Running the match engine (grep, or the attachment scan).
+   */
+  queryMs: number
+  /**
+   * Building snippets and session groups, sorting, and pagination.
+   */
+  snippetMs: number
+}
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Lightweight session summary for the recent-sessions feed.
+ */
+export type SessionCard = {
+  sessionId: string
+  projectId: string
+  projectDisplayName: string
+  preview: string
+  gitBranch: string | null
+  primaryModel: string | null
+  messageCount: number
+  lastMessageAt: number
+}
@@ -6,6 +6,10 @@
 export type SessionContribution = {
   sessionId: string
   workType: string | null
+  /**
+   * Secondary work types, best first.
+   */
+  workTypeAlternates: Array<string>
   durationSeconds: number
   promptCount: number
   aiLinesAdded: number
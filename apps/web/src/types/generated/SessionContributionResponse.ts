@@ -16,6 +16,10 @@ export type SessionContributionResponse = {
    * Work type classification
    */
   workType: string | null
+  /**
+   * Secondary work types, best first
+   */
+  workTypeAlternates: Array<string>
   /**
    * Duration in seconds
    */
@@ -2,6 +2,7 @@
 import type { AgentTodos } from './AgentTodos'
 import type { CommitWithTier } from './CommitWithTier'
 import type { DerivedMetrics } from './DerivedMetrics'
+import type { SessionModelCost } from './SessionModelCost'
 import type { TaskItem } from './TaskItem'
 import type { ToolCounts } from './ToolCounts'
 
@@ -24,6 +25,22 @@ export type SessionDetail = {
    * Whether plan files exist for this session's slug
    */
   hasPlans: boolean
+  /**
+   * Session cost priced per model used, itemized by token type.
+   */
+  modelCost?: SessionModelCost
+  /**
+   * Times the user interrupted the model mid-turn (ESC / abort).
+   */
+  interruptionCount: number
+  /**
+   * Bash calls by command category (`git`, `test`, `build`, `misc`).
+   */
+  bashCategories: { [key in string]?: number }
+  /**
+   * Argument strings passed to each slash command or skill, counted.
+   */
+  invocationArgs: { [key in string]?: { [key in string]?: number } }
   /**
    * Warnings for non-fatal data read failures (e.g. task/plan file errors)
    */
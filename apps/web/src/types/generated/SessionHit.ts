@@ -17,7 +17,8 @@ export type SessionHit = {
    */
   matchCount: number
   /**
-   * Search score. Grep-backed session search sets this to 0.
+   * Search score: the best match's role boost (see
+   * [`crate::RoleBoosts`]), or 0 when no role boosts are set.
    */
   bestScore: number
   /**
@@ -28,6 +29,11 @@ export type SessionHit = {
    * All matches in this session (for expanded view).
    */
   matches: Array<MatchHit>
+  /**
+   * Every turn with a match, sorted and deduped (for a scrollbar
+   * mini-map). Unlike `matches`, never collapsed by snippet dedupe.
+   */
+  matchTurns: number[]
   /**
    * Which search engines contributed to this session's results.
    * Session search currently returns ["grep"].
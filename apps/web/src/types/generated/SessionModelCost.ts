@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CostBreakdown } from '../../../../../packages/shared/src/types/generated/CostBreakdown'
+
+/**
+ * One session's cost, priced per model it used (for the session detail view).
+ */
+export type SessionModelCost = {
+  /**
+   * The session's `primary_model`; `None` if unknown.
+   */
+  model: string | null
+  cost: CostBreakdown
+}
@@ -5,6 +5,7 @@ import type { HealthInfo } from './HealthInfo'
 import type { IndexRunInfo } from './IndexRunInfo'
 import type { IntegrityInfo } from './IntegrityInfo'
 import type { PerformanceInfo } from './PerformanceInfo'
+import type { SearchIndexRebuildProgress } from './SearchIndexRebuildProgress'
 import type { StorageInfo } from './StorageInfo'
 
 /**
@@ -16,6 +17,10 @@ export type SystemResponse = {
   health: HealthInfo
   integrity: IntegrityInfo
   indexHistory: Array<IndexRunInfo>
+  /**
+   * Checkpoint of the running or last full deep index rebuild.
+   */
+  rebuildProgress: SearchIndexRebuildProgress | null
   classification: ClassificationInfo
   claudeCli: ClaudeCliStatus
 }
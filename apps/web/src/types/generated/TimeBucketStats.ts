@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Sessions and tokens in one time-of-week bucket (a weekday or an hour).
+ */
+export type TimeBucketStats = {
+  sessions: number
+  /**
+   * Input + output tokens (cache reads/writes excluded).
+   */
+  totalTokens: number
+}
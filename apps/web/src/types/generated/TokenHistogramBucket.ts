@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One bucket of the per-session token histogram: sessions whose total
+ * tokens fall in `[min_tokens, max_tokens)`.
+ */
+export type TokenHistogramBucket = {
+  minTokens: number
+  /**
+   * Exclusive upper edge; `None` for the open-ended last bucket.
+   */
+  maxTokens: number | null
+  sessions: number
+}
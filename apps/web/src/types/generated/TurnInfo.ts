@@ -25,4 +25,9 @@ export type TurnInfo = {
    * First 60 characters of the user prompt text.
    */
   promptPreview: string
+  /**
+   * Tokens billed for the turn's assistant messages (input + output +
+   * cache read + cache creation), each message counted once.
+   */
+  tokens: number
 }
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One point of a session's per-turn token timeline.
+ */
+export type TurnTokens = {
+  /**
+   * 1-based turn index.
+   */
+  index: number
+  /**
+   * Unix timestamp (seconds) when the turn started (user prompt).
+   */
+  startedAt: number
+  /**
+   * Tokens used by this turn alone.
+   */
+  tokens: number
+  /**
+   * Tokens used by this turn and every turn before it.
+   */
+  cumulativeTokens: number
+}
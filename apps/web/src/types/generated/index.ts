@@ -8,20 +8,31 @@ export type { ToolCall } from './ToolCall'
 export type { Message } from './Message'
 export type { SessionMetadata } from './SessionMetadata'
 export type { ParsedSession } from './ParsedSession'
+export type { Attachment } from './Attachment'
 export type { PaginatedMessages } from './PaginatedMessages'
 export type { SessionInfo } from './SessionInfo'
 export type { ProjectInfo } from './ProjectInfo'
 
 // Stats types
+export type { InvocablePair } from './InvocablePair'
 export type { InvocableWithCount } from './InvocableWithCount'
 export type { ModelWithStats } from './ModelWithStats'
+export type { ModelTurnCount } from './ModelTurnCount'
+export type { ModelAdoption } from './ModelAdoption'
+export type { ModelMonthTurns } from './ModelMonthTurns'
 export type { TokenStats } from './TokenStats'
+export type { TimeBucketStats } from './TimeBucketStats'
+export type { ErrorSession } from './ErrorSession'
+export type { SessionCard } from './SessionCard'
 export type { StatsOverview } from './StatsOverview'
+export type { DuplicateSessionGroup } from './DuplicateSessionGroup'
 export type { DashboardStats } from './DashboardStats'
 export type { DayActivity } from './DayActivity'
 export type { SkillStat } from './SkillStat'
 export type { ProjectStat } from './ProjectStat'
 export type { SessionDurationStat } from './SessionDurationStat'
+export type { TokenHistogramBucket } from './TokenHistogramBucket'
+export type { ActivityStreak } from './ActivityStreak'
 
 // Extended dashboard (Phase 3)
 export type { CurrentPeriodMetrics } from './CurrentPeriodMetrics'
@@ -35,6 +46,7 @@ export type { WeekTrends } from './WeekTrends'
 
 // Status/metadata types (Phase 3)
 export type { IndexMetadata } from './IndexMetadata'
+export type { IndexRunHistoryEntry } from './IndexRunHistoryEntry'
 
 // Export types (Phase 3)
 export type { ExportedSession } from './ExportedSession'
@@ -52,6 +64,9 @@ export type { SystemStorageStats } from './SystemStorageStats'
 export type { AggregateCostBreakdown } from './AggregateCostBreakdown'
 export type { AIGenerationStats } from './AIGenerationStats'
 export type { AIGenerationStatsResponse } from './AIGenerationStatsResponse'
+export type { FileChurn } from './FileChurn'
+export type { ModelCost } from './ModelCost'
+export type { ProjectCost } from './ProjectCost'
 export type { TokensByModel } from './TokensByModel'
 export type { TokensByProject } from './TokensByProject'
 
@@ -59,6 +74,7 @@ export type { TokensByProject } from './TokensByProject'
 export type { CommitWithTier } from './CommitWithTier'
 export type { DerivedMetrics } from './DerivedMetrics'
 export type { SessionDetail } from './SessionDetail'
+export type { SessionModelCost } from './SessionModelCost'
 export type { SessionsListResponse } from './SessionsListResponse'
 
 // Insights types (Phase 4/5)
@@ -90,6 +106,7 @@ export type { AnalyticsSessionBreakdown } from './AnalyticsSessionBreakdown'
 export type { MetricDataPoint } from './MetricDataPoint'
 export type { CategoryDataPoint } from './CategoryDataPoint'
 export type { HeatmapCell } from './HeatmapCell'
+export type { BaselineComparison } from './BaselineComparison'
 export type { InsightsTrendsResponse } from './InsightsTrendsResponse'
 
 // Benchmarks types (Phase 8)
@@ -131,11 +148,13 @@ export type { StorageInfo } from './StorageInfo'
 export type { PerformanceInfo } from './PerformanceInfo'
 export type { HealthInfo } from './HealthInfo'
 export type { HealthStats } from './HealthStats'
+export type { ProjectHealth } from './ProjectHealth'
 export type { HealthStatus } from './HealthStatus'
 export type { IndexRunInfo } from './IndexRunInfo'
 export type { IndexRun } from './IndexRun'
 export type { IndexRunType } from './IndexRunType'
 export type { IndexRunStatus } from './IndexRunStatus'
+export type { SearchIndexRebuildProgress } from './SearchIndexRebuildProgress'
 export type { ActionResponse } from './ActionResponse'
 export type { ClearCacheResponse } from './ClearCacheResponse'
 export type { ClaudeCliStatus } from './ClaudeCliStatus'
@@ -174,6 +193,7 @@ export type { SessionContribution } from './SessionContribution'
 export type { SessionContributionResponse } from './SessionContributionResponse'
 export type { SkillStats } from './SkillStats'
 export type { TurnInfo } from './TurnInfo'
+export type { TurnTokens } from './TurnTokens'
 export type { UncommittedWork } from './UncommittedWork'
 
 // Live Monitor: Sub-Agent Visualization (Phase D)
@@ -196,8 +216,11 @@ export type { HealthResponse } from './HealthResponse'
 
 // Session search types
 export type { SearchResponse } from './SearchResponse'
+export type { SearchTimings } from './SearchTimings'
 export type { SessionHit } from './SessionHit'
 export type { MatchHit } from './MatchHit'
+export type { SearchSessionCard } from './SearchSessionCard'
+export type { SearchCardsResponse } from './SearchCardsResponse'
 
 // Grep search types
 export type { GrepResponse } from './GrepResponse'
@@ -15,7 +15,7 @@ mod tests;
 // Re-export all public items to preserve the module's public API.
 pub use parsing::{parse_batch_response, parse_category_string, BATCH_SIZE};
 pub use prompt::{build_batch_prompt, truncate_preview, SYSTEM_PROMPT};
-pub use taxonomy::{CategoryL1, CategoryL2, CategoryL3};
+pub use taxonomy::{CategoryL1, CategoryL2, CategoryL3, TAXONOMY_VERSION};
 pub use types::{
     BatchClassificationResponse, ClassificationInput, ClassificationResult, ValidatedClassification,
 };
@@ -4,6 +4,11 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
+/// Version of the L1/L2/L3 taxonomy below. Bump it whenever categories are
+/// added, removed, or renamed: classifications stamped with an older version
+/// are then reported stale and can be cleared for reclassification.
+pub const TAXONOMY_VERSION: i64 = 1;
+
 /// Top-level category (L1).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
 #[cfg_attr(feature = "codegen", ts(export))]
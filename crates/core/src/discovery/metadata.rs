@@ -4,9 +4,10 @@
 use super::paths::{clean_for_preview, truncate_preview};
 use crate::types::ToolCounts;
 use regex_lite::Regex;
+use std::io::Read;
 use std::path::Path;
 use tokio::fs;
-use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, BufReader};
 
 /// Metadata extracted from a session file without full parsing.
 #[derive(Debug, Clone, Default)]
@@ -22,13 +23,27 @@ pub struct ExtractedMetadata {
 
 /// Extract metadata from a session file without fully parsing it.
 /// This is an efficient scan that looks for specific patterns.
+/// `.jsonl.gz` files are decompressed into memory first.
 pub async fn extract_session_metadata(file_path: &Path) -> ExtractedMetadata {
-    let file = match fs::File::open(file_path).await {
-        Ok(f) => f,
-        Err(_) => return ExtractedMetadata::default(),
-    };
-
-    let reader = BufReader::new(file);
+    let reader: Box<dyn AsyncBufRead + Unpin + Send> =
+        if file_path.extension().is_some_and(|ext| ext == "gz") {
+            let path = file_path.to_path_buf();
+            let bytes = tokio::task::spawn_blocking(move || {
+                let mut bytes = Vec::new();
+                crate::jsonl_reader::open_reader(&path, true)?.read_to_end(&mut bytes)?;
+                Ok::<_, std::io::Error>(bytes)
+            })
+            .await;
+            match bytes {
+                Ok(Ok(bytes)) => Box::new(std::io::Cursor::new(bytes)),
+                _ => return ExtractedMetadata::default(),
+            }
+        } else {
+            match fs::File::open(file_path).await {
+                Ok(f) => Box::new(BufReader::new(f)),
+                Err(_) => return ExtractedMetadata::default(),
+            }
+        };
     let mut lines = reader.lines();
 
     let mut metadata = ExtractedMetadata::default();
@@ -10,6 +10,7 @@ mod metadata;
 mod paths;
 mod projects;
 mod resolve;
+mod subagents;
 
 // Re-export public API — preserves all downstream `use` paths.
 pub use git::{
@@ -20,5 +21,10 @@ pub use metadata::{extract_session_metadata, ExtractedMetadata};
 pub use paths::{claude_projects_dir, truncate_preview};
 pub use projects::{count_active_sessions, get_projects};
 pub use resolve::{
-    encode_project_name, resolve_project_path_with_cwd, resolve_worktree_parent, ResolvedProject,
+    decode_project_name, encode_project_name, resolve_project_path_with_cwd,
+    resolve_worktree_parent, ResolvedProject,
+};
+pub use subagents::{
+    discover_subagent_files, subagent_depth, subagents_dir, SubagentFile, DEFAULT_SUBAGENT_DEPTH,
+    SUBAGENT_DEPTH_ENV,
 };
@@ -132,22 +132,25 @@ async fn get_project_sessions(
     while let Some(entry) = entries.next_entry().await? {
         let path = entry.path();
 
-        // Only process .jsonl files
-        if path.extension().map(|e| e != "jsonl").unwrap_or(true) {
+        // Only process .jsonl (or .jsonl.gz) files
+        let file_name = entry.file_name();
+        let Some((session_id, is_gz)) = file_name
+            .to_str()
+            .and_then(crate::jsonl_reader::split_session_file_name)
+        else {
+            continue;
+        };
+        // A gzipped copy next to the plain file is the same session.
+        if is_gz && path.with_extension("").exists() {
             continue;
         }
+        let session_id = session_id.to_string();
 
         let metadata = match fs::metadata(&path).await {
             Ok(m) => m,
             Err(_) => continue,
         };
 
-        // Extract session ID from filename
-        let session_id = path
-            .file_stem()
-            .map(|s| s.to_string_lossy().to_string())
-            .unwrap_or_default();
-
         // Extract metadata efficiently
         let extracted = extract_session_metadata(&path).await;
 
@@ -300,6 +303,46 @@ mod tests {
         assert_eq!(sessions[0].preview, "Test");
     }
 
+    #[tokio::test]
+    async fn test_get_project_sessions_reads_gzipped_files() {
+        use flate2::write::GzEncoder;
+        use std::io::Write;
+
+        let temp_dir = TempDir::new().unwrap();
+        let resolved = ResolvedProject {
+            full_path: temp_dir.path().to_string_lossy().to_string(),
+            display_name: "test".to_string(),
+        };
+
+        let content = br#"{"type":"user","message":{"content":"Zipped"}}"#;
+        for name in ["zipped.jsonl.gz", "both.jsonl.gz"] {
+            let mut enc = GzEncoder::new(
+                std::fs::File::create(temp_dir.path().join(name)).unwrap(),
+                flate2::Compression::default(),
+            );
+            enc.write_all(content).unwrap();
+            enc.finish().unwrap();
+        }
+        tokio::fs::write(
+            temp_dir.path().join("both.jsonl"),
+            r#"{"type":"user","message":{"content":"Plain"}}"#,
+        )
+        .await
+        .unwrap();
+
+        let mut sessions = get_project_sessions(temp_dir.path(), "test", &resolved)
+            .await
+            .unwrap();
+        sessions.sort_by(|a, b| a.id.cmp(&b.id));
+
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].id, "both");
+        assert_eq!(sessions[0].preview, "Plain");
+        assert_eq!(sessions[1].id, "zipped");
+        assert_eq!(sessions[1].preview, "Zipped");
+        assert!(sessions[1].file_path.ends_with("zipped.jsonl.gz"));
+    }
+
     #[tokio::test]
     async fn test_get_project_sessions_ignores_non_jsonl() {
         let temp_dir = TempDir::new().unwrap();
@@ -1,5 +1,7 @@
 // crates/core/src/discovery/resolve.rs
-//! Project path resolution: encoding, worktree parent, display names.
+//! Project path resolution: encoding, decoding, worktree parent, display names.
+
+use std::path::{Path, PathBuf};
 
 use super::paths::derive_display_name;
 
@@ -13,7 +15,8 @@ pub struct ResolvedProject {
 }
 
 /// Resolve project path. Primary source: cwd from JSONL.
-/// When cwd is None, returns the encoded name as-is (no guessing).
+/// When cwd is None, falls back to [`decode_project_name`] (filesystem
+/// evidence); if that fails too, returns the encoded name as-is.
 pub fn resolve_project_path_with_cwd(encoded_name: &str, cwd: Option<&str>) -> ResolvedProject {
     if let Some(path) = cwd {
         return ResolvedProject {
@@ -22,11 +25,18 @@ pub fn resolve_project_path_with_cwd(encoded_name: &str, cwd: Option<&str>) -> R
         };
     }
 
-    // No cwd available — return encoded name verbatim as both fields.
-    // Per design: "no heuristic / guessing, all evidence based."
+    if let Some(path) = decode_project_name(encoded_name) {
+        return ResolvedProject {
+            display_name: derive_display_name(&path),
+            full_path: path,
+        };
+    }
+
+    // No cwd and no matching directory — return encoded name verbatim as
+    // both fields. Per design: "no heuristic / guessing, all evidence based."
     // The naive `-` split produces wrong paths for directories with `@` or
-    // `-` in names (e.g. @acme-corp -> /@acme/corp). Without CWD evidence
-    // we show the raw encoded string rather than guessing wrong.
+    // `-` in names (e.g. @acme-corp -> /@acme/corp). Without evidence we
+    // show the raw encoded string rather than guessing wrong.
     ResolvedProject {
         full_path: encoded_name.to_string(),
         display_name: encoded_name.to_string(),
@@ -53,6 +63,65 @@ pub fn encode_project_name(cwd: &str) -> String {
     cwd.replace(['/', '@', '.'], "-")
 }
 
+/// Decode a project directory name back to the cwd that produced it.
+///
+/// [`encode_project_name`] is lossy — `my-app`, `my/app`, `my.app` and
+/// `my@app` all encode to `my-app` — so decoding cannot be done on the
+/// string alone. Instead walk the real filesystem from `/`: at each level,
+/// descend into every child whose *encoded* name is a prefix of the
+/// remaining input at a `-` boundary, backtracking on dead ends. Longer
+/// child names are tried first so `my-app/` wins over `my/app/` when both
+/// exist.
+///
+/// Returns `None` when no existing directory encodes to `encoded_name`.
+/// Any `Some(path)` satisfies `encode_project_name(&path) == encoded_name`.
+pub fn decode_project_name(encoded_name: &str) -> Option<String> {
+    let rest = encoded_name.strip_prefix('-')?;
+    if rest.is_empty() {
+        return None;
+    }
+    let decoded = decode_under(Path::new("/"), rest)?;
+    Some(decoded.to_string_lossy().to_string())
+}
+
+/// Depth cap for [`decode_under`] — real project paths are nowhere near this.
+const MAX_DECODE_DEPTH: usize = 64;
+
+fn decode_under(dir: &Path, rest: &str) -> Option<PathBuf> {
+    decode_under_depth(dir, rest, 0)
+}
+
+fn decode_under_depth(dir: &Path, rest: &str, depth: usize) -> Option<PathBuf> {
+    if depth >= MAX_DECODE_DEPTH {
+        return None;
+    }
+    let mut children: Vec<(String, String)> = std::fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+        .filter_map(|e| {
+            let name = e.file_name().to_str()?.to_string();
+            let encoded = encode_project_name(&name);
+            rest.starts_with(&encoded).then_some((name, encoded))
+        })
+        .collect();
+    children.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then_with(|| a.0.cmp(&b.0)));
+
+    for (name, encoded) in children {
+        let child = dir.join(&name);
+        let tail = &rest[encoded.len()..];
+        if tail.is_empty() {
+            return Some(child);
+        }
+        if let Some(next) = tail.strip_prefix('-') {
+            if let Some(found) = decode_under_depth(&child, next, depth + 1) {
+                return Some(found);
+            }
+        }
+    }
+    None
+}
+
 pub fn resolve_worktree_parent(encoded_name: &str) -> Option<String> {
     let marker = "--worktrees-";
     let pos = encoded_name.find(marker)?;
@@ -160,4 +229,87 @@ mod tests {
     fn test_encode_project_name_empty() {
         assert_eq!(encode_project_name(""), "");
     }
+
+    // ========================================================================
+    // decode_project_name Tests — round-trip against a real directory tree
+    // ========================================================================
+
+    #[test]
+    fn test_decode_project_name_round_trips_tricky_paths() {
+        let temp = tempfile::TempDir::new().unwrap();
+        // TempDir names start with `.tmp`, so the root itself exercises dots.
+        let root = temp.path().canonicalize().unwrap();
+        let cases = [
+            "my-app",
+            "a.b.c",
+            "nested/deeper/leaf",
+            "@acme-corp/my-project",
+            "@org.co/app",
+            "dash-then/dot.dir/-leading-dash",
+            "repo/.worktrees/feature-x",
+            "v1.2-rc/x",
+        ];
+        for rel in cases {
+            std::fs::create_dir_all(root.join(rel)).unwrap();
+        }
+
+        for rel in cases {
+            let path = root.join(rel).to_string_lossy().to_string();
+            let encoded = encode_project_name(&path);
+            let decoded = decode_project_name(&encoded)
+                .unwrap_or_else(|| panic!("{rel}: no decode for {encoded}"));
+            assert_eq!(decoded, path, "{rel}: decoded to wrong directory");
+            assert_eq!(encode_project_name(&decoded), encoded, "{rel}: round-trip");
+        }
+    }
+
+    #[test]
+    fn test_decode_project_name_prefers_longest_existing_segment() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        // Both `my-app/` and `my/app/` exist; the dashed directory wins.
+        std::fs::create_dir_all(root.join("my-app")).unwrap();
+        std::fs::create_dir_all(root.join("my/app")).unwrap();
+
+        let encoded = encode_project_name(&root.join("my-app").to_string_lossy());
+        let decoded = decode_project_name(&encoded).unwrap();
+        assert_eq!(decoded, root.join("my-app").to_string_lossy());
+    }
+
+    #[test]
+    fn test_decode_project_name_backtracks_on_dead_end() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        // `my-app/` exists but has no `src`; only `my/app/src` matches.
+        std::fs::create_dir_all(root.join("my-app")).unwrap();
+        std::fs::create_dir_all(root.join("my/app/src")).unwrap();
+
+        let target = root.join("my/app/src").to_string_lossy().to_string();
+        let decoded = decode_project_name(&encode_project_name(&target)).unwrap();
+        assert_eq!(decoded, target);
+    }
+
+    #[test]
+    fn test_decode_project_name_missing_or_relative() {
+        assert_eq!(decode_project_name(""), None);
+        assert_eq!(decode_project_name("-"), None);
+        assert_eq!(decode_project_name("relative-name"), None);
+        assert_eq!(
+            decode_project_name("-definitely-not-a-real-dir-for-claude-view-tests"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_resolve_project_path_without_cwd_uses_filesystem() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        let project = root.join("@acme-corp/my-app");
+        std::fs::create_dir_all(&project).unwrap();
+
+        let path = project.to_string_lossy().to_string();
+        let result = resolve_project_path_with_cwd(&encode_project_name(&path), None);
+        assert_eq!(result.full_path, path);
+        assert_eq!(result.display_name, "my-app");
+    }
 }
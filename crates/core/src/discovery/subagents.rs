@@ -0,0 +1,149 @@
+// crates/core/src/discovery/subagents.rs
+//! Subagent sidecar discovery.
+//!
+//! A session logged at `<project>/<id>.jsonl` keeps its subagents'
+//! transcripts in `<project>/<id>/subagents/*.jsonl`. A subagent that spawns
+//! subagents of its own repeats the layout one level down:
+//! `subagents/agent-x.jsonl` owns `subagents/agent-x/subagents/*.jsonl`.
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Default for how many subagent levels below a session are walked.
+pub const DEFAULT_SUBAGENT_DEPTH: usize = 4;
+
+/// Env var overriding [`DEFAULT_SUBAGENT_DEPTH`]. Sessions already indexed
+/// keep their merged totals until they are reparsed.
+pub const SUBAGENT_DEPTH_ENV: &str = "CLAUDE_VIEW_SUBAGENT_DEPTH";
+
+/// How many subagent levels the indexer walks: [`SUBAGENT_DEPTH_ENV`] when
+/// it holds a number, else [`DEFAULT_SUBAGENT_DEPTH`]. Read once per process.
+pub fn subagent_depth() -> usize {
+    static DEPTH: OnceLock<usize> = OnceLock::new();
+    *DEPTH.get_or_init(|| parse_depth(std::env::var(SUBAGENT_DEPTH_ENV).ok().as_deref()))
+}
+
+fn parse_depth(value: Option<&str>) -> usize {
+    value
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(DEFAULT_SUBAGENT_DEPTH)
+}
+
+/// One subagent transcript found under a session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubagentFile {
+    pub path: PathBuf,
+    /// Transcript of the agent that spawned it: the session's own JSONL at
+    /// depth 1, otherwise another subagent's.
+    pub parent: PathBuf,
+    /// 1 = spawned by the session, 2 = by one of its subagents, ...
+    pub depth: usize,
+}
+
+/// Directory holding the sidecars of the agent logged at `jsonl`.
+pub fn subagents_dir(jsonl: &Path) -> PathBuf {
+    jsonl.with_extension("").join("subagents")
+}
+
+/// Every subagent transcript under the session at `session_jsonl`, walking
+/// at most `max_depth` levels (0 finds nothing).
+///
+/// Parents come before their own subagents and siblings are sorted by path,
+/// so iterating in reverse visits every subagent before the agent that
+/// spawned it.
+pub fn discover_subagent_files(session_jsonl: &Path, max_depth: usize) -> Vec<SubagentFile> {
+    let mut found = Vec::new();
+    walk(session_jsonl, 1, max_depth, &mut found);
+    found
+}
+
+fn walk(parent: &Path, depth: usize, max_depth: usize, found: &mut Vec<SubagentFile>) {
+    if depth > max_depth {
+        return;
+    }
+    let Ok(entries) = std::fs::read_dir(subagents_dir(parent)) else {
+        return;
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("jsonl"))
+        .filter(|path| path.is_file())
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        found.push(SubagentFile {
+            path: path.clone(),
+            parent: parent.to_path_buf(),
+            depth,
+        });
+        walk(&path, depth + 1, max_depth, found);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn touch(path: &Path) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "{}\n").unwrap();
+    }
+
+    #[test]
+    fn test_two_level_tree_attributed_to_parents() {
+        let temp = TempDir::new().unwrap();
+        let session = temp.path().join("proj/sess-1.jsonl");
+        let subs = temp.path().join("proj/sess-1/subagents");
+        let agent_a = subs.join("agent-a.jsonl");
+        let agent_b = subs.join("agent-b.jsonl");
+        let nested = subs.join("agent-a/subagents/agent-c.jsonl");
+        for path in [&session, &agent_a, &agent_b, &nested] {
+            touch(path);
+        }
+        // Not a transcript.
+        touch(&subs.join("agent-a/subagents/notes.txt"));
+
+        let files = discover_subagent_files(&session, DEFAULT_SUBAGENT_DEPTH);
+        let found: Vec<(&Path, &Path, usize)> = files
+            .iter()
+            .map(|f| (f.path.as_path(), f.parent.as_path(), f.depth))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (agent_a.as_path(), session.as_path(), 1),
+                (nested.as_path(), agent_a.as_path(), 2),
+                (agent_b.as_path(), session.as_path(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_depth_falls_back_to_default() {
+        assert_eq!(parse_depth(Some("2")), 2);
+        assert_eq!(parse_depth(Some(" 0 ")), 0);
+        assert_eq!(parse_depth(Some("deep")), DEFAULT_SUBAGENT_DEPTH);
+        assert_eq!(parse_depth(None), DEFAULT_SUBAGENT_DEPTH);
+    }
+
+    #[test]
+    fn test_max_depth_limits_the_walk() {
+        let temp = TempDir::new().unwrap();
+        let session = temp.path().join("sess.jsonl");
+        let child = temp.path().join("sess/subagents/agent-a.jsonl");
+        let grandchild = temp
+            .path()
+            .join("sess/subagents/agent-a/subagents/agent-b.jsonl");
+        for path in [&session, &child, &grandchild] {
+            touch(path);
+        }
+
+        let one_level = discover_subagent_files(&session, 1);
+        assert_eq!(one_level.len(), 1);
+        assert_eq!(one_level[0].path, child);
+        assert!(discover_subagent_files(&session, 0).is_empty());
+        assert!(discover_subagent_files(&temp.path().join("missing.jsonl"), 4).is_empty());
+    }
+}
@@ -0,0 +1,93 @@
+//! Normalization of file paths taken from tool calls.
+//!
+//! The same file can reach a session as `./src/main.rs`, `src/main.rs` or
+//! `/repo/src/main.rs`. [`normalize_file_path`] maps all of them to one
+//! spelling so per-session file sets and counts dedup correctly.
+
+use std::path::{Component, Path, PathBuf};
+
+/// Normalize a tool-call file path for deduplication.
+///
+/// Relative paths are resolved against `project_root` (the session's `cwd`)
+/// when given; then `.` components are dropped and `..` collapses its
+/// parent. This is purely lexical: the file need not exist and symlinks are
+/// not resolved. A relative path with no root stays relative, keeping any
+/// `..` that cannot be collapsed.
+pub fn normalize_file_path(path: &str, project_root: Option<&str>) -> String {
+    let path = Path::new(path);
+    let joined;
+    let path = match project_root {
+        Some(root) if path.is_relative() && !root.is_empty() => {
+            joined = Path::new(root).join(path);
+            joined.as_path()
+        }
+        _ => path,
+    };
+
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match out.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    out.pop();
+                }
+                // `..` at the root stays at the root.
+                Some(Component::RootDir | Component::Prefix(_)) => {}
+                _ => out.push(".."),
+            },
+            other => out.push(other.as_os_str()),
+        }
+    }
+    if out.as_os_str().is_empty() {
+        return ".".to_string();
+    }
+    out.to_string_lossy().into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variants_of_one_file_normalize_to_one_path() {
+        let root = Some("/home/u/proj");
+        let variants = [
+            "./src/main.rs",
+            "src/main.rs",
+            "/home/u/proj/src/main.rs",
+            "/home/u/proj/./lib/../src/main.rs",
+        ];
+        for v in variants {
+            assert_eq!(
+                normalize_file_path(v, root),
+                "/home/u/proj/src/main.rs",
+                "variant {v}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_root_with_trailing_slash() {
+        assert_eq!(
+            normalize_file_path("./src/main.rs", Some("/home/u/proj/")),
+            "/home/u/proj/src/main.rs"
+        );
+    }
+
+    #[test]
+    fn test_without_root_stays_relative() {
+        assert_eq!(normalize_file_path("./src/main.rs", None), "src/main.rs");
+        assert_eq!(
+            normalize_file_path("src/./a/../main.rs", None),
+            "src/main.rs"
+        );
+        assert_eq!(normalize_file_path("../other/x.rs", None), "../other/x.rs");
+        assert_eq!(normalize_file_path("./", None), ".");
+    }
+
+    #[test]
+    fn test_parent_dir_never_climbs_above_root() {
+        assert_eq!(normalize_file_path("/../etc/hosts", None), "/etc/hosts");
+    }
+}
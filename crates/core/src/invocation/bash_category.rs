@@ -0,0 +1,112 @@
+// crates/core/src/invocation/bash_category.rs
+//
+// Classify Bash tool_use command strings into coarse categories (git, test,
+// build, misc) so sessions can report where shell time went.
+
+use serde::{Deserialize, Serialize};
+
+/// What a Bash tool call was doing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BashCategory {
+    /// `git` / `gh` commands.
+    Git,
+    /// Test runners (`cargo test`, `npm test`, `pytest`, ...).
+    Test,
+    /// Builds and type checks (`cargo build`, `npm run build`, `tsc`, ...).
+    Build,
+    /// Anything else.
+    Misc,
+}
+
+impl BashCategory {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Git => "git",
+            Self::Test => "test",
+            Self::Build => "build",
+            Self::Misc => "misc",
+        }
+    }
+}
+
+/// Classify a Bash command string.
+///
+/// Chains (`&&`, `||`, `;`, `|`) are split and the first segment that isn't
+/// misc decides, so `cd app && npm test` is a test run. Leading `VAR=value`
+/// assignments and `sudo`/`time`/`env` wrappers are skipped.
+pub fn classify_bash_command(command: &str) -> BashCategory {
+    command
+        .split(['&', '|', ';', '\n'])
+        .map(classify_segment)
+        .find(|c| *c != BashCategory::Misc)
+        .unwrap_or(BashCategory::Misc)
+}
+
+fn classify_segment(segment: &str) -> BashCategory {
+    let mut words = segment
+        .split_whitespace()
+        .skip_while(|w| is_env_assignment(w) || matches!(*w, "sudo" | "time" | "env" | "exec"));
+    let Some(program) = words.next() else {
+        return BashCategory::Misc;
+    };
+    // `/usr/bin/git` and `./node_modules/.bin/jest` classify like `git`/`jest`.
+    let program = program.rsplit('/').next().unwrap_or(program);
+    let args: Vec<&str> = words.filter(|w| !w.starts_with('-')).collect();
+    let first = args.first().copied().unwrap_or("");
+
+    match program {
+        "git" | "gh" => BashCategory::Git,
+        "pytest" | "jest" | "vitest" | "mocha" | "rspec" | "phpunit" => BashCategory::Test,
+        "tsc" | "webpack" | "esbuild" | "rollup" => BashCategory::Build,
+        "cargo" => match first {
+            "test" | "nextest" => BashCategory::Test,
+            "build" | "check" | "clippy" => BashCategory::Build,
+            _ => BashCategory::Misc,
+        },
+        "go" => match first {
+            "test" => BashCategory::Test,
+            "build" | "vet" => BashCategory::Build,
+            _ => BashCategory::Misc,
+        },
+        "npm" | "yarn" | "pnpm" | "bun" => {
+            let script = if first == "run" {
+                args.get(1).copied().unwrap_or("")
+            } else {
+                first
+            };
+            classify_script(script)
+        }
+        "npx" | "bunx" => match first {
+            "jest" | "vitest" | "mocha" | "playwright" => BashCategory::Test,
+            "tsc" | "vite" | "webpack" | "next" => BashCategory::Build,
+            _ => BashCategory::Misc,
+        },
+        // `python -m pytest`: the `-m` flag is filtered out of `args`.
+        "python" | "python3" if first == "pytest" => BashCategory::Test,
+        "make" | "just" => match first {
+            "" | "build" | "all" => BashCategory::Build,
+            s => classify_script(s),
+        },
+        "vite" | "next" if first == "build" => BashCategory::Build,
+        _ => BashCategory::Misc,
+    }
+}
+
+/// Package-manager script or make target: `test*` is a test run, `build*`
+/// or a type check (`check`, `typecheck`) a build, as with `cargo check`.
+fn classify_script(script: &str) -> BashCategory {
+    if script.starts_with("test") {
+        BashCategory::Test
+    } else if script.starts_with("build") || matches!(script, "check" | "typecheck") {
+        BashCategory::Build
+    } else {
+        BashCategory::Misc
+    }
+}
+
+fn is_env_assignment(word: &str) -> bool {
+    word.split_once('=').is_some_and(|(name, _)| {
+        !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
@@ -3,6 +3,8 @@
 // MCP tool name parser for extracting plugin and tool names from structured
 // tool_use names like `mcp__plugin_playwright_playwright__browser_navigate`.
 
+use serde::{Deserialize, Serialize};
+
 /// Parse an MCP tool name like `mcp__plugin_playwright_playwright__browser_navigate`
 /// into (plugin_name, tool_name).
 ///
@@ -36,3 +38,36 @@ pub(crate) fn parse_mcp_tool_name(name: &str) -> Option<(&str, &str)> {
 
     Some((plugin, tool))
 }
+
+/// An MCP tool call split into the server that provides it and the tool name.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct McpTool {
+    pub server: String,
+    pub tool: String,
+}
+
+impl McpTool {
+    /// Split a tool_use name into server and tool.
+    ///
+    /// Accepts user-configured servers (`mcp__{server}__{tool}`, e.g.
+    /// `mcp__playwright__browser_navigate`) and plugin servers
+    /// (`mcp__plugin_{plugin}_{server}__{tool}`), where the server is
+    /// reported as the plugin name, as the registry keys it. Returns `None`
+    /// for built-in tools and malformed names.
+    pub fn parse(name: &str) -> Option<Self> {
+        let (server, tool) = if name.starts_with("mcp__plugin_") {
+            parse_mcp_tool_name(name)?
+        } else {
+            let rest = name.strip_prefix("mcp__")?;
+            let (server, tool) = rest.split_once("__")?;
+            if server.is_empty() || tool.is_empty() {
+                return None;
+            }
+            (server, tool)
+        };
+        Some(Self {
+            server: server.to_string(),
+            tool: tool.to_string(),
+        })
+    }
+}
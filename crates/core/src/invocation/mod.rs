@@ -1,9 +1,11 @@
 // crates/core/src/invocation/mod.rs
 //
 // Classify tool_use calls from JSONL lines against a Registry to determine
-// which invocable was called (skill, command, agent, MCP tool, or built-in).
+// which invocable was called (skill, command, agent, MCP tool, or built-in),
+// and classify Bash command strings by what they run.
 
 mod agents;
+mod bash_category;
 mod classify;
 mod mcp_parser;
 mod types;
@@ -13,5 +15,7 @@ mod tests;
 
 // Re-export all public items to preserve the module's public API.
 pub use agents::BUILTIN_AGENT_NAMES;
+pub use bash_category::{classify_bash_command, BashCategory};
 pub use classify::classify_tool_use;
+pub use mcp_parser::McpTool;
 pub use types::{ClassifyResult, RawToolUse};
@@ -420,3 +420,59 @@ fn test_parse_mcp_empty_tool() {
 fn test_parse_mcp_empty_plugin_server() {
     assert_eq!(parse_mcp_tool_name("mcp__plugin___tool"), None);
 }
+
+#[test]
+fn test_mcp_tool_parse_splits_server_and_tool() {
+    let tool = |name| McpTool::parse(name).map(|t| (t.server, t.tool));
+    assert_eq!(
+        tool("mcp__playwright__browser_navigate"),
+        Some(("playwright".into(), "browser_navigate".into()))
+    );
+    assert_eq!(
+        tool("mcp__plugin_supabase_supabase__execute_sql"),
+        Some(("supabase".into(), "execute_sql".into()))
+    );
+    assert_eq!(tool("Bash"), None);
+    assert_eq!(tool("mcp__playwright"), None);
+    assert_eq!(tool("mcp____tool"), None);
+}
+
+#[test]
+fn test_classify_bash_command_categories() {
+    assert_eq!(
+        classify_bash_command("git commit -m \"fix\""),
+        BashCategory::Git
+    );
+    assert_eq!(
+        classify_bash_command("cargo test -p core"),
+        BashCategory::Test
+    );
+    assert_eq!(classify_bash_command("npm run build"), BashCategory::Build);
+    assert_eq!(classify_bash_command("ls -la /tmp"), BashCategory::Misc);
+}
+
+#[test]
+fn test_classify_bash_command_chains_and_wrappers() {
+    assert_eq!(
+        classify_bash_command("cd apps/web && npm test 2>&1 | tail"),
+        BashCategory::Test
+    );
+    assert_eq!(
+        classify_bash_command("RUST_LOG=debug cargo build --release"),
+        BashCategory::Build
+    );
+    assert_eq!(
+        classify_bash_command("python -m pytest tests/"),
+        BashCategory::Test
+    );
+    assert_eq!(
+        classify_bash_command("/usr/bin/git status"),
+        BashCategory::Git
+    );
+    assert_eq!(classify_bash_command("cargo fmt"), BashCategory::Misc);
+    // Type checks are builds whichever tool runs them.
+    assert_eq!(classify_bash_command("cargo check"), BashCategory::Build);
+    assert_eq!(classify_bash_command("npm run check"), BashCategory::Build);
+    assert_eq!(classify_bash_command("make check"), BashCategory::Build);
+    assert_eq!(classify_bash_command(""), BashCategory::Misc);
+}
@@ -54,6 +54,26 @@ pub fn open_reader(path: &Path, is_compressed: bool) -> std::io::Result<Box<dyn
     }
 }
 
+/// Leading bytes of every gzip stream (RFC 1952).
+pub const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Whether a session file is gzip-compressed, judged by a `.gz` extension
+/// or by `head` (the file's first bytes) starting with [`GZIP_MAGIC`].
+/// The magic check catches compressed files that kept a `.jsonl` name.
+pub fn is_gzip(path: &Path, head: &[u8]) -> bool {
+    path.extension().is_some_and(|ext| ext == "gz") || head.starts_with(&GZIP_MAGIC)
+}
+
+/// Split a transcript file name into its session id and whether it is
+/// gzip-compressed: `<id>.jsonl` or `<id>.jsonl.gz`. `None` for any other
+/// name.
+pub fn split_session_file_name(name: &str) -> Option<(&str, bool)> {
+    if let Some(id) = name.strip_suffix(".jsonl.gz") {
+        return Some((id, true));
+    }
+    name.strip_suffix(".jsonl").map(|id| (id, false))
+}
+
 /// Read the full session into memory and return a vector of typed
 /// lines. Individual parse errors are silently skipped — see the
 /// module docs for rationale.
@@ -152,6 +172,22 @@ mod tests {
         enc.finish().unwrap();
     }
 
+    #[test]
+    fn detects_gzip_by_extension_or_magic() {
+        assert!(is_gzip(Path::new("s.jsonl.gz"), b""));
+        assert!(is_gzip(Path::new("s.jsonl"), &[0x1f, 0x8b, 0x08]));
+        assert!(!is_gzip(Path::new("s.jsonl"), b"{\"type\""));
+        assert!(!is_gzip(Path::new("s.jsonl"), b""));
+    }
+
+    #[test]
+    fn splits_plain_and_gzipped_session_file_names() {
+        assert_eq!(split_session_file_name("abc.jsonl"), Some(("abc", false)));
+        assert_eq!(split_session_file_name("abc.jsonl.gz"), Some(("abc", true)));
+        assert_eq!(split_session_file_name("abc.json"), None);
+        assert_eq!(split_session_file_name("abc.gz"), None);
+    }
+
     #[test]
     fn reads_plain_jsonl() {
         let tmp = tempdir().unwrap();
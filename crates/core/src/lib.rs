@@ -18,6 +18,7 @@ pub mod facets;
 pub mod features;
 pub mod field_inventory;
 pub mod file_history;
+pub mod file_paths;
 pub mod fluency_score;
 pub mod hook_to_block;
 pub mod insights;
@@ -25,6 +26,7 @@ pub mod invocation;
 pub mod jsonl_reader;
 pub mod live_parser;
 pub mod llm;
+pub mod loop_detection;
 pub mod mcp_files;
 pub mod memory_files;
 pub mod metrics;
@@ -38,6 +40,7 @@ pub mod pricing;
 pub mod progress;
 pub mod prompt_history;
 pub mod prompt_templates;
+pub mod quality_score;
 pub mod registry;
 pub mod report;
 pub mod session_catalog;
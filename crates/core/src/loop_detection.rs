@@ -0,0 +1,224 @@
+// crates/core/src/loop_detection.rs
+//! Detection of agent loops: runs of near-identical consecutive turns.
+//!
+//! An agent turn is an assistant message (text or tool calls only). Its
+//! signature is its text plus each tool call's name and input, and two
+//! turns are similar when the Jaccard index of their signatures' word sets
+//! reaches [`LoopConfig::similarity`]. A run of at least
+//! [`LoopConfig::min_repeats`] consecutive similar turns is a loop. A real
+//! user prompt ends any run: repeating after new instructions is not a loop.
+
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use crate::{Message, Role};
+
+/// Characters of the first turn kept as [`LoopRun::preview`].
+const PREVIEW_CHARS: usize = 80;
+
+/// Tunables for [`detect_loops`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoopConfig {
+    /// Minimum Jaccard similarity (0.0-1.0) of consecutive turns.
+    pub similarity: f64,
+    /// Minimum run length, in turns, reported as a loop.
+    pub min_repeats: usize,
+    /// Most runs kept in [`LoopReport::offenders`].
+    pub max_offenders: usize,
+}
+
+impl Default for LoopConfig {
+    fn default() -> Self {
+        Self {
+            similarity: 0.9,
+            min_repeats: 3,
+            max_offenders: 5,
+        }
+    }
+}
+
+/// One run of near-identical consecutive agent turns.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoopRun {
+    /// Index of the run's first message in the session's messages.
+    pub start_message: usize,
+    /// Number of turns in the run.
+    pub repeats: usize,
+    /// Lowest similarity between two consecutive turns of the run.
+    pub similarity: f64,
+    /// Start of the first turn's signature.
+    pub preview: String,
+}
+
+/// Result of [`detect_loops`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoopReport {
+    pub loop_detected: bool,
+    /// Loops found, longest first (ties: most similar, then earliest).
+    pub offenders: Vec<LoopRun>,
+}
+
+/// Text compared between turns: content plus tool call names and inputs.
+fn turn_signature(message: &Message) -> String {
+    let mut signature = message.content.clone();
+    for call in message.tool_calls.iter().flatten() {
+        signature.push(' ');
+        signature.push_str(&call.name);
+        if let Some(input) = &call.input {
+            signature.push(' ');
+            signature.push_str(&input.to_string());
+        }
+    }
+    signature
+}
+
+fn word_set(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+/// Find loops in `messages`, a session's parsed messages in order.
+pub fn detect_loops(messages: &[Message], config: &LoopConfig) -> LoopReport {
+    struct Open {
+        start_message: usize,
+        repeats: usize,
+        similarity: f64,
+        preview: String,
+    }
+
+    let mut offenders = Vec::new();
+    let mut close = |run: Option<Open>| {
+        if let Some(run) = run.filter(|r| r.repeats >= config.min_repeats.max(2)) {
+            offenders.push(LoopRun {
+                start_message: run.start_message,
+                repeats: run.repeats,
+                similarity: run.similarity,
+                preview: run.preview,
+            });
+        }
+    };
+
+    let mut previous: Option<HashSet<String>> = None;
+    let mut run: Option<Open> = None;
+    for (index, message) in messages.iter().enumerate() {
+        match message.role {
+            Role::User => {
+                close(run.take());
+                previous = None;
+            }
+            Role::Assistant | Role::ToolUse => {
+                let signature = turn_signature(message);
+                let words = word_set(&signature);
+                if words.is_empty() {
+                    continue;
+                }
+                let similarity = previous.as_ref().map_or(0.0, |p| jaccard(p, &words));
+                match run.as_mut() {
+                    Some(open) if similarity >= config.similarity => {
+                        open.repeats += 1;
+                        open.similarity = open.similarity.min(similarity);
+                    }
+                    _ => {
+                        close(run.take());
+                        run = Some(Open {
+                            start_message: index,
+                            repeats: 1,
+                            similarity: 1.0,
+                            preview: signature.trim().chars().take(PREVIEW_CHARS).collect(),
+                        });
+                    }
+                }
+                previous = Some(words);
+            }
+            _ => {}
+        }
+    }
+    close(run.take());
+
+    offenders.sort_by(|a, b| {
+        b.repeats
+            .cmp(&a.repeats)
+            .then(b.similarity.total_cmp(&a.similarity))
+            .then(a.start_message.cmp(&b.start_message))
+    });
+    offenders.truncate(config.max_offenders);
+    LoopReport {
+        loop_detected: !offenders.is_empty(),
+        offenders,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ToolCall;
+
+    fn read(path: &str) -> Message {
+        Message::tool_use("").with_tools(vec![ToolCall {
+            name: "Read".to_string(),
+            count: 1,
+            input: Some(serde_json::json!({ "file_path": path })),
+            category: None,
+        }])
+    }
+
+    #[test]
+    fn test_repeated_tool_calls_are_a_loop() {
+        let messages = vec![
+            Message::user("fix the build"),
+            Message::assistant("Let me look at the config"),
+            read("/repo/Cargo.toml"),
+            read("/repo/Cargo.toml"),
+            read("/repo/Cargo.toml"),
+            read("/repo/Cargo.toml"),
+            Message::assistant("Found it, the feature flag is missing"),
+        ];
+        let report = detect_loops(&messages, &LoopConfig::default());
+        assert!(report.loop_detected);
+        assert_eq!(report.offenders.len(), 1);
+        let run = &report.offenders[0];
+        assert_eq!(run.start_message, 2);
+        assert_eq!(run.repeats, 4);
+        assert_eq!(run.similarity, 1.0);
+        assert!(run.preview.starts_with("Read"), "{}", run.preview);
+    }
+
+    #[test]
+    fn test_varied_turns_are_not_a_loop() {
+        let messages = vec![
+            Message::user("fix the build"),
+            read("/repo/Cargo.toml"),
+            read("/repo/src/main.rs"),
+            read("/repo/src/lib.rs"),
+        ];
+        let report = detect_loops(&messages, &LoopConfig::default());
+        assert!(!report.loop_detected);
+        assert!(report.offenders.is_empty());
+    }
+
+    #[test]
+    fn test_user_prompt_breaks_a_run() {
+        let messages = vec![
+            read("/repo/Cargo.toml"),
+            read("/repo/Cargo.toml"),
+            Message::user("try again"),
+            read("/repo/Cargo.toml"),
+            read("/repo/Cargo.toml"),
+        ];
+        let report = detect_loops(&messages, &LoopConfig::default());
+        assert!(!report.loop_detected);
+    }
+}
@@ -6,7 +6,7 @@
 
 use crate::block_types::ImageContent;
 use crate::category::categorize_tool;
-use crate::types::{ContentBlock, JsonlContent, ToolCall};
+use crate::types::{Attachment, ContentBlock, JsonlContent, ToolCall};
 
 /// Extract pasted images from a raw `message.content[]` array.
 ///
@@ -43,6 +43,58 @@ pub(super) fn extract_images(content: &[serde_json::Value]) -> Vec<ImageContent>
         .collect()
 }
 
+/// Extract attachment references from a raw `message.content[]` array.
+///
+/// `image` and `document` blocks are attachments. The name comes from the
+/// block's `title`, else the last path segment of `source.url`, else a
+/// placeholder built from the media type (`image/png` → `image.png`).
+pub fn extract_attachments(content: &[serde_json::Value]) -> Vec<Attachment> {
+    content
+        .iter()
+        .filter_map(|item| {
+            let kind = item.get("type").and_then(|v| v.as_str())?;
+            if kind != "image" && kind != "document" {
+                return None;
+            }
+            let source = item.get("source");
+            let media_type = source
+                .and_then(|s| s.get("media_type"))
+                .and_then(|m| m.as_str())
+                .unwrap_or("")
+                .to_string();
+            let name = item
+                .get("title")
+                .and_then(|t| t.as_str())
+                .filter(|t| !t.is_empty())
+                .map(String::from)
+                .or_else(|| {
+                    source
+                        .and_then(|s| s.get("url"))
+                        .and_then(|u| u.as_str())
+                        .and_then(url_file_name)
+                })
+                .unwrap_or_else(|| placeholder_name(kind, &media_type));
+            Some(Attachment { name, media_type })
+        })
+        .collect()
+}
+
+/// Last non-empty path segment of a URL, without query string or fragment.
+fn url_file_name(url: &str) -> Option<String> {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    path.rsplit('/')
+        .find(|seg| !seg.is_empty())
+        .filter(|seg| !seg.contains(':'))
+        .map(String::from)
+}
+
+fn placeholder_name(kind: &str, media_type: &str) -> String {
+    match media_type.split_once('/') {
+        Some((_, subtype)) if !subtype.is_empty() => format!("{kind}.{subtype}"),
+        _ => kind.to_string(),
+    }
+}
+
 /// Extract readable content from tool_result array blocks.
 pub(super) fn extract_tool_result_content(blocks: &[serde_json::Value]) -> String {
     let mut parts: Vec<String> = Vec::new();
@@ -9,6 +9,7 @@
 //! - `helpers` — shared field-attachment utilities
 //! - `session` — core session parsing and pagination
 //! - `tags` — command tag stripping from user messages
+//! - `timestamp` — RFC3339 / epoch-seconds / epoch-millis normalization
 
 mod content;
 mod helpers;
@@ -16,6 +17,12 @@ mod session;
 mod tags;
 #[cfg(test)]
 mod tests;
+mod timestamp;
+
+pub use content::extract_attachments;
+pub use timestamp::{
+    epoch_to_seconds, normalize_timestamp, normalize_timestamp_or_mtime, parse_timestamp_str,
+};
 
 // Re-export the public API (preserves `claude_view_core::parser::*` and `claude_view_core::*`)
 pub use session::{
@@ -9,13 +9,15 @@
 use crate::category::{categorize_progress, categorize_tool};
 use crate::error::ParseError;
 use crate::types::*;
+use std::io::Read;
 use std::path::Path;
 use tokio::fs::File;
-use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, BufReader};
 use tracing::debug;
 
 use super::content::{
-    extract_assistant_content, extract_images, extract_text_content, extract_tool_result_content,
+    extract_assistant_content, extract_attachments, extract_images, extract_text_content,
+    extract_tool_result_content,
 };
 use super::helpers::{attach_common_fields, maybe_attach_raw};
 use super::tags::{clean_command_tags, TagRegexes};
@@ -24,6 +26,8 @@ use super::tags::{clean_command_tags, TagRegexes};
 ///
 /// # Features
 /// - Async streaming with tokio BufReader for memory efficiency
+/// - Gzip-compressed transcripts (`.jsonl.gz` or gzip magic bytes) are
+///   decompressed transparently and parse identically to plain ones
 /// - Skips malformed JSON lines (logs at debug level, doesn't fail)
 /// - Skips meta messages (isMeta: true)
 /// - Cleans command tags from user messages
@@ -53,15 +57,45 @@ pub async fn parse_session_with_raw(file_path: &Path) -> Result<ParsedSession, P
     parse_session_inner(file_path, true).await
 }
 
+/// Open a transcript for line reading. Gzip files (see
+/// [`crate::jsonl_reader::is_gzip`]) are decompressed into memory up front;
+/// plain files stream from disk.
+async fn open_session_reader(
+    file_path: &Path,
+) -> std::io::Result<Box<dyn AsyncBufRead + Unpin + Send>> {
+    let mut file = File::open(file_path).await?;
+    let mut head = [0u8; 2];
+    let mut filled = 0;
+    while filled < head.len() {
+        match file.read(&mut head[filled..]).await? {
+            0 => break,
+            n => filled += n,
+        }
+    }
+    if !crate::jsonl_reader::is_gzip(file_path, &head[..filled]) {
+        file.rewind().await?;
+        return Ok(Box::new(BufReader::new(file)));
+    }
+
+    let mut compressed = head[..filled].to_vec();
+    file.read_to_end(&mut compressed).await?;
+    let decoded = tokio::task::spawn_blocking(move || {
+        let mut decoded = Vec::new();
+        flate2::read::GzDecoder::new(compressed.as_slice()).read_to_end(&mut decoded)?;
+        Ok::<_, std::io::Error>(decoded)
+    })
+    .await
+    .map_err(std::io::Error::other)??;
+    Ok(Box::new(std::io::Cursor::new(decoded)))
+}
+
 async fn parse_session_inner(
     file_path: &Path,
     include_raw: bool,
 ) -> Result<ParsedSession, ParseError> {
-    let file = File::open(file_path)
+    let reader = open_session_reader(file_path)
         .await
         .map_err(|e| ParseError::io(file_path, e))?;
-
-    let reader = BufReader::new(file);
     let mut lines = reader.lines();
 
     let mut messages: Vec<Message> = Vec::new();
@@ -73,6 +107,8 @@ async fn parse_session_inner(
     // Track pending thinking text from thinking-only assistant messages
     let mut pending_thinking: Option<String> = None;
 
+    let mut attachments: Vec<Attachment> = Vec::new();
+
     while let Some(line_result) = lines
         .next_line()
         .await
@@ -122,6 +158,17 @@ async fn parse_session_inner(
 
         match entry_type {
             "user" => {
+                if let Some(blocks) = value
+                    .get("message")
+                    .and_then(|m| m.get("content"))
+                    .and_then(|c| c.as_array())
+                {
+                    for attachment in extract_attachments(blocks) {
+                        if !attachments.contains(&attachment) {
+                            attachments.push(attachment);
+                        }
+                    }
+                }
                 handle_user_entry(
                     &value,
                     &timestamp,
@@ -195,7 +242,7 @@ async fn parse_session_inner(
         }
     }
 
-    Ok(ParsedSession::new(messages, total_tool_calls))
+    Ok(ParsedSession::new(messages, total_tool_calls).with_attachments(attachments))
 }
 
 /// Parse a session JSONL file and return a paginated slice of messages.
@@ -316,6 +316,40 @@ mod tests {
         assert_eq!(session.messages[1].content, "Test answer");
     }
 
+    #[tokio::test]
+    async fn test_parse_session_captures_image_attachments() {
+        use tempfile::NamedTempFile;
+        use tokio::io::AsyncWriteExt;
+
+        let temp_file = NamedTempFile::new().unwrap();
+        let path = temp_file.path().to_path_buf();
+
+        let content = r#"{"type":"user","message":{"content":[{"type":"text","text":"See the diagram"},{"type":"image","source":{"type":"url","media_type":"image/png","url":"https://example.com/shots/screenshot.png?v=2"}},{"type":"image","source":{"type":"base64","media_type":"image/jpeg","data":"AAAA"}}]},"timestamp":"2026-01-27T12:00:00Z"}
+{"type":"assistant","message":{"content":"Looks good"},"timestamp":"2026-01-27T12:00:01Z"}
+{"type":"user","message":{"content":"No attachments here"},"timestamp":"2026-01-27T12:00:02Z"}"#;
+
+        let mut file = tokio::fs::File::create(&path).await.unwrap();
+        file.write_all(content.as_bytes()).await.unwrap();
+        file.flush().await.unwrap();
+
+        let session = parse_session(&path).await.unwrap();
+
+        let names: Vec<&str> = session
+            .attachments
+            .iter()
+            .map(|a| a.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["screenshot.png", "image.jpeg"]);
+        assert_eq!(session.attachments[0].media_type, "image/png");
+    }
+
+    #[tokio::test]
+    async fn test_parse_session_without_attachments_is_empty() {
+        let path = fixtures_path().join("simple.jsonl");
+        let session = parse_session(&path).await.unwrap();
+        assert!(session.attachments.is_empty());
+    }
+
     #[tokio::test]
     async fn test_parse_session_empty_temp_file() {
         use tempfile::NamedTempFile;
@@ -585,4 +619,97 @@ mod tests {
             );
         }
     }
+
+    // ============================================================================
+    // Timestamp Normalization Tests
+    // ============================================================================
+
+    mod timestamps {
+        use crate::parser::{normalize_timestamp, normalize_timestamp_or_mtime};
+        use serde_json::json;
+
+        // 2024-01-25T16:26:40Z
+        const EXPECTED: i64 = 1_706_200_000;
+
+        #[test]
+        fn rfc3339_string() {
+            assert_eq!(
+                normalize_timestamp(&json!("2024-01-25T16:26:40Z")),
+                Some(EXPECTED)
+            );
+            assert_eq!(
+                normalize_timestamp(&json!("2024-01-25T17:26:40.123+01:00")),
+                Some(EXPECTED)
+            );
+        }
+
+        #[test]
+        fn epoch_seconds() {
+            assert_eq!(normalize_timestamp(&json!(1_706_200_000)), Some(EXPECTED));
+            assert_eq!(normalize_timestamp(&json!("1706200000")), Some(EXPECTED));
+            assert_eq!(
+                normalize_timestamp(&json!(1_706_200_000.75)),
+                Some(EXPECTED)
+            );
+        }
+
+        #[test]
+        fn epoch_millis() {
+            assert_eq!(
+                normalize_timestamp(&json!(1_706_200_000_123_i64)),
+                Some(EXPECTED)
+            );
+            assert_eq!(normalize_timestamp(&json!("1706200000123")), Some(EXPECTED));
+        }
+
+        #[test]
+        fn missing_or_invalid_falls_back_to_mtime() {
+            let mtime = 1_700_000_000;
+            assert_eq!(normalize_timestamp_or_mtime(None, mtime), mtime);
+            for bad in [
+                json!(null),
+                json!("yesterday"),
+                json!(""),
+                json!(true),
+                json!({}),
+            ] {
+                assert_eq!(normalize_timestamp(&bad), None, "{bad}");
+                assert_eq!(normalize_timestamp_or_mtime(Some(&bad), mtime), mtime);
+            }
+            assert_eq!(
+                normalize_timestamp_or_mtime(Some(&json!("2024-01-25T16:26:40Z")), mtime),
+                EXPECTED
+            );
+        }
+    }
+
+    // ============================================================================
+    // Compressed Transcripts
+    // ============================================================================
+
+    #[tokio::test]
+    async fn test_gzipped_transcript_parses_like_plain() {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        use std::io::Write;
+
+        let plain_path = fixtures_path().join("simple.jsonl");
+        let plain_bytes = std::fs::read(&plain_path).unwrap();
+        let plain = parse_session(&plain_path).await.unwrap();
+
+        let tmp = tempfile::tempdir().unwrap();
+        // Detected by extension and, for the misnamed copy, by magic bytes.
+        for name in ["simple.jsonl.gz", "compressed.jsonl"] {
+            let gz_path = tmp.path().join(name);
+            let mut enc = GzEncoder::new(
+                std::fs::File::create(&gz_path).unwrap(),
+                Compression::default(),
+            );
+            enc.write_all(&plain_bytes).unwrap();
+            enc.finish().unwrap();
+
+            let gzipped = parse_session(&gz_path).await.unwrap();
+            assert_eq!(gzipped, plain, "{name} should parse like its plain form");
+        }
+    }
 }
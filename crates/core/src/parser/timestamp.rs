@@ -0,0 +1,55 @@
+// crates/core/src/parser/timestamp.rs
+//! Timestamp normalization across Claude Code JSONL versions.
+//!
+//! Transcripts have carried `timestamp` as an RFC3339 string, epoch seconds,
+//! or epoch milliseconds (as a number or a numeric string). Everything here
+//! returns epoch seconds.
+
+use serde_json::Value;
+
+/// Epoch values at or above this are milliseconds. As seconds it would be
+/// the year 5138; as milliseconds it is 1973, so real data never straddles it.
+const EPOCH_MILLIS_THRESHOLD: i64 = 100_000_000_000;
+
+/// Convert an epoch value in seconds or milliseconds to seconds.
+pub fn epoch_to_seconds(raw: i64) -> i64 {
+    if raw.abs() >= EPOCH_MILLIS_THRESHOLD {
+        raw / 1000
+    } else {
+        raw
+    }
+}
+
+/// Parse a timestamp string: RFC3339 first, then a bare epoch number.
+pub fn parse_timestamp_str(s: &str) -> Option<i64> {
+    let s = s.trim();
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(s) {
+        return Some(dt.timestamp());
+    }
+    if let Ok(n) = s.parse::<i64>() {
+        return Some(epoch_to_seconds(n));
+    }
+    s.parse::<f64>()
+        .ok()
+        .filter(|f| f.is_finite())
+        .map(|f| epoch_to_seconds(f as i64))
+}
+
+/// Normalize a JSON `timestamp` value (string or number) to epoch seconds.
+/// `None` for missing, null, or unparseable values.
+pub fn normalize_timestamp(value: &Value) -> Option<i64> {
+    match value {
+        Value::String(s) => parse_timestamp_str(s),
+        Value::Number(n) => n
+            .as_i64()
+            .or_else(|| n.as_f64().filter(|f| f.is_finite()).map(|f| f as i64))
+            .map(epoch_to_seconds),
+        _ => None,
+    }
+}
+
+/// Like [`normalize_timestamp`], falling back to `file_mtime` (epoch
+/// seconds) when the value is absent or invalid.
+pub fn normalize_timestamp_or_mtime(value: Option<&Value>, file_mtime: i64) -> i64 {
+    value.and_then(normalize_timestamp).unwrap_or(file_mtime)
+}
@@ -102,6 +102,13 @@ pub async fn parse_history(path: &Path) -> Result<Vec<PromptEntry>, std::io::Err
 // Intent classification
 // ============================================================================
 
+/// Name of the slash command a prompt runs (`commit` for `/commit --amend`),
+/// or `None` when the prompt is not a slash command.
+pub fn slash_command_name(display: &str) -> Option<&str> {
+    let name = display.strip_prefix('/')?.split_whitespace().next()?;
+    Some(name)
+}
+
 /// Classify a prompt's intent based on keywords in the display text.
 pub fn classify_intent(display: &str) -> &'static str {
     if display.starts_with('/') {
@@ -349,6 +356,14 @@ mod tests {
         assert_eq!(classify_intent("/clear"), "command");
     }
 
+    #[test]
+    fn slash_command_name_takes_first_word() {
+        assert_eq!(slash_command_name("/commit --amend"), Some("commit"));
+        assert_eq!(slash_command_name("/clear"), Some("clear"));
+        assert_eq!(slash_command_name("/ "), None);
+        assert_eq!(slash_command_name("fix /tmp perms"), None);
+    }
+
     #[test]
     fn classify_intent_other() {
         assert_eq!(
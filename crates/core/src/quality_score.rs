@@ -0,0 +1,195 @@
+// crates/core/src/quality_score.rs
+//! Pure math for the per-session quality heuristic (0-100).
+//!
+//! The score starts at 100 and loses points for three penalties, each
+//! normalized to [0.0, 1.0]:
+//!
+//! | Penalty  | Signal                                            | Default weight |
+//! |----------|---------------------------------------------------|----------------|
+//! | rework   | `reedited_files_count / files_edited_count`       | 0.35           |
+//! | errors   | `api_error_count / api_call_count`                | 0.40           |
+//! | pace     | shortfall of output tokens per minute vs a target | 0.25           |
+//!
+//! `score = 100 * (1 - Σ weight·penalty / Σ weight)`, rounded. Weights are
+//! relative, so they need not sum to 1; tune them via [`QualityWeights`].
+//!
+//! Per-session user correction counts are not stored (the `correction_count`
+//! column was dropped in migration 63), so re-edits stand in for rework.
+
+use serde::Serialize;
+
+/// Raw per-session counters the score is computed from.
+#[derive(Debug, Clone, Default)]
+pub struct QualityInput {
+    pub files_edited_count: u32,
+    pub reedited_files_count: u32,
+    pub api_call_count: u32,
+    pub api_error_count: u32,
+    pub duration_seconds: u32,
+    pub total_output_tokens: u64,
+}
+
+/// Tunable weights for [`compute_session_quality`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QualityWeights {
+    /// Weight of the re-edited-files ratio.
+    pub rework: f64,
+    /// Weight of the API error rate.
+    pub errors: f64,
+    /// Weight of the output-pace shortfall.
+    pub pace: f64,
+    /// Output tokens per minute at or above which a session gets no pace
+    /// penalty. Sessions with no recorded duration are never penalized.
+    pub target_output_tokens_per_minute: f64,
+}
+
+impl Default for QualityWeights {
+    fn default() -> Self {
+        Self {
+            rework: 0.35,
+            errors: 0.40,
+            pace: 0.25,
+            target_output_tokens_per_minute: 300.0,
+        }
+    }
+}
+
+/// The computed quality score plus the penalty breakdown.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionQualityScore {
+    pub session_id: String,
+    /// Composite score 0-100 (higher = cleaner session).
+    pub score: i32,
+    /// Re-edited files / edited files (0.0-1.0).
+    pub rework_ratio: f64,
+    /// API errors / API calls (0.0-1.0).
+    pub error_rate: f64,
+    /// Output tokens per minute of session duration.
+    pub output_tokens_per_minute: f64,
+}
+
+/// Compute the quality score of session `session_id` from `input`.
+///
+/// Ratios are clamped to [0.0, 1.0] and negative weights count as 0, so
+/// the score always lands in [0, 100].
+pub fn compute_session_quality(
+    session_id: impl Into<String>,
+    input: &QualityInput,
+    weights: &QualityWeights,
+) -> SessionQualityScore {
+    let ratio = |num: u32, den: u32| {
+        if den == 0 {
+            0.0
+        } else {
+            (num as f64 / den as f64).clamp(0.0, 1.0)
+        }
+    };
+    let rework_ratio = ratio(input.reedited_files_count, input.files_edited_count);
+    // Errors without a recorded call count still count as fully failing.
+    let error_rate = if input.api_call_count == 0 && input.api_error_count > 0 {
+        1.0
+    } else {
+        ratio(input.api_error_count, input.api_call_count)
+    };
+
+    let minutes = input.duration_seconds as f64 / 60.0;
+    let output_tokens_per_minute = if minutes > 0.0 {
+        input.total_output_tokens as f64 / minutes
+    } else {
+        0.0
+    };
+    let pace_penalty = if minutes > 0.0 && weights.target_output_tokens_per_minute > 0.0 {
+        1.0 - (output_tokens_per_minute / weights.target_output_tokens_per_minute).min(1.0)
+    } else {
+        0.0
+    };
+
+    let w_rework = weights.rework.max(0.0);
+    let w_errors = weights.errors.max(0.0);
+    let w_pace = weights.pace.max(0.0);
+    let total = w_rework + w_errors + w_pace;
+    let penalty = if total > 0.0 {
+        (w_rework * rework_ratio + w_errors * error_rate + w_pace * pace_penalty) / total
+    } else {
+        0.0
+    };
+    let score = ((1.0 - penalty) * 100.0).round().clamp(0.0, 100.0) as i32;
+
+    SessionQualityScore {
+        session_id: session_id.into(),
+        score,
+        rework_ratio,
+        error_rate,
+        output_tokens_per_minute,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clean() -> QualityInput {
+        QualityInput {
+            files_edited_count: 5,
+            reedited_files_count: 0,
+            api_call_count: 40,
+            api_error_count: 0,
+            duration_seconds: 600,
+            total_output_tokens: 10_000,
+        }
+    }
+
+    #[test]
+    fn test_clean_session_scores_100() {
+        let q = compute_session_quality("s", &clean(), &QualityWeights::default());
+        assert_eq!(q.score, 100);
+        assert_eq!(q.rework_ratio, 0.0);
+        assert_eq!(q.error_rate, 0.0);
+        assert_eq!(q.output_tokens_per_minute, 1000.0);
+    }
+
+    #[test]
+    fn test_error_heavy_session_scores_lower() {
+        let noisy = QualityInput {
+            reedited_files_count: 4,
+            api_error_count: 20,
+            total_output_tokens: 500,
+            ..clean()
+        };
+        let weights = QualityWeights::default();
+        let clean_q = compute_session_quality("clean", &clean(), &weights);
+        let noisy_q = compute_session_quality("noisy", &noisy, &weights);
+        assert!(noisy_q.score < clean_q.score, "{noisy_q:?} vs {clean_q:?}");
+        assert_eq!(noisy_q.rework_ratio, 0.8);
+        assert_eq!(noisy_q.error_rate, 0.5);
+    }
+
+    #[test]
+    fn test_weights_are_tunable() {
+        let noisy = QualityInput {
+            api_error_count: 40,
+            ..clean()
+        };
+        let errors_only = QualityWeights {
+            rework: 0.0,
+            pace: 0.0,
+            ..QualityWeights::default()
+        };
+        let ignore_errors = QualityWeights {
+            errors: 0.0,
+            ..QualityWeights::default()
+        };
+        assert_eq!(compute_session_quality("s", &noisy, &errors_only).score, 0);
+        assert_eq!(
+            compute_session_quality("s", &noisy, &ignore_errors).score,
+            100
+        );
+    }
+
+    #[test]
+    fn test_empty_session_is_not_penalized() {
+        let q = compute_session_quality("s", &QualityInput::default(), &QualityWeights::default());
+        assert_eq!(q.score, 100);
+    }
+}
@@ -224,12 +224,19 @@ impl SessionCatalog {
         let mut stats = WalkStats::default();
         let mut by_id: HashMap<SessionId, CatalogRow> = HashMap::new();
 
-        // Pass 1 — live
+        // Pass 1 — live. Transcripts may be gzipped in place; a plain
+        // copy of the same session wins.
         if live_root.is_dir() {
             for row in walk_root(live_root, ".jsonl", false) {
                 stats.live_found += 1;
                 by_id.insert(row.id.clone(), row);
             }
+            for row in walk_root(live_root, ".jsonl.gz", true) {
+                if !by_id.contains_key(&row.id) {
+                    stats.live_found += 1;
+                    by_id.insert(row.id.clone(), row);
+                }
+            }
         }
 
         // Pass 2 — backup (one project dir per machine)
@@ -403,6 +410,35 @@ mod tests {
         assert!(sess3.is_compressed);
     }
 
+    #[test]
+    fn catalog_picks_up_gzipped_live_sessions() {
+        let tmp = tempdir().unwrap();
+        let live = tmp.path().join("live");
+        fs::create_dir_all(live.join("proj-a")).unwrap();
+        make_jsonl(
+            &live.join("proj-a/sess-1.jsonl"),
+            &[r#"{"type":"assistant"}"#],
+        );
+        // Same session gzipped alongside: the plain copy wins.
+        make_gz(
+            &live.join("proj-a/sess-1.jsonl.gz"),
+            &[r#"{"type":"assistant"}"#],
+        );
+        make_gz(
+            &live.join("proj-a/sess-2.jsonl.gz"),
+            &[r#"{"type":"assistant"}"#],
+        );
+
+        let catalog = SessionCatalog::new();
+        let stats = catalog
+            .rebuild_from_filesystem(&live, &tmp.path().join("nonexistent"))
+            .unwrap();
+        assert_eq!(stats.live_found, 2);
+        assert_eq!(stats.total_after_dedup, 2);
+        assert!(!catalog.get("sess-1").unwrap().is_compressed);
+        assert!(catalog.get("sess-2").unwrap().is_compressed);
+    }
+
     #[test]
     fn catalog_filter_by_project() {
         let tmp = tempdir().unwrap();
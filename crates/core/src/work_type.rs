@@ -13,6 +13,10 @@
 //! | Planning | skills contain "brainstorming" or "plan", low edits | Clipboard |
 //! | Bug Fix | skills contain "debugging", moderate edits | Bug |
 //! | Standard | Everything else | None |
+//!
+//! [`rank_work_types`] scores every type by how many of its heuristics hold,
+//! so a session can carry secondary labels (e.g. Planning + Bug Fix) next to
+//! the primary one from [`classify_work_type`].
 
 use serde::{Deserialize, Serialize};
 
@@ -209,6 +213,104 @@ pub fn classify_work_type(input: &ClassificationInput) -> WorkType {
     WorkType::Standard
 }
 
+/// A work type with the share of its heuristics the session satisfies.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkTypeScore {
+    pub work_type: WorkType,
+    /// 0.0 (no signal) to 1.0 (every heuristic holds).
+    pub score: f32,
+}
+
+/// Minimum score for a non-primary work type to be reported as an alternate.
+pub const MIN_ALTERNATE_SCORE: f32 = 0.5;
+
+/// Fraction of `checks` that hold.
+fn share(checks: &[bool]) -> f32 {
+    checks.iter().filter(|&&c| c).count() as f32 / checks.len() as f32
+}
+
+/// Skill-gated score: 0 without a matching skill, 1 when the edit bound also
+/// holds, 0.5 when only the skill matches.
+fn skill_score(skills: &[String], keywords: &[&str], edits_ok: bool) -> f32 {
+    if !skills_contain_any(skills, keywords) {
+        0.0
+    } else if edits_ok {
+        1.0
+    } else {
+        0.5
+    }
+}
+
+/// Score every work type for a session, best first.
+///
+/// Each type scores the share of its heuristics that hold (Planning and Bug
+/// Fix need their skill signal to score at all). Standard scores 1.0 only
+/// when no other type fully matches. Ties keep the [`classify_work_type`]
+/// priority order, so the first entry is always its result. Types scoring 0
+/// are omitted.
+pub fn rank_work_types(input: &ClassificationInput) -> Vec<WorkTypeScore> {
+    use thresholds::*;
+
+    let no_edits = input.files_edited_count == 0;
+    // Priority order matches `classify_work_type`.
+    let mut scores = vec![
+        WorkTypeScore {
+            work_type: WorkType::QuickAsk,
+            score: share(&[
+                input.duration_seconds <= QUICK_ASK_MAX_DURATION_SECS,
+                input.turn_count <= QUICK_ASK_MAX_TURNS,
+                no_edits,
+            ]),
+        },
+        WorkTypeScore {
+            work_type: WorkType::Planning,
+            score: skill_score(
+                &input.skills_used,
+                PLANNING_SKILLS,
+                input.files_edited_count <= PLANNING_MAX_FILES_EDITED,
+            ),
+        },
+        WorkTypeScore {
+            work_type: WorkType::BugFix,
+            score: skill_score(
+                &input.skills_used,
+                DEBUGGING_SKILLS,
+                input.files_edited_count <= BUG_FIX_MAX_FILES_EDITED,
+            ),
+        },
+        WorkTypeScore {
+            work_type: WorkType::DeepWork,
+            score: share(&[
+                input.duration_seconds >= DEEP_WORK_MIN_DURATION_SECS,
+                input.files_edited_count >= DEEP_WORK_MIN_FILES_EDITED,
+                input.ai_lines_added >= DEEP_WORK_MIN_LOC,
+            ]),
+        },
+    ];
+    let any_full = scores.iter().any(|s| s.score >= 1.0);
+    scores.push(WorkTypeScore {
+        work_type: WorkType::Standard,
+        score: if any_full { 0.0 } else { 1.0 },
+    });
+
+    scores.retain(|s| s.score > 0.0);
+    // Stable sort: equal scores keep priority order.
+    scores.sort_by(|a, b| b.score.total_cmp(&a.score));
+    scores
+}
+
+/// Secondary work types worth showing next to the primary: every ranked type
+/// after the first that scores at least [`MIN_ALTERNATE_SCORE`].
+pub fn work_type_alternates(ranked: &[WorkTypeScore]) -> Vec<WorkTypeScore> {
+    ranked
+        .iter()
+        .skip(1)
+        .filter(|s| s.score >= MIN_ALTERNATE_SCORE)
+        .copied()
+        .collect()
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -515,4 +617,107 @@ mod tests {
         };
         assert_eq!(classify_work_type(&input), WorkType::BugFix);
     }
+
+    // ========================================================================
+    // rank_work_types tests
+    // ========================================================================
+
+    fn ranked_types(input: &ClassificationInput) -> Vec<WorkType> {
+        rank_work_types(input)
+            .into_iter()
+            .map(|s| s.work_type)
+            .collect()
+    }
+
+    #[test]
+    fn test_rank_planning_and_bug_fix_signals() {
+        let input = ClassificationInput {
+            duration_seconds: 20 * 60,
+            turn_count: 12,
+            files_edited_count: 2,
+            ai_lines_added: 40,
+            skills_used: vec!["brainstorming".to_string(), "debugging".to_string()],
+        };
+        let ranked = rank_work_types(&input);
+        assert_eq!(ranked[0].work_type, classify_work_type(&input));
+        assert_eq!(
+            ranked_types(&input),
+            vec![WorkType::Planning, WorkType::BugFix]
+        );
+        assert_eq!(ranked[0].score, 1.0);
+        assert_eq!(ranked[1].score, 1.0);
+
+        let alternates = work_type_alternates(&ranked);
+        assert_eq!(alternates.len(), 1);
+        assert_eq!(alternates[0].work_type, WorkType::BugFix);
+    }
+
+    #[test]
+    fn test_rank_bug_fix_with_partial_deep_work() {
+        let input = ClassificationInput {
+            duration_seconds: 45 * 60,
+            turn_count: 30,
+            files_edited_count: 8,
+            ai_lines_added: 150,
+            skills_used: vec!["troubleshooting".to_string()],
+        };
+        let ranked = rank_work_types(&input);
+        assert_eq!(
+            ranked_types(&input),
+            vec![WorkType::BugFix, WorkType::DeepWork]
+        );
+        assert!((ranked[1].score - 2.0 / 3.0).abs() < 1e-6);
+        assert_eq!(
+            work_type_alternates(&ranked)
+                .iter()
+                .map(|s| s.work_type)
+                .collect::<Vec<_>>(),
+            vec![WorkType::DeepWork]
+        );
+    }
+
+    #[test]
+    fn test_rank_standard_primary_keeps_partial_signals() {
+        // Just short of deep work on duration, planning skill with too many edits.
+        let input = ClassificationInput {
+            duration_seconds: 29 * 60,
+            turn_count: 20,
+            files_edited_count: 6,
+            ai_lines_added: 300,
+            skills_used: vec!["plan".to_string()],
+        };
+        let ranked = rank_work_types(&input);
+        assert_eq!(classify_work_type(&input), WorkType::Standard);
+        assert_eq!(
+            ranked_types(&input),
+            vec![WorkType::Standard, WorkType::DeepWork, WorkType::Planning]
+        );
+        assert_eq!(work_type_alternates(&ranked).len(), 2);
+    }
+
+    #[test]
+    fn test_rank_first_entry_matches_classify() {
+        let inputs = [
+            ClassificationInput::default(),
+            ClassificationInput::new(45 * 60, 50, 10, 500, vec!["tdd".to_string()]),
+            ClassificationInput::new(2 * 60, 2, 0, 0, vec!["brainstorming".to_string()]),
+            ClassificationInput::new(15 * 60, 10, 3, 50, vec!["commit".to_string()]),
+        ];
+        for input in &inputs {
+            assert_eq!(
+                rank_work_types(input)[0].work_type,
+                classify_work_type(input)
+            );
+        }
+    }
+
+    #[test]
+    fn test_work_type_score_serializes_camel_case() {
+        let json = serde_json::to_string(&WorkTypeScore {
+            work_type: WorkType::BugFix,
+            score: 0.5,
+        })
+        .unwrap();
+        assert_eq!(json, r#"{"workType":"bug_fix","score":0.5}"#);
+    }
 }
@@ -11,6 +11,9 @@
 //!   - classify: apply iff `event.at >= session_flags.classified_at`.
 //!     On equal timestamps we APPLY (prefer the later-seq writer so
 //!     back-to-back classifier updates commit in order).
+//!   - unclassify: always applies; clears `category_*` and
+//!     `classified_at` so the session counts as unclassified again (used
+//!     when the taxonomy version changes).
 //!   - archive / unarchive / dismiss: last-write-wins naturally — the
 //!     UPSERT always overwrites. The legacy ordering invariant is
 //!     preserved because `applied_seq` consumes events in strictly
@@ -95,6 +98,21 @@ pub(crate) async fn fold_event_tx(
             }
             Ok((!lww_skipped, lww_skipped))
         }
+        "unclassify" => {
+            let before_category = read_category_l1(tx, &event.session_id).await?;
+            fold_unclassify_tx(tx, event).await?;
+            insert_flag_delta_tx(
+                &mut **tx,
+                &FlagDelta::Classify {
+                    session_id: event.session_id.clone(),
+                    before_category_l1: before_category,
+                    after_category_l1: None,
+                    at_ms: event.at,
+                },
+            )
+            .await?;
+            Ok((true, false))
+        }
         _ => Ok((false, false)),
     }
 }
@@ -186,8 +204,8 @@ async fn fold_classify_tx(tx: &mut Transaction<'_, Sqlite>, event: &ActionEvent)
         r#"INSERT INTO session_flags
              (session_id, category_l1, category_l2, category_l3,
               category_confidence, category_source, classified_at,
-              applied_seq)
-           VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+              category_version, applied_seq)
+           VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
            ON CONFLICT(session_id) DO UPDATE SET
              category_l1         = excluded.category_l1,
              category_l2         = excluded.category_l2,
@@ -195,6 +213,7 @@ async fn fold_classify_tx(tx: &mut Transaction<'_, Sqlite>, event: &ActionEvent)
              category_confidence = excluded.category_confidence,
              category_source     = excluded.category_source,
              classified_at       = excluded.classified_at,
+             category_version    = excluded.category_version,
              applied_seq         = excluded.applied_seq"#,
     )
     .bind(&event.session_id)
@@ -204,9 +223,30 @@ async fn fold_classify_tx(tx: &mut Transaction<'_, Sqlite>, event: &ActionEvent)
     .bind(payload.confidence)
     .bind(&payload.source)
     .bind(event.at)
+    .bind(payload.version)
     .bind(event.seq)
     .execute(&mut **tx)
     .await?;
 
     Ok(false)
 }
+
+async fn fold_unclassify_tx(tx: &mut Transaction<'_, Sqlite>, event: &ActionEvent) -> DbResult<()> {
+    sqlx::query(
+        r#"INSERT INTO session_flags (session_id, applied_seq)
+           VALUES (?1, ?2)
+           ON CONFLICT(session_id) DO UPDATE SET
+             category_l1         = NULL,
+             category_l2         = NULL,
+             category_l3         = NULL,
+             category_confidence = NULL,
+             category_source     = NULL,
+             classified_at       = NULL,
+             applied_seq         = excluded.applied_seq"#,
+    )
+    .bind(&event.session_id)
+    .bind(event.seq)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
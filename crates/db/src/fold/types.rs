@@ -35,6 +35,14 @@ pub struct ClassifyPayload {
     pub confidence: f64,
     #[serde(default)]
     pub source: String,
+    /// Taxonomy version the classifier ran under. Payloads written before
+    /// versioning carry none and were all version 1.
+    #[serde(default = "legacy_taxonomy_version")]
+    pub version: i64,
+}
+
+fn legacy_taxonomy_version() -> i64 {
+    1
 }
 
 /// Return value of a single fold-batch pass.
@@ -1,7 +1,10 @@
 // crates/db/src/git_correlation/db_ops.rs
 //! Database CRUD operations for commits and session-commit links.
 
-use super::types::{CorrelationMatch, DiffStats, GitCommit, SessionSyncInfo};
+use super::types::{
+    BranchStatus, CommitLagReport, CorrelationMatch, DiffStats, GitCommit, ProjectRepoLink,
+    RepoInfo, SessionCommitLag, SessionSyncInfo, UncommittedSession,
+};
 use crate::{Database, DbResult};
 
 impl Database {
@@ -364,6 +367,224 @@ impl Database {
             )
             .collect())
     }
+
+    /// Sessions that edited files but have no row in `session_commits`.
+    ///
+    /// Optionally narrowed to one project (matched by project id, git root or
+    /// project path) and to a `last_message_at` window (Unix seconds,
+    /// inclusive). Most recent first.
+    pub async fn sessions_without_commits(
+        &self,
+        project_id: Option<&str>,
+        since: Option<i64>,
+        until: Option<i64>,
+    ) -> DbResult<Vec<UncommittedSession>> {
+        let rows: Vec<(String, String, String, i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT s.id, COALESCE(s.project_id, ''), COALESCE(s.preview, ''),
+                   s.files_edited_count, COALESCE(s.last_message_at, 0)
+            FROM valid_sessions s
+            WHERE s.files_edited_count > 0
+              AND NOT EXISTS (
+                  SELECT 1 FROM session_commits sc WHERE sc.session_id = s.id
+              )
+              AND (?1 IS NULL OR s.project_id = ?1
+                   OR (s.git_root IS NOT NULL AND s.git_root <> '' AND s.git_root = ?1)
+                   OR (s.project_path IS NOT NULL AND s.project_path <> '' AND s.project_path = ?1))
+              AND (?2 IS NULL OR s.last_message_at >= ?2)
+              AND (?3 IS NULL OR s.last_message_at <= ?3)
+            ORDER BY s.last_message_at DESC
+            "#,
+        )
+        .bind(project_id)
+        .bind(since)
+        .bind(until)
+        .fetch_all(self.pool())
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(session_id, project_id, preview, files_edited_count, last_message_at)| {
+                    UncommittedSession {
+                        session_id,
+                        project_id,
+                        preview,
+                        files_edited_count,
+                        last_message_at,
+                    }
+                },
+            )
+            .collect())
+    }
+
+    /// Lag from each session's last message to its first linked commit.
+    ///
+    /// A session linked to several commits uses the earliest one (ties broken
+    /// by hash). Sessions without `last_message_at` are skipped. Optionally
+    /// narrowed to one project (matched by project id, git root or project
+    /// path).
+    pub async fn session_commit_lag(&self, project_id: Option<&str>) -> DbResult<CommitLagReport> {
+        let rows: Vec<(String, String, i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT s.id, c.hash, s.last_message_at, c.timestamp
+            FROM valid_sessions s
+            JOIN commits c ON c.hash = (
+                SELECT c2.hash
+                FROM session_commits sc
+                JOIN commits c2 ON c2.hash = sc.commit_hash
+                WHERE sc.session_id = s.id
+                ORDER BY c2.timestamp ASC, c2.hash ASC
+                LIMIT 1
+            )
+            WHERE s.last_message_at IS NOT NULL
+              AND (?1 IS NULL OR s.project_id = ?1
+                   OR (s.git_root IS NOT NULL AND s.git_root <> '' AND s.git_root = ?1)
+                   OR (s.project_path IS NOT NULL AND s.project_path <> '' AND s.project_path = ?1))
+            ORDER BY s.last_message_at DESC
+            "#,
+        )
+        .bind(project_id)
+        .fetch_all(self.pool())
+        .await?;
+
+        let sessions: Vec<SessionCommitLag> = rows
+            .into_iter()
+            .map(
+                |(session_id, commit_hash, last_message_at, committed_at)| SessionCommitLag {
+                    session_id,
+                    commit_hash,
+                    last_message_at,
+                    committed_at,
+                    lag_seconds: committed_at - last_message_at,
+                },
+            )
+            .collect();
+
+        let mut lags: Vec<i64> = sessions.iter().map(|s| s.lag_seconds).collect();
+        lags.sort_unstable();
+        let median_lag_seconds = match lags.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(lags[n / 2]),
+            n => Some((lags[n / 2 - 1] + lags[n / 2]) / 2),
+        };
+
+        Ok(CommitLagReport {
+            sessions,
+            median_lag_seconds,
+        })
+    }
+
+    /// Distinct non-empty `git_branch` values of sessions under `project_path`.
+    pub async fn session_branches_for_path(&self, project_path: &str) -> DbResult<Vec<String>> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            r#"
+            SELECT DISTINCT git_branch
+            FROM session_stats
+            WHERE project_path = ?1 AND git_branch IS NOT NULL AND git_branch != ''
+            ORDER BY git_branch
+            "#,
+        )
+        .bind(project_path)
+        .fetch_all(self.pool())
+        .await?;
+        Ok(rows.into_iter().map(|(b,)| b).collect())
+    }
+
+    /// Record branch statuses for a repo, replacing earlier ones.
+    pub async fn upsert_branch_statuses(
+        &self,
+        project_path: &str,
+        statuses: &[(String, BranchStatus)],
+    ) -> DbResult<()> {
+        if statuses.is_empty() {
+            return Ok(());
+        }
+        let now = chrono::Utc::now().timestamp();
+        let mut tx = self.pool().begin().await?;
+        for (branch, status) in statuses {
+            sqlx::query(
+                r#"
+                INSERT INTO branch_status (project_path, branch, status, checked_at)
+                VALUES (?1, ?2, ?3, ?4)
+                ON CONFLICT(project_path, branch) DO UPDATE SET
+                    status = excluded.status,
+                    checked_at = excluded.checked_at
+                "#,
+            )
+            .bind(project_path)
+            .bind(branch)
+            .bind(status.as_str())
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Record the remote and default branch of the repo at `project_path`,
+    /// replacing what an earlier sync found.
+    pub async fn upsert_project_repo(&self, project_path: &str, info: &RepoInfo) -> DbResult<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO project_repos (project_path, remote_url, web_url, default_branch, checked_at)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT(project_path) DO UPDATE SET
+                remote_url = excluded.remote_url,
+                web_url = excluded.web_url,
+                default_branch = excluded.default_branch,
+                checked_at = excluded.checked_at
+            "#,
+        )
+        .bind(project_path)
+        .bind(&info.remote_url)
+        .bind(&info.web_url)
+        .bind(&info.default_branch)
+        .bind(chrono::Utc::now().timestamp())
+        .execute(self.pool())
+        .await?;
+        Ok(())
+    }
+
+    /// Repo links for every project whose path git sync has scanned,
+    /// ordered by project id. Repos without a remote are included with
+    /// `remote_url`/`web_url` unset.
+    pub async fn get_project_readme_links(&self) -> DbResult<Vec<ProjectRepoLink>> {
+        let rows: Vec<(
+            String,
+            String,
+            Option<String>,
+            Option<String>,
+            Option<String>,
+        )> = sqlx::query_as(
+            r#"
+            SELECT p.project_id, r.project_path, r.remote_url, r.web_url, r.default_branch
+            FROM project_repos r
+            JOIN (
+                SELECT project_path, MIN(project_id) AS project_id
+                FROM valid_sessions
+                WHERE project_path IS NOT NULL AND project_path != ''
+                GROUP BY project_path
+            ) p ON p.project_path = r.project_path
+            ORDER BY p.project_id, r.project_path
+            "#,
+        )
+        .fetch_all(self.pool())
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(
+                |(project_id, project_path, remote_url, web_url, default_branch)| ProjectRepoLink {
+                    project_id,
+                    project_path,
+                    remote_url,
+                    web_url,
+                    default_branch,
+                },
+            )
+            .collect())
+    }
 }
 
 #[cfg(test)]
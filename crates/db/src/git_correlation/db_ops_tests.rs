@@ -450,3 +450,154 @@ async fn test_get_sessions_for_git_sync_filters_correctly() {
     assert_eq!(sessions[1].first_message_at, Some(1000));
     assert_eq!(sessions[1].last_message_at, Some(2000));
 }
+
+#[tokio::test]
+async fn test_sessions_without_commits_skips_linked_sessions() {
+    let db = Database::new_in_memory().await.unwrap();
+
+    for (id, last_message_at) in [("committed", 2000), ("uncommitted", 3000)] {
+        crate::test_support::SessionSeedBuilder::new(id)
+            .project_id("project-1")
+            .project_path("/repo/path")
+            .file_path(format!("/tmp/{id}.jsonl"))
+            .preview("Edit things")
+            .last_message_at(last_message_at)
+            .with_parsed(|p| p.files_edited_count = 2)
+            .seed(&db)
+            .await
+            .unwrap();
+    }
+    // Edited nothing: never reported, even without a commit.
+    crate::test_support::SessionSeedBuilder::new("read-only")
+        .project_id("project-1")
+        .file_path("/tmp/read-only.jsonl")
+        .last_message_at(2500)
+        .seed(&db)
+        .await
+        .unwrap();
+
+    db.batch_upsert_commits(&[GitCommit {
+        hash: "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_string(),
+        repo_path: "/repo/path".to_string(),
+        message: "Commit the work".to_string(),
+        author: None,
+        timestamp: 2000,
+        branch: None,
+        files_changed: None,
+        insertions: None,
+        deletions: None,
+    }])
+    .await
+    .unwrap();
+    db.batch_insert_session_commits(&[CorrelationMatch {
+        session_id: "committed".to_string(),
+        commit_hash: "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_string(),
+        tier: 2,
+        evidence: CorrelationEvidence {
+            rule: "during_session".to_string(),
+            skill_ts: None,
+            commit_ts: Some(2000),
+            skill_name: None,
+            session_start: None,
+            session_end: None,
+        },
+    }])
+    .await
+    .unwrap();
+
+    let sessions = db.sessions_without_commits(None, None, None).await.unwrap();
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0].session_id, "uncommitted");
+    assert_eq!(sessions[0].files_edited_count, 2);
+
+    // Project and time filters.
+    let other = db
+        .sessions_without_commits(Some("project-2"), None, None)
+        .await
+        .unwrap();
+    assert!(other.is_empty());
+    let before = db
+        .sessions_without_commits(Some("project-1"), None, Some(2999))
+        .await
+        .unwrap();
+    assert!(before.is_empty());
+
+    // The project filter also matches the sidebar's git root and project path.
+    db.set_git_root("uncommitted", "/repo/root").await.unwrap();
+    for project in ["/repo/root", "/repo/path"] {
+        let by_path = db
+            .sessions_without_commits(Some(project), None, None)
+            .await
+            .unwrap();
+        assert_eq!(by_path.len(), 1, "{project}");
+        assert_eq!(by_path[0].session_id, "uncommitted");
+    }
+}
+
+#[tokio::test]
+async fn test_session_commit_lag_uses_first_commit() {
+    let db = Database::new_in_memory().await.unwrap();
+
+    for (id, last_message_at) in [("quick", 1000), ("slow", 5000), ("unlinked", 6000)] {
+        crate::test_support::SessionSeedBuilder::new(id)
+            .project_id("project-1")
+            .file_path(format!("/tmp/{id}.jsonl"))
+            .last_message_at(last_message_at)
+            .seed(&db)
+            .await
+            .unwrap();
+    }
+
+    let commit = |hash: char, timestamp: i64| GitCommit {
+        hash: hash.to_string().repeat(40),
+        repo_path: "/repo/path".to_string(),
+        message: "Commit".to_string(),
+        author: None,
+        timestamp,
+        branch: None,
+        files_changed: None,
+        insertions: None,
+        deletions: None,
+    };
+    db.batch_upsert_commits(&[commit('a', 1120), commit('b', 1500), commit('c', 8600)])
+        .await
+        .unwrap();
+    let link = |session_id: &str, hash: char| CorrelationMatch {
+        session_id: session_id.to_string(),
+        commit_hash: hash.to_string().repeat(40),
+        tier: 2,
+        evidence: CorrelationEvidence {
+            rule: "during_session".to_string(),
+            skill_ts: None,
+            commit_ts: None,
+            skill_name: None,
+            session_start: None,
+            session_end: None,
+        },
+    };
+    // "quick" has two commits; only the earlier one counts.
+    db.batch_insert_session_commits(&[link("quick", 'b'), link("quick", 'a'), link("slow", 'c')])
+        .await
+        .unwrap();
+
+    let report = db.session_commit_lag(None).await.unwrap();
+    let lags: Vec<(&str, i64)> = report
+        .sessions
+        .iter()
+        .map(|s| (s.session_id.as_str(), s.lag_seconds))
+        .collect();
+    assert_eq!(lags, vec![("slow", 3600), ("quick", 120)]);
+    assert_eq!(report.sessions[1].commit_hash, "a".repeat(40));
+    assert_eq!(report.median_lag_seconds, Some(1860));
+
+    let other = db.session_commit_lag(Some("project-2")).await.unwrap();
+    assert!(other.sessions.is_empty());
+    assert_eq!(other.median_lag_seconds, None);
+
+    // The project filter also matches the sidebar's git root.
+    db.set_git_root("slow", "/repo/root").await.unwrap();
+    let by_root = db.session_commit_lag(Some("/repo/root")).await.unwrap();
+    assert_eq!(by_root.sessions.len(), 1);
+    assert_eq!(by_root.sessions[0].session_id, "slow");
+    assert_eq!(by_root.median_lag_seconds, Some(3600));
+}
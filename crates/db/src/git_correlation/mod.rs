@@ -5,7 +5,10 @@
 //! - `scan_repo_commits()`: Spawn git log, parse output, handle edge cases
 //! - `Tier1Matcher`: Match commit skills to commits within [-60s, +300s] window
 //! - `Tier2Matcher`: Match commits during session time range
-//! - CRUD operations for commits and session_commits tables
+//! - `scan_branch_statuses()`: Classify session branches as active/merged/deleted
+//! - `scan_repo_info()`: Read a repo's remote URL and default branch
+//! - CRUD operations for commits, session_commits, branch_status and
+//!   project_repos tables
 
 mod db_ops;
 mod diff_stats;
@@ -21,12 +24,13 @@ pub use types::{TIER1_WINDOW_AFTER_SECS, TIER1_WINDOW_BEFORE_SECS};
 
 // Types
 pub use types::{
-    CommitSkillInvocation, CorrelationEvidence, CorrelationMatch, DiffStats, GitCommit,
-    GitSyncProgress, GitSyncResult, ScanResult, SessionCorrelationInfo, SessionSyncInfo,
+    BranchStatus, CommitLagReport, CommitSkillInvocation, CorrelationEvidence, CorrelationMatch,
+    DiffStats, GitCommit, GitSyncProgress, GitSyncResult, ProjectRepoLink, RepoInfo, ScanResult,
+    SessionCommitLag, SessionCorrelationInfo, SessionSyncInfo, UncommittedSession,
 };
 
 // Git scanning
-pub use scanning::scan_repo_commits;
+pub use scanning::{remote_web_url, scan_branch_statuses, scan_repo_commits, scan_repo_info};
 
 // Diff stats
 pub use diff_stats::{extract_commit_diff_stats, get_batch_diff_stats, get_commit_diff_stats};
@@ -3,7 +3,7 @@
 
 use super::diff_stats::extract_commit_diff_stats;
 use super::matching::{tier1_match, tier2_match};
-use super::scanning::scan_repo_commits;
+use super::scanning::{scan_branch_statuses, scan_repo_commits, scan_repo_info};
 use super::types::{DiffStats, GitCommit, GitSyncProgress, GitSyncResult, SessionCorrelationInfo};
 use crate::{Database, DbResult};
 
@@ -146,6 +146,28 @@ where
             continue;
         }
 
+        // Remote + default branch for linking the project to its repo.
+        // Best-effort, like branch status: a failure only leaves the link stale.
+        let repo_info = scan_repo_info(path).await;
+        if let Err(e) = db.upsert_project_repo(project_path, &repo_info).await {
+            tracing::warn!(
+                "Git sync: repo info upsert failed for {}: {}",
+                project_path,
+                e
+            );
+        }
+
+        // Branch status is best-effort: a failure only leaves it stale.
+        let branches = db.session_branches_for_path(project_path).await?;
+        if !branches.is_empty() {
+            match scan_branch_statuses(path, &branches).await {
+                Ok(statuses) => db.upsert_branch_statuses(project_path, &statuses).await?,
+                Err(e) => {
+                    tracing::debug!("Git sync: branch status failed for {}: {}", project_path, e)
+                }
+            }
+        }
+
         if scan.commits.is_empty() {
             continue;
         }
@@ -888,3 +888,141 @@ async fn test_run_git_sync_idempotent() {
     let count = db.count_commits_for_session("s1").await.unwrap();
     assert_eq!(count, 1);
 }
+
+#[tokio::test]
+async fn test_run_git_sync_records_branch_status_and_hides_deleted() {
+    let db = Database::new_in_memory().await.unwrap();
+
+    let tmp = TempDir::new().unwrap();
+    let repo_path = tmp.path();
+    let git = |args: &[&str]| {
+        let out = std::process::Command::new("git")
+            .args(args)
+            .current_dir(repo_path)
+            .output()
+            .expect("git");
+        String::from_utf8_lossy(&out.stdout).trim().to_string()
+    };
+    git(&["init"]);
+    git(&["config", "user.email", "test@test.com"]);
+    git(&["config", "user.name", "Test"]);
+    std::fs::write(repo_path.join("file.txt"), "hello").unwrap();
+    git(&["add", "."]);
+    git(&["commit", "-m", "test commit"]);
+    let head = git(&["rev-parse", "--abbrev-ref", "HEAD"]);
+    git(&["branch", "feature/done"]);
+    git(&["branch", "feature/gone"]);
+    git(&["branch", "-D", "feature/gone"]);
+    // Pushed, then deleted locally: only the remote-tracking ref is left.
+    git(&["update-ref", "refs/remotes/origin/feature/pushed", "HEAD"]);
+
+    let dir_str = repo_path.to_str().unwrap();
+    for (id, branch) in [
+        ("s-head", head.as_str()),
+        ("s-done", "feature/done"),
+        ("s-gone", "feature/gone"),
+        ("s-pushed", "feature/pushed"),
+    ] {
+        let file_path = format!("/tmp/{id}.jsonl");
+        seed_git_session(&db, id, dir_str, Some(100), Some(200), &file_path).await;
+        sqlx::query("UPDATE session_stats SET git_branch = ?1 WHERE session_id = ?2")
+            .bind(branch)
+            .bind(id)
+            .execute(db.pool())
+            .await
+            .unwrap();
+    }
+
+    run_git_sync(&db, |_| {}).await.unwrap();
+
+    let branches = db.list_branches_for_project("p1").await.unwrap();
+    let status_of = |name: &str| {
+        branches
+            .iter()
+            .find(|b| b.branch.as_deref() == Some(name))
+            .and_then(|b| b.status.clone())
+    };
+    assert_eq!(status_of(&head).as_deref(), Some("active"));
+    assert_eq!(status_of("feature/done").as_deref(), Some("merged"));
+    assert_eq!(status_of("feature/gone").as_deref(), Some("deleted"));
+    assert_eq!(status_of("feature/pushed").as_deref(), Some("remote"));
+
+    let active: Vec<_> = db
+        .list_active_branches_for_project("p1")
+        .await
+        .unwrap()
+        .into_iter()
+        .filter_map(|b| b.branch)
+        .collect();
+    assert_eq!(active.len(), 3);
+    assert!(!active.contains(&"feature/gone".to_string()));
+    assert!(active.contains(&"feature/pushed".to_string()));
+}
+
+#[tokio::test]
+async fn test_run_git_sync_records_project_repo_links() {
+    let db = Database::new_in_memory().await.unwrap();
+
+    let tmp = TempDir::new().unwrap();
+    let with_remote = tmp.path().join("widgets");
+    let local_only = tmp.path().join("scratch");
+    for repo in [&with_remote, &local_only] {
+        std::fs::create_dir_all(repo).unwrap();
+        for args in [
+            &["init", "-b", "main"][..],
+            &["config", "user.email", "test@test.com"],
+            &["config", "user.name", "Test"],
+            &["commit", "--allow-empty", "-m", "init"],
+        ] {
+            std::process::Command::new("git")
+                .args(args)
+                .current_dir(repo)
+                .output()
+                .expect("git");
+        }
+    }
+    std::process::Command::new("git")
+        .args(["remote", "add", "origin", "git@github.com:acme/widgets.git"])
+        .current_dir(&with_remote)
+        .output()
+        .expect("git remote add");
+
+    for (id, repo) in [("s-remote", &with_remote), ("s-local", &local_only)] {
+        seed_git_session(
+            &db,
+            id,
+            repo.to_str().unwrap(),
+            Some(100),
+            Some(200),
+            &format!("/tmp/{id}.jsonl"),
+        )
+        .await;
+    }
+
+    run_git_sync(&db, |_| {}).await.unwrap();
+
+    let links = db.get_project_readme_links().await.unwrap();
+    assert_eq!(links.len(), 2);
+    let link_for = |repo: &std::path::Path| {
+        links
+            .iter()
+            .find(|l| l.project_path == repo.to_str().unwrap())
+            .unwrap()
+    };
+    let remote = link_for(&with_remote);
+    assert_eq!(remote.project_id, "p1");
+    assert_eq!(
+        remote.remote_url.as_deref(),
+        Some("git@github.com:acme/widgets.git")
+    );
+    assert_eq!(
+        remote.web_url.as_deref(),
+        Some("https://github.com/acme/widgets")
+    );
+    assert_eq!(remote.default_branch.as_deref(), Some("main"));
+
+    let local = link_for(&local_only);
+    assert_eq!(local.remote_url, None);
+    assert_eq!(local.web_url, None);
+    assert_eq!(local.default_branch.as_deref(), Some("main"));
+}
@@ -35,6 +35,43 @@ pub struct GitCommit {
     pub deletions: Option<u32>,
 }
 
+/// Where a branch stands in its repo as of the last git sync.
+///
+/// Variants are ordered most alive first, so `min` picks the status to show
+/// for a branch checked in several worktrees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BranchStatus {
+    /// Still exists locally and is not merged into HEAD (or is HEAD).
+    Active,
+    /// Still exists locally and is fully merged into HEAD.
+    Merged,
+    /// Not a local branch, but still exists on a remote.
+    Remote,
+    /// Exists neither locally nor on any remote.
+    Deleted,
+}
+
+impl BranchStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Merged => "merged",
+            Self::Remote => "remote",
+            Self::Deleted => "deleted",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "active" => Some(Self::Active),
+            "merged" => Some(Self::Merged),
+            "remote" => Some(Self::Remote),
+            "deleted" => Some(Self::Deleted),
+            _ => None,
+        }
+    }
+}
+
 /// Diff stats for a commit.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct DiffStats {
@@ -106,6 +143,60 @@ pub struct SessionSyncInfo {
     pub last_message_at: Option<i64>,
 }
 
+/// A session that edited files but has no linked commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UncommittedSession {
+    pub session_id: String,
+    pub project_id: String,
+    pub preview: String,
+    pub files_edited_count: i64,
+    pub last_message_at: i64,
+}
+
+/// Time from a session's last message to its first linked commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionCommitLag {
+    pub session_id: String,
+    /// Hash of the earliest linked commit.
+    pub commit_hash: String,
+    pub last_message_at: i64,
+    pub committed_at: i64,
+    /// `committed_at - last_message_at` in seconds. Negative when the
+    /// commit landed before the session's last message.
+    pub lag_seconds: i64,
+}
+
+/// Per-session commit lags plus their median.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommitLagReport {
+    /// Most recent session first.
+    pub sessions: Vec<SessionCommitLag>,
+    /// Median of `lag_seconds`; `None` when no session has a linked commit.
+    pub median_lag_seconds: Option<i64>,
+}
+
+/// Remote and default branch of a repo, as found by git sync.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RepoInfo {
+    /// URL of the `origin` remote (or the first remote), credentials removed.
+    /// `None` for a repo with no remote.
+    pub remote_url: Option<String>,
+    /// Browsable `https://` form of `remote_url`, when it has a host and path.
+    pub web_url: Option<String>,
+    /// `origin`'s default branch, else the checked-out branch.
+    pub default_branch: Option<String>,
+}
+
+/// A project's repo link, read back from `project_repos`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectRepoLink {
+    pub project_id: String,
+    pub project_path: String,
+    pub remote_url: Option<String>,
+    pub web_url: Option<String>,
+    pub default_branch: Option<String>,
+}
+
 /// Information needed to correlate a session with commits.
 #[derive(Debug, Clone)]
 pub struct SessionCorrelationInfo {
@@ -211,6 +211,7 @@ pub async fn ingest_backup_sessions(db: &Database) -> (usize, usize) {
                 meta.skills_used.clone(),
             );
             let work_type = classify_work_type(&work_type_input);
+            let work_type_alternates = work_type_alternates_json(&work_type_input);
 
             // Build ParsedSession -- mirrors scan_and_index_all exactly
             let preview = meta.first_user_prompt.clone().unwrap_or_default();
@@ -218,6 +219,7 @@ pub async fn ingest_backup_sessions(db: &Database) -> (usize, usize) {
             let message_count = (meta.user_prompt_count + meta.api_call_count) as i32;
             let total_cost_usd = calculate_per_turn_cost(&parse_result.turns, &pricing);
             let primary_model = compute_primary_model(&parse_result.turns);
+            let model_turn_counts = model_turn_counts_json(&parse_result.turns);
             let decompressed_len = decompressed.len() as i64;
 
             let parsed = ParsedSession {
@@ -242,6 +244,9 @@ pub async fn ingest_backup_sessions(db: &Database) -> (usize, usize) {
                 tool_counts_write: meta.tool_counts.write as i32,
                 files_touched: serde_json::to_string(&meta.files_touched).unwrap_or_default(),
                 skills_used: serde_json::to_string(&meta.skills_used).unwrap_or_default(),
+                mcp_tools: serde_json::to_string(&meta.mcp_tools).unwrap_or_default(),
+                bash_categories: serde_json::to_string(&meta.bash_categories).unwrap_or_default(),
+                invocation_args: serde_json::to_string(&meta.invocation_args).unwrap_or_default(),
                 user_prompt_count: meta.user_prompt_count as i32,
                 api_call_count: meta.api_call_count as i32,
                 tool_call_count: meta.tool_call_count as i32,
@@ -257,6 +262,7 @@ pub async fn ingest_backup_sessions(db: &Database) -> (usize, usize) {
                 cache_read_tokens: meta.cache_read_tokens as i64,
                 cache_creation_tokens: meta.cache_creation_tokens as i64,
                 thinking_block_count: meta.thinking_block_count as i32,
+                thinking_output_tokens: meta.thinking_output_tokens as i64,
                 turn_duration_avg_ms: dur_avg,
                 turn_duration_max_ms: dur_max,
                 turn_duration_total_ms: dur_total,
@@ -264,11 +270,13 @@ pub async fn ingest_backup_sessions(db: &Database) -> (usize, usize) {
                 api_retry_count: meta.api_retry_count as i32,
                 compaction_count: meta.compaction_count as i32,
                 hook_blocked_count: meta.hook_blocked_count as i32,
+                interruption_count: meta.interruption_count as i32,
                 agent_spawn_count: meta.agent_spawn_count as i32,
                 bash_progress_count: meta.bash_progress_count as i32,
                 hook_progress_count: meta.hook_progress_count as i32,
                 mcp_progress_count: meta.mcp_progress_count as i32,
                 summary_text: meta.summary_text.clone(),
+                summary_source: meta.summary_source.as_str().to_string(),
                 parse_version: CURRENT_PARSE_VERSION,
                 file_size_at_index: decompressed_len,
                 file_mtime_at_index: 0, // backup files don't have meaningful mtime
@@ -278,7 +286,9 @@ pub async fn ingest_backup_sessions(db: &Database) -> (usize, usize) {
                 ai_lines_added: meta.ai_lines_added as i64,
                 ai_lines_removed: meta.ai_lines_removed as i64,
                 work_type: Some(work_type.as_str().to_string()),
+                work_type_alternates,
                 primary_model,
+                model_turn_counts,
                 total_task_time_seconds: Some(meta.total_task_time_seconds as i64),
                 longest_task_seconds: meta.longest_task_seconds.map(|v| v as i64),
                 longest_task_preview: meta.longest_task_preview.clone(),
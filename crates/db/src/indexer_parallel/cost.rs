@@ -46,6 +46,20 @@ pub(crate) fn compute_primary_model(turns: &[claude_view_core::RawTurn]) -> Opti
         .map(|(model, _)| model.to_string())
 }
 
+/// Turns per model as a JSON object (`{"model_id": turns}`, keys sorted), so
+/// readers can see mid-session model switches that `primary_model` hides.
+/// `None` when the session has no assistant turns.
+pub(crate) fn model_turn_counts_json(turns: &[claude_view_core::RawTurn]) -> Option<String> {
+    if turns.is_empty() {
+        return None;
+    }
+    let mut counts: std::collections::BTreeMap<&str, i64> = std::collections::BTreeMap::new();
+    for turn in turns {
+        *counts.entry(&turn.model_id).or_insert(0) += 1;
+    }
+    serde_json::to_string(&counts).ok()
+}
+
 /// Compute total cost by summing per-turn costs (each turn = one API call).
 /// This avoids inflating cost by applying 200k tiered pricing to cumulative tokens.
 /// Returns `None` if any turn had tokens for an unpriced model.
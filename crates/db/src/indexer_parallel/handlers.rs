@@ -2,7 +2,7 @@
 // Handler functions for dispatched JSONL line parsing: assistant (typed + Value) and system lines.
 
 use super::cost::normalize_model_id;
-use super::helpers::extract_tool_input_file_path;
+use super::helpers::{extract_tool_input_file_path, truncate};
 use super::serde_types::*;
 use super::types::*;
 
@@ -78,6 +78,39 @@ pub(crate) fn should_count_usage_block(
     }
 }
 
+/// Estimate output tokens spent on thinking text (~4 chars per token).
+pub(crate) fn estimate_thinking_tokens(thinking_chars: usize) -> u64 {
+    thinking_chars.div_ceil(4) as u64
+}
+
+/// Attribute one assistant line's thinking text and counted output tokens to
+/// its API call.
+///
+/// Usage payloads report a single `output_tokens` figure per API call, so the
+/// thinking share of each call is estimated from its thinking text and clamped
+/// to that call's output when the parse finishes. Lines without both IDs are
+/// their own call, matching the usage dedup.
+pub(crate) fn record_call_thinking(
+    deep: &mut ExtendedMetadata,
+    msg_id: Option<&str>,
+    req_id: Option<&str>,
+    byte_offset: usize,
+    thinking_chars: usize,
+    counted_output: Option<u64>,
+) {
+    let counted_output = counted_output.unwrap_or(0);
+    if thinking_chars == 0 && counted_output == 0 {
+        return;
+    }
+    let key = match (msg_id, req_id) {
+        (Some(mid), Some(rid)) => format!("{}:{}", mid, rid),
+        _ => format!("@{}", byte_offset),
+    };
+    let call = deep.thinking_by_call.entry(key).or_default();
+    call.0 += estimate_thinking_tokens(thinking_chars);
+    call.1 += counted_output;
+}
+
 /// Handle an assistant line parsed via typed `AssistantLine` struct.
 /// Produces identical output to the Value-based path.
 ///
@@ -225,6 +258,13 @@ pub(crate) fn handle_assistant_line(
             diag.turns_extracted += 1;
         }
 
+        let counted_output = if count_usage_block {
+            message.usage.as_ref().and_then(|u| u.output_tokens)
+        } else {
+            None
+        };
+        let mut thinking_chars = 0;
+
         // Extract tool_use blocks from content
         match message.content {
             ContentResult::Blocks(blocks) => {
@@ -288,6 +328,7 @@ pub(crate) fn handle_assistant_line(
                         }
                         "thinking" => {
                             deep.thinking_block_count += 1;
+                            thinking_chars += block.thinking_chars;
                         }
                         _ => {}
                     }
@@ -298,6 +339,15 @@ pub(crate) fn handle_assistant_line(
             }
             ContentResult::Missing => {}
         }
+
+        record_call_thinking(
+            deep,
+            message.id.as_deref(),
+            parsed.request_id.as_deref(),
+            byte_offset,
+            thinking_chars,
+            counted_output,
+        );
     }
 }
 
@@ -439,6 +489,15 @@ pub(crate) fn handle_assistant_value(
         }
     }
 
+    let counted_output = if count_usage_block {
+        usage_value
+            .and_then(|u| u.get("output_tokens"))
+            .and_then(|v| v.as_u64())
+    } else {
+        None
+    };
+    let mut thinking_chars = 0;
+
     // Extract tool_use blocks from content
     let content = value.get("message").and_then(|m| m.get("content"));
     match content {
@@ -499,6 +558,10 @@ pub(crate) fn handle_assistant_value(
                     }
                     "thinking" => {
                         deep.thinking_block_count += 1;
+                        thinking_chars += block
+                            .get("thinking")
+                            .and_then(|v| v.as_str())
+                            .map_or(0, |t| t.chars().count());
                     }
                     _ => {}
                 }
@@ -509,6 +572,31 @@ pub(crate) fn handle_assistant_value(
         }
         _ => {}
     }
+
+    record_call_thinking(
+        deep,
+        msg_id,
+        req_id,
+        byte_offset,
+        thinking_chars,
+        counted_output,
+    );
+}
+
+/// Record the compact summary message that follows a compact boundary as the
+/// session summary, attributed to that boundary's trigger.
+pub(crate) fn record_compact_summary(deep: &mut ExtendedMetadata, content: &str) {
+    deep.summary_text = Some(truncate(content, 500));
+    deep.summary_source = deep.pending_compact_trigger;
+}
+
+/// Whether a user message's text is Claude Code's interruption marker itself,
+/// rather than a prompt or tool output that merely quotes it.
+pub(crate) fn is_interruption_marker(content: &str) -> bool {
+    matches!(
+        content.trim(),
+        "[Request interrupted by user]" | "[Request interrupted by user for tool use]"
+    )
 }
 
 /// Handle a system line parsed via typed `SystemLine` struct.
@@ -544,6 +632,12 @@ pub(crate) fn handle_system_line(
         }
         "compact_boundary" | "microcompact_boundary" => {
             deep.compaction_count += 1;
+            deep.pending_compact_trigger = SummarySource::from_compact_trigger(
+                parsed
+                    .compact_metadata
+                    .as_ref()
+                    .and_then(|m| m.trigger.as_deref()),
+            );
         }
         "stop_hook_summary" if parsed.prevented_continuation == Some(true) => {
             deep.hook_blocked_count += 1;
@@ -2,8 +2,11 @@
 // Utility functions: string extraction, line splitting, file path extraction,
 // content extraction, skill extraction, timestamp helpers.
 
+use claude_view_core::{rank_work_types, work_type_alternates, ClassificationInput};
 use memchr::memmem;
 
+use super::types::{RawInvocation, MAX_INVOCATION_ARGS_CHARS};
+
 pub(crate) const TOOL_INPUT_FILE_PATH_KEYS: [&str; 5] = [
     "file_path",
     "path",
@@ -79,20 +82,17 @@ pub(crate) fn extract_loc_from_assistant_tool_uses(line: &[u8]) -> (u32, u32) {
     (added, removed)
 }
 
-/// Extract timestamp from an already-parsed JSON value.
-/// Handles both ISO8601 strings and Unix integers.
+/// Extract timestamp from an already-parsed JSON value, in epoch seconds.
+/// Handles RFC3339 strings and epoch seconds or millis.
 pub(crate) fn extract_timestamp_from_value(value: &serde_json::Value) -> Option<i64> {
-    value.get("timestamp").and_then(|v| {
-        v.as_i64().or_else(|| {
-            v.as_str()
-                .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
-                .map(|dt| dt.timestamp())
-        })
-    })
+    value
+        .get("timestamp")
+        .and_then(claude_view_core::normalize_timestamp)
 }
 
 /// Extract timestamp from raw JSONL bytes without JSON parsing.
 /// Uses memmem::Finder to locate `"timestamp":` then parses the value inline.
+/// Epoch millis are converted to seconds, as in [`extract_timestamp_from_value`].
 pub(crate) fn extract_timestamp_from_bytes(line: &[u8], finder: &memmem::Finder) -> Option<i64> {
     let pos = finder.find(line)?;
     let rest = &line[pos + b"\"timestamp\":".len()..];
@@ -106,14 +106,13 @@ pub(crate) fn extract_timestamp_from_bytes(line: &[u8], finder: &memmem::Finder)
                 .iter()
                 .position(|&b| !(b == b'-' || b.is_ascii_digit()))
                 .unwrap_or(rest.len());
-            std::str::from_utf8(&rest[..end]).ok()?.parse().ok()
+            let raw: i64 = std::str::from_utf8(&rest[..end]).ok()?.parse().ok()?;
+            Some(claude_view_core::epoch_to_seconds(raw))
         }
         b'"' => {
             // ISO8601 string timestamp
             let s = extract_quoted_string(&rest[1..])?;
-            chrono::DateTime::parse_from_rfc3339(&s)
-                .ok()
-                .map(|dt| dt.timestamp())
+            claude_view_core::parse_timestamp_str(&s)
         }
         _ => None,
     }
@@ -149,25 +148,6 @@ pub(crate) fn split_lines_simd(data: &[u8]) -> impl Iterator<Item = &[u8]> {
     })
 }
 
-/// Split data into lines with byte offsets using SIMD-accelerated newline search.
-/// Returns `(byte_offset, line_slice)` for each line.
-pub(crate) fn split_lines_with_offsets(data: &[u8]) -> impl Iterator<Item = (usize, &[u8])> {
-    let mut start = 0;
-    let mut positions = memchr::memchr_iter(b'\n', data).chain(std::iter::once(data.len()));
-
-    std::iter::from_fn(move || {
-        if start > data.len() {
-            return None;
-        }
-        positions.next().map(|end| {
-            let offset = start;
-            let line = &data[start..end];
-            start = end + 1;
-            (offset, line)
-        })
-    })
-}
-
 /// Extract the first text content from a JSONL line (best-effort, no full JSON parse).
 pub(crate) fn extract_first_text_content(
     line: &[u8],
@@ -349,3 +329,133 @@ pub(crate) fn parse_hook_progress_line(
         source: "hook_progress".to_string(),
     })
 }
+
+/// Secondary work types as a JSON array of `{workType, score}`, best first.
+/// `None` when the session has no alternate worth showing.
+pub(crate) fn work_type_alternates_json(input: &ClassificationInput) -> Option<String> {
+    let alternates = work_type_alternates(&rank_work_types(input));
+    if alternates.is_empty() {
+        return None;
+    }
+    serde_json::to_string(&alternates).ok()
+}
+
+/// Bash calls among `invocations` counted by command category. Calls
+/// without a `command` string are skipped.
+pub(crate) fn collect_bash_categories(
+    invocations: &[RawInvocation],
+) -> std::collections::BTreeMap<String, u32> {
+    let mut counts = std::collections::BTreeMap::new();
+    for inv in invocations.iter().filter(|inv| inv.name == "Bash") {
+        let Some(command) = inv
+            .input
+            .as_ref()
+            .and_then(|input| input.get("command"))
+            .and_then(|c| c.as_str())
+        else {
+            continue;
+        };
+        let category = claude_view_core::classify_bash_command(command);
+        *counts.entry(category.as_str().to_string()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Trim `args` and cap it at [`MAX_INVOCATION_ARGS_CHARS`] characters,
+/// marking a cut with `…`. Blank arguments become `None`.
+pub(crate) fn truncate_invocation_args(args: &str) -> Option<String> {
+    let args = args.trim();
+    if args.is_empty() {
+        return None;
+    }
+    match args.char_indices().nth(MAX_INVOCATION_ARGS_CHARS) {
+        Some((cut, _)) => Some(format!("{}…", &args[..cut])),
+        None => Some(args.to_string()),
+    }
+}
+
+/// Command and arguments of a slash command the user typed, from the raw
+/// (still JSON-escaped) text of a user message such as
+/// `<command-name>/commit</command-name><command-args>--amend</command-args>`.
+///
+/// Returns `None` when the message is not a slash command.
+pub(crate) fn parse_slash_command(raw_content: &str) -> Option<(String, Option<String>)> {
+    fn tag<'a>(content: &'a str, name: &str) -> Option<&'a str> {
+        let open = format!("<{name}>");
+        let start = content.find(&open)? + open.len();
+        let end = content[start..].find(&format!("</{name}>"))?;
+        Some(&content[start..start + end])
+    }
+
+    if !raw_content.trim_start().starts_with("<command-") {
+        return None;
+    }
+    let command = tag(raw_content, "command-name")?.trim();
+    if command.is_empty() {
+        return None;
+    }
+    let args = tag(raw_content, "command-args").and_then(|raw| {
+        let unescaped: String = serde_json::from_str(&format!("\"{raw}\"")).ok()?;
+        truncate_invocation_args(&unescaped)
+    });
+    Some((command.to_string(), args))
+}
+
+/// Count `args` under `name` in an [`invocation_args`] breakdown.
+///
+/// [`invocation_args`]: super::types::ExtendedMetadata::invocation_args
+pub(crate) fn record_invocation_args(
+    counts: &mut std::collections::BTreeMap<String, std::collections::BTreeMap<String, u32>>,
+    name: &str,
+    args: String,
+) {
+    *counts
+        .entry(name.to_string())
+        .or_default()
+        .entry(args)
+        .or_insert(0) += 1;
+}
+
+/// Argument strings of the `Skill` and `SlashCommand` calls among
+/// `invocations`, added to `counts`. A skill is keyed by its name, a slash
+/// command by the command itself (`/test` of `/test --watch`). Calls
+/// without arguments are skipped.
+pub(crate) fn collect_invocation_args(
+    invocations: &[RawInvocation],
+    counts: &mut std::collections::BTreeMap<String, std::collections::BTreeMap<String, u32>>,
+) {
+    for inv in invocations {
+        let Some(input) = inv.input.as_ref() else {
+            continue;
+        };
+        let parsed = match inv.name.as_str() {
+            "Skill" => input
+                .get("skill")
+                .and_then(|s| s.as_str())
+                .and_then(|skill| {
+                    let args = input.get("args")?.as_str()?;
+                    Some((skill, truncate_invocation_args(args)?))
+                }),
+            "SlashCommand" => input
+                .get("command")
+                .and_then(|c| c.as_str())
+                .and_then(|command| command.trim().split_once(char::is_whitespace))
+                .and_then(|(command, args)| Some((command, truncate_invocation_args(args)?))),
+            _ => None,
+        };
+        if let Some((name, args)) = parsed {
+            record_invocation_args(counts, name, args);
+        }
+    }
+}
+
+/// Distinct MCP tools among `invocations`, split by server, sorted.
+pub(crate) fn collect_mcp_tools(invocations: &[RawInvocation]) -> Vec<claude_view_core::McpTool> {
+    let mut tools: Vec<_> = invocations
+        .iter()
+        .filter_map(|inv| claude_view_core::McpTool::parse(&inv.name))
+        .collect();
+    tools.sort();
+    tools.dedup();
+    tools
+}
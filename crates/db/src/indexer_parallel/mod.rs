@@ -20,10 +20,10 @@ pub(crate) mod writer;
 pub use backup::ingest_backup_sessions;
 pub use helpers::extract_commit_skill_invocations;
 pub use orchestrator::scan_and_index_all;
-pub use parser::parse_bytes;
+pub use parser::{parse_bytes, parse_reader};
 pub use pipeline::{build_index_hints, prune_stale_sessions};
 pub use types::{
     read_file_fast, CommitSkillInvocation, DeepIndexResult, ExtendedMetadata, FileData, IndexHints,
-    ParseDiagnostics, ParseResult, ParsedSession, RawInvocation, COMMIT_SKILL_NAMES,
-    CURRENT_PARSE_VERSION,
+    ParseDiagnostics, ParseResult, ParsedSession, RawInvocation, SummarySource, COMMIT_SKILL_NAMES,
+    CURRENT_PARSE_VERSION, MAX_INVOCATION_ARGS_CHARS,
 };
@@ -1,10 +1,11 @@
 // crates/db/src/indexer_parallel/orchestrator/discovery.rs
-// Filesystem walk to collect all .jsonl session files under ~/.claude/projects/.
+// Filesystem walk to collect all .jsonl (and .jsonl.gz) session files under ~/.claude/projects/.
 
 use std::path::{Path, PathBuf};
 
 /// Collect all `.jsonl` files at depth 2: `{projects_dir}/{project_encoded}/{session_id}.jsonl`.
-/// Returns `(file_path, project_encoded, session_id)` triples.
+/// Gzipped `{session_id}.jsonl.gz` files count too, unless the plain file is
+/// also present. Returns `(file_path, project_encoded, session_id)` triples.
 #[tracing::instrument(skip_all)]
 pub(crate) fn discover_jsonl_files(
     projects_dir: &Path,
@@ -31,14 +32,18 @@ pub(crate) fn discover_jsonl_files(
 
         for file_entry in session_entries.flatten() {
             let file_path = file_entry.path();
-            if file_path.extension().and_then(|e| e.to_str()) != Some("jsonl") {
+            let file_name = file_entry.file_name();
+            let Some((session_id, is_gz)) = file_name
+                .to_str()
+                .and_then(claude_view_core::jsonl_reader::split_session_file_name)
+            else {
+                continue;
+            };
+            // `x.jsonl.gz` -> `x.jsonl`: the plain copy wins.
+            if is_gz && file_path.with_extension("").exists() {
                 continue;
             }
-            let session_id = file_path
-                .file_stem()
-                .unwrap_or_default()
-                .to_string_lossy()
-                .to_string();
+            let session_id = session_id.to_string();
             files.push((file_path, project_encoded.clone(), session_id));
         }
     }
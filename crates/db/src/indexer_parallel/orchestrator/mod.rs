@@ -5,6 +5,8 @@ mod discovery;
 mod phase_finalize;
 mod phase_parse;
 mod phase_write;
+mod previews;
+mod recompute;
 
 use claude_view_core::Registry;
 use std::collections::HashMap;
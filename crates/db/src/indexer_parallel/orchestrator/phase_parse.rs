@@ -203,7 +203,7 @@ where
 
 /// Build the final `IndexedSession` from parse results and resolved metadata.
 #[allow(clippy::too_many_arguments)]
-fn build_indexed_session(
+pub(super) fn build_indexed_session(
     path: &Path,
     session_id: &str,
     effective_encoded: &str,
@@ -242,19 +242,29 @@ fn build_indexed_session(
         meta.skills_used.clone(),
     );
     let work_type = classify_work_type(&work_type_input);
+    let work_type_alternates = work_type_alternates_json(&work_type_input);
     let primary_model = compute_primary_model(&parse_result.turns);
+    let model_turn_counts = model_turn_counts_json(&parse_result.turns);
 
     let files_touched_json =
         serde_json::to_string(&meta.files_touched).unwrap_or_else(|_| "[]".to_string());
     let skills_used_json =
         serde_json::to_string(&meta.skills_used).unwrap_or_else(|_| "[]".to_string());
+    let mcp_tools_json =
+        serde_json::to_string(&meta.mcp_tools).unwrap_or_else(|_| "[]".to_string());
+    let bash_categories_json =
+        serde_json::to_string(&meta.bash_categories).unwrap_or_else(|_| "{}".to_string());
+    let invocation_args_json =
+        serde_json::to_string(&meta.invocation_args).unwrap_or_else(|_| "{}".to_string());
     let files_read_json =
         serde_json::to_string(&meta.files_read).unwrap_or_else(|_| "[]".to_string());
     let files_edited_json =
         serde_json::to_string(&meta.files_edited).unwrap_or_else(|_| "[]".to_string());
 
     let git_branch = parse_result.git_branch.clone().or(git_branch_hint);
-    let summary = meta.summary_text.clone().or(summary_hint);
+    // The sessions-index summary wins; a parsed summary line or compact
+    // summary only fills in when there is none (it stays in `summary_text`).
+    let summary = summary_hint.or_else(|| meta.summary_text.clone());
     let preview = meta.first_user_prompt.clone().unwrap_or_default();
     let message_count = (meta.user_prompt_count + meta.api_call_count) as i32;
 
@@ -314,6 +324,9 @@ fn build_indexed_session(
         tool_counts_write: meta.tool_counts.write as i32,
         files_touched: files_touched_json,
         skills_used: skills_used_json,
+        mcp_tools: mcp_tools_json,
+        bash_categories: bash_categories_json,
+        invocation_args: invocation_args_json,
         user_prompt_count: meta.user_prompt_count as i32,
         api_call_count: meta.api_call_count as i32,
         tool_call_count: meta.tool_call_count as i32,
@@ -329,6 +342,7 @@ fn build_indexed_session(
         cache_read_tokens: meta.cache_read_tokens as i64,
         cache_creation_tokens: meta.cache_creation_tokens as i64,
         thinking_block_count: meta.thinking_block_count as i32,
+        thinking_output_tokens: meta.thinking_output_tokens as i64,
         turn_duration_avg_ms: dur_avg,
         turn_duration_max_ms: dur_max,
         turn_duration_total_ms: dur_total,
@@ -336,11 +350,13 @@ fn build_indexed_session(
         api_retry_count: meta.api_retry_count as i32,
         compaction_count: meta.compaction_count as i32,
         hook_blocked_count: meta.hook_blocked_count as i32,
+        interruption_count: meta.interruption_count as i32,
         agent_spawn_count: meta.agent_spawn_count as i32,
         bash_progress_count: meta.bash_progress_count as i32,
         hook_progress_count: meta.hook_progress_count as i32,
         mcp_progress_count: meta.mcp_progress_count as i32,
         summary_text: meta.summary_text.clone(),
+        summary_source: meta.summary_source.as_str().to_string(),
         parse_version: CURRENT_PARSE_VERSION,
         file_size_at_index: current_size,
         file_mtime_at_index: current_mtime,
@@ -350,7 +366,9 @@ fn build_indexed_session(
         ai_lines_added: meta.ai_lines_added as i64,
         ai_lines_removed: meta.ai_lines_removed as i64,
         work_type: Some(work_type.as_str().to_string()),
+        work_type_alternates,
         primary_model,
+        model_turn_counts,
         total_task_time_seconds: Some(meta.total_task_time_seconds as i64),
         longest_task_seconds: meta.longest_task_seconds.map(|v| v as i64),
         longest_task_preview: meta.longest_task_preview.clone(),
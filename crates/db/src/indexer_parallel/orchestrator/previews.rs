@@ -0,0 +1,65 @@
+// crates/db/src/indexer_parallel/orchestrator/previews.rs
+// Backfill empty `preview` columns left behind by older parsers.
+
+use std::path::PathBuf;
+
+use claude_view_core::session_stats::extract_stats;
+
+use crate::{Database, DbResult};
+
+impl Database {
+    /// Re-derive `preview` from the first user message for sessions whose
+    /// stored preview is empty, without touching any other column.
+    ///
+    /// Only rows with at least one user prompt are considered, so sessions
+    /// that genuinely have no user text aren't re-read on every call.
+    /// Missing or unreadable files are skipped. Returns how many previews
+    /// were filled.
+    pub async fn regenerate_previews(&self) -> DbResult<usize> {
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            "SELECT session_id, file_path FROM session_stats \
+             WHERE TRIM(preview) = '' AND user_prompt_count > 0 AND file_path != ''",
+        )
+        .fetch_all(self.pool())
+        .await?;
+        if rows.is_empty() {
+            return Ok(0);
+        }
+
+        let previews: Vec<(String, String)> = tokio::task::spawn_blocking(move || {
+            rows.into_iter()
+                .filter_map(|(session_id, file_path)| {
+                    let path = PathBuf::from(&file_path);
+                    let is_compressed = path
+                        .extension()
+                        .and_then(|e| e.to_str())
+                        .is_some_and(|e| e.eq_ignore_ascii_case("gz"));
+                    match extract_stats(&path, is_compressed) {
+                        Ok(stats) if !stats.preview.trim().is_empty() => {
+                            Some((session_id, stats.preview))
+                        }
+                        Ok(_) => None,
+                        Err(e) => {
+                            tracing::debug!(session_id, error = %e, "preview regeneration skipped");
+                            None
+                        }
+                    }
+                })
+                .collect()
+        })
+        .await
+        .unwrap_or_default();
+
+        let mut tx = self.pool().begin().await?;
+        for (session_id, preview) in &previews {
+            sqlx::query("UPDATE session_stats SET preview = ?1 WHERE session_id = ?2")
+                .bind(preview)
+                .bind(session_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+
+        Ok(previews.len())
+    }
+}
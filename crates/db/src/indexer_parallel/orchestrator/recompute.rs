@@ -0,0 +1,108 @@
+// crates/db/src/indexer_parallel/orchestrator/recompute.rs
+// Single-session Pass 2: re-parse one JSONL file and rewrite its row.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::Database;
+
+use super::super::cost::load_indexing_pricing;
+use super::super::parser::{merge_subagent_workload, parse_file_bytes};
+use super::phase_parse::build_indexed_session;
+use super::phase_write::run_phase_write;
+
+impl Database {
+    /// Re-read one session's JSONL and rewrite its `session_stats` row from a
+    /// fresh parse, bypassing the size/mtime/parse-version staleness check.
+    ///
+    /// Use after a parser fix to refresh a single session without a full
+    /// rescan. Project identity, sidechain flag, and the branch/summary
+    /// fallbacks come from the stored row. Session text search greps the
+    /// JSONL directly, so there is no search index to rebuild.
+    ///
+    /// Returns `Ok(false)` if the session has no row. Errors if its file is
+    /// missing or has no parseable timestamps.
+    pub async fn recompute_session_aggregates(&self, session_id: &str) -> Result<bool, String> {
+        #[allow(clippy::type_complexity)]
+        let row: Option<(
+            String,
+            String,
+            String,
+            String,
+            bool,
+            Option<String>,
+            Option<String>,
+        )> = sqlx::query_as(
+            "SELECT file_path, project_id, COALESCE(project_display_name, ''), \
+                        COALESCE(project_path, ''), is_sidechain, git_branch, summary \
+                 FROM session_stats WHERE session_id = ?1",
+        )
+        .bind(session_id)
+        .fetch_optional(self.pool())
+        .await
+        .map_err(|e| format!("Failed to load session {session_id}: {e}"))?;
+        let Some((
+            file_path,
+            project_id,
+            project_display_name,
+            project_path,
+            is_sidechain,
+            git_branch,
+            summary,
+        )) = row
+        else {
+            return Ok(false);
+        };
+
+        let path = Path::new(&file_path).to_path_buf();
+        let metadata = std::fs::metadata(&path)
+            .map_err(|e| format!("Failed to stat {}: {e}", path.display()))?;
+        let current_size = metadata.len() as i64;
+        let current_mtime = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+
+        let path_for_parse = path.clone();
+        let mut parse_result = tokio::task::spawn_blocking(move || {
+            let mut result = parse_file_bytes(&path_for_parse);
+            merge_subagent_workload(&path_for_parse, &mut result);
+            result
+        })
+        .await
+        .map_err(|e| format!("spawn_blocking join error: {e}"))?;
+
+        if parse_result.deep.last_timestamp.is_none() {
+            return Err(format!("No parseable timestamps in {}", path.display()));
+        }
+
+        let session = build_indexed_session(
+            &path,
+            session_id,
+            &project_id,
+            project_display_name,
+            project_path,
+            is_sidechain,
+            git_branch,
+            summary,
+            current_size,
+            current_mtime,
+            &mut parse_result,
+            None,
+            &load_indexing_pricing(),
+        );
+
+        let seen_at = chrono::Utc::now().timestamp();
+        run_phase_write(
+            self,
+            std::slice::from_ref(&session),
+            seen_at,
+            &Arc::new(|_: &str| {}),
+        )
+        .await?;
+
+        Ok(true)
+    }
+}
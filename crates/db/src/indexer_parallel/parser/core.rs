@@ -1,6 +1,9 @@
 // crates/db/src/indexer_parallel/parser/core.rs
 // Full JSON parser that extracts all 7 JSONL line types.
 
+use std::io::{self, BufRead};
+
+use claude_view_core::file_paths::normalize_file_path;
 use claude_view_core::{count_ai_lines, is_human_tool_result_content, is_system_user_content};
 use memchr::memmem;
 
@@ -9,15 +12,34 @@ use crate::indexer_parallel::helpers::*;
 use crate::indexer_parallel::serde_types::*;
 use crate::indexer_parallel::types::*;
 
+use super::lines::{LineSource, ReaderLines, SliceLines};
+
 /// Full JSON parser that extracts all 7 JSONL line types.
 ///
 /// Returns a `ParseResult` containing deep metadata (tool counts, skills, token usage,
 /// system metrics, progress counts, etc.) and raw tool_use invocations for downstream
 /// classification.
 pub fn parse_bytes(data: &[u8]) -> ParseResult {
+    parse_lines(&mut SliceLines::new(data))
+}
+
+/// Streaming variant of [`parse_bytes`] for huge transcripts.
+///
+/// Reads one line at a time from `reader`, so memory is bounded by the
+/// longest line plus the aggregates, not by the file size. Produces the same
+/// `ParseResult` as `parse_bytes` on the same input.
+pub fn parse_reader<R: BufRead>(reader: R) -> io::Result<ParseResult> {
+    let mut lines = ReaderLines::new(reader);
+    let result = parse_lines(&mut lines);
+    match lines.error {
+        Some(e) => Err(e),
+        None => Ok(result),
+    }
+}
+
+fn parse_lines(lines: &mut impl LineSource) -> ParseResult {
     let mut result = ParseResult::default();
     let diag = &mut result.diagnostics;
-    diag.bytes_total = data.len() as u64;
 
     let mut user_count = 0u32;
     let mut assistant_count = 0u32;
@@ -71,6 +93,14 @@ pub fn parse_bytes(data: &[u8]) -> ParseResult {
     let tool_result_rejected_finder =
         memmem::Finder::new(b"\"toolUseResult\":\"User rejected tool use\"");
 
+    // User interruption (ESC mid-turn): Claude Code records a user line whose
+    // text is "[Request interrupted by user]" or "... for tool use]". The
+    // finder is only a prefilter; `is_interruption_marker` makes the call.
+    let interrupted_finder = memmem::Finder::new(b"[Request interrupted by user");
+
+    // Compact summary: the user message injected right after a compact boundary
+    let compact_summary_finder = memmem::Finder::new(b"\"isCompactSummary\":true");
+
     // gitBranch extraction
     let git_branch_finder = memmem::Finder::new(b"\"gitBranch\":\"");
 
@@ -87,7 +117,7 @@ pub fn parse_bytes(data: &[u8]) -> ParseResult {
     let cv_trace_id_finder = memmem::Finder::new(b"\"claude_view_trace_id\":\"");
     let cv_cli_session_id_finder = memmem::Finder::new(b"\"claude_view_cli_session_id\":\"");
 
-    for (byte_offset, line) in split_lines_with_offsets(data) {
+    while let Some((byte_offset, line)) = lines.next_line() {
         if line.is_empty() {
             diag.lines_empty += 1;
             continue;
@@ -212,7 +242,14 @@ pub fn parse_bytes(data: &[u8]) -> ParseResult {
             let has_interactive_tool_result_marker = is_tool_result
                 && (tool_result_questions_finder.find(line).is_some()
                     || tool_result_rejected_finder.find(line).is_some());
+            let maybe_interrupted = !is_tool_result && interrupted_finder.find(line).is_some();
             if let Some(content) = extract_first_text_content(line, &content_finder, &text_finder) {
+                if maybe_interrupted && is_interruption_marker(&content) {
+                    result.deep.interruption_count += 1;
+                }
+                if compact_summary_finder.find(line).is_some() {
+                    record_compact_summary(&mut result.deep, &content);
+                }
                 let is_human_tool_result = is_tool_result
                     && (has_interactive_tool_result_marker
                         || is_human_tool_result_content(&content));
@@ -224,6 +261,10 @@ pub fn parse_bytes(data: &[u8]) -> ParseResult {
                 }
                 last_user_content = Some(content.clone());
 
+                if let Some((command, Some(args))) = parse_slash_command(&content) {
+                    record_invocation_args(&mut result.deep.invocation_args, &command, args);
+                }
+
                 if is_real_user_input {
                     let current_ts = extract_timestamp_from_bytes(line, &timestamp_finder);
                     if let (Some(start_ts), Some(end_ts)) =
@@ -344,10 +385,17 @@ pub fn parse_bytes(data: &[u8]) -> ParseResult {
                 diag.lines_user += 1;
                 user_count += 1;
                 let is_tool_result = tool_result_finder.find(line).is_some();
+                let maybe_interrupted = !is_tool_result && interrupted_finder.find(line).is_some();
                 let fallback_user_ts = extract_timestamp_from_value(&value);
                 if let Some(content) =
                     extract_first_text_content(line, &content_finder, &text_finder)
                 {
+                    if maybe_interrupted && is_interruption_marker(&content) {
+                        result.deep.interruption_count += 1;
+                    }
+                    if value.get("isCompactSummary").and_then(|v| v.as_bool()) == Some(true) {
+                        record_compact_summary(&mut result.deep, &content);
+                    }
                     let has_interactive_tool_result_marker =
                         value.get("toolUseResult").is_some_and(|tr| {
                             tr.get("questions").is_some()
@@ -364,6 +412,10 @@ pub fn parse_bytes(data: &[u8]) -> ParseResult {
                     }
                     last_user_content = Some(content.clone());
 
+                    if let Some((command, Some(args))) = parse_slash_command(&content) {
+                        record_invocation_args(&mut result.deep.invocation_args, &command, args);
+                    }
+
                     if is_real_user_input {
                         let current_ts = fallback_user_ts;
                         if let (Some(start_ts), Some(end_ts)) =
@@ -429,6 +481,12 @@ pub fn parse_bytes(data: &[u8]) -> ParseResult {
                     }
                     "compact_boundary" | "microcompact_boundary" => {
                         result.deep.compaction_count += 1;
+                        result.deep.pending_compact_trigger = SummarySource::from_compact_trigger(
+                            value
+                                .get("compactMetadata")
+                                .and_then(|m| m.get("trigger"))
+                                .and_then(|t| t.as_str()),
+                        );
                     }
                     "stop_hook_summary"
                         if value.get("preventedContinuation").and_then(|v| v.as_bool())
@@ -471,12 +529,24 @@ pub fn parse_bytes(data: &[u8]) -> ParseResult {
                 diag.lines_file_snapshot += 1;
                 result.deep.file_snapshot_count += 1;
             }
+            "summary" => {
+                if let Some(text) = value
+                    .get("summary")
+                    .and_then(|s| s.as_str())
+                    .filter(|s| !s.trim().is_empty())
+                {
+                    result.deep.summary_text = Some(text.to_string());
+                    result.deep.summary_source = SummarySource::Auto;
+                }
+            }
             _ => {
                 diag.lines_unknown_type += 1;
             }
         }
     }
 
+    result.diagnostics.bytes_total = lines.bytes_read();
+
     // Close the final turn at EOF using the last known timestamp
     if let (Some(start_ts), Some(end_ts)) = (result.deep.current_turn_start_ts, last_timestamp) {
         let wall_secs = (end_ts - start_ts).max(0) as u32;
@@ -501,6 +571,16 @@ pub fn parse_bytes(data: &[u8]) -> ParseResult {
     result.deep.api_call_count = unique_api_call_count;
     result.deep.tool_call_count = tool_call_count;
 
+    // Normalize paths so `./a.rs`, `a.rs` and `<cwd>/a.rs` dedup as one file.
+    let cwd = result.cwd.clone();
+    for path in files_read_all
+        .iter_mut()
+        .chain(files_edited_all.iter_mut())
+        .chain(result.deep.files_touched.iter_mut())
+    {
+        *path = normalize_file_path(path, cwd.as_deref());
+    }
+
     // files_read: deduplicated
     let mut files_read_unique = files_read_all.clone();
     files_read_unique.sort();
@@ -535,6 +615,16 @@ pub fn parse_bytes(data: &[u8]) -> ParseResult {
         _ => 0,
     };
 
+    // A call's thinking share can never exceed the output it reported
+    result.deep.thinking_output_tokens = std::mem::take(&mut result.deep.thinking_by_call)
+        .into_values()
+        .map(|(thinking, output)| thinking.min(output))
+        .sum();
+
+    result.deep.mcp_tools = collect_mcp_tools(&result.raw_invocations);
+    result.deep.bash_categories = collect_bash_categories(&result.raw_invocations);
+    collect_invocation_args(&result.raw_invocations, &mut result.deep.invocation_args);
+
     // Deduplicate
     result.deep.skills_used.sort();
     result.deep.skills_used.dedup();
@@ -1,16 +1,35 @@
 // crates/db/src/indexer_parallel/parser/file_io.rs
-// File-level JSONL reading with mmap for large files.
+// File-level JSONL reading with mmap for large files and streaming for huge
+// (or gzip-compressed) ones.
 
-use super::core::parse_bytes;
+use std::io::{Read, Seek};
+
+use super::core::{parse_bytes, parse_reader};
 use crate::indexer_parallel::types::*;
 
-/// Parse a JSONL file from disk, using mmap for large files.
-/// Returns a default ParseResult on any I/O error.
+/// Files at or above this size are parsed line-by-line through a buffered
+/// reader instead of being mapped whole, so resident memory stays bounded on
+/// multi-hundred-MB transcripts.
+pub(crate) const STREAMING_PARSE_THRESHOLD: usize = 128 * 1024 * 1024;
+
+/// Parse a JSONL file from disk, using mmap for large files and a streaming
+/// reader for huge ones. Gzip transcripts (`.jsonl.gz` or gzip magic bytes)
+/// are streamed through a decoder. Returns a default ParseResult on any I/O
+/// error.
 pub(crate) fn parse_file_bytes(path: &std::path::Path) -> ParseResult {
-    let file = match std::fs::File::open(path) {
+    let mut file = match std::fs::File::open(path) {
         Ok(f) => f,
         Err(_) => return ParseResult::default(),
     };
+    let mut head = [0u8; 2];
+    let head_len = file.read(&mut head).unwrap_or(0);
+    if claude_view_core::jsonl_reader::is_gzip(path, &head[..head_len]) {
+        if file.rewind().is_err() {
+            return ParseResult::default();
+        }
+        let decoder = flate2::read::GzDecoder::new(std::io::BufReader::new(file));
+        return parse_reader(std::io::BufReader::new(decoder)).unwrap_or_default();
+    }
     let len = match file.metadata() {
         Ok(m) => m.len() as usize,
         Err(_) => return ParseResult::default(),
@@ -24,6 +43,9 @@ pub(crate) fn parse_file_bytes(path: &std::path::Path) -> ParseResult {
             Err(_) => return ParseResult::default(),
         }
     }
+    if len >= STREAMING_PARSE_THRESHOLD {
+        return parse_reader(std::io::BufReader::new(file)).unwrap_or_default();
+    }
     match unsafe { memmap2::Mmap::map(&file) } {
         Ok(mmap) => parse_bytes(&mmap),
         Err(_) => match std::fs::read(path) {
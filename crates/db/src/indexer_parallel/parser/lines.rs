@@ -0,0 +1,100 @@
+// crates/db/src/indexer_parallel/parser/lines.rs
+// Line sources for the JSONL parser: borrowed byte slices and streaming readers.
+
+use std::io::{self, BufRead};
+
+/// A source of JSONL lines paired with their starting byte offset.
+///
+/// Lines are lent one at a time so a streaming source can reuse a single
+/// buffer instead of holding the whole file in memory. Line terminators are
+/// stripped. Both sources split identically (including the empty segment
+/// after a trailing newline) so they produce identical diagnostics.
+pub(crate) trait LineSource {
+    fn next_line(&mut self) -> Option<(usize, &[u8])>;
+
+    /// Total bytes consumed so far.
+    fn bytes_read(&self) -> u64;
+}
+
+/// Lines of an in-memory buffer (file read, mmap, or decompressed backup).
+pub(crate) struct SliceLines<'a> {
+    data: &'a [u8],
+    start: usize,
+}
+
+impl<'a> SliceLines<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data, start: 0 }
+    }
+}
+
+impl LineSource for SliceLines<'_> {
+    fn next_line(&mut self) -> Option<(usize, &[u8])> {
+        if self.start > self.data.len() {
+            return None;
+        }
+        let offset = self.start;
+        let rest = &self.data[offset..];
+        let end = memchr::memchr(b'\n', rest).map_or(self.data.len(), |i| offset + i);
+        self.start = end + 1;
+        Some((offset, &self.data[offset..end]))
+    }
+
+    fn bytes_read(&self) -> u64 {
+        self.data.len() as u64
+    }
+}
+
+/// Lines read incrementally from a `BufRead`. Only the current line is held
+/// in memory. The first I/O error ends iteration and is kept in `error`.
+pub(crate) struct ReaderLines<R> {
+    reader: R,
+    buf: Vec<u8>,
+    offset: usize,
+    done: bool,
+    pub(crate) error: Option<io::Error>,
+}
+
+impl<R: BufRead> ReaderLines<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: Vec::new(),
+            offset: 0,
+            done: false,
+            error: None,
+        }
+    }
+}
+
+impl<R: BufRead> LineSource for ReaderLines<R> {
+    fn next_line(&mut self) -> Option<(usize, &[u8])> {
+        if self.done {
+            return None;
+        }
+        self.buf.clear();
+        let start = self.offset;
+        match self.reader.read_until(b'\n', &mut self.buf) {
+            Ok(n) => {
+                self.offset += n;
+                if self.buf.last() == Some(&b'\n') {
+                    self.buf.pop();
+                } else {
+                    // EOF: a partial last line, or the empty segment after a
+                    // trailing newline. Either way this is the final line.
+                    self.done = true;
+                }
+                Some((start, &self.buf))
+            }
+            Err(e) => {
+                self.done = true;
+                self.error = Some(e);
+                None
+            }
+        }
+    }
+
+    fn bytes_read(&self) -> u64 {
+        self.offset as u64
+    }
+}
@@ -1,10 +1,13 @@
 // crates/db/src/indexer_parallel/parser/mod.rs
-// Core JSONL parser: parse_bytes(), parse_file_bytes(), and subagent merge logic.
+// Core JSONL parser: parse_bytes(), parse_reader(), parse_file_bytes(), and subagent merge logic.
 
 mod core;
 mod file_io;
+mod lines;
 mod subagent;
 
-pub use self::core::parse_bytes;
+pub use self::core::{parse_bytes, parse_reader};
 pub(crate) use file_io::parse_file_bytes;
 pub(crate) use subagent::merge_subagent_workload;
+#[cfg(test)]
+pub(crate) use subagent::merge_subagent_workload_to_depth;
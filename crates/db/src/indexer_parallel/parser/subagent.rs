@@ -1,7 +1,11 @@
 // crates/db/src/indexer_parallel/parser/subagent.rs
 // Subagent workload merging: merge parse results and recompute productivity metrics.
 
+use std::collections::HashMap;
+use std::path::PathBuf;
+
 use claude_view_core::count_ai_lines;
+use claude_view_core::discovery::{discover_subagent_files, subagent_depth};
 
 use super::file_io::parse_file_bytes;
 use crate::indexer_parallel::helpers::*;
@@ -11,6 +15,7 @@ pub(crate) fn merge_subagent_parse_result(parent: &mut ParseResult, subagent: Pa
     // Preserve existing token + turn merge behavior.
     parent.deep.total_input_tokens += subagent.deep.total_input_tokens;
     parent.deep.total_output_tokens += subagent.deep.total_output_tokens;
+    parent.deep.thinking_output_tokens += subagent.deep.thinking_output_tokens;
     parent.deep.cache_read_tokens += subagent.deep.cache_read_tokens;
     parent.deep.cache_creation_tokens += subagent.deep.cache_creation_tokens;
 
@@ -28,6 +33,12 @@ pub(crate) fn merge_subagent_parse_result(parent: &mut ParseResult, subagent: Pa
         .files_touched
         .extend(subagent.deep.files_touched);
     parent.deep.skills_used.extend(subagent.deep.skills_used);
+    for (name, args) in subagent.deep.invocation_args {
+        let parent_args = parent.deep.invocation_args.entry(name).or_default();
+        for (args, count) in args {
+            *parent_args.entry(args).or_insert(0) += count;
+        }
+    }
     parent
         .deep
         .hook_progress_events
@@ -68,38 +79,53 @@ fn recompute_merged_productivity_metrics(result: &mut ParseResult) {
     result.deep.ai_lines_added = ai_line_count.lines_added;
     result.deep.ai_lines_removed = ai_line_count.lines_removed;
 
+    result.deep.mcp_tools = collect_mcp_tools(&result.raw_invocations);
+    result.deep.bash_categories = collect_bash_categories(&result.raw_invocations);
+
     result.deep.skills_used.sort();
     result.deep.skills_used.dedup();
     result.deep.files_touched.sort();
     result.deep.files_touched.dedup();
 }
 
+/// [`merge_subagent_workload_to_depth`] with the configured
+/// [`subagent_depth`] (`CLAUDE_VIEW_SUBAGENT_DEPTH`).
 pub(crate) fn merge_subagent_workload(
     parent_jsonl_path: &std::path::Path,
     result: &mut ParseResult,
 ) {
-    let subagent_dir = parent_jsonl_path.with_extension("").join("subagents");
-    if !subagent_dir.is_dir() {
-        return;
-    }
-
-    let Ok(entries) = std::fs::read_dir(&subagent_dir) else {
-        return;
-    };
-
-    let mut subagent_jsonls: Vec<std::path::PathBuf> = entries
-        .filter_map(|entry| entry.ok().map(|e| e.path()))
-        .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("jsonl"))
-        .collect();
-    subagent_jsonls.sort();
+    merge_subagent_workload_to_depth(parent_jsonl_path, result, subagent_depth());
+}
 
-    if subagent_jsonls.is_empty() {
+/// Merge every subagent transcript up to `max_depth` levels below the
+/// session into `result`. Nested subagents are folded into the subagent that
+/// spawned them first, so each level is attributed to its own parent.
+pub(crate) fn merge_subagent_workload_to_depth(
+    parent_jsonl_path: &std::path::Path,
+    result: &mut ParseResult,
+    max_depth: usize,
+) {
+    let files = discover_subagent_files(parent_jsonl_path, max_depth);
+    if files.is_empty() {
         return;
     }
 
-    for sub_path in subagent_jsonls {
-        let sub_result = parse_file_bytes(&sub_path);
-        merge_subagent_parse_result(result, sub_result);
+    let mut parsed: HashMap<PathBuf, ParseResult> = files
+        .iter()
+        .map(|file| (file.path.clone(), parse_file_bytes(&file.path)))
+        .collect();
+    // Deepest level first, so every subagent is complete before it is
+    // merged into its parent; siblings keep their sorted order.
+    let mut bottom_up: Vec<_> = files.iter().collect();
+    bottom_up.sort_by_key(|file| std::cmp::Reverse(file.depth));
+    for file in bottom_up {
+        let Some(sub_result) = parsed.remove(&file.path) else {
+            continue;
+        };
+        match parsed.get_mut(&file.parent) {
+            Some(parent) => merge_subagent_parse_result(parent, sub_result),
+            None => merge_subagent_parse_result(result, sub_result),
+        }
     }
 
     recompute_merged_productivity_metrics(result);
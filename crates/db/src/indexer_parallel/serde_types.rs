@@ -110,13 +110,48 @@ fn deserialize_content<'de, D: Deserializer<'de>>(d: D) -> Result<ContentResult,
 }
 
 /// Flat content block -- only declares fields we actually read.
-/// Serde skips undeclared fields (text content, thinking content) without allocating them.
+/// Serde skips undeclared fields (text content) without allocating them.
 #[derive(Deserialize)]
 pub(crate) struct FlatContentBlock {
     #[serde(rename = "type")]
     pub block_type: Option<String>,
     pub name: Option<String>,
     pub input: Option<serde_json::Value>,
+    /// Character count of a thinking block's text. Counted while
+    /// deserializing so the text itself is never allocated.
+    #[serde(
+        default,
+        rename = "thinking",
+        deserialize_with = "deserialize_char_count"
+    )]
+    pub thinking_chars: usize,
+}
+
+/// Counts the characters of a string field without keeping it.
+fn deserialize_char_count<'de, D: Deserializer<'de>>(d: D) -> Result<usize, D::Error> {
+    struct CharCountVisitor;
+
+    impl<'de> Visitor<'de> for CharCountVisitor {
+        type Value = usize;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a string or null")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<usize, E> {
+            Ok(v.chars().count())
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<usize, E> {
+            Ok(0)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<usize, E> {
+            Ok(0)
+        }
+    }
+
+    d.deserialize_any(CharCountVisitor)
 }
 
 #[derive(Deserialize)]
@@ -129,4 +164,11 @@ pub(crate) struct SystemLine {
     pub retry_attempt: Option<u64>,
     #[serde(rename = "preventedContinuation")]
     pub prevented_continuation: Option<bool>,
+    #[serde(rename = "compactMetadata")]
+    pub compact_metadata: Option<CompactMetadata>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct CompactMetadata {
+    pub trigger: Option<String>,
 }
@@ -376,6 +376,20 @@ mod tests {
         assert_eq!(result.deep.files_edited_count, 1);
     }
 
+    #[test]
+    fn test_files_edited_path_variants_dedup() {
+        let data = br#"{"type":"user","cwd":"/home/u/proj","message":{"content":"q1"}}
+{"type":"assistant","message":{"content":[{"type":"tool_use","name":"Edit","input":{"file_path":"./src/main.rs"}},{"type":"tool_use","name":"Edit","input":{"file_path":"src/main.rs"}},{"type":"tool_use","name":"Edit","input":{"file_path":"/home/u/proj/src/main.rs"}}]}}
+"#;
+        let result = parse_bytes(data);
+        assert_eq!(result.deep.files_edited_count, 1);
+        assert_eq!(result.deep.reedited_files_count, 1);
+        assert_eq!(
+            result.deep.files_touched,
+            vec!["/home/u/proj/src/main.rs".to_string()]
+        );
+    }
+
     #[test]
     fn test_files_edited_missing_file_path() {
         let data = br#"{"type":"user","message":{"content":"q1"}}
@@ -486,6 +500,18 @@ mod tests {
         assert_eq!(result.deep.duration_seconds, 500);
     }
 
+    #[test]
+    fn test_duration_seconds_mixed_timestamp_formats() {
+        // Epoch millis, epoch seconds and RFC3339 in one transcript.
+        let data = br#"{"type":"user","timestamp":1706400000000,"message":{"content":"q1"}}
+{"type":"assistant","timestamp":1706400300,"message":{"content":"a1"}}
+{"type":"user","timestamp":"2024-01-28T00:08:20Z","message":{"content":"q2"}}
+"#;
+        let result = parse_bytes(data);
+        assert_eq!(result.deep.first_timestamp, Some(1706400000));
+        assert_eq!(result.deep.duration_seconds, 500);
+    }
+
     #[test]
     fn test_duration_seconds_mixed_messages() {
         let data =
@@ -657,6 +683,47 @@ mod tests {
         assert_eq!(session.last_message, "now edit it");
     }
 
+    #[tokio::test]
+    async fn test_scan_discovers_gzipped_sessions() {
+        use flate2::write::GzEncoder;
+        use std::io::Write;
+
+        let (_tmp, claude_dir) = setup_test_claude_dir();
+        let project_dir = claude_dir.join("projects").join("test-project");
+        let mut enc = GzEncoder::new(
+            std::fs::File::create(project_dir.join("sess-002.jsonl.gz")).unwrap(),
+            flate2::Compression::default(),
+        );
+        enc.write_all(
+            br#"{"type":"user","timestamp":"2026-01-26T09:00:00Z","message":{"content":"from the archive"}}
+{"type":"assistant","timestamp":"2026-01-26T09:00:05Z","message":{"content":"ok"}}
+"#,
+        )
+        .unwrap();
+        enc.finish().unwrap();
+        // A gzipped copy of an existing plain session is not a second session.
+        std::fs::copy(
+            project_dir.join("sess-001.jsonl"),
+            project_dir.join("sess-001.jsonl.gz"),
+        )
+        .unwrap();
+
+        let db = Database::new_in_memory().await.unwrap();
+        let (indexed, _skipped) = run_scan(&claude_dir, &db).await.unwrap();
+        assert_eq!(indexed, 2);
+
+        let projects = db.list_projects().await.unwrap();
+        let mut ids: Vec<&str> = projects[0].sessions.iter().map(|s| s.id.as_str()).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, vec!["sess-001", "sess-002"]);
+        let archived = projects[0]
+            .sessions
+            .iter()
+            .find(|s| s.id == "sess-002")
+            .unwrap();
+        assert_eq!(archived.preview, "from the archive");
+    }
+
     #[tokio::test]
     async fn test_scan_skips_already_indexed() {
         let (_tmp, claude_dir) = setup_test_claude_dir();
@@ -829,6 +896,63 @@ mod tests {
         assert!(meta.turn_durations_ms.is_empty());
     }
 
+    #[test]
+    fn test_summary_source_auto_from_summary_line() {
+        let data = br#"{"type":"summary","summary":"Fix flaky login test","leafUuid":"u1"}
+{"type":"user","uuid":"u1","message":{"content":"hello"}}
+"#;
+        let result = parse_bytes(data);
+        assert_eq!(
+            result.deep.summary_text.as_deref(),
+            Some("Fix flaky login test")
+        );
+        assert_eq!(result.deep.summary_source, SummarySource::Auto);
+        assert_eq!(result.diagnostics.lines_unknown_type, 0);
+    }
+
+    #[test]
+    fn test_summary_source_manual_from_user_compact() {
+        let data = br#"{"type":"user","uuid":"u1","message":{"content":"hello"}}
+{"type":"system","subtype":"compact_boundary","content":"Conversation compacted","compactMetadata":{"trigger":"manual","preTokens":42000}}
+{"type":"user","uuid":"u2","isCompactSummary":true,"message":{"role":"user","content":"This session is being continued from a previous conversation."}}
+"#;
+        let result = parse_bytes(data);
+        assert_eq!(result.deep.compaction_count, 1);
+        assert_eq!(
+            result.deep.summary_text.as_deref(),
+            Some("This session is being continued from a previous conversation.")
+        );
+        assert_eq!(result.deep.summary_source, SummarySource::Manual);
+    }
+
+    #[test]
+    fn test_summary_source_auto_compact_and_unknown_trigger() {
+        let auto = br#"{"type":"system","subtype":"compact_boundary","compactMetadata":{"trigger":"auto","preTokens":160000}}
+{"type":"user","uuid":"u1","isCompactSummary":true,"message":{"role":"user","content":[{"type":"text","text":"Summary of earlier work"}]}}
+"#;
+        let result = parse_bytes(auto);
+        assert_eq!(
+            result.deep.summary_text.as_deref(),
+            Some("Summary of earlier work")
+        );
+        assert_eq!(result.deep.summary_source, SummarySource::Auto);
+
+        // Boundary without compactMetadata: trigger unknown.
+        let unknown = br#"{"type":"system","subtype":"compact_boundary"}
+{"type":"user","uuid":"u1","isCompactSummary":true,"message":{"role":"user","content":"Summary of earlier work"}}
+"#;
+        let result = parse_bytes(unknown);
+        assert!(result.deep.summary_text.is_some());
+        assert_eq!(result.deep.summary_source, SummarySource::Unknown);
+
+        // No summary at all: default is unknown.
+        let none = br#"{"type":"user","uuid":"u1","message":{"content":"hello"}}
+"#;
+        let result = parse_bytes(none);
+        assert!(result.deep.summary_text.is_none());
+        assert_eq!(result.deep.summary_source, SummarySource::Unknown);
+    }
+
     #[test]
     fn test_parse_bytes_deduplicates_content_blocks() {
         let data = br#"{"type":"user","uuid":"u1","message":{"content":"hello"}}
@@ -846,6 +970,224 @@ mod tests {
         assert_eq!(result.turns[2].input_tokens, None);
     }
 
+    #[test]
+    fn test_parse_bytes_splits_thinking_and_answer_tokens() {
+        // 400 chars of thinking ~= 100 tokens; the other 150 are the answer.
+        let thinking = "x".repeat(400);
+        let data = format!(
+            r#"{{"type":"user","uuid":"u1","message":{{"content":"hello"}}}}
+{{"type":"assistant","uuid":"a1","parentUuid":"u1","requestId":"req_001","message":{{"id":"msg_001","model":"claude-opus-4-6","content":[{{"type":"thinking","thinking":"{thinking}"}}],"usage":{{"input_tokens":10,"output_tokens":250}}}}}}
+{{"type":"assistant","uuid":"a2","parentUuid":"a1","requestId":"req_001","message":{{"id":"msg_001","model":"claude-opus-4-6","content":[{{"type":"text","text":"answer"}}],"usage":{{"input_tokens":10,"output_tokens":250}}}}}}
+"#
+        );
+        let result = parse_bytes(data.as_bytes());
+        assert_eq!(result.deep.thinking_block_count, 1);
+        assert_eq!(result.deep.total_output_tokens, 250);
+        assert_eq!(result.deep.thinking_output_tokens, 100);
+        assert_eq!(
+            result.deep.total_output_tokens - result.deep.thinking_output_tokens,
+            150
+        );
+    }
+
+    #[test]
+    fn test_parse_bytes_thinking_tokens_clamped_to_output_total() {
+        let thinking = "y".repeat(4000);
+        let data = format!(
+            r#"{{"type":"assistant","uuid":"a1","message":{{"model":"claude-opus-4-6","content":[{{"type":"thinking","thinking":"{thinking}"}}],"usage":{{"input_tokens":10,"output_tokens":20}}}}}}
+"#
+        );
+        let result = parse_bytes(data.as_bytes());
+        assert_eq!(result.deep.thinking_output_tokens, 20);
+    }
+
+    #[test]
+    fn test_parse_bytes_thinking_tokens_clamped_per_turn() {
+        // Turn 1 thinks ~100 tokens but reports only 50 output; turn 2 thinks
+        // ~10 of its 200. A session-wide clamp would report 110.
+        let long = "a".repeat(400);
+        let short = "b".repeat(40);
+        let data = format!(
+            r#"{{"type":"assistant","uuid":"a1","requestId":"req_1","message":{{"id":"msg_1","model":"claude-opus-4-6","content":[{{"type":"thinking","thinking":"{long}"}}],"usage":{{"input_tokens":10,"output_tokens":50}}}}}}
+{{"type":"assistant","uuid":"a2","requestId":"req_2","message":{{"id":"msg_2","model":"claude-opus-4-6","content":[{{"type":"thinking","thinking":"{short}"}}],"usage":{{"input_tokens":10,"output_tokens":200}}}}}}
+{{"type":"assistant","uuid":"a3","requestId":"req_2","message":{{"id":"msg_2","model":"claude-opus-4-6","content":[{{"type":"text","text":"done"}}],"usage":{{"input_tokens":10,"output_tokens":200}}}}}}
+"#
+        );
+        let result = parse_bytes(data.as_bytes());
+        assert_eq!(result.deep.total_output_tokens, 250);
+        assert_eq!(result.deep.thinking_output_tokens, 60);
+        assert!(result.deep.thinking_by_call.is_empty());
+
+        // The Value fallback path (spaced keys) attributes the same way.
+        let spaced = data.replace("\"type\":\"assistant\"", "\"type\": \"assistant\"");
+        let result = parse_bytes(spaced.as_bytes());
+        assert_eq!(result.deep.thinking_output_tokens, 60);
+    }
+
+    #[test]
+    fn test_parse_bytes_counts_user_interruptions() {
+        let data = r#"{"type":"user","uuid":"u1","timestamp":"2026-01-01T00:00:00Z","message":{"role":"user","content":"refactor the parser"}}
+{"type":"assistant","uuid":"a1","parentUuid":"u1","timestamp":"2026-01-01T00:00:05Z","message":{"id":"msg_001","model":"claude-opus-4-6","content":[{"type":"text","text":"Starting"}],"usage":{"input_tokens":10,"output_tokens":5}}}
+{"type":"user","uuid":"u2","parentUuid":"a1","timestamp":"2026-01-01T00:00:06Z","message":{"role":"user","content":[{"type":"text","text":"[Request interrupted by user]"}]}}
+{"type":"user","uuid":"u3","parentUuid":"u2","timestamp":"2026-01-01T00:00:10Z","message":{"role":"user","content":"actually, only the tests"}}
+{"type":"user","uuid":"u4","parentUuid":"u3","timestamp":"2026-01-01T00:00:12Z","message":{"role":"user","content":[{"type":"text","text":"[Request interrupted by user for tool use]"}]}}
+"#;
+        let result = parse_bytes(data.as_bytes());
+        assert_eq!(result.deep.interruption_count, 2);
+
+        // Prompts and tool output that merely mention the marker don't count.
+        let quoted = parse_bytes(
+            br#"{"type":"user","uuid":"u1","message":{"role":"user","content":"why does it print [Request interrupted by user] here?"}}
+{"type":"user","uuid":"u2","message":{"role":"user","content":[{"type":"tool_result","tool_use_id":"t1","content":[{"type":"text","text":"[Request interrupted by user]"}]}]}}
+"#,
+        );
+        assert_eq!(quoted.deep.interruption_count, 0);
+
+        let clean = parse_bytes(
+            br#"{"type":"user","uuid":"u1","message":{"role":"user","content":"hello"}}
+"#,
+        );
+        assert_eq!(clean.deep.interruption_count, 0);
+    }
+
+    #[test]
+    fn test_parse_bytes_splits_mcp_tools_by_server() {
+        let data = r#"{"type":"user","uuid":"u1","timestamp":"2026-01-01T00:00:00Z","message":{"role":"user","content":"open the docs"}}
+{"type":"assistant","uuid":"a1","parentUuid":"u1","timestamp":"2026-01-01T00:00:05Z","message":{"id":"msg_001","model":"claude-opus-4-6","content":[{"type":"tool_use","id":"t1","name":"mcp__playwright__browser_navigate","input":{"url":"https://example.com"}},{"type":"tool_use","id":"t2","name":"Bash","input":{"command":"ls"}}],"usage":{"input_tokens":10,"output_tokens":5}}}
+{"type":"assistant","uuid":"a2","parentUuid":"a1","timestamp":"2026-01-01T00:00:09Z","message":{"id":"msg_002","model":"claude-opus-4-6","content":[{"type":"tool_use","id":"t3","name":"mcp__playwright__browser_navigate","input":{"url":"https://example.org"}},{"type":"tool_use","id":"t4","name":"mcp__plugin_supabase_supabase__execute_sql","input":{"query":"select 1"}}],"usage":{"input_tokens":10,"output_tokens":5}}}
+"#;
+        let result = parse_bytes(data.as_bytes());
+        let tools: Vec<(&str, &str)> = result
+            .deep
+            .mcp_tools
+            .iter()
+            .map(|t| (t.server.as_str(), t.tool.as_str()))
+            .collect();
+        assert_eq!(
+            tools,
+            vec![
+                ("playwright", "browser_navigate"),
+                ("supabase", "execute_sql")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_bytes_records_invocation_args() {
+        let long = "x".repeat(MAX_INVOCATION_ARGS_CHARS + 50);
+        let data = format!(
+            r#"{{"type":"user","timestamp":"2026-01-21T05:00:00Z","message":{{"content":"<command-message>commit</command-message>\n<command-name>/commit</command-name>\n<command-args>--amend \"fix typo\"</command-args>"}}}}
+{{"type":"user","message":{{"content":"<command-name>/clear</command-name>\n<command-args></command-args>"}}}}
+{{"type":"assistant","message":{{"content":[{{"type":"tool_use","name":"Skill","input":{{"skill":"review","args":"{long}"}}}},{{"type":"tool_use","name":"SlashCommand","input":{{"command":"/commit --amend \"fix typo\""}}}},{{"type":"tool_use","name":"SlashCommand","input":{{"command":"/test"}}}}]}}}}
+"#
+        );
+        let result = parse_bytes(data.as_bytes());
+        let args: Vec<(&str, &str, u32)> = result
+            .deep
+            .invocation_args
+            .iter()
+            .flat_map(|(name, args)| {
+                args.iter()
+                    .map(move |(a, n)| (name.as_str(), a.as_str(), *n))
+            })
+            .collect();
+        let truncated = format!("{}…", &long[..MAX_INVOCATION_ARGS_CHARS]);
+        assert_eq!(
+            args,
+            vec![
+                ("/commit", r#"--amend "fix typo""#, 2),
+                ("review", truncated.as_str(), 1),
+            ]
+        );
+        // Typed slash commands are not tool calls.
+        assert_eq!(result.raw_invocations.len(), 3);
+    }
+
+    #[test]
+    fn test_parse_bytes_counts_bash_categories() {
+        let data = r#"{"type":"user","uuid":"u1","timestamp":"2026-01-01T00:00:00Z","message":{"role":"user","content":"ship it"}}
+{"type":"assistant","uuid":"a1","parentUuid":"u1","timestamp":"2026-01-01T00:00:05Z","message":{"id":"msg_001","model":"claude-opus-4-6","content":[{"type":"tool_use","id":"t1","name":"Bash","input":{"command":"cargo test"}},{"type":"tool_use","id":"t2","name":"Bash","input":{"command":"cargo test -p db"}}],"usage":{"input_tokens":10,"output_tokens":5}}}
+{"type":"assistant","uuid":"a2","parentUuid":"a1","timestamp":"2026-01-01T00:00:09Z","message":{"id":"msg_002","model":"claude-opus-4-6","content":[{"type":"tool_use","id":"t3","name":"Bash","input":{"command":"git commit -m wip"}},{"type":"tool_use","id":"t4","name":"Bash","input":{"command":"ls"}},{"type":"tool_use","id":"t5","name":"Read","input":{"file_path":"/tmp/a"}}],"usage":{"input_tokens":10,"output_tokens":5}}}
+"#;
+        let result = parse_bytes(data.as_bytes());
+        let counts: Vec<(&str, u32)> = result
+            .deep
+            .bash_categories
+            .iter()
+            .map(|(k, v)| (k.as_str(), *v))
+            .collect();
+        assert_eq!(counts, vec![("git", 1), ("misc", 1), ("test", 2)]);
+    }
+
+    #[test]
+    fn test_parse_reader_matches_parse_bytes_on_large_input() {
+        let fixture = include_str!("../../tests/golden_fixtures/dedup_content_blocks.jsonl");
+        let mut data = String::new();
+        for i in 0..5_000 {
+            // Unique message/request IDs so every copy counts as a new API call.
+            data.push_str(&fixture.replace("req_", &format!("req_{i}_")));
+            data.push_str(r#"{"type":"progress","data":{"type":"bash_progress"}}"#);
+            data.push('\n');
+        }
+        // No trailing newline on the final line.
+        data.push_str(r#"{"type":"user","uuid":"last","message":{"content":"bye"}}"#);
+
+        let buffered = parse_bytes(data.as_bytes());
+        let streamed =
+            parse_reader(std::io::BufReader::with_capacity(4096, data.as_bytes())).unwrap();
+
+        assert_eq!(buffered.deep.api_call_count, 10_000);
+        assert_eq!(streamed.diagnostics.bytes_total, data.len() as u64);
+        assert_eq!(format!("{streamed:?}"), format!("{buffered:?}"));
+    }
+
+    #[test]
+    fn test_parse_reader_handles_trailing_newline_and_empty_input() {
+        for data in [
+            "",
+            "\n",
+            "{\"type\":\"user\",\"message\":{\"content\":\"hi\"}}\n",
+        ] {
+            let buffered = parse_bytes(data.as_bytes());
+            let streamed = parse_reader(data.as_bytes()).unwrap();
+            assert_eq!(
+                format!("{streamed:?}"),
+                format!("{buffered:?}"),
+                "input {data:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_file_bytes_reads_gzipped_transcripts() {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        use std::io::Write;
+
+        let data = include_bytes!("../../tests/golden_fixtures/dedup_content_blocks.jsonl");
+        let tmp = tempfile::tempdir().unwrap();
+        let plain_path = tmp.path().join("plain.jsonl");
+        std::fs::write(&plain_path, data).unwrap();
+        let plain = parser::parse_file_bytes(&plain_path);
+        assert!(plain.deep.api_call_count > 0);
+
+        // Detected by extension and, for the misnamed copy, by magic bytes.
+        for name in ["s.jsonl.gz", "compressed.jsonl"] {
+            let gz_path = tmp.path().join(name);
+            let mut enc = GzEncoder::new(
+                std::fs::File::create(&gz_path).unwrap(),
+                Compression::default(),
+            );
+            enc.write_all(data).unwrap();
+            enc.finish().unwrap();
+            assert_eq!(
+                format!("{:?}", parser::parse_file_bytes(&gz_path)),
+                format!("{plain:?}"),
+                "{name}"
+            );
+        }
+    }
+
     #[test]
     fn test_golden_dedup_content_blocks() {
         let data = include_bytes!("../../tests/golden_fixtures/dedup_content_blocks.jsonl");
@@ -1014,4 +1356,101 @@ mod scan_and_index_tests {
         assert_eq!(indexed2, 0);
         assert_eq!(skipped2, 1);
     }
+
+    #[tokio::test]
+    async fn summary_hint_wins_over_compact_summary() {
+        let db = Database::new_in_memory().await.unwrap();
+        let tmp = tempdir().unwrap();
+        let project_dir = tmp.path().join("projects").join("-test-project");
+        std::fs::create_dir_all(&project_dir).unwrap();
+        let compacted = concat!(
+            r#"{"type":"user","uuid":"u1","timestamp":"2026-01-01T00:00:00Z","message":{"role":"user","content":"hello"}}"#,
+            "\n",
+            r#"{"type":"system","subtype":"compact_boundary","timestamp":"2026-01-01T00:01:00Z","compactMetadata":{"trigger":"auto"}}"#,
+            "\n",
+            r#"{"type":"user","uuid":"u2","isCompactSummary":true,"timestamp":"2026-01-01T00:02:00Z","message":{"role":"user","content":"Compacted context"}}"#,
+            "\n",
+        );
+        std::fs::write(project_dir.join("with-hint.jsonl"), compacted).unwrap();
+        std::fs::write(project_dir.join("no-hint.jsonl"), compacted).unwrap();
+        let hints = HashMap::from([(
+            "with-hint".to_string(),
+            IndexHints {
+                summary: Some("Fix the login flow".to_string()),
+                ..Default::default()
+            },
+        )]);
+
+        let (indexed, _) = scan_and_index_all(tmp.path(), &db, &hints, None, |_| {}, |_| {}, || {})
+            .await
+            .unwrap();
+        assert_eq!(indexed, 2);
+
+        let rows: Vec<(String, Option<String>, Option<String>)> = sqlx::query_as(
+            "SELECT session_id, summary, summary_text FROM session_stats ORDER BY session_id",
+        )
+        .fetch_all(db.pool())
+        .await
+        .unwrap();
+        let compact = Some("Compacted context".to_string());
+        assert_eq!(
+            rows,
+            vec![
+                ("no-hint".to_string(), compact.clone(), compact.clone()),
+                (
+                    "with-hint".to_string(),
+                    Some("Fix the login flow".to_string()),
+                    compact
+                ),
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod subagent_merge_tests {
+    use crate::indexer_parallel::parser::{merge_subagent_workload_to_depth, parse_file_bytes};
+
+    /// One user prompt plus one assistant turn reading `file` with `tokens` input tokens.
+    fn write_agent(path: &std::path::Path, file: &str, tokens: u64) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let lines = [
+            r#"{"type":"user","timestamp":"2026-01-01T00:00:00Z","message":{"content":"go"}}"#
+                .to_string(),
+            format!(
+                r#"{{"type":"assistant","timestamp":"2026-01-01T00:00:01Z","message":{{"id":"msg-{tokens}","content":[{{"type":"tool_use","name":"Read","input":{{"file_path":"{file}"}}}}],"usage":{{"input_tokens":{tokens},"output_tokens":1}}}}}}"#
+            ),
+        ];
+        std::fs::write(path, lines.join("\n")).unwrap();
+    }
+
+    #[test]
+    fn test_nested_subagents_merged_into_session() {
+        let tmp = tempfile::tempdir().unwrap();
+        let session = tmp.path().join("proj/sess-1.jsonl");
+        let subs = tmp.path().join("proj/sess-1/subagents");
+        write_agent(&session, "/src/main.rs", 1);
+        write_agent(&subs.join("agent-a.jsonl"), "/src/a.rs", 10);
+        write_agent(&subs.join("agent-b.jsonl"), "/src/b.rs", 100);
+        write_agent(
+            &subs.join("agent-a/subagents/agent-c.jsonl"),
+            "/src/c.rs",
+            1000,
+        );
+
+        let mut merged = parse_file_bytes(&session);
+        merge_subagent_workload_to_depth(&session, &mut merged, 4);
+        assert_eq!(merged.deep.total_input_tokens, 1111);
+        assert_eq!(merged.deep.tool_counts.read, 4);
+        assert_eq!(
+            merged.deep.files_read,
+            vec!["/src/a.rs", "/src/b.rs", "/src/c.rs", "/src/main.rs"]
+        );
+
+        // One level: the grandchild is out of reach.
+        let mut shallow = parse_file_bytes(&session);
+        merge_subagent_workload_to_depth(&session, &mut shallow, 1);
+        assert_eq!(shallow.deep.total_input_tokens, 111);
+        assert!(!shallow.deep.files_read.contains(&"/src/c.rs".to_string()));
+    }
 }
@@ -28,7 +28,21 @@ use std::path::Path;
 /// Version 20: Strict cost integrity: if any turn model is unpriced, session total_cost_usd is NULL.
 /// Version 21: Source-message integrity hardening (role filtering, summary exclusion, strict tool_use LOC/path extraction).
 /// Version 22: Extract hook_progress events into hook_events table for backfill.
-pub const CURRENT_PARSE_VERSION: i32 = 22;
+/// Version 23: Split output tokens into thinking vs answer (thinking_output_tokens).
+/// Version 24: Capture summary text from summary lines / compact summaries and record summary_source.
+/// Version 25: Ranked work types; persist secondary labels in work_type_alternates.
+/// Version 26: Persist per-model turn counts (model_turn_counts) for mid-session model switches.
+/// Version 27: Count user interruptions (ESC/abort markers) in interruption_count.
+/// Version 28: Normalize epoch-millis and numeric-string timestamps to seconds.
+/// Version 29: Record MCP tools by server and tool name in mcp_tools.
+/// Version 30: Count Bash calls by command category (git/test/build/misc) in bash_categories.
+/// Version 31: Normalize tool-call file paths before deduplicating files_edited/reedited/files_touched.
+/// Version 32: Merge nested subagent transcripts (subagents of subagents) into session totals.
+/// Version 33: Count only exact interruption markers, not prompts or tool output quoting them.
+/// Version 34: Classify `check`/`typecheck` scripts as builds in bash_categories, like `cargo check`.
+/// Version 35: Clamp the thinking-token estimate per API call instead of per session.
+/// Version 36: Record slash-command and skill argument strings in invocation_args.
+pub const CURRENT_PARSE_VERSION: i32 = 36;
 
 /// Complete parsed session data -- the sole input to any DB write.
 /// Every field is populated by the parser. No field is ever set from
@@ -57,6 +71,12 @@ pub struct ParsedSession {
     pub tool_counts_write: i32,
     pub files_touched: String, // JSON array
     pub skills_used: String,   // JSON array
+    /// JSON array of `{server, tool}` MCP tools called, sorted and unique.
+    pub mcp_tools: String,
+    /// JSON object of Bash command category -> call count.
+    pub bash_categories: String,
+    /// JSON object of slash command or skill -> argument string -> count.
+    pub invocation_args: String,
     pub user_prompt_count: i32,
     pub api_call_count: i32,
     pub tool_call_count: i32,
@@ -72,6 +92,7 @@ pub struct ParsedSession {
     pub cache_read_tokens: i64,
     pub cache_creation_tokens: i64,
     pub thinking_block_count: i32,
+    pub thinking_output_tokens: i64,
     pub turn_duration_avg_ms: Option<i64>,
     pub turn_duration_max_ms: Option<i64>,
     pub turn_duration_total_ms: Option<i64>,
@@ -79,11 +100,15 @@ pub struct ParsedSession {
     pub api_retry_count: i32,
     pub compaction_count: i32,
     pub hook_blocked_count: i32,
+    /// User messages that are exactly an interruption marker (ESC mid-turn).
+    pub interruption_count: i32,
     pub agent_spawn_count: i32,
     pub bash_progress_count: i32,
     pub hook_progress_count: i32,
     pub mcp_progress_count: i32,
     pub summary_text: Option<String>,
+    /// `SummarySource::as_str()` of `summary_text`: "auto", "manual" or "unknown".
+    pub summary_source: String,
     pub parse_version: i32,
     pub file_size_at_index: i64,
     pub file_mtime_at_index: i64,
@@ -93,7 +118,11 @@ pub struct ParsedSession {
     pub ai_lines_added: i64,
     pub ai_lines_removed: i64,
     pub work_type: Option<String>,
+    /// JSON array of secondary `WorkTypeScore`s, best first.
+    pub work_type_alternates: Option<String>,
     pub primary_model: Option<String>,
+    /// JSON object of model id -> assistant turns using it.
+    pub model_turn_counts: Option<String>,
     pub total_task_time_seconds: Option<i64>,
     pub longest_task_seconds: Option<i64>,
     pub longest_task_preview: Option<String>,
@@ -118,6 +147,14 @@ pub struct IndexHints {
 pub struct ExtendedMetadata {
     pub tool_counts: ToolCounts,
     pub skills_used: Vec<String>,
+    /// MCP tools called, split into server and tool; sorted and unique.
+    pub mcp_tools: Vec<claude_view_core::McpTool>,
+    /// Bash calls per command category (`"git"`, `"test"`, ...).
+    pub bash_categories: std::collections::BTreeMap<String, u32>,
+    /// Argument strings per slash command (`"/commit"`) or skill
+    /// (`"review"`), counted; each truncated to [`MAX_INVOCATION_ARGS_CHARS`].
+    pub invocation_args:
+        std::collections::BTreeMap<String, std::collections::BTreeMap<String, u32>>,
     pub files_touched: Vec<String>,
     pub last_message: String,
     pub turn_count: usize,
@@ -151,6 +188,12 @@ pub struct ExtendedMetadata {
     pub cache_read_tokens: u64,
     pub cache_creation_tokens: u64,
     pub thinking_block_count: u32,
+    /// Estimated share of `total_output_tokens` spent on thinking blocks.
+    /// Answer tokens are `total_output_tokens - thinking_output_tokens`.
+    pub thinking_output_tokens: u64,
+    /// Per-API-call (thinking estimate, counted output tokens), folded into
+    /// `thinking_output_tokens` once the parse finishes.
+    pub thinking_by_call: std::collections::HashMap<String, (u64, u64)>,
 
     // System line metrics
     pub turn_durations_ms: Vec<u64>,
@@ -159,6 +202,9 @@ pub struct ExtendedMetadata {
     pub compaction_count: u32,
     pub hook_blocked_count: u32,
 
+    // User line metrics
+    pub interruption_count: u32,
+
     // Progress line metrics
     pub agent_spawn_count: u32,
     pub bash_progress_count: u32,
@@ -166,8 +212,13 @@ pub struct ExtendedMetadata {
     pub hook_progress_events: Vec<crate::queries::hook_events::HookEventRow>,
     pub mcp_progress_count: u32,
 
-    // Summary text (from summary lines)
+    // Summary text (from summary lines or compact summaries)
     pub summary_text: Option<String>,
+    /// Whether `summary_text` was produced automatically or by a user `/compact`.
+    pub summary_source: SummarySource,
+    /// Trigger of the most recent compact boundary; applied to the compact
+    /// summary message that follows it.
+    pub pending_compact_trigger: SummarySource,
 
     // Queue operations
     pub queue_enqueue_count: u32,
@@ -199,6 +250,37 @@ pub struct ExtendedMetadata {
     pub total_task_time_seconds: u32,
 }
 
+/// Origin of a session's summary text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SummarySource {
+    /// Claude-generated: a `summary` line or an auto-triggered compaction.
+    Auto,
+    /// A compaction the user ran with `/compact`.
+    Manual,
+    /// No summary, or the trigger was not recorded.
+    #[default]
+    Unknown,
+}
+
+impl SummarySource {
+    /// Map a `compactMetadata.trigger` value.
+    pub fn from_compact_trigger(trigger: Option<&str>) -> Self {
+        match trigger {
+            Some("auto") => Self::Auto,
+            Some("manual") => Self::Manual,
+            _ => Self::Unknown,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Manual => "manual",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
 /// A raw tool_use extracted from JSONL, before classification.
 #[derive(Debug, Clone)]
 pub struct RawInvocation {
@@ -208,6 +290,10 @@ pub struct RawInvocation {
     pub timestamp: i64,
 }
 
+/// Longest argument string kept in [`ExtendedMetadata::invocation_args`],
+/// in characters.
+pub const MAX_INVOCATION_ARGS_CHARS: usize = 200;
+
 /// A commit-related skill invocation detected for git correlation (Tier 1).
 ///
 /// Detected when:
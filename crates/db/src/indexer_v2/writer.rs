@@ -8,7 +8,7 @@
 use claude_view_session_parser::{PARSER_VERSION, STATS_VERSION};
 
 use super::config::StatsDelta;
-use crate::{Database, DbResult};
+use crate::{Database, DbResult, PROJECT_MERGE_KEY_PREFIX};
 
 /// Atomically upsert a `session_stats` row from a [`StatsDelta`].
 ///
@@ -31,6 +31,9 @@ use crate::{Database, DbResult};
 /// infallible in practice (HashMap + TokenUsage derive Serialize with
 /// no trait objects) — the unwrap_or falls back to `'{}'` for
 /// belt-and-braces.
+///
+/// `project_id` follows a recorded project merge (see
+/// [`PROJECT_MERGE_KEY_PREFIX`]) so reindexing does not undo the merge.
 pub async fn upsert_session_stats(db: &Database, delta: &StatsDelta) -> DbResult<()> {
     let now = chrono::Utc::now().timestamp();
     let first_ts = delta
@@ -87,7 +90,7 @@ pub async fn upsert_session_stats(db: &Database, delta: &StatsDelta) -> DbResult
                 ?, ?, ?,
                 ?, ?, ?, ?,
                 ?,
-                ?, ?, ?, ?,
+                COALESCE((SELECT value FROM settings WHERE key = ? || ?), ?), ?, ?, ?,
                 ?,
                 ?, ?, ?, ?
            )
@@ -165,6 +168,8 @@ pub async fn upsert_session_stats(db: &Database, delta: &StatsDelta) -> DbResult
     .bind(delta.stats.preview.as_str())
     .bind(delta.stats.last_message.as_str())
     .bind(per_model_tokens_json)
+    .bind(PROJECT_MERGE_KEY_PREFIX)
+    .bind(delta.project_id.as_str())
     .bind(delta.project_id.as_str())
     .bind(delta.source_file_path.as_str())
     .bind(is_compressed_int)
@@ -0,0 +1,98 @@
+//! Comparison of the user's metrics against a bundled baseline distribution.
+//!
+//! The baseline is static data compiled into the binary: nothing is sent or
+//! fetched, and callers opt in by asking for the comparison.
+
+use crate::{Database, DbResult};
+
+use super::types::BaselineComparison;
+
+/// Baseline quantiles are sampled at every 10th percentile (p0, p10, ..
+/// p100), so each table has 11 ascending values.
+const BASELINE_QUANTILE_COUNT: usize = 11;
+
+/// Coarse reference distribution of sessions per week.
+const SESSIONS_PER_WEEK_BASELINE: [f64; BASELINE_QUANTILE_COUNT] = [
+    0.0, 2.0, 4.0, 7.0, 10.0, 14.0, 19.0, 25.0, 34.0, 50.0, 120.0,
+];
+
+/// Coarse reference distribution of cache hit ratio
+/// (`cache_read / (cache_read + cache_creation)`, as in token stats).
+const CACHE_HIT_RATIO_BASELINE: [f64; BASELINE_QUANTILE_COUNT] = [
+    0.0, 0.55, 0.70, 0.78, 0.83, 0.87, 0.90, 0.92, 0.94, 0.96, 1.0,
+];
+
+/// Percentile (0-100) of `value` within a distribution given as ascending
+/// quantiles at evenly spaced percentiles, interpolating linearly between
+/// neighbouring quantiles. Values outside the range clamp to 0 or 100.
+pub fn baseline_percentile(value: f64, quantiles: &[f64]) -> f64 {
+    let (Some(&lo), Some(&hi)) = (quantiles.first(), quantiles.last()) else {
+        return 0.0;
+    };
+    if quantiles.len() == 1 || value.is_nan() {
+        return 0.0;
+    }
+    if value <= lo {
+        return 0.0;
+    }
+    if value >= hi {
+        return 100.0;
+    }
+    let step = 100.0 / (quantiles.len() - 1) as f64;
+    for (i, pair) in quantiles.windows(2).enumerate() {
+        let (a, b) = (pair[0], pair[1]);
+        if value < b {
+            let frac = if b > a { (value - a) / (b - a) } else { 0.0 };
+            return (i as f64 + frac) * step;
+        }
+    }
+    100.0
+}
+
+impl Database {
+    /// The user's sessions per week and cache hit ratio over `[from, to]`,
+    /// each with its percentile against the bundled baseline.
+    pub async fn insights_baseline_comparison(
+        &self,
+        from: i64,
+        to: i64,
+    ) -> DbResult<Vec<BaselineComparison>> {
+        let (sessions, cache_read, cache_creation): (i64, i64, i64) = sqlx::query_as(
+            r#"
+            SELECT
+                COUNT(*),
+                COALESCE(SUM(cache_read_tokens), 0),
+                COALESCE(SUM(cache_creation_tokens), 0)
+            FROM valid_sessions
+            WHERE last_message_at >= ?1 AND last_message_at <= ?2
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .fetch_one(self.pool())
+        .await?;
+
+        // Ranges shorter than a week count as one week.
+        let weeks = ((to - from).max(0) as f64 / (7.0 * 86400.0)).max(1.0);
+        let sessions_per_week = sessions as f64 / weeks;
+        let cache_denominator = cache_read + cache_creation;
+        let cache_hit_ratio = if cache_denominator > 0 {
+            cache_read as f64 / cache_denominator as f64
+        } else {
+            0.0
+        };
+
+        Ok(vec![
+            BaselineComparison {
+                metric: "sessions_per_week".to_string(),
+                value: sessions_per_week,
+                percentile: baseline_percentile(sessions_per_week, &SESSIONS_PER_WEEK_BASELINE),
+            },
+            BaselineComparison {
+                metric: "cache_hit_ratio".to_string(),
+                value: cache_hit_ratio,
+                percentile: baseline_percentile(cache_hit_ratio, &CACHE_HIT_RATIO_BASELINE),
+            },
+        ])
+    }
+}
@@ -4,7 +4,9 @@
 //! - Metric trends (re-edit rate, session count, lines, cost-per-line, prompts)
 //! - Category evolution (code/support/thinking work distribution over time)
 //! - Activity heatmap (day-of-week x hour session density and efficiency)
+//! - Baseline comparison (percentile against a bundled reference distribution)
 
+mod baseline;
 mod insights;
 mod queries;
 mod types;
@@ -12,8 +14,11 @@ mod types;
 #[cfg(test)]
 mod tests;
 
+pub use baseline::baseline_percentile;
 pub use insights::{
     calculate_trend_stats, generate_category_insight, generate_heatmap_insight,
     generate_metric_insight,
 };
-pub use types::{CategoryDataPoint, HeatmapCell, InsightsTrendsResponse, MetricDataPoint};
+pub use types::{
+    BaselineComparison, CategoryDataPoint, HeatmapCell, InsightsTrendsResponse, MetricDataPoint,
+};
@@ -1,5 +1,6 @@
 //! Tests for the insights trends module.
 
+use super::baseline::*;
 use super::insights::*;
 use super::types::*;
 use crate::Database;
@@ -324,3 +325,52 @@ fn test_generate_heatmap_insight_consistent() {
 fn test_get_session_count_in_range() {
     // Just test the function signature compiles - actual DB test above covers it
 }
+
+#[test]
+fn test_baseline_percentile_interpolates_known_distribution() {
+    // p0=0, p25=10, p50=20, p75=40, p100=100
+    let quantiles = [0.0, 10.0, 20.0, 40.0, 100.0];
+    assert_eq!(baseline_percentile(0.0, &quantiles), 0.0);
+    assert_eq!(baseline_percentile(10.0, &quantiles), 25.0);
+    assert_eq!(baseline_percentile(15.0, &quantiles), 37.5);
+    assert_eq!(baseline_percentile(30.0, &quantiles), 62.5);
+    assert_eq!(baseline_percentile(100.0, &quantiles), 100.0);
+    assert_eq!(baseline_percentile(-5.0, &quantiles), 0.0);
+    assert_eq!(baseline_percentile(500.0, &quantiles), 100.0);
+    assert_eq!(baseline_percentile(5.0, &[]), 0.0);
+}
+
+#[tokio::test]
+async fn test_insights_baseline_comparison_reports_value_and_percentile() {
+    use crate::test_support::SessionSeedBuilder;
+
+    let db = Database::new_in_memory().await.unwrap();
+    let to = 1_700_000_000;
+    let from = to - 14 * 86400;
+    for i in 0..20 {
+        SessionSeedBuilder::new(format!("baseline-{i}"))
+            .project_id("proj")
+            .file_path(format!("/tmp/baseline-{i}.jsonl"))
+            .last_message_at(from + 3600 * (i + 1))
+            .with_parsed(|s| {
+                s.cache_read_tokens = 900;
+                s.cache_creation_tokens = 100;
+            })
+            .seed(&db)
+            .await
+            .unwrap();
+    }
+
+    let comparison = db.insights_baseline_comparison(from, to).await.unwrap();
+    assert_eq!(comparison.len(), 2);
+
+    let sessions = &comparison[0];
+    assert_eq!(sessions.metric, "sessions_per_week");
+    assert_eq!(sessions.value, 10.0);
+    assert_eq!(sessions.percentile, 40.0);
+
+    let cache = &comparison[1];
+    assert_eq!(cache.metric, "cache_hit_ratio");
+    assert!((cache.value - 0.9).abs() < 1e-9);
+    assert!((cache.percentile - 60.0).abs() < 1e-9);
+}
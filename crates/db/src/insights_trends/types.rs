@@ -35,6 +35,18 @@ pub struct HeatmapCell {
     pub avg_reedit_rate: f64,
 }
 
+/// One of the user's metrics placed against the bundled baseline.
+#[derive(Debug, Clone, Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineComparison {
+    /// `"sessions_per_week"` or `"cache_hit_ratio"`.
+    pub metric: String,
+    pub value: f64,
+    /// Share of the baseline at or below `value`, 0-100.
+    pub percentile: f64,
+}
+
 /// Full trends response.
 #[derive(Debug, Clone, Serialize, TS, utoipa::ToSchema)]
 #[cfg_attr(feature = "codegen", ts(export))]
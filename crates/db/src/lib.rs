@@ -29,24 +29,46 @@ pub mod stage_c;
 pub mod test_support;
 pub mod trends;
 
+pub use queries::backup::{BackupConflict, BackupSummary};
 pub use queries::facets::{FacetAggregateStats, FacetRow};
 pub use queries::hook_events::{self as hook_events_queries, HookEventRow};
+pub use queries::prompt_bootstrap::DB_PROMPT_ID_PREFIX;
 pub use queries::reports::{ProjectPreview, ReportPreview, ReportRow};
 pub use queries::settings::AppSettings;
 pub use queries::AIGenerationStats;
 pub use queries::ActivityPoint;
+pub use queries::ActivityStreak;
 pub use queries::AggregateCostBreakdown;
 pub use queries::BranchCount;
+pub use queries::BudgetState;
+pub use queries::BudgetStatus;
 pub use queries::ClassificationStatus;
+pub use queries::DuplicateSessionGroup;
+pub use queries::ErrorSession;
+pub use queries::FileChurn;
 pub use queries::HealthStats;
 pub use queries::HealthStatus;
 pub use queries::IndexRunIntegrityCounters;
 pub use queries::IndexerEntry;
+pub use queries::InvocablePair;
 pub use queries::InvocableWithCount;
+pub use queries::ModelAdoption;
+pub use queries::ModelCost;
+pub use queries::ModelMonthTurns;
+pub use queries::ModelTurnCount;
 pub use queries::ModelWithStats;
+pub use queries::ProjectCost;
+pub use queries::ProjectHealth;
+pub use queries::SearchFileMeta;
 pub use queries::SearchPrefilter;
+pub use queries::SessionCard;
+pub use queries::SessionModelCost;
+pub use queries::SessionsFilter;
+pub use queries::SessionsSort;
 pub use queries::StatsOverview;
 pub use queries::SystemStorageStats;
+pub use queries::TimeBucketStats;
+pub use queries::TokenHistogramBucket;
 pub use queries::TokenStats;
 pub use queries::TokensByModel;
 pub use queries::TokensByProject;
@@ -59,6 +81,7 @@ pub use queries::{CatalogFilter, CatalogSort, FullSessionStatsRow, StatsCatalogR
 // Re-export session upsert helpers for the live manager's flush_batch
 pub use queries::sessions::{
     execute_upsert_parsed_session, execute_upsert_session_stats_from_parsed,
+    PROJECT_DISPLAY_NAME_KEY_PREFIX, PROJECT_MERGE_KEY_PREFIX,
     UPSERT_SESSION_STATS_FROM_PARSED_SQL,
 };
 
@@ -66,6 +89,8 @@ pub use queries::sessions::{
 pub use trends::current_week_bounds;
 pub use trends::previous_week_bounds;
 pub use trends::IndexMetadata;
+pub use trends::IndexRunHistoryEntry;
+pub use trends::SearchIndexRebuildProgress;
 pub use trends::TrendMetric;
 pub use trends::WeekTrends;
 
@@ -112,6 +137,12 @@ pub enum DbError {
 
     #[error("Failed to create database directory: {0}")]
     CreateDir(#[from] std::io::Error),
+
+    #[error("Backup error: {0}")]
+    Backup(String),
+
+    #[error("Search index error: {0}")]
+    Search(#[from] claude_view_search::SearchError),
 }
 
 pub type DbResult<T> = Result<T, DbError>;
@@ -418,4 +418,96 @@ CREATE INDEX IF NOT EXISTS idx_session_stats_project_first_message ON session_st
 DROP TABLE IF EXISTS sessions;
 COMMIT;
 PRAGMA foreign_keys=ON;"#,
+    // Migration 92: append-only history of successful index runs, so the UI
+    // can chart indexing duration and session growth over time.
+    // `index_metadata` keeps only the latest run.
+    r#"CREATE TABLE IF NOT EXISTS index_metadata_history (
+    id                  INTEGER PRIMARY KEY AUTOINCREMENT,
+    indexed_at          INTEGER NOT NULL,
+    duration_ms         INTEGER NOT NULL,
+    sessions_indexed    INTEGER NOT NULL,
+    projects_indexed    INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_index_metadata_history_indexed_at
+    ON index_metadata_history(indexed_at DESC);"#,
+    // Migration 93: estimated output tokens spent on thinking blocks, so the
+    // UI can split total_output_tokens into thinking vs answer.
+    r#"ALTER TABLE session_stats ADD COLUMN thinking_output_tokens INTEGER NOT NULL DEFAULT 0;"#,
+    // Migration 94: where summary_text came from — "auto" (summary line or
+    // auto compaction), "manual" (user /compact) or "unknown".
+    r#"ALTER TABLE session_stats ADD COLUMN summary_source TEXT NOT NULL DEFAULT 'unknown';"#,
+    // Migration 95: secondary work types (JSON array of {workType, score},
+    // best first) next to the primary work_type.
+    r#"ALTER TABLE session_stats ADD COLUMN work_type_alternates TEXT;"#,
+    // Migration 96: assistant turns per model (JSON object of model id ->
+    // turns), replacing the per-turn model_id the dropped `turns` table held.
+    r#"ALTER TABLE session_stats ADD COLUMN model_turn_counts TEXT;"#,
+    // Migration 97: user interruptions (ESC/abort mid-turn) per session.
+    r#"ALTER TABLE session_stats ADD COLUMN interruption_count INTEGER NOT NULL DEFAULT 0;"#,
+    // Migration 98: MCP tools called, as a JSON array of {server, tool}, for
+    // the `mcp:<server>` search qualifier.
+    r#"ALTER TABLE session_stats ADD COLUMN mcp_tools TEXT NOT NULL DEFAULT '[]';"#,
+    // Migration 99: generic key-value settings store. Seeds the git sync
+    // interval from index_metadata, which no longer owns it.
+    r#"CREATE TABLE IF NOT EXISTS settings (
+    key     TEXT PRIMARY KEY,
+    value   TEXT NOT NULL
+);
+INSERT OR IGNORE INTO settings (key, value)
+    SELECT 'git_sync_interval_secs', CAST(git_sync_interval_secs AS TEXT)
+    FROM index_metadata WHERE id = 1;"#,
+    // Migration 100: per-repo status of branches sessions ran on, recorded by
+    // git sync ("active", "merged" into HEAD, or "deleted" locally).
+    r#"CREATE TABLE IF NOT EXISTS branch_status (
+    project_path    TEXT NOT NULL,
+    branch          TEXT NOT NULL,
+    status          TEXT NOT NULL CHECK (status IN ('active', 'merged', 'deleted')),
+    checked_at      INTEGER NOT NULL,
+    PRIMARY KEY (project_path, branch)
+);"#,
+    // Migration 101: Bash calls per command category (JSON object of
+    // git/test/build/misc -> count).
+    r#"ALTER TABLE session_stats ADD COLUMN bash_categories TEXT NOT NULL DEFAULT '{}';"#,
+    // Migration 102: taxonomy version each classification was made under
+    // (`claude_view_core::classification::TAXONOMY_VERSION`). Rows written
+    // before versioning existed were all classified under version 1.
+    r#"ALTER TABLE session_flags ADD COLUMN category_version INTEGER NOT NULL DEFAULT 1;"#,
+    // Migration 103: remote and default branch of each repo git sync scans,
+    // so project names can link to their hosted repo. remote_url and
+    // web_url are NULL for a repo with no remote.
+    r#"CREATE TABLE IF NOT EXISTS project_repos (
+    project_path    TEXT PRIMARY KEY,
+    remote_url      TEXT,
+    web_url         TEXT,
+    default_branch  TEXT,
+    checked_at      INTEGER NOT NULL
+);"#,
+    // Migration 104: checkpoint of the running (or last) full deep index
+    // rebuild, so a rebuild interrupted by a restart resumes instead of
+    // starting over. Singleton row; completed_at is NULL while in progress.
+    r#"CREATE TABLE IF NOT EXISTS search_index_rebuild_progress (
+    id                  INTEGER PRIMARY KEY CHECK (id = 1),
+    started_at          INTEGER NOT NULL,
+    last_session_id     TEXT,
+    sessions_processed  INTEGER NOT NULL DEFAULT 0,
+    updated_at          INTEGER NOT NULL,
+    completed_at        INTEGER
+);"#,
+    // Migration 105: allow "remote" in branch_status, for branches that are
+    // gone locally but still exist on a remote. SQLite can't alter a CHECK
+    // constraint, so the table is rebuilt.
+    r#"CREATE TABLE branch_status_new (
+    project_path    TEXT NOT NULL,
+    branch          TEXT NOT NULL,
+    status          TEXT NOT NULL CHECK (status IN ('active', 'merged', 'remote', 'deleted')),
+    checked_at      INTEGER NOT NULL,
+    PRIMARY KEY (project_path, branch)
+);
+INSERT INTO branch_status_new (project_path, branch, status, checked_at)
+    SELECT project_path, branch, status, checked_at FROM branch_status;
+DROP TABLE branch_status;
+ALTER TABLE branch_status_new RENAME TO branch_status;"#,
+    // Migration 106: slash-command and skill argument strings per session
+    // (JSON object of command or skill -> args -> count).
+    r#"ALTER TABLE session_stats ADD COLUMN invocation_args TEXT NOT NULL DEFAULT '{}';"#,
 ];
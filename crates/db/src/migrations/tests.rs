@@ -1992,10 +1992,18 @@ async fn test_migration64_session_stats_columns_exist() {
     // column onto `session_stats` so the table can be DROPped in Phase 7.h.6)
     // = 83. Each ADD in migration 89 lands one column; the rebuilt valid_sessions
     // view and the DROP ship in subsequent migrations.
+    // + 1 thinking_output_tokens (migration 93) = 84.
+    // + 1 summary_source (migration 94) = 85.
+    // + 1 work_type_alternates (migration 95) = 86.
+    // + 1 model_turn_counts (migration 96) = 87.
+    // + 1 interruption_count (migration 97) = 88.
+    // + 1 mcp_tools (migration 98) = 89.
+    // + 1 bash_categories (migration 101) = 90.
+    // + 1 invocation_args (migration 106) = 91.
     assert_eq!(
         names.len(),
-        83,
-        "session_stats column count drifted (expected 83 after migration 89; got {})",
+        91,
+        "session_stats column count drifted (expected 91 after migration 106; got {})",
         names.len()
     );
 }
@@ -2095,10 +2103,12 @@ async fn test_migration65_session_flags_columns_exist() {
         "category_source",
         "classified_at",
         "applied_seq",
+        // Migration 102.
+        "category_version",
     ] {
         assert!(names.contains(&col), "missing session_flags.{}", col);
     }
-    assert_eq!(names.len(), 10, "session_flags column count drifted");
+    assert_eq!(names.len(), 11, "session_flags column count drifted");
 }
 
 #[tokio::test]
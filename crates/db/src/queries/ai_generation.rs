@@ -6,11 +6,17 @@
 
 use std::collections::HashMap;
 
-use claude_view_core::pricing::TokenUsage;
+use claude_view_core::pricing::{calculate_cost, load_pricing, ModelPricing, TokenUsage};
 
-use super::{AIGenerationStats, AggregateCostBreakdown, TokensByModel, TokensByProject};
+use super::{
+    AIGenerationStats, AggregateCostBreakdown, FileChurn, ModelCost, ProjectCost, TokensByModel,
+    TokensByProject,
+};
 use crate::{Database, DbResult};
 
+/// Session references kept per file in `files_most_reedited`.
+const CHURN_SESSION_REFS: usize = 5;
+
 /// Aggregated per-model token buckets: (input, output, cache_read, cache_creation).
 type PerModelAgg = (i64, i64, i64, i64);
 
@@ -37,6 +43,48 @@ fn aggregate_per_model(rows: Vec<(String,)>) -> HashMap<String, PerModelAgg> {
     agg
 }
 
+/// Like [`aggregate_per_model`], but keeps every token field (including the
+/// 5m / 1h cache-creation split) so the result can be priced.
+pub(super) fn aggregate_usage_per_model(rows: Vec<(String,)>) -> HashMap<String, TokenUsage> {
+    let mut agg: HashMap<String, TokenUsage> = HashMap::new();
+    for (json,) in rows {
+        let per_model: HashMap<String, TokenUsage> =
+            serde_json::from_str(&json).unwrap_or_default();
+        for (model_id, usage) in per_model {
+            if model_id.is_empty() {
+                continue;
+            }
+            let entry = agg.entry(model_id).or_default();
+            entry.input_tokens += usage.input_tokens;
+            entry.output_tokens += usage.output_tokens;
+            entry.cache_read_tokens += usage.cache_read_tokens;
+            entry.cache_creation_tokens += usage.cache_creation_tokens;
+            entry.cache_creation_5m_tokens += usage.cache_creation_5m_tokens;
+            entry.cache_creation_1hr_tokens += usage.cache_creation_1hr_tokens;
+            entry.total_tokens += usage.total_tokens;
+        }
+    }
+    agg
+}
+
+/// Pricing table with the 200k-tier rates removed. The tier applies per API
+/// call, and usage summed across sessions would trip it for every busy model.
+pub(super) fn base_rate_pricing() -> HashMap<String, ModelPricing> {
+    load_pricing()
+        .into_iter()
+        .map(|(model, mp)| {
+            let base = ModelPricing {
+                input_cost_per_token_above_200k: None,
+                output_cost_per_token_above_200k: None,
+                cache_creation_cost_per_token_above_200k: None,
+                cache_read_cost_per_token_above_200k: None,
+                ..mp
+            };
+            (model, base)
+        })
+        .collect()
+}
+
 impl Database {
     // ========================================================================
     // AI Generation Statistics (for dashboard AI generation breakdown)
@@ -59,21 +107,24 @@ impl Database {
             total_output_tokens,
             cache_read_tokens,
             cache_creation_tokens,
-        ): (i64, i64, i64, i64, i64) = sqlx::query_as(
+            thinking_output_tokens,
+        ): (i64, i64, i64, i64, i64, i64) = sqlx::query_as(
             r#"
                 SELECT
-                    COALESCE(SUM(files_edited_count), 0),
-                    COALESCE(SUM(total_input_tokens), 0),
-                    COALESCE(SUM(total_output_tokens), 0),
-                    COALESCE(SUM(cache_read_tokens), 0),
-                    COALESCE(SUM(cache_creation_tokens), 0)
-                FROM valid_sessions
-                WHERE last_message_at >= ?1
-                  AND last_message_at <= ?2
-                  AND (?3 IS NULL OR project_id = ?3
-                       OR (git_root IS NOT NULL AND git_root <> '' AND git_root = ?3)
-                       OR (project_path IS NOT NULL AND project_path <> '' AND project_path = ?3))
-                  AND (?4 IS NULL OR git_branch = ?4)
+                    COALESCE(SUM(s.files_edited_count), 0),
+                    COALESCE(SUM(s.total_input_tokens), 0),
+                    COALESCE(SUM(s.total_output_tokens), 0),
+                    COALESCE(SUM(s.cache_read_tokens), 0),
+                    COALESCE(SUM(s.cache_creation_tokens), 0),
+                    COALESCE(SUM(ss.thinking_output_tokens), 0)
+                FROM valid_sessions s
+                LEFT JOIN session_stats ss ON ss.session_id = s.id
+                WHERE s.last_message_at >= ?1
+                  AND s.last_message_at <= ?2
+                  AND (?3 IS NULL OR s.project_id = ?3
+                       OR (s.git_root IS NOT NULL AND s.git_root <> '' AND s.git_root = ?3)
+                       OR (s.project_path IS NOT NULL AND s.project_path <> '' AND s.project_path = ?3))
+                  AND (?4 IS NULL OR s.git_branch = ?4)
                 "#,
         )
         .bind(from)
@@ -185,6 +236,7 @@ impl Database {
             total_output_tokens,
             cache_read_tokens,
             cache_creation_tokens,
+            thinking_output_tokens,
             tokens_by_model,
             tokens_by_project,
             cost: AggregateCostBreakdown::default(),
@@ -199,6 +251,198 @@ impl Database {
         project: Option<&str>,
         branch: Option<&str>,
     ) -> DbResult<Vec<(String, i64, i64, i64, i64)>> {
+        let rows = self
+            .per_model_tokens_rows(from, to, project, branch)
+            .await?;
+
+        Ok(aggregate_per_model(rows)
+            .into_iter()
+            .map(|(model, (i, o, cr, cc))| (model, i, o, cr, cc))
+            .collect())
+    }
+
+    /// Full cost per model over the filtered sessions, including cache read
+    /// and cache creation pricing. Sorted by total cost, most expensive first.
+    ///
+    /// Uses base per-token rates: the 200k tier applies per API call, and
+    /// summed usage would trip it for every busy model.
+    pub async fn cost_by_model(
+        &self,
+        from: Option<i64>,
+        to: Option<i64>,
+        project: Option<&str>,
+        branch: Option<&str>,
+    ) -> DbResult<Vec<ModelCost>> {
+        let rows = self
+            .per_model_tokens_rows(from, to, project, branch)
+            .await?;
+
+        let pricing = base_rate_pricing();
+
+        let mut costs: Vec<ModelCost> = aggregate_usage_per_model(rows)
+            .into_iter()
+            .map(|(model, usage)| {
+                let cost = calculate_cost(&usage, Some(&model), &pricing);
+                ModelCost {
+                    input_tokens: usage.input_tokens as i64,
+                    output_tokens: usage.output_tokens as i64,
+                    cache_read_tokens: usage.cache_read_tokens as i64,
+                    cache_creation_tokens: usage.cache_creation_tokens as i64,
+                    input_cost_usd: cost.input_cost_usd,
+                    output_cost_usd: cost.output_cost_usd,
+                    cache_read_cost_usd: cost.cache_read_cost_usd,
+                    cache_creation_cost_usd: cost.cache_creation_cost_usd,
+                    cache_savings_usd: cost.cache_savings_usd,
+                    total_cost_usd: cost.total_usd,
+                    has_unpriced_usage: cost.has_unpriced_usage,
+                    model,
+                }
+            })
+            .collect();
+        costs.sort_by(|a, b| {
+            b.total_cost_usd
+                .total_cmp(&a.total_cost_usd)
+                .then_with(|| a.model.cmp(&b.model))
+        });
+        Ok(costs)
+    }
+
+    /// Projects ranked by estimated cost over `[from, to]` (by
+    /// `last_message_at`), most expensive first, at most `limit` entries.
+    ///
+    /// Cost is priced per model from `per_model_tokens_json` with cache
+    /// read/write accounting, at base rates like [`Self::cost_by_model`].
+    /// Models without a pricing entry contribute tokens but no cost and set
+    /// `has_unpriced_usage`.
+    pub async fn project_cost_leaderboard(
+        &self,
+        from: Option<i64>,
+        to: Option<i64>,
+        limit: usize,
+    ) -> DbResult<Vec<ProjectCost>> {
+        let from = from.unwrap_or(1);
+        let to = to.unwrap_or(i64::MAX);
+
+        let rows: Vec<(String, String, String)> = sqlx::query_as(
+            r#"
+            SELECT s.project_id,
+                   COALESCE(NULLIF(s.project_display_name, ''), s.project_id),
+                   ss.per_model_tokens_json
+            FROM valid_sessions s
+            JOIN session_stats ss ON ss.session_id = s.id
+            WHERE s.last_message_at >= ?1
+              AND s.last_message_at <= ?2
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .fetch_all(self.pool())
+        .await?;
+
+        let mut by_project: HashMap<String, (String, Vec<(String,)>)> = HashMap::new();
+        for (project_id, display_name, json) in rows {
+            by_project
+                .entry(project_id)
+                .or_insert_with(|| (display_name, Vec::new()))
+                .1
+                .push((json,));
+        }
+
+        let pricing = base_rate_pricing();
+        let mut leaderboard: Vec<ProjectCost> = by_project
+            .into_iter()
+            .map(|(project_id, (project_display_name, jsons))| {
+                let session_count = jsons.len() as i64;
+                let mut entry = ProjectCost {
+                    project_id,
+                    project_display_name,
+                    session_count,
+                    input_tokens: 0,
+                    output_tokens: 0,
+                    cache_read_tokens: 0,
+                    cache_creation_tokens: 0,
+                    total_cost_cents: 0,
+                    has_unpriced_usage: false,
+                };
+                let mut total_usd = 0.0;
+                for (model, usage) in aggregate_usage_per_model(jsons) {
+                    let cost = calculate_cost(&usage, Some(&model), &pricing);
+                    entry.input_tokens += usage.input_tokens as i64;
+                    entry.output_tokens += usage.output_tokens as i64;
+                    entry.cache_read_tokens += usage.cache_read_tokens as i64;
+                    entry.cache_creation_tokens += usage.cache_creation_tokens as i64;
+                    entry.has_unpriced_usage |= cost.has_unpriced_usage;
+                    total_usd += cost.total_usd;
+                }
+                entry.total_cost_cents = (total_usd * 100.0).round() as i64;
+                entry
+            })
+            .collect();
+        leaderboard.sort_by(|a, b| {
+            b.total_cost_cents
+                .cmp(&a.total_cost_cents)
+                .then_with(|| a.project_id.cmp(&b.project_id))
+        });
+        leaderboard.truncate(limit);
+        Ok(leaderboard)
+    }
+
+    /// Files the AI re-edits most: per file, edits beyond the first within a
+    /// session, summed across sessions. Most re-edited first (ties by session
+    /// count, then path), at most `limit` entries.
+    ///
+    /// Unrolls `session_stats.files_edited`, which keeps one entry per edit.
+    pub async fn files_most_reedited(&self, limit: usize) -> DbResult<Vec<FileChurn>> {
+        let rows: Vec<(String, String, i64)> = sqlx::query_as(
+            r#"
+            SELECT j.value, s.id, COUNT(*)
+            FROM valid_sessions s, json_each(s.files_edited) j
+            WHERE json_valid(s.files_edited)
+            GROUP BY s.id, j.value
+            HAVING COUNT(*) > 1
+            ORDER BY s.last_message_at DESC, s.id
+            "#,
+        )
+        .fetch_all(self.pool())
+        .await?;
+
+        let mut by_file: HashMap<String, FileChurn> = HashMap::new();
+        for (file_path, session_id, edits) in rows {
+            let entry = by_file
+                .entry(file_path.clone())
+                .or_insert_with(|| FileChurn {
+                    file_path,
+                    reedit_count: 0,
+                    session_count: 0,
+                    session_ids: Vec::new(),
+                });
+            entry.reedit_count += edits - 1;
+            entry.session_count += 1;
+            if entry.session_ids.len() < CHURN_SESSION_REFS {
+                entry.session_ids.push(session_id);
+            }
+        }
+
+        let mut files: Vec<FileChurn> = by_file.into_values().collect();
+        files.sort_by(|a, b| {
+            b.reedit_count
+                .cmp(&a.reedit_count)
+                .then_with(|| b.session_count.cmp(&a.session_count))
+                .then_with(|| a.file_path.cmp(&b.file_path))
+        });
+        files.truncate(limit);
+        Ok(files)
+    }
+
+    /// `per_model_tokens_json` blobs for the sessions matching the shared
+    /// time / project / branch filter.
+    async fn per_model_tokens_rows(
+        &self,
+        from: Option<i64>,
+        to: Option<i64>,
+        project: Option<&str>,
+        branch: Option<&str>,
+    ) -> DbResult<Vec<(String,)>> {
         let from = from.unwrap_or(1);
         let to = to.unwrap_or(i64::MAX);
 
@@ -221,10 +465,6 @@ impl Database {
         .bind(branch)
         .fetch_all(self.pool())
         .await?;
-
-        Ok(aggregate_per_model(rows)
-            .into_iter()
-            .map(|(model, (i, o, cr, cc))| (model, i, o, cr, cc))
-            .collect())
+        Ok(rows)
     }
 }
@@ -0,0 +1,519 @@
+//! Portable NDJSON backup of every table, for moving indexed data between
+//! machines without re-parsing JSONL.
+//!
+//! Bundle layout — a header line, then one line per row:
+//!
+//! ```text
+//! {"format":"claude-view-backup","schemaVersion":102}
+//! {"table":"session_stats","row":{"session_id":"abc", ...}}
+//! ```
+//!
+//! BLOB values are written as `{"$blob":"<hex>"}`. Import only accepts a
+//! bundle whose schema version (highest applied migration) equals the
+//! target database's, so every column maps one-to-one.
+//!
+//! Rows conflict on their table's primary key. Tables keyed by an INTEGER
+//! rowid (`id INTEGER PRIMARY KEY`, `seq ... AUTOINCREMENT`) have ids that
+//! only mean something in the database that assigned them, so import refuses
+//! to write into such a table unless it is empty. Singleton tables pinned by
+//! `CHECK (id = 1)` are keyed by that fixed id and merge like any other.
+
+use std::collections::{BTreeMap, HashSet};
+use std::io::{BufRead, Write};
+
+use futures_util::TryStreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sqlx::sqlite::{SqliteArguments, SqliteRow};
+use sqlx::{Column, Row, Sqlite, TypeInfo, ValueRef};
+
+use crate::{Database, DbError, DbResult};
+
+/// `format` value of a backup header line.
+pub const BACKUP_FORMAT: &str = "claude-view-backup";
+
+/// What to do when an imported row collides with an existing one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BackupConflict {
+    /// Keep the existing row.
+    #[default]
+    Skip,
+    /// Update the existing row in place with the backup's values.
+    Overwrite,
+}
+
+/// Rows written (export) or inserted/updated (import), per table.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BackupSummary {
+    pub schema_version: i64,
+    pub rows: BTreeMap<String, u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BackupHeader {
+    format: String,
+    schema_version: i64,
+}
+
+/// A table's columns, and whether its key is a local rowid (see module docs).
+struct BackupTable {
+    columns: HashSet<String>,
+    rowid_key: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BackupRow {
+    table: String,
+    row: Map<String, Value>,
+}
+
+fn backup_err(msg: impl Into<String>) -> DbError {
+    DbError::Backup(msg.into())
+}
+
+fn write_line<W: Write, T: Serialize>(out: &mut W, value: &T) -> DbResult<()> {
+    serde_json::to_writer(&mut *out, value).map_err(|e| backup_err(e.to_string()))?;
+    out.write_all(b"\n").map_err(|e| backup_err(e.to_string()))
+}
+
+impl Database {
+    /// Highest applied migration — the schema version stamped on backups.
+    pub async fn schema_version(&self) -> DbResult<i64> {
+        let (version,): (i64,) =
+            sqlx::query_as("SELECT COALESCE(MAX(version), 0) FROM _migrations")
+                .fetch_one(self.pool())
+                .await?;
+        Ok(version)
+    }
+
+    /// Write every table to `out` as an NDJSON bundle (see module docs).
+    /// Reads run in one transaction so the bundle is a consistent snapshot.
+    pub async fn export_full_backup<W: Write>(&self, out: &mut W) -> DbResult<BackupSummary> {
+        let schema_version = self.schema_version().await?;
+        write_line(
+            out,
+            &BackupHeader {
+                format: BACKUP_FORMAT.to_string(),
+                schema_version,
+            },
+        )?;
+
+        let mut summary = BackupSummary {
+            schema_version,
+            ..Default::default()
+        };
+        let mut tx = self.pool().begin().await?;
+        for table in self.backup_tables().await?.into_keys() {
+            let sql = format!("SELECT * FROM \"{table}\"");
+            let mut rows = sqlx::query(&sql).fetch(&mut *tx);
+            let mut count = 0u64;
+            while let Some(row) = rows.try_next().await? {
+                write_line(
+                    out,
+                    &BackupRow {
+                        table: table.clone(),
+                        row: row_to_json(&row)?,
+                    },
+                )?;
+                count += 1;
+            }
+            drop(rows);
+            summary.rows.insert(table, count);
+        }
+        tx.commit().await?;
+        out.flush().map_err(|e| backup_err(e.to_string()))?;
+        Ok(summary)
+    }
+
+    /// Load a bundle written by [`Self::export_full_backup`] in a single
+    /// transaction. Fails without writing anything if the header is missing,
+    /// the schema version differs from this database's, a row names an
+    /// unknown table or column, or a row targets a non-empty rowid-keyed
+    /// table (see module docs).
+    pub async fn import_backup<R: BufRead>(
+        &self,
+        input: R,
+        conflict: BackupConflict,
+    ) -> DbResult<BackupSummary> {
+        let mut lines = input.lines();
+        let header_line = lines
+            .next()
+            .ok_or_else(|| backup_err("backup is empty"))?
+            .map_err(|e| backup_err(e.to_string()))?;
+        let header: BackupHeader = serde_json::from_str(&header_line)
+            .map_err(|e| backup_err(format!("invalid backup header: {e}")))?;
+        if header.format != BACKUP_FORMAT {
+            return Err(backup_err(format!(
+                "not a claude-view backup: {}",
+                header.format
+            )));
+        }
+        let schema_version = self.schema_version().await?;
+        if header.schema_version != schema_version {
+            return Err(backup_err(format!(
+                "backup schema version {} does not match database schema version {}",
+                header.schema_version, schema_version
+            )));
+        }
+
+        let tables = self.backup_tables().await?;
+        let mut summary = BackupSummary {
+            schema_version,
+            ..Default::default()
+        };
+        let mut tx = self.pool().begin().await?;
+        // Tables are exported alphabetically, not in dependency order.
+        sqlx::query("PRAGMA defer_foreign_keys = ON")
+            .execute(&mut *tx)
+            .await?;
+        // Rowid-keyed tables found empty before this import started.
+        let mut empty_rowid_tables: HashSet<String> = HashSet::new();
+        for (i, line) in lines.enumerate() {
+            let line = line.map_err(|e| backup_err(e.to_string()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let line_no = i + 2;
+            let record: BackupRow = serde_json::from_str(&line)
+                .map_err(|e| backup_err(format!("line {line_no}: {e}")))?;
+            let table = tables.get(&record.table).ok_or_else(|| {
+                backup_err(format!("line {line_no}: unknown table {}", record.table))
+            })?;
+            if let Some(col) = record.row.keys().find(|c| !table.columns.contains(*c)) {
+                return Err(backup_err(format!(
+                    "line {line_no}: unknown column {}.{col}",
+                    record.table
+                )));
+            }
+            if record.row.is_empty() {
+                continue;
+            }
+            if table.rowid_key && !empty_rowid_tables.contains(&record.table) {
+                let (has_rows,): (bool,) = sqlx::query_as(&format!(
+                    "SELECT EXISTS (SELECT 1 FROM \"{}\")",
+                    record.table
+                ))
+                .fetch_one(&mut *tx)
+                .await?;
+                if has_rows {
+                    return Err(backup_err(format!(
+                        "line {line_no}: table {} already has rows and is keyed by local ids; \
+                         import into an empty database",
+                        record.table
+                    )));
+                }
+                empty_rowid_tables.insert(record.table.clone());
+            }
+
+            let sql = insert_sql(&record.table, record.row.keys(), conflict);
+            let mut query = sqlx::query::<Sqlite>(&sql);
+            for value in record.row.values() {
+                query = bind_json(query, value)
+                    .map_err(|e| backup_err(format!("line {line_no}: {e}")))?;
+            }
+            let affected = query.execute(&mut *tx).await?.rows_affected();
+            *summary.rows.entry(record.table).or_default() += affected;
+        }
+        tx.commit().await?;
+        Ok(summary)
+    }
+
+    /// Backed-up tables and their columns. Skips SQLite internals and the
+    /// migration tracker, which the target database owns.
+    async fn backup_tables(&self) -> DbResult<BTreeMap<String, BackupTable>> {
+        let rows: Vec<(String, String, String, i64, String)> = sqlx::query_as(
+            r#"
+            SELECT m.name, p.name, UPPER(p.type), p.pk, COALESCE(m.sql, '')
+            FROM sqlite_master m, pragma_table_info(m.name) p
+            WHERE m.type = 'table'
+              AND m.name NOT LIKE 'sqlite_%'
+              AND m.name != '_migrations'
+            "#,
+        )
+        .fetch_all(self.pool())
+        .await?;
+        // table -> (columns, primary key columns with their types, CREATE sql)
+        type Grouped = (HashSet<String>, Vec<(String, String)>, String);
+        let mut grouped: BTreeMap<String, Grouped> = BTreeMap::new();
+        for (table, column, ty, pk, sql) in rows {
+            let (columns, key, _) = grouped
+                .entry(table)
+                .or_insert_with(|| (HashSet::new(), Vec::new(), sql));
+            if pk > 0 {
+                key.push((column.clone(), ty));
+            }
+            columns.insert(column);
+        }
+        let tables = grouped
+            .into_iter()
+            .map(|(table, (columns, key, sql))| {
+                let rowid_key = matches!(key.as_slice(),
+                    [(column, ty)] if ty == "INTEGER" && !is_singleton(&sql, column));
+                (table, BackupTable { columns, rowid_key })
+            })
+            .collect();
+        Ok(tables)
+    }
+}
+
+/// `INSERT` for one row. `Overwrite` upserts column-by-column rather than
+/// `INSERT OR REPLACE`, which would delete the old row and fire
+/// `ON DELETE CASCADE` on its children.
+fn insert_sql<'a>(
+    table: &str,
+    columns: impl Iterator<Item = &'a String>,
+    conflict: BackupConflict,
+) -> String {
+    let columns: Vec<&String> = columns.collect();
+    let names = columns
+        .iter()
+        .map(|c| format!("\"{c}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let params = (1..=columns.len())
+        .map(|i| format!("?{i}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let on_conflict = match conflict {
+        BackupConflict::Skip => "DO NOTHING".to_string(),
+        BackupConflict::Overwrite => format!(
+            "DO UPDATE SET {}",
+            columns
+                .iter()
+                .map(|c| format!("\"{c}\" = excluded.\"{c}\""))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    format!("INSERT INTO \"{table}\" ({names}) VALUES ({params}) ON CONFLICT {on_conflict}")
+}
+
+/// Whether `sql` pins the table to a single row with `CHECK (<column> = N)`.
+fn is_singleton(sql: &str, column: &str) -> bool {
+    let compact: String = sql
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '"')
+        .collect::<String>()
+        .to_lowercase();
+    compact.contains(&format!("check({}=", column.to_lowercase()))
+}
+
+fn row_to_json(row: &SqliteRow) -> DbResult<Map<String, Value>> {
+    let mut map = Map::new();
+    for (i, column) in row.columns().iter().enumerate() {
+        let raw = row.try_get_raw(i)?;
+        let value = if raw.is_null() {
+            Value::Null
+        } else {
+            match raw.type_info().name() {
+                "INTEGER" => Value::from(row.try_get::<i64, _>(i)?),
+                "REAL" => serde_json::Number::from_f64(row.try_get::<f64, _>(i)?)
+                    .map_or(Value::Null, Value::Number),
+                "BLOB" => serde_json::json!({ "$blob": to_hex(&row.try_get::<Vec<u8>, _>(i)?) }),
+                _ => Value::String(row.try_get::<String, _>(i)?),
+            }
+        };
+        map.insert(column.name().to_string(), value);
+    }
+    Ok(map)
+}
+
+fn bind_json<'q>(
+    query: sqlx::query::Query<'q, Sqlite, SqliteArguments<'q>>,
+    value: &Value,
+) -> Result<sqlx::query::Query<'q, Sqlite, SqliteArguments<'q>>, String> {
+    Ok(match value {
+        Value::Null => query.bind(None::<i64>),
+        Value::Bool(b) => query.bind(i64::from(*b)),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => query.bind(i),
+            None => query.bind(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => query.bind(s.clone()),
+        Value::Object(obj) => match obj.get("$blob").and_then(Value::as_str) {
+            Some(hex) => query.bind(from_hex(hex).ok_or("invalid $blob hex")?),
+            None => return Err(format!("unsupported value {value}")),
+        },
+        Value::Array(_) => return Err(format!("unsupported value {value}")),
+    })
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SessionSeedBuilder;
+
+    async fn seeded_db() -> Database {
+        let db = Database::new_in_memory().await.unwrap();
+        for (i, id) in ["s1", "s2", "s3"].into_iter().enumerate() {
+            SessionSeedBuilder::new(id)
+                .project_id("proj")
+                .file_path(format!("/tmp/{id}.jsonl"))
+                .last_message_at(1_700_000_000 + i as i64)
+                .total_input_tokens(100 * (i as i64 + 1))
+                .total_cost_usd(0.25)
+                .seed(&db)
+                .await
+                .unwrap();
+        }
+        db.set_setting("theme", "dark").await.unwrap();
+        db
+    }
+
+    async fn export(db: &Database) -> Vec<u8> {
+        let mut bundle = Vec::new();
+        db.export_full_backup(&mut bundle).await.unwrap();
+        bundle
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_into_fresh_db() {
+        let source = seeded_db().await;
+        let bundle = export(&source).await;
+
+        let target = Database::new_in_memory().await.unwrap();
+        let summary = target
+            .import_backup(bundle.as_slice(), BackupConflict::Skip)
+            .await
+            .unwrap();
+        assert_eq!(summary.rows.get("session_stats"), Some(&3));
+
+        assert_eq!(
+            target.count_all_sessions().await.unwrap(),
+            source.count_all_sessions().await.unwrap()
+        );
+        assert_eq!(target.count_all_sessions().await.unwrap(), 3);
+        assert_eq!(
+            target.get_setting("theme").await.unwrap().as_deref(),
+            Some("dark")
+        );
+        // BLOB and REAL columns survive the JSON encoding.
+        let row: (Vec<u8>, f64) = sqlx::query_as(
+            "SELECT source_content_hash, total_cost_usd FROM session_stats WHERE session_id = 's2'",
+        )
+        .fetch_one(target.pool())
+        .await
+        .unwrap();
+        let expected: (Vec<u8>, f64) = sqlx::query_as(
+            "SELECT source_content_hash, total_cost_usd FROM session_stats WHERE session_id = 's2'",
+        )
+        .fetch_one(source.pool())
+        .await
+        .unwrap();
+        assert_eq!(row, expected);
+    }
+
+    #[tokio::test]
+    async fn test_conflicts_skip_or_overwrite() {
+        let source = seeded_db().await;
+        let bundle = export(&source).await;
+
+        let target = Database::new_in_memory().await.unwrap();
+        target.set_setting("theme", "light").await.unwrap();
+
+        let skipped = target
+            .import_backup(bundle.as_slice(), BackupConflict::Skip)
+            .await
+            .unwrap();
+        assert_eq!(skipped.rows.get("settings"), Some(&0), "both keys exist");
+        assert_eq!(
+            target.get_setting("theme").await.unwrap().as_deref(),
+            Some("light")
+        );
+
+        target
+            .import_backup(bundle.as_slice(), BackupConflict::Overwrite)
+            .await
+            .unwrap();
+        assert_eq!(
+            target.get_setting("theme").await.unwrap().as_deref(),
+            Some("dark")
+        );
+        assert_eq!(target.count_all_sessions().await.unwrap(), 3);
+    }
+
+    async fn insert_hook_event(db: &Database, session_id: &str) {
+        sqlx::query(
+            "INSERT INTO hook_events (id, session_id, timestamp, event_name, label, group_name)
+             VALUES (1, ?1, 1700000000, 'Stop', 'Stop', 'lifecycle')",
+        )
+        .bind(session_id)
+        .execute(db.pool())
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_refuses_rowid_tables_that_already_have_rows() {
+        let source = seeded_db().await;
+        insert_hook_event(&source, "s1").await;
+        let bundle = export(&source).await;
+
+        // An unrelated local row holds the same surrogate id.
+        let target = Database::new_in_memory().await.unwrap();
+        insert_hook_event(&target, "local").await;
+
+        for conflict in [BackupConflict::Skip, BackupConflict::Overwrite] {
+            let err = target
+                .import_backup(bundle.as_slice(), conflict)
+                .await
+                .unwrap_err();
+            assert!(matches!(err, DbError::Backup(ref msg) if msg.contains("hook_events")));
+        }
+        let (session_id,): (String,) =
+            sqlx::query_as("SELECT session_id FROM hook_events WHERE id = 1")
+                .fetch_one(target.pool())
+                .await
+                .unwrap();
+        assert_eq!(session_id, "local", "local row must be untouched");
+        assert_eq!(target.count_all_sessions().await.unwrap(), 0);
+
+        // Into an empty database the ids carry over as-is.
+        let fresh = Database::new_in_memory().await.unwrap();
+        fresh
+            .import_backup(bundle.as_slice(), BackupConflict::Skip)
+            .await
+            .unwrap();
+        let (session_id,): (String,) =
+            sqlx::query_as("SELECT session_id FROM hook_events WHERE id = 1")
+                .fetch_one(fresh.pool())
+                .await
+                .unwrap();
+        assert_eq!(session_id, "s1");
+    }
+
+    #[tokio::test]
+    async fn test_rejects_schema_version_mismatch() {
+        let source = seeded_db().await;
+        let bundle = String::from_utf8(export(&source).await).unwrap();
+        let version = source.schema_version().await.unwrap();
+        let tampered = bundle.replacen(
+            &format!("\"schemaVersion\":{version}"),
+            &format!("\"schemaVersion\":{}", version - 1),
+            1,
+        );
+
+        let target = Database::new_in_memory().await.unwrap();
+        let err = target
+            .import_backup(tampered.as_bytes(), BackupConflict::Skip)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DbError::Backup(ref msg) if msg.contains("schema version")));
+        assert_eq!(target.count_all_sessions().await.unwrap(), 0);
+    }
+}
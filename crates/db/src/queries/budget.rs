@@ -0,0 +1,173 @@
+// crates/db/src/queries/budget.rs
+//! Monthly cost budget check.
+//!
+//! Spend is the sum of `total_cost_usd` over valid sessions that started in
+//! the month (UTC), the same per-session cost the indexer prices from the
+//! model pricing table. Per-turn cost is not stored, so a session counts
+//! toward the month it started in.
+
+use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Utc};
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::{Database, DbResult};
+
+/// Where spend stands against the monthly cap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "snake_case")]
+pub enum BudgetState {
+    /// Spend and the end-of-month projection are within the cap.
+    Under,
+    /// Spend is within the cap, but the projection exceeds it.
+    ProjectedOver,
+    /// Spend already exceeds the cap.
+    Over,
+}
+
+/// Spend-to-date and end-of-month projection for one month.
+#[derive(Debug, Clone, Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetStatus {
+    /// Month as `YYYY-MM`.
+    pub month: String,
+    #[ts(type = "number")]
+    pub cap_cents: i64,
+    /// Priced spend of sessions started in the month so far.
+    #[ts(type = "number")]
+    pub spent_cents: i64,
+    /// Spend extrapolated linearly to the end of the month. Equals
+    /// `spent_cents` for a past month.
+    #[ts(type = "number")]
+    pub projected_cents: i64,
+    /// Fraction of the month elapsed (0.0-1.0).
+    pub month_elapsed: f64,
+    /// Sessions in the month with no price (unknown model); their cost is
+    /// missing from the totals.
+    #[ts(type = "number")]
+    pub unpriced_session_count: i64,
+    pub state: BudgetState,
+}
+
+/// UTC bounds `[start, end)` of the month containing `month`.
+fn month_bounds(month: NaiveDate) -> (DateTime<Utc>, DateTime<Utc>) {
+    let first = month.with_day(1).expect("day 1 exists in every month");
+    let next = if first.month() == 12 {
+        NaiveDate::from_ymd_opt(first.year() + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(first.year(), first.month() + 1, 1)
+    }
+    .expect("first of next month is a valid date");
+    let at_midnight = |d: NaiveDate| Utc.from_utc_datetime(&d.and_hms_opt(0, 0, 0).unwrap());
+    (at_midnight(first), at_midnight(next))
+}
+
+impl Database {
+    /// Budget status of the month containing `month` against `cap_cents`.
+    pub async fn budget_status(&self, month: NaiveDate, cap_cents: i64) -> DbResult<BudgetStatus> {
+        self.budget_status_at(month, cap_cents, Utc::now()).await
+    }
+
+    /// [`Database::budget_status`] as seen at `now`, which sets how much of
+    /// the month has elapsed for the projection.
+    pub async fn budget_status_at(
+        &self,
+        month: NaiveDate,
+        cap_cents: i64,
+        now: DateTime<Utc>,
+    ) -> DbResult<BudgetStatus> {
+        let (start, end) = month_bounds(month);
+        let (spent_usd, unpriced_session_count): (Option<f64>, i64) = sqlx::query_as(
+            r#"SELECT
+                SUM(total_cost_usd),
+                COALESCE(SUM(CASE WHEN total_cost_usd IS NULL THEN 1 ELSE 0 END), 0)
+            FROM valid_sessions
+            WHERE first_message_at >= ?1 AND first_message_at < ?2"#,
+        )
+        .bind(start.timestamp())
+        .bind(end.timestamp())
+        .fetch_one(self.pool())
+        .await?;
+
+        let spent_cents = (spent_usd.unwrap_or(0.0) * 100.0).round() as i64;
+        let month_elapsed = ((now - start).num_seconds() as f64
+            / (end - start).num_seconds() as f64)
+            .clamp(0.0, 1.0);
+        let projected_cents = if month_elapsed > 0.0 {
+            (spent_cents as f64 / month_elapsed).round() as i64
+        } else {
+            spent_cents
+        };
+        let state = if spent_cents > cap_cents {
+            BudgetState::Over
+        } else if projected_cents > cap_cents {
+            BudgetState::ProjectedOver
+        } else {
+            BudgetState::Under
+        };
+
+        Ok(BudgetStatus {
+            month: start.format("%Y-%m").to_string(),
+            cap_cents,
+            spent_cents,
+            projected_cents,
+            month_elapsed,
+            unpriced_session_count,
+            state,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SessionSeedBuilder;
+
+    #[tokio::test]
+    async fn mid_month_spend_projects_to_month_end() {
+        let db = Database::new_in_memory().await.unwrap();
+        let ts = |d: u32| {
+            Utc.with_ymd_and_hms(2026, 4, d, 12, 0, 0)
+                .unwrap()
+                .timestamp()
+        };
+        // $30 spent by mid-April, plus sessions outside the month.
+        for (id, started, cost) in [
+            ("a", ts(2), 10.0),
+            ("b", ts(10), 20.0),
+            ("march", ts(1) - 3 * 86_400, 50.0),
+        ] {
+            SessionSeedBuilder::new(id)
+                .file_path(format!("/tmp/{id}.jsonl"))
+                .first_message_at(started)
+                .last_message_at(started)
+                .total_cost_usd(cost)
+                .seed(&db)
+                .await
+                .unwrap();
+        }
+
+        // April has 30 days; the 16th at 00:00 UTC is exactly halfway.
+        let now = Utc.with_ymd_and_hms(2026, 4, 16, 0, 0, 0).unwrap();
+        let april = NaiveDate::from_ymd_opt(2026, 4, 20).unwrap();
+
+        let status = db.budget_status_at(april, 5_000, now).await.unwrap();
+        assert_eq!(status.month, "2026-04");
+        assert_eq!(status.spent_cents, 3_000);
+        assert_eq!(status.month_elapsed, 0.5);
+        assert_eq!(status.projected_cents, 6_000);
+        assert_eq!(status.state, BudgetState::ProjectedOver);
+
+        let roomy = db.budget_status_at(april, 10_000, now).await.unwrap();
+        assert_eq!(roomy.state, BudgetState::Under);
+        let tight = db.budget_status_at(april, 2_000, now).await.unwrap();
+        assert_eq!(tight.state, BudgetState::Over);
+
+        // Once the month is over the projection is the actual spend.
+        let later = Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap();
+        let closed = db.budget_status_at(april, 5_000, later).await.unwrap();
+        assert_eq!(closed.projected_cents, 3_000);
+        assert_eq!(closed.state, BudgetState::Under);
+    }
+}
@@ -450,6 +450,7 @@ impl Database {
                 "l3": l3,
                 "confidence": confidence,
                 "source": source,
+                "version": claude_view_core::classification::TAXONOMY_VERSION,
             })
             .to_string();
             let actor = format!("classifier:{source}");
@@ -467,6 +468,44 @@ impl Database {
         Ok(())
     }
 
+    /// Session IDs whose classification was made under a taxonomy version
+    /// older than `current_version`.
+    pub async fn get_stale_classified_sessions(
+        &self,
+        current_version: i64,
+    ) -> DbResult<Vec<String>> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            r#"
+            SELECT session_id
+            FROM session_flags
+            WHERE category_l1 IS NOT NULL AND category_version < ?1
+            ORDER BY session_id
+            "#,
+        )
+        .bind(current_version)
+        .fetch_all(self.pool())
+        .await?;
+        Ok(rows.into_iter().map(|(id,)| id).collect())
+    }
+
+    /// Clear every classification older than `current_version` so the next
+    /// classification job picks those sessions up again. Returns how many
+    /// sessions were queued.
+    ///
+    /// Writes one `unclassify` action per session (single transaction); the
+    /// fold worker clears `session_flags.category_*` when it consumes them.
+    pub async fn reclassify_stale_categories(&self, current_version: i64) -> DbResult<u64> {
+        let stale = self.get_stale_classified_sessions(current_version).await?;
+        let at_ms = Utc::now().timestamp_millis();
+        let actor = format!("taxonomy:v{current_version}");
+        let mut tx = self.pool().begin().await?;
+        for session_id in &stale {
+            insert_action_log_tx(&mut *tx, session_id, "unclassify", "{}", &actor, at_ms).await?;
+        }
+        tx.commit().await?;
+        Ok(stale.len() as u64)
+    }
+
     /// Get a classification job by ID.
     pub async fn get_classification_job(
         &self,
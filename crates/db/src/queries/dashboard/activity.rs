@@ -1,10 +1,60 @@
 // crates/db/src/queries/dashboard/activity.rs
 // Activity histogram and rich activity aggregation queries.
 
-use super::types::{ActivityPoint, ActivitySummaryRow, ProjectActivityRow, RichActivityResponse};
+use super::types::{
+    ActivityPoint, ActivityStreak, ActivitySummaryRow, ProjectActivityRow, RichActivityResponse,
+};
 use crate::{Database, DbResult};
 
 impl Database {
+    /// Current and longest runs of consecutive days with at least one session.
+    ///
+    /// Days are bucketed in local time by shifting `last_message_at` by
+    /// `utc_offset_secs`. The current streak only counts if `now` (unix
+    /// seconds) falls on an active local day.
+    pub async fn activity_streak(
+        &self,
+        now: i64,
+        utc_offset_secs: i64,
+    ) -> DbResult<ActivityStreak> {
+        let days: Vec<(String,)> = sqlx::query_as(
+            "SELECT DISTINCT date(last_message_at + ?1, 'unixepoch') AS day \
+             FROM valid_sessions WHERE last_message_at > 0 ORDER BY day",
+        )
+        .bind(utc_offset_secs)
+        .fetch_all(self.pool())
+        .await?;
+        let days: Vec<chrono::NaiveDate> = days
+            .iter()
+            .filter_map(|(d,)| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+            .collect();
+
+        let mut longest = 0;
+        let mut run = 0;
+        let mut prev: Option<chrono::NaiveDate> = None;
+        for &day in &days {
+            run = match prev {
+                Some(p) if p.succ_opt() == Some(day) => run + 1,
+                _ => 1,
+            };
+            longest = longest.max(run);
+            prev = Some(day);
+        }
+
+        let today =
+            chrono::DateTime::from_timestamp(now + utc_offset_secs, 0).map(|dt| dt.date_naive());
+        let current = if prev.is_some() && prev == today {
+            run
+        } else {
+            0
+        };
+
+        Ok(ActivityStreak {
+            current_days: current,
+            longest_days: longest,
+        })
+    }
+
     /// Activity histogram for sparkline chart.
     /// Auto-buckets by day/week/month based on data span.
     /// Optional time_after/time_before filter to match page context.
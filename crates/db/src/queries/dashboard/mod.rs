@@ -7,4 +7,7 @@ mod session_queries;
 mod stats;
 mod types;
 
-pub use types::{ActivityPoint, ActivitySummaryRow, ProjectActivityRow, RichActivityResponse};
+pub use types::{
+    ActivityPoint, ActivityStreak, ActivitySummaryRow, ProjectActivityRow, RichActivityResponse,
+    TokenHistogramBucket,
+};
@@ -4,12 +4,15 @@
 // see `crates/server/src/routes/projects.rs`.)
 
 use super::super::BranchCount;
+use crate::git_correlation::BranchStatus;
 use crate::{Database, DbResult};
 
 impl Database {
-    /// List distinct branches with session counts for a project identity.
+    /// List distinct branches with session counts and last activity for a
+    /// project identity.
     ///
-    /// Returns branches sorted by session count DESC.
+    /// Returns branches sorted by most recent activity DESC (ties by session
+    /// count DESC).
     /// Includes sessions with `git_branch = NULL` as a separate entry.
     ///
     /// `project_identity` may be either:
@@ -19,17 +22,21 @@ impl Database {
         &self,
         project_identity: &str,
     ) -> DbResult<Vec<BranchCount>> {
-        let rows: Vec<(Option<String>, i64)> = sqlx::query_as(
+        let rows: Vec<(Option<String>, i64, i64, Option<String>)> = sqlx::query_as(
             r#"
-            SELECT NULLIF(git_branch, '') as branch, COUNT(*) as count
-            FROM valid_sessions
+            SELECT NULLIF(s.git_branch, '') as branch, COUNT(*) as count,
+                   COALESCE(MAX(s.last_message_at), 0) as last_activity_at,
+                   GROUP_CONCAT(DISTINCT bs.status) as statuses
+            FROM valid_sessions s
+            LEFT JOIN branch_status bs
+                ON bs.project_path = s.project_path AND bs.branch = s.git_branch
             WHERE (
-                project_id = ?1
-                OR (git_root IS NOT NULL AND git_root != '' AND git_root = ?1)
-                OR (project_path IS NOT NULL AND project_path != '' AND project_path = ?1)
+                s.project_id = ?1
+                OR (s.git_root IS NOT NULL AND s.git_root != '' AND s.git_root = ?1)
+                OR (s.project_path IS NOT NULL AND s.project_path != '' AND s.project_path = ?1)
             )
-            GROUP BY NULLIF(git_branch, '')
-            ORDER BY count DESC
+            GROUP BY NULLIF(s.git_branch, '')
+            ORDER BY last_activity_at DESC, count DESC
             "#,
         )
         .bind(project_identity)
@@ -38,7 +45,28 @@ impl Database {
 
         Ok(rows
             .into_iter()
-            .map(|(branch, count)| BranchCount { branch, count })
+            .map(|(branch, count, last_activity_at, statuses)| BranchCount {
+                branch,
+                count,
+                last_activity_at,
+                // One status per worktree that checked the branch.
+                status: statuses
+                    .as_deref()
+                    .and_then(|s| s.split(',').filter_map(BranchStatus::parse).min())
+                    .map(|status| status.as_str().to_string()),
+            })
             .collect())
     }
+
+    /// [`Self::list_branches_for_project`] without branches git sync found
+    /// deleted, for a decluttered branch switcher. Unchecked branches and
+    /// branches still on a remote stay.
+    pub async fn list_active_branches_for_project(
+        &self,
+        project_identity: &str,
+    ) -> DbResult<Vec<BranchCount>> {
+        let mut branches = self.list_branches_for_project(project_identity).await?;
+        branches.retain(|b| b.status.as_deref() != Some(BranchStatus::Deleted.as_str()));
+        Ok(branches)
+    }
 }
@@ -3,12 +3,16 @@
 
 use std::collections::HashMap;
 
+use super::types::TokenHistogramBucket;
 use crate::queries::invocation_agg::{classify_key, display_name, ToolKind};
 use crate::{Database, DbResult};
 use claude_view_core::{
     DashboardStats, DayActivity, ProjectStat, SessionDurationStat, SkillStat, ToolCounts,
 };
 
+/// Lower edges of the [`Database::session_token_histogram`] buckets.
+const TOKEN_HISTOGRAM_EDGES: [i64; 6] = [0, 1_000, 10_000, 100_000, 1_000_000, 10_000_000];
+
 impl Database {
     /// Fetch top 10 invocables for the four surfaced kinds (no time range).
     /// Returns (skills, commands, mcp_tools, agents) — each Vec has at most 10 entries.
@@ -411,6 +415,58 @@ impl Database {
         ))
     }
 
+    /// Histogram of per-session total tokens (input + output): `0–1k`, one
+    /// bucket per decade up to 10M, and an open-ended `10M+`. Every bucket is returned, empty ones
+    /// with `sessions = 0`.
+    ///
+    /// `project` matches `project_id`, `git_root` or `project_path`; the time
+    /// bounds are inclusive on `last_message_at`.
+    pub async fn session_token_histogram(
+        &self,
+        project: Option<&str>,
+        time_after: Option<i64>,
+        time_before: Option<i64>,
+    ) -> DbResult<Vec<TokenHistogramBucket>> {
+        let bucket_expr = TOKEN_HISTOGRAM_EDGES
+            .iter()
+            .skip(1)
+            .enumerate()
+            .map(|(i, edge)| format!("WHEN tokens < {edge} THEN {i}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let sql = format!(
+            r#"SELECT CASE {bucket_expr} ELSE {last} END AS bucket, COUNT(*)
+               FROM (
+                   SELECT COALESCE(total_input_tokens, 0) + COALESCE(total_output_tokens, 0) AS tokens
+                   FROM valid_sessions
+                   WHERE last_message_at > 0
+                     AND (?1 IS NULL OR project_id = ?1 OR (git_root IS NOT NULL AND git_root <> '' AND git_root = ?1) OR (project_path IS NOT NULL AND project_path <> '' AND project_path = ?1))
+                     AND (?2 IS NULL OR last_message_at >= ?2)
+                     AND (?3 IS NULL OR last_message_at <= ?3)
+               )
+               GROUP BY bucket"#,
+            last = TOKEN_HISTOGRAM_EDGES.len() - 1,
+        );
+        let counts: HashMap<i64, i64> = sqlx::query_as::<_, (i64, i64)>(&sql)
+            .bind(project)
+            .bind(time_after)
+            .bind(time_before)
+            .fetch_all(self.pool())
+            .await?
+            .into_iter()
+            .collect();
+
+        Ok(TOKEN_HISTOGRAM_EDGES
+            .iter()
+            .enumerate()
+            .map(|(i, &min_tokens)| TokenHistogramBucket {
+                min_tokens,
+                max_tokens: TOKEN_HISTOGRAM_EDGES.get(i + 1).copied(),
+                sessions: counts.get(&(i as i64)).copied().unwrap_or(0),
+            })
+            .collect())
+    }
+
     /// Get the total count of sessions (excluding sidechains).
     pub async fn get_session_count(&self) -> DbResult<i64> {
         let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM valid_sessions")
@@ -48,3 +48,31 @@ pub struct RichActivityResponse {
     pub summary: ActivitySummaryRow,
     pub total: i64,
 }
+
+/// One bucket of the per-session token histogram: sessions whose total
+/// tokens fall in `[min_tokens, max_tokens)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, ts_rs::TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct TokenHistogramBucket {
+    #[ts(type = "number")]
+    pub min_tokens: i64,
+    /// Exclusive upper edge; `None` for the open-ended last bucket.
+    #[ts(type = "number | null")]
+    pub max_tokens: Option<i64>,
+    #[ts(type = "number")]
+    pub sessions: i64,
+}
+
+/// Runs of consecutive local days with at least one session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, ts_rs::TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityStreak {
+    /// Active days in the run ending today (0 if today has no session).
+    #[ts(type = "number")]
+    pub current_days: i64,
+    /// Longest run of active days ever recorded.
+    #[ts(type = "number")]
+    pub longest_days: i64,
+}
@@ -4,9 +4,10 @@
 use std::collections::HashMap;
 
 use super::invocation_agg::{
-    aggregate_all, classify_key, display_name, load_invocation_totals, ToolKind,
+    aggregate_all, aggregate_totals, classify_key, display_name, load_invocation_totals,
+    load_invocation_totals_filtered, load_pair_counts, ToolKind,
 };
-use super::{InvocableWithCount, StatsOverview};
+use super::{InvocablePair, InvocableWithCount, StatsOverview};
 use crate::{Database, DbResult};
 
 /// Best-effort heuristic that maps a JSON `invocation_counts` key to the
@@ -21,6 +22,32 @@ fn key_to_invocable_id(key: &str) -> String {
     }
 }
 
+/// Upper bound on pairs returned by `co_occurring_skills`.
+const MAX_CO_OCCURRING_PAIRS: usize = 100;
+
+/// Relevance tier of `inv` for a lowercased `needle` (lower is better), or
+/// `None` if nothing matches.
+fn invocable_match_rank(inv: &InvocableWithCount, needle: &str) -> Option<u8> {
+    let name = inv.name.to_lowercase();
+    if name == needle {
+        Some(0)
+    } else if name.starts_with(needle) {
+        Some(1)
+    } else if name.contains(needle) {
+        Some(2)
+    } else if inv
+        .plugin_name
+        .as_deref()
+        .is_some_and(|p| p.to_lowercase().contains(needle))
+    {
+        Some(3)
+    } else if inv.description.to_lowercase().contains(needle) {
+        Some(4)
+    } else {
+        None
+    }
+}
+
 impl Database {
     /// Insert or update a single invocable.
     ///
@@ -70,14 +97,45 @@ impl Database {
     /// `last_used_at` is no longer available in the CQRS path — the
     /// column is left as `None` rather than fabricated.
     pub async fn list_invocables_with_counts(&self) -> DbResult<Vec<InvocableWithCount>> {
+        let totals = load_invocation_totals(self.pool()).await?;
+        self.invocables_with_totals(&totals).await
+    }
+
+    /// Case-insensitive search over invocable `name`, `plugin_name` and
+    /// `description`, for the tools page search box.
+    ///
+    /// Ordered by relevance — exact name, name prefix, name substring, plugin
+    /// name, then description match — and within a tier by
+    /// `invocation_count DESC`, `name ASC`. A blank query returns the full
+    /// [`list_invocables_with_counts`](Self::list_invocables_with_counts).
+    pub async fn search_invocables(&self, query: &str) -> DbResult<Vec<InvocableWithCount>> {
+        let all = self.list_invocables_with_counts().await?;
+        let needle = query.trim().to_lowercase();
+        if needle.is_empty() {
+            return Ok(all);
+        }
+        let mut ranked: Vec<(u8, InvocableWithCount)> = all
+            .into_iter()
+            .filter_map(|inv| invocable_match_rank(&inv, &needle).map(|rank| (rank, inv)))
+            .collect();
+        // Stable sort keeps the count/name order within a tier.
+        ranked.sort_by_key(|(rank, _)| *rank);
+        Ok(ranked.into_iter().map(|(_, inv)| inv).collect())
+    }
+
+    /// Join the `invocables` registry against pre-aggregated per-key totals.
+    /// Shared by the global and filtered listings.
+    async fn invocables_with_totals(
+        &self,
+        totals: &HashMap<String, i64>,
+    ) -> DbResult<Vec<InvocableWithCount>> {
         let registry_rows: Vec<(String, Option<String>, String, String, String)> =
             sqlx::query_as(r#"SELECT id, plugin_name, name, kind, description FROM invocables"#)
                 .fetch_all(self.pool())
                 .await?;
 
-        let totals = load_invocation_totals(self.pool()).await?;
         let mut by_id: HashMap<String, i64> = HashMap::new();
-        for (key, count) in &totals {
+        for (key, count) in totals {
             *by_id.entry(key_to_invocable_id(key)).or_default() += *count;
         }
 
@@ -104,6 +162,36 @@ impl Database {
         Ok(out)
     }
 
+    /// Pairs of invocables used together in the same session, by number of
+    /// sessions using both, descending (ties by name).
+    ///
+    /// Pairs seen in fewer than `min_sessions` sessions are dropped as noise;
+    /// at most `limit` pairs are returned, capped at 100.
+    pub async fn co_occurring_skills(
+        &self,
+        min_sessions: i64,
+        limit: usize,
+    ) -> DbResult<Vec<InvocablePair>> {
+        let pairs = load_pair_counts(self.pool()).await?;
+        let mut items: Vec<InvocablePair> = pairs
+            .into_iter()
+            .filter(|(_, count)| *count >= min_sessions.max(1))
+            .map(|((first, second), session_count)| InvocablePair {
+                first,
+                second,
+                session_count,
+            })
+            .collect();
+        items.sort_by(|a, b| {
+            b.session_count
+                .cmp(&a.session_count)
+                .then_with(|| a.first.cmp(&b.first))
+                .then_with(|| a.second.cmp(&b.second))
+        });
+        items.truncate(limit.min(MAX_CO_OCCURRING_PAIRS));
+        Ok(items)
+    }
+
     /// Batch insert/update invocables from a registry snapshot.
     ///
     /// Each tuple is `(id, plugin_name, name, kind, description)`.
@@ -165,4 +253,48 @@ impl Database {
             top_invocables,
         })
     }
+
+    /// [`Self::get_stats_overview`] scoped to a project, branch, and/or
+    /// `last_message_at` range (Unix seconds, inclusive).
+    ///
+    /// `project` matches polymorphically (`project_id`, `git_root`, or
+    /// `project_path`), same as the dashboard filters. All-`None` yields the
+    /// same totals as the global overview.
+    pub async fn get_stats_overview_filtered(
+        &self,
+        project: Option<&str>,
+        branch: Option<&str>,
+        from: Option<i64>,
+        to: Option<i64>,
+    ) -> DbResult<StatsOverview> {
+        let (total_sessions,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(*) FROM valid_sessions
+            WHERE (?1 IS NULL OR project_id = ?1 OR (git_root IS NOT NULL AND git_root <> '' AND git_root = ?1) OR (project_path IS NOT NULL AND project_path <> '' AND project_path = ?1))
+              AND (?2 IS NULL OR git_branch = ?2)
+              AND (?3 IS NULL OR last_message_at >= ?3)
+              AND (?4 IS NULL OR last_message_at <= ?4)
+            "#,
+        )
+        .bind(project)
+        .bind(branch)
+        .bind(from)
+        .bind(to)
+        .fetch_one(self.pool())
+        .await?;
+
+        let totals =
+            load_invocation_totals_filtered(self.pool(), project, branch, from, to).await?;
+        let summary = aggregate_totals(&totals);
+
+        let all = self.invocables_with_totals(&totals).await?;
+        let top_invocables: Vec<InvocableWithCount> = all.into_iter().take(10).collect();
+
+        Ok(StatsOverview {
+            total_sessions,
+            total_invocations: summary.total_invocations,
+            unique_invocables_used: summary.unique_invocables,
+            top_invocables,
+        })
+    }
 }
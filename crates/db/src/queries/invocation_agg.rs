@@ -84,6 +84,34 @@ async fn fetch_all_jsonl(pool: &Pool<Sqlite>) -> DbResult<Vec<(String, String)>>
     Ok(rows)
 }
 
+/// Fetch `invocation_counts` blobs for sessions matching the dashboard
+/// filter set: polymorphic project (id / git_root / project_path), exact
+/// branch, and `last_message_at` in `[from, to]`. `None` means unfiltered.
+async fn fetch_filtered_jsonl(
+    pool: &Pool<Sqlite>,
+    project: Option<&str>,
+    branch: Option<&str>,
+    from: Option<i64>,
+    to: Option<i64>,
+) -> DbResult<Vec<(String, String)>> {
+    let rows: Vec<(String, String)> = sqlx::query_as(
+        r#"SELECT s.id, ss.invocation_counts
+           FROM valid_sessions s
+           JOIN session_stats ss ON ss.session_id = s.id
+           WHERE (?1 IS NULL OR s.project_id = ?1 OR (s.git_root IS NOT NULL AND s.git_root <> '' AND s.git_root = ?1) OR (s.project_path IS NOT NULL AND s.project_path <> '' AND s.project_path = ?1))
+             AND (?2 IS NULL OR s.git_branch = ?2)
+             AND (?3 IS NULL OR s.last_message_at >= ?3)
+             AND (?4 IS NULL OR s.last_message_at <= ?4)"#,
+    )
+    .bind(project)
+    .bind(branch)
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
 /// Aggregate per-key totals across the provided `(session, json)` rows.
 /// Parse errors and empty blobs are silently skipped.
 fn fold_totals(rows: Vec<(String, String)>) -> HashMap<String, i64> {
@@ -97,6 +125,34 @@ fn fold_totals(rows: Vec<(String, String)>) -> HashMap<String, i64> {
     totals
 }
 
+/// Count, for each unordered pair of keys used in the same session, how many
+/// sessions used both. Pairs are `(a, b)` with `a < b`; keys with a zero
+/// count don't participate.
+fn fold_pairs(rows: Vec<(String, String)>) -> HashMap<(String, String), i64> {
+    let mut pairs: HashMap<(String, String), i64> = HashMap::new();
+    for (_session_id, json) in rows {
+        let per_session: HashMap<String, u64> = serde_json::from_str(&json).unwrap_or_default();
+        let mut keys: Vec<String> = per_session
+            .into_iter()
+            .filter(|(_, count)| *count > 0)
+            .map(|(key, _)| key)
+            .collect();
+        keys.sort();
+        for (i, a) in keys.iter().enumerate() {
+            for b in &keys[i + 1..] {
+                *pairs.entry((a.clone(), b.clone())).or_default() += 1;
+            }
+        }
+    }
+    pairs
+}
+
+/// Load per-pair session counts across all sessions — no range filter.
+pub async fn load_pair_counts(pool: &Pool<Sqlite>) -> DbResult<HashMap<(String, String), i64>> {
+    let rows = fetch_all_jsonl(pool).await?;
+    Ok(fold_pairs(rows))
+}
+
 /// Load aggregated per-key totals across all sessions — no range filter.
 pub async fn load_invocation_totals(pool: &Pool<Sqlite>) -> DbResult<HashMap<String, i64>> {
     let rows = fetch_all_jsonl(pool).await?;
@@ -113,6 +169,20 @@ pub async fn load_invocation_totals_in_range(
     Ok(fold_totals(rows))
 }
 
+/// Load aggregated per-key totals for sessions matching project / branch /
+/// `last_message_at` range filters. All-`None` is equivalent to
+/// [`load_invocation_totals`].
+pub async fn load_invocation_totals_filtered(
+    pool: &Pool<Sqlite>,
+    project: Option<&str>,
+    branch: Option<&str>,
+    from: Option<i64>,
+    to: Option<i64>,
+) -> DbResult<HashMap<String, i64>> {
+    let rows = fetch_filtered_jsonl(pool, project, branch, from, to).await?;
+    Ok(fold_totals(rows))
+}
+
 /// Top `limit` display names for a given `ToolKind`, ordered by total
 /// count descending with lexicographic tie-break.
 pub fn top_n_by_prefix(totals: &HashMap<String, i64>, kind: ToolKind, limit: i64) -> Vec<String> {
@@ -139,10 +209,13 @@ pub struct InvocationAggregate {
 /// Compute `(total, unique)` across all sessions.
 pub async fn aggregate_all(pool: &Pool<Sqlite>) -> DbResult<InvocationAggregate> {
     let totals = load_invocation_totals(pool).await?;
-    let total_invocations: i64 = totals.values().sum();
-    let unique_invocables = totals.len() as i64;
-    Ok(InvocationAggregate {
-        total_invocations,
-        unique_invocables,
-    })
+    Ok(aggregate_totals(&totals))
+}
+
+/// Compute `(total, unique)` from already-loaded per-key totals.
+pub fn aggregate_totals(totals: &HashMap<String, i64>) -> InvocationAggregate {
+    InvocationAggregate {
+        total_invocations: totals.values().sum(),
+        unique_invocables: totals.len() as i64,
+    }
 }
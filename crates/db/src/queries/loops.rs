@@ -0,0 +1,85 @@
+// crates/db/src/queries/loops.rs
+//! Database bridge for agent loop detection.
+//!
+//! Per-turn content is not stored (the `turns` table was dropped in
+//! migration 87), so the session's JSONL is parsed and handed to
+//! [`claude_view_core::loop_detection`], which documents the similarity
+//! rule.
+
+use std::path::Path;
+
+use claude_view_core::loop_detection::{detect_loops, LoopConfig, LoopReport};
+
+use crate::{Database, DbResult};
+
+impl Database {
+    /// Runs of near-identical consecutive turns in session `session_id`.
+    ///
+    /// `None` if it is not a valid (non-sidechain, non-archived) session or
+    /// its JSONL file can no longer be parsed.
+    pub async fn detect_loops(
+        &self,
+        session_id: &str,
+        config: &LoopConfig,
+    ) -> DbResult<Option<LoopReport>> {
+        let row: Option<(String,)> =
+            sqlx::query_as("SELECT file_path FROM valid_sessions WHERE id = ?1")
+                .bind(session_id)
+                .fetch_optional(self.pool())
+                .await?;
+        let Some((file_path,)) = row else {
+            return Ok(None);
+        };
+        match claude_view_core::parse_session(Path::new(&file_path)).await {
+            Ok(parsed) => Ok(Some(detect_loops(&parsed.messages, config))),
+            Err(e) => {
+                tracing::warn!(session_id, error = %e, "loop detection: failed to parse session");
+                Ok(None)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SessionSeedBuilder;
+
+    #[tokio::test]
+    async fn repeated_tool_calls_are_detected() {
+        let db = Database::new_in_memory().await.unwrap();
+        let tmp = tempfile::tempdir().unwrap();
+        let bash_line = |uuid: &str| {
+            format!(
+                r#"{{"type":"assistant","uuid":"{uuid}","timestamp":"2026-01-21T05:00:01Z","message":{{"role":"assistant","content":[{{"type":"tool_use","id":"t{uuid}","name":"Bash","input":{{"command":"cargo build"}}}}]}}}}"#
+            )
+        };
+        let mut lines = vec![
+            r#"{"type":"user","uuid":"u1","timestamp":"2026-01-21T05:00:00Z","message":{"role":"user","content":"fix the build"}}"#.to_string(),
+        ];
+        lines.extend(["a1", "a2", "a3", "a4"].map(bash_line));
+        let looping = tmp.path().join("looping.jsonl");
+        std::fs::write(&looping, lines.join("\n")).unwrap();
+
+        let calm = tmp.path().join("calm.jsonl");
+        std::fs::write(&calm, lines[..2].join("\n")).unwrap();
+
+        for (id, path) in [("looping", &looping), ("calm", &calm)] {
+            SessionSeedBuilder::new(id)
+                .file_path(path.to_string_lossy())
+                .seed(&db)
+                .await
+                .unwrap();
+        }
+
+        let config = LoopConfig::default();
+        let report = db.detect_loops("looping", &config).await.unwrap().unwrap();
+        assert!(report.loop_detected);
+        assert_eq!(report.offenders[0].repeats, 4);
+
+        let report = db.detect_loops("calm", &config).await.unwrap().unwrap();
+        assert!(!report.loop_detected);
+
+        assert!(db.detect_loops("missing", &config).await.unwrap().is_none());
+    }
+}
@@ -3,6 +3,8 @@
 
 mod action_log;
 mod ai_generation;
+pub mod backup;
+mod budget;
 mod catalog;
 mod classification;
 mod dashboard;
@@ -11,7 +13,10 @@ mod fluency;
 pub mod hook_events;
 mod invocables;
 pub mod invocation_agg;
+mod loops;
 mod models;
+pub mod prompt_bootstrap;
+mod quality;
 pub mod reports;
 pub(crate) mod row_types;
 pub mod search_prefilter;
@@ -22,9 +27,12 @@ pub mod stats;
 mod system;
 mod types;
 
+pub use budget::{BudgetState, BudgetStatus};
 pub use dashboard::ActivityPoint;
+pub use dashboard::ActivityStreak;
+pub use dashboard::TokenHistogramBucket;
 pub use dashboard::{ActivitySummaryRow, ProjectActivityRow, RichActivityResponse};
-pub use search_prefilter::SearchPrefilter;
+pub use search_prefilter::{SearchFileMeta, SearchPrefilter};
 // Phase 3 PR 3.a: catalog-shape reads consumed by `SessionCatalogAdapter`
 // (crates/core/src/session_catalog.rs). `StatsHeader` stays private — it's
 // still an internal indexer_v2 type.
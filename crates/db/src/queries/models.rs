@@ -1,8 +1,16 @@
 // crates/db/src/queries/models.rs
 // Model + Turn CRUD operations (Phase 2B).
 
+use claude_view_core::pricing::{
+    calculate_cost, finalize_cost_breakdown, CostBreakdown, TokenUsage,
+};
+
+use super::ai_generation::{aggregate_usage_per_model, base_rate_pricing};
 use super::row_types::batch_upsert_models_tx;
-use super::{ModelWithStats, TokenStats};
+use super::{
+    ModelAdoption, ModelMonthTurns, ModelTurnCount, ModelWithStats, SessionModelCost,
+    TimeBucketStats, TokenStats,
+};
 use crate::{Database, DbResult};
 
 impl Database {
@@ -123,8 +131,160 @@ impl Database {
         Ok(rows)
     }
 
+    /// Distinct models used in one session with their assistant turn
+    /// counts, most-used first (ties by model id), so the transcript view can
+    /// badge mid-session model switches.
+    ///
+    /// Rows indexed before `model_turn_counts` existed fall back to the
+    /// models in `per_model_tokens_json` with `turn_count = 0`. Unknown
+    /// sessions yield an empty list.
+    pub async fn models_used_in_session(&self, session_id: &str) -> DbResult<Vec<ModelTurnCount>> {
+        use std::collections::HashMap;
+
+        let row: Option<(Option<String>, String)> = sqlx::query_as(
+            "SELECT model_turn_counts, per_model_tokens_json FROM session_stats WHERE session_id = ?1",
+        )
+        .bind(session_id)
+        .fetch_optional(self.pool())
+        .await?;
+        let Some((turn_counts_json, per_model_json)) = row else {
+            return Ok(Vec::new());
+        };
+
+        let counts: HashMap<String, i64> = match turn_counts_json {
+            Some(json) => serde_json::from_str(&json).unwrap_or_default(),
+            None => serde_json::from_str::<HashMap<String, serde_json::Value>>(&per_model_json)
+                .unwrap_or_default()
+                .into_keys()
+                .map(|model| (model, 0))
+                .collect(),
+        };
+        let mut models: Vec<ModelTurnCount> = counts
+            .into_iter()
+            .map(|(model_id, turn_count)| ModelTurnCount {
+                model_id,
+                turn_count,
+            })
+            .collect();
+        models.sort_by(|a, b| {
+            b.turn_count
+                .cmp(&a.turn_count)
+                .then_with(|| a.model_id.cmp(&b.model_id))
+        });
+        Ok(models)
+    }
+
+    /// `primary_model` of every session that has one, keyed by session id.
+    /// Lets prompt-history indexing tag each prompt with its session's model.
+    pub async fn session_primary_models(
+        &self,
+    ) -> DbResult<std::collections::HashMap<String, String>> {
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            "SELECT session_id, primary_model FROM session_stats \
+             WHERE primary_model IS NOT NULL AND primary_model <> ''",
+        )
+        .fetch_all(self.pool())
+        .await?;
+        Ok(rows.into_iter().collect())
+    }
+
+    /// Per-model adoption timeline: first and last use plus assistant turns
+    /// per UTC month, ordered by first use (ties by model id).
+    ///
+    /// The `turns` table is gone, so usage comes from each session's
+    /// `model_turn_counts`, bucketed by `last_message_at`. Sessions indexed
+    /// before that column existed credit their `turn_count` to
+    /// `primary_model`, as [`Self::get_all_models`] does. `family` comes from
+    /// the `models` table; its own `first_seen`/`last_seen` record indexing
+    /// time, not usage, so they are not used here.
+    pub async fn model_adoption_timeline(&self) -> DbResult<Vec<ModelAdoption>> {
+        use std::collections::{BTreeMap, HashMap};
+
+        let rows: Vec<(Option<String>, Option<String>, i64, i64, String)> = sqlx::query_as(
+            r#"SELECT ss.model_turn_counts, ss.primary_model, COALESCE(ss.turn_count, 0),
+                      ss.last_message_at, strftime('%Y-%m', ss.last_message_at, 'unixepoch')
+               FROM session_stats ss
+               JOIN valid_sessions s ON s.id = ss.session_id
+               WHERE ss.last_message_at > 0"#,
+        )
+        .fetch_all(self.pool())
+        .await?;
+
+        struct Usage {
+            first_seen: i64,
+            last_seen: i64,
+            months: BTreeMap<String, i64>,
+        }
+        let mut usage: HashMap<String, Usage> = HashMap::new();
+        for (turn_counts_json, primary_model, turn_count, last_message_at, month) in rows {
+            let counts: Vec<(String, i64)> = match turn_counts_json {
+                Some(json) => serde_json::from_str::<HashMap<String, i64>>(&json)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect(),
+                None => primary_model
+                    .map(|model| vec![(model, turn_count)])
+                    .unwrap_or_default(),
+            };
+            for (model_id, turns) in counts {
+                let entry = usage.entry(model_id).or_insert_with(|| Usage {
+                    first_seen: last_message_at,
+                    last_seen: last_message_at,
+                    months: BTreeMap::new(),
+                });
+                entry.first_seen = entry.first_seen.min(last_message_at);
+                entry.last_seen = entry.last_seen.max(last_message_at);
+                *entry.months.entry(month.clone()).or_default() += turns;
+            }
+        }
+
+        let families: HashMap<String, Option<String>> =
+            sqlx::query_as::<_, (String, Option<String>)>("SELECT id, family FROM models")
+                .fetch_all(self.pool())
+                .await?
+                .into_iter()
+                .collect();
+
+        let mut timeline: Vec<ModelAdoption> = usage
+            .into_iter()
+            .map(|(model_id, usage)| ModelAdoption {
+                family: families.get(&model_id).cloned().flatten(),
+                model_id,
+                first_seen: usage.first_seen,
+                last_seen: usage.last_seen,
+                months: usage
+                    .months
+                    .into_iter()
+                    .map(|(month, turn_count)| ModelMonthTurns { month, turn_count })
+                    .collect(),
+            })
+            .collect();
+        timeline.sort_by(|a, b| {
+            a.first_seen
+                .cmp(&b.first_seen)
+                .then_with(|| a.model_id.cmp(&b.model_id))
+        });
+        Ok(timeline)
+    }
+
     /// Get aggregate token statistics (for GET /api/stats/tokens).
     pub async fn get_token_stats(&self) -> DbResult<TokenStats> {
+        self.get_token_stats_filtered(None, None, None, None).await
+    }
+
+    /// [`Self::get_token_stats`] scoped to a `last_message_at` range (Unix
+    /// seconds, inclusive), project, and/or branch.
+    ///
+    /// `project` matches polymorphically (`project_id`, `git_root`, or
+    /// `project_path`), same as the dashboard filters. All-`None` yields the
+    /// global totals.
+    pub async fn get_token_stats_filtered(
+        &self,
+        from: Option<i64>,
+        to: Option<i64>,
+        project: Option<&str>,
+        branch: Option<&str>,
+    ) -> DbResult<TokenStats> {
         let row: (i64, i64, i64, i64, i64, i64) = sqlx::query_as(
             r#"
             SELECT
@@ -135,8 +295,16 @@ impl Database {
                 COALESCE(SUM(turn_count), 0),
                 COUNT(*)
             FROM valid_sessions
+            WHERE (?1 IS NULL OR last_message_at >= ?1)
+              AND (?2 IS NULL OR last_message_at <= ?2)
+              AND (?3 IS NULL OR project_id = ?3 OR (git_root IS NOT NULL AND git_root <> '' AND git_root = ?3) OR (project_path IS NOT NULL AND project_path <> '' AND project_path = ?3))
+              AND (?4 IS NULL OR git_branch = ?4)
             "#,
         )
+        .bind(from)
+        .bind(to)
+        .bind(project)
+        .bind(branch)
         .fetch_one(self.pool())
         .await?;
 
@@ -160,4 +328,171 @@ impl Database {
             sessions_count: row.5 as u64,
         })
     }
+
+    /// Price a session's tokens per model from `per_model_tokens_json`,
+    /// including cache reads and cache writes (5m / 1h TTL split when
+    /// recorded), so sessions that switch models are priced at each model's
+    /// rates. Sessions without a per-model breakdown fall back to pricing
+    /// their totals at `primary_model` rates.
+    ///
+    /// Uses base per-token rates, like [`Self::cost_by_model`]: the 200k tier
+    /// applies per API call, not to a session's summed usage. Model ids
+    /// missing from the table fall back through `lookup_pricing` (alias,
+    /// prefix, then family); anything still unmatched is reported as
+    /// unpriced usage. Returns `None` if the session is not indexed.
+    pub async fn session_model_cost(&self, session_id: &str) -> DbResult<Option<SessionModelCost>> {
+        #[allow(clippy::type_complexity)]
+        let row: Option<(Option<String>, String, i64, i64, i64, i64, i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT
+                s.primary_model,
+                COALESCE(ss.per_model_tokens_json, '{}'),
+                COALESCE(ss.total_input_tokens, 0),
+                COALESCE(ss.total_output_tokens, 0),
+                COALESCE(ss.cache_read_tokens, 0),
+                COALESCE(ss.cache_creation_tokens, 0),
+                COALESCE(ss.cache_creation_5m_tokens, 0),
+                COALESCE(ss.cache_creation_1hr_tokens, 0)
+            FROM valid_sessions s
+            JOIN session_stats ss ON ss.session_id = s.id
+            WHERE s.id = ?1
+            "#,
+        )
+        .bind(session_id)
+        .fetch_optional(self.pool())
+        .await?;
+
+        let Some((model, per_model_json, input, output, cache_read, cache_creation, cc_5m, cc_1hr)) =
+            row
+        else {
+            return Ok(None);
+        };
+        let pricing = base_rate_pricing();
+        let per_model = aggregate_usage_per_model(vec![(per_model_json,)]);
+        if per_model.is_empty() {
+            let tokens = TokenUsage {
+                input_tokens: input as u64,
+                output_tokens: output as u64,
+                cache_read_tokens: cache_read as u64,
+                cache_creation_tokens: cache_creation as u64,
+                cache_creation_5m_tokens: cc_5m as u64,
+                cache_creation_1hr_tokens: cc_1hr as u64,
+                total_tokens: (input + output + cache_read + cache_creation) as u64,
+            };
+            let cost = calculate_cost(&tokens, model.as_deref(), &pricing);
+            return Ok(Some(SessionModelCost { model, cost }));
+        }
+
+        let mut cost = CostBreakdown::default();
+        let mut tokens = TokenUsage::default();
+        for (model_id, usage) in &per_model {
+            let part = calculate_cost(usage, Some(model_id), &pricing);
+            cost.total_usd += part.total_usd;
+            cost.input_cost_usd += part.input_cost_usd;
+            cost.output_cost_usd += part.output_cost_usd;
+            cost.cache_read_cost_usd += part.cache_read_cost_usd;
+            cost.cache_creation_cost_usd += part.cache_creation_cost_usd;
+            cost.cache_savings_usd += part.cache_savings_usd;
+            cost.unpriced_input_tokens += part.unpriced_input_tokens;
+            cost.unpriced_output_tokens += part.unpriced_output_tokens;
+            cost.unpriced_cache_read_tokens += part.unpriced_cache_read_tokens;
+            cost.unpriced_cache_creation_tokens += part.unpriced_cache_creation_tokens;
+            tokens.input_tokens += usage.input_tokens;
+            tokens.output_tokens += usage.output_tokens;
+            tokens.cache_read_tokens += usage.cache_read_tokens;
+            tokens.cache_creation_tokens += usage.cache_creation_tokens;
+        }
+        finalize_cost_breakdown(&mut cost, &tokens);
+        Ok(Some(SessionModelCost { model, cost }))
+    }
+
+    /// Sessions and tokens per weekday, bucketed by `last_message_at`.
+    ///
+    /// Index 0 = Monday .. 6 = Sunday (same as the activity heatmap).
+    /// Timestamps are UTC; `utc_offset_secs` shifts them into the caller's
+    /// local time before bucketing (e.g. `-25200` for UTC-7). SQLite's own
+    /// `'localtime'` modifier is not used so results don't depend on the
+    /// server's timezone.
+    pub async fn token_stats_by_day_of_week(
+        &self,
+        from: i64,
+        to: i64,
+        utc_offset_secs: i64,
+    ) -> DbResult<Vec<TimeBucketStats>> {
+        let rows = self
+            .token_stats_by_time_bucket("%w", from, to, utc_offset_secs)
+            .await?;
+        let mut buckets = vec![TimeBucketStats::default(); 7];
+        for (dow, stats) in rows {
+            // SQLite's %w is 0 = Sunday; shift to 0 = Monday.
+            let idx = (dow + 6) % 7;
+            if let Some(slot) = buckets.get_mut(idx as usize) {
+                *slot = stats;
+            }
+        }
+        Ok(buckets)
+    }
+
+    /// Sessions and tokens per hour of day (index 0..=23), bucketed by
+    /// `last_message_at` shifted by `utc_offset_secs` — see
+    /// [`Self::token_stats_by_day_of_week`] for the timezone handling.
+    pub async fn token_stats_by_hour_of_day(
+        &self,
+        from: i64,
+        to: i64,
+        utc_offset_secs: i64,
+    ) -> DbResult<Vec<TimeBucketStats>> {
+        let rows = self
+            .token_stats_by_time_bucket("%H", from, to, utc_offset_secs)
+            .await?;
+        let mut buckets = vec![TimeBucketStats::default(); 24];
+        for (hour, stats) in rows {
+            if let Some(slot) = buckets.get_mut(hour as usize) {
+                *slot = stats;
+            }
+        }
+        Ok(buckets)
+    }
+
+    /// `(bucket, stats)` rows for a `strftime` format yielding an integer.
+    async fn token_stats_by_time_bucket(
+        &self,
+        format: &str,
+        from: i64,
+        to: i64,
+        utc_offset_secs: i64,
+    ) -> DbResult<Vec<(i64, TimeBucketStats)>> {
+        let rows: Vec<(i64, i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT
+                CAST(strftime(?1, last_message_at + ?4, 'unixepoch') AS INTEGER) as bucket,
+                COUNT(*) as sessions,
+                COALESCE(SUM(total_input_tokens + total_output_tokens), 0) as tokens
+            FROM valid_sessions
+            WHERE last_message_at > 0
+              AND last_message_at >= ?2
+              AND last_message_at <= ?3
+            GROUP BY bucket
+            "#,
+        )
+        .bind(format)
+        .bind(from)
+        .bind(to)
+        .bind(utc_offset_secs)
+        .fetch_all(self.pool())
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(bucket, sessions, total_tokens)| {
+                (
+                    bucket,
+                    TimeBucketStats {
+                        sessions,
+                        total_tokens,
+                    },
+                )
+            })
+            .collect())
+    }
 }
@@ -0,0 +1,103 @@
+// crates/db/src/queries/prompt_bootstrap.rs
+//! Repopulate the prompt search index from SQLite.
+//!
+//! A schema-version bump wipes the prompt index, and it stays empty until
+//! `history.jsonl` is re-indexed. The DB already holds each session's first
+//! prompt as its `preview`, so those can be indexed straight away without
+//! touching any JSONL.
+
+use claude_view_core::prompt_history::{classify_intent, complexity_bucket, slash_command_name};
+use claude_view_search::prompt_index::{PromptDocument, PromptSearchIndex};
+
+use crate::{Database, DbResult};
+
+/// Sessions read (and indexed) per round trip.
+const BOOTSTRAP_BATCH: i64 = 500;
+
+/// `prompt_id` prefix for documents built from the DB, so they never collide
+/// with history ids (`<timestamp_ms>-<n>`).
+pub const DB_PROMPT_ID_PREFIX: &str = "db:";
+
+impl Database {
+    /// Index one prompt per valid session (its preview) into `index`, then
+    /// commit. Sessions without a preview are skipped. Returns the number of
+    /// documents indexed.
+    ///
+    /// Meant for a freshly wiped index. The documents are stand-ins: once
+    /// history is re-indexed, drop them with
+    /// `delete_by_prompt_id_prefix(DB_PROMPT_ID_PREFIX)` before marking the
+    /// schema synced, or every session's first prompt shows up twice.
+    pub async fn rebuild_prompt_index(&self, index: &PromptSearchIndex) -> DbResult<usize> {
+        type Row = (
+            String,
+            String,
+            String,
+            String,
+            Option<String>,
+            Option<String>,
+            String,
+            i64,
+        );
+
+        let mut after = String::new();
+        let mut indexed = 0;
+        loop {
+            let rows: Vec<Row> = sqlx::query_as(
+                r#"
+                SELECT id, project_id, project_display_name,
+                       COALESCE(NULLIF(git_root, ''), project_path, ''),
+                       git_branch, primary_model, preview,
+                       COALESCE(first_message_at, last_message_at, 0)
+                FROM valid_sessions
+                WHERE id > ?1 AND preview <> ''
+                ORDER BY id
+                LIMIT ?2
+                "#,
+            )
+            .bind(&after)
+            .bind(BOOTSTRAP_BATCH)
+            .fetch_all(self.pool())
+            .await?;
+
+            let Some(last) = rows.last() else {
+                break;
+            };
+            after = last.0.clone();
+
+            let docs: Vec<PromptDocument> = rows
+                .into_iter()
+                .map(
+                    |(id, project_id, display_name, git_root, branch, model, preview, ts)| {
+                        let project = if display_name.is_empty() {
+                            project_id
+                        } else {
+                            display_name
+                        };
+                        PromptDocument {
+                            prompt_id: format!("{DB_PROMPT_ID_PREFIX}{id}"),
+                            skills: slash_command_name(&preview).unwrap_or_default().to_string(),
+                            intent: classify_intent(&preview).to_string(),
+                            complexity: complexity_bucket(&preview).to_string(),
+                            display: preview,
+                            paste_text: None,
+                            project_display: project.clone(),
+                            project,
+                            session_id: Some(id),
+                            branch: branch.unwrap_or_default(),
+                            model: model.unwrap_or_default(),
+                            git_root,
+                            timestamp: ts,
+                            has_paste: false,
+                        }
+                    },
+                )
+                .collect();
+
+            index.index_prompts(&docs)?;
+            indexed += docs.len();
+        }
+
+        index.commit()?;
+        Ok(indexed)
+    }
+}
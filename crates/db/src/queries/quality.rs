@@ -0,0 +1,132 @@
+// crates/db/src/queries/quality.rs
+//! Database bridge for the per-session quality score.
+//!
+//! Reads the counters from `valid_sessions` and feeds them into the pure
+//! math in [`claude_view_core::quality_score`], which documents the
+//! weighting.
+
+use claude_view_core::quality_score::{
+    compute_session_quality, QualityInput, QualityWeights, SessionQualityScore,
+};
+
+use crate::{Database, DbResult};
+
+type QualityRow = (String, i64, i64, i64, i64, i64, i64);
+
+const QUALITY_COLUMNS: &str = "id, files_edited_count, reedited_files_count, api_call_count, \
+     api_error_count, duration_seconds, total_output_tokens";
+
+fn score_row(row: QualityRow, weights: &QualityWeights) -> SessionQualityScore {
+    let (id, files_edited, reedited, api_calls, api_errors, duration, output_tokens) = row;
+    let count = |n: i64| n.clamp(0, u32::MAX as i64) as u32;
+    let input = QualityInput {
+        files_edited_count: count(files_edited),
+        reedited_files_count: count(reedited),
+        api_call_count: count(api_calls),
+        api_error_count: count(api_errors),
+        duration_seconds: count(duration),
+        total_output_tokens: output_tokens.max(0) as u64,
+    };
+    compute_session_quality(id, &input, weights)
+}
+
+impl Database {
+    /// Quality score (0-100) of one session, or `None` if it is not a
+    /// valid (non-sidechain, non-archived) session.
+    pub async fn session_quality_score(
+        &self,
+        session_id: &str,
+        weights: &QualityWeights,
+    ) -> DbResult<Option<SessionQualityScore>> {
+        let row: Option<QualityRow> = sqlx::query_as(&format!(
+            "SELECT {QUALITY_COLUMNS} FROM valid_sessions WHERE id = ?1"
+        ))
+        .bind(session_id)
+        .fetch_optional(self.pool())
+        .await?;
+        Ok(row.map(|r| score_row(r, weights)))
+    }
+
+    /// The `limit` lowest-scoring sessions, worst first (ties broken by
+    /// most recent activity), for reviewing what went wrong.
+    pub async fn lowest_quality_sessions(
+        &self,
+        weights: &QualityWeights,
+        limit: usize,
+    ) -> DbResult<Vec<SessionQualityScore>> {
+        let rows: Vec<QualityRow> = sqlx::query_as(&format!(
+            "SELECT {QUALITY_COLUMNS} FROM valid_sessions ORDER BY last_message_at DESC"
+        ))
+        .fetch_all(self.pool())
+        .await?;
+        let mut scores: Vec<SessionQualityScore> =
+            rows.into_iter().map(|r| score_row(r, weights)).collect();
+        // Stable sort keeps the recency order within a score.
+        scores.sort_by_key(|s| s.score);
+        scores.truncate(limit);
+        Ok(scores)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SessionSeedBuilder;
+
+    #[tokio::test]
+    async fn clean_session_outranks_error_heavy_one() {
+        let db = Database::new_in_memory().await.unwrap();
+        SessionSeedBuilder::new("clean")
+            .file_path("/tmp/clean.jsonl")
+            .last_message_at(1_700_000_000)
+            .seed(&db)
+            .await
+            .unwrap();
+        SessionSeedBuilder::new("noisy")
+            .file_path("/tmp/noisy.jsonl")
+            .last_message_at(1_700_000_100)
+            .seed(&db)
+            .await
+            .unwrap();
+        for (id, edited, reedited, calls, errors) in
+            [("clean", 5, 0, 40, 0), ("noisy", 5, 4, 40, 20)]
+        {
+            sqlx::query(
+                "UPDATE session_stats SET files_edited_count = ?2, reedited_files_count = ?3, \
+                 api_call_count = ?4, api_error_count = ?5, duration_seconds = 600, \
+                 total_output_tokens = 10000 WHERE session_id = ?1",
+            )
+            .bind(id)
+            .bind(edited)
+            .bind(reedited)
+            .bind(calls)
+            .bind(errors)
+            .execute(db.pool())
+            .await
+            .unwrap();
+        }
+
+        let weights = QualityWeights::default();
+        let clean = db
+            .session_quality_score("clean", &weights)
+            .await
+            .unwrap()
+            .unwrap();
+        let noisy = db
+            .session_quality_score("noisy", &weights)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(clean.score, 100);
+        assert!(noisy.score < clean.score, "{noisy:?}");
+        assert!(db
+            .session_quality_score("missing", &weights)
+            .await
+            .unwrap()
+            .is_none());
+
+        let worst = db.lowest_quality_sessions(&weights, 1).await.unwrap();
+        assert_eq!(worst.len(), 1);
+        assert_eq!(worst[0].session_id, "noisy");
+    }
+}
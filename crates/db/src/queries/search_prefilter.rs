@@ -1,10 +1,12 @@
 //! Lightweight SQLite pre-filter for search.
 //!
-//! Returns session IDs matching structured filters (project, branch, model, date).
+//! Returns session IDs matching structured filters (project, branch, model,
+//! date, duration, tokens, MCP server).
 //! Used by the search handler to narrow the file set before grep runs.
+//! [`Database::search_file_meta`] supplies the per-file labels grep reports.
 
 use crate::{Database, DbResult};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 /// Structured filters for search pre-filtering.
 /// All fields are optional — None means no filter on that dimension.
@@ -15,6 +17,14 @@ pub struct SearchPrefilter {
     pub model: Option<String>,
     pub after: Option<i64>,  // Unix timestamp — filter on last_message_at
     pub before: Option<i64>, // Unix timestamp — filter on last_message_at
+    /// Inclusive bounds on `duration_seconds`.
+    pub min_duration: Option<i64>,
+    pub max_duration: Option<i64>,
+    /// Inclusive bounds on `total_input_tokens + total_output_tokens`.
+    pub min_tokens: Option<i64>,
+    pub max_tokens: Option<i64>,
+    /// MCP servers (as in `McpTool::server`) the session must have called.
+    pub mcp_servers: Vec<String>,
 }
 
 impl SearchPrefilter {
@@ -25,10 +35,71 @@ impl SearchPrefilter {
             && self.model.is_none()
             && self.after.is_none()
             && self.before.is_none()
+            && self.min_duration.is_none()
+            && self.max_duration.is_none()
+            && self.min_tokens.is_none()
+            && self.max_tokens.is_none()
+            && self.mcp_servers.is_empty()
     }
 }
 
+/// What search needs to know about an indexed session's JSONL file.
+#[derive(Debug, Clone, Default)]
+pub struct SearchFileMeta {
+    pub project_display_name: String,
+    pub project_path: String,
+    pub is_sidechain: bool,
+}
+
 impl Database {
+    /// Project display name, path and sidechain flag of every indexed
+    /// session, keyed by session id. Search labels and scopes files from this
+    /// instead of decoding each project directory name or probing each file
+    /// on every request.
+    pub async fn search_file_meta(&self) -> DbResult<HashMap<String, SearchFileMeta>> {
+        let rows: Vec<(String, String, String, bool)> = sqlx::query_as(
+            "SELECT session_id, COALESCE(project_display_name, ''), COALESCE(project_path, ''), \
+             is_sidechain FROM session_stats",
+        )
+        .fetch_all(self.pool())
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|(id, project_display_name, project_path, is_sidechain)| {
+                (
+                    id,
+                    SearchFileMeta {
+                        project_display_name,
+                        project_path,
+                        is_sidechain,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    /// Ids of the `limit` most recently active primary sessions, optionally
+    /// only those in `project` (matched against `project_id`, `git_root`,
+    /// display name and path). Bounds the candidates for related-session
+    /// scoring, which reads every candidate's JSONL.
+    pub async fn recent_session_ids(
+        &self,
+        project: Option<&str>,
+        limit: i64,
+    ) -> DbResult<HashSet<String>> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            "SELECT id FROM valid_sessions \
+             WHERE ?1 IS NULL OR project_id = ?1 OR git_root = ?1 \
+                OR project_display_name = ?1 OR project_path = ?1 \
+             ORDER BY last_message_at DESC LIMIT ?2",
+        )
+        .bind(project)
+        .bind(limit)
+        .fetch_all(self.pool())
+        .await?;
+        Ok(rows.into_iter().map(|(id,)| id).collect())
+    }
+
     /// Return session IDs matching structured filters.
     ///
     /// Uses the polymorphic project filter pattern (CLAUDE.md Hard Rule):
@@ -39,6 +110,9 @@ impl Database {
     /// - model   → `primary_model`
     /// - project → `project_id` OR `git_root`
     /// - after/before → `last_message_at`
+    /// - min/max_duration → `duration_seconds`
+    /// - min/max_tokens → `total_input_tokens + total_output_tokens`
+    /// - mcp_servers → `mcp_tools[].server`, one `EXISTS` per server
     pub async fn search_prefilter_session_ids(
         &self,
         filter: &SearchPrefilter,
@@ -78,6 +152,36 @@ impl Database {
             qb.push_bind(before);
         }
 
+        if let Some(min) = filter.min_duration {
+            qb.push(" AND duration_seconds >= ");
+            qb.push_bind(min);
+        }
+
+        if let Some(max) = filter.max_duration {
+            qb.push(" AND duration_seconds <= ");
+            qb.push_bind(max);
+        }
+
+        // Same expression as idx_session_stats_total_tokens so SQLite can use it.
+        if let Some(min) = filter.min_tokens {
+            qb.push(" AND (total_input_tokens + total_output_tokens) >= ");
+            qb.push_bind(min);
+        }
+
+        if let Some(max) = filter.max_tokens {
+            qb.push(" AND (total_input_tokens + total_output_tokens) <= ");
+            qb.push_bind(max);
+        }
+
+        for server in &filter.mcp_servers {
+            qb.push(
+                " AND EXISTS (SELECT 1 FROM json_each(mcp_tools) \
+                 WHERE json_extract(value, '$.server') = ",
+            );
+            qb.push_bind(server);
+            qb.push(")");
+        }
+
         let rows: Vec<(String,)> = qb.build_query_as().fetch_all(self.pool()).await?;
 
         Ok(rows.into_iter().map(|(id,)| id).collect())
@@ -94,11 +198,11 @@ mod tests {
     async fn setup_db() -> Database {
         let db = Database::new_in_memory().await.unwrap();
         sqlx::query(
-            "INSERT INTO session_stats (session_id, source_content_hash, source_size, parser_version, stats_version, indexed_at, project_id, git_root, git_branch, primary_model, file_path, last_message_at)
+            "INSERT INTO session_stats (session_id, source_content_hash, source_size, parser_version, stats_version, indexed_at, project_id, git_root, git_branch, primary_model, file_path, last_message_at, duration_seconds, total_input_tokens, total_output_tokens)
              VALUES
-             ('s1', X'00', 0, 1, 4, 0, 'proj-a', '/dev/proj-a', 'main',   'claude-3-opus-20240229',   '/dev/proj-a/s1.jsonl', 1710000100),
-             ('s2', X'00', 0, 1, 4, 0, 'proj-a', '/dev/proj-a', 'feat',   'claude-3-5-sonnet-20241022', '/dev/proj-a/s2.jsonl', 1710000200),
-             ('s3', X'00', 0, 1, 4, 0, 'proj-b', '/dev/proj-b', 'main',   'claude-3-opus-20240229',   '/dev/proj-b/s3.jsonl', 1710000300)",
+             ('s1', X'00', 0, 1, 4, 0, 'proj-a', '/dev/proj-a', 'main',   'claude-3-opus-20240229',   '/dev/proj-a/s1.jsonl', 1710000100, 120,  1000,  500),
+             ('s2', X'00', 0, 1, 4, 0, 'proj-a', '/dev/proj-a', 'feat',   'claude-3-5-sonnet-20241022', '/dev/proj-a/s2.jsonl', 1710000200, 600,  90000, 10000),
+             ('s3', X'00', 0, 1, 4, 0, 'proj-b', '/dev/proj-b', 'main',   'claude-3-opus-20240229',   '/dev/proj-b/s3.jsonl', 1710000300, 3600, 150000, 50000)",
         )
         .execute(db.pool())
         .await
@@ -106,6 +210,39 @@ mod tests {
         db
     }
 
+    #[tokio::test]
+    async fn test_search_file_meta() {
+        let db = setup_db().await;
+        sqlx::query(
+            "UPDATE session_stats SET project_display_name = 'proj-a', project_path = '/dev/proj-a', \
+             is_sidechain = 1 WHERE session_id = 's1'",
+        )
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+        let meta = db.search_file_meta().await.unwrap();
+        assert_eq!(meta.len(), 3);
+        assert_eq!(meta["s1"].project_display_name, "proj-a");
+        assert_eq!(meta["s1"].project_path, "/dev/proj-a");
+        assert!(meta["s1"].is_sidechain);
+        assert_eq!(meta["s2"].project_path, "");
+        assert!(!meta["s2"].is_sidechain);
+    }
+
+    #[tokio::test]
+    async fn test_recent_session_ids() {
+        let db = setup_db().await;
+        let ids = db.recent_session_ids(None, 2).await.unwrap();
+        assert_eq!(ids, HashSet::from(["s3".to_string(), "s2".to_string()]));
+
+        let ids = db
+            .recent_session_ids(Some("/dev/proj-a"), 10)
+            .await
+            .unwrap();
+        assert_eq!(ids, HashSet::from(["s1".to_string(), "s2".to_string()]));
+    }
+
     #[tokio::test]
     async fn test_prefilter_no_filters_returns_all() {
         let db = setup_db().await;
@@ -223,6 +360,84 @@ mod tests {
         assert!(ids.contains("s2"));
     }
 
+    #[tokio::test]
+    async fn test_prefilter_by_duration_range() {
+        let db = setup_db().await;
+        // duration_seconds >= 601 → s3 (3600) only
+        let filter = SearchPrefilter {
+            min_duration: Some(601),
+            ..Default::default()
+        };
+        let ids = db.search_prefilter_session_ids(&filter).await.unwrap();
+        assert_eq!(ids.len(), 1);
+        assert!(ids.contains("s3"));
+
+        // exact 600 → s2 only
+        let filter = SearchPrefilter {
+            min_duration: Some(600),
+            max_duration: Some(600),
+            ..Default::default()
+        };
+        let ids = db.search_prefilter_session_ids(&filter).await.unwrap();
+        assert_eq!(ids.len(), 1);
+        assert!(ids.contains("s2"));
+    }
+
+    #[tokio::test]
+    async fn test_prefilter_by_token_range() {
+        let db = setup_db().await;
+        // input + output >= 100001 → s3 (200000) only
+        let filter = SearchPrefilter {
+            min_tokens: Some(100_001),
+            ..Default::default()
+        };
+        let ids = db.search_prefilter_session_ids(&filter).await.unwrap();
+        assert_eq!(ids.len(), 1);
+        assert!(ids.contains("s3"));
+
+        // input + output <= 99999 → s1 (1500) only; s2 is exactly 100000
+        let filter = SearchPrefilter {
+            max_tokens: Some(99_999),
+            ..Default::default()
+        };
+        let ids = db.search_prefilter_session_ids(&filter).await.unwrap();
+        assert_eq!(ids.len(), 1);
+        assert!(ids.contains("s1"));
+    }
+
+    #[tokio::test]
+    async fn test_prefilter_by_mcp_server() {
+        let db = setup_db().await;
+        sqlx::query(
+            r#"UPDATE session_stats SET mcp_tools = CASE session_id
+                 WHEN 's1' THEN '[{"server":"playwright","tool":"browser_navigate"}]'
+                 WHEN 's2' THEN '[{"server":"playwright","tool":"browser_click"},{"server":"supabase","tool":"execute_sql"}]'
+                 ELSE '[]' END"#,
+        )
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+        let ids = |servers: &[&str]| {
+            let filter = SearchPrefilter {
+                mcp_servers: servers.iter().map(|s| s.to_string()).collect(),
+                ..Default::default()
+            };
+            let db = &db;
+            async move { db.search_prefilter_session_ids(&filter).await.unwrap() }
+        };
+        assert_eq!(
+            ids(&["playwright"]).await,
+            HashSet::from(["s1".to_string(), "s2".to_string()])
+        );
+        assert_eq!(
+            ids(&["playwright", "supabase"]).await,
+            HashSet::from(["s2".to_string()])
+        );
+        // A tool name is not a server.
+        assert!(ids(&["browser_navigate"]).await.is_empty());
+    }
+
     #[tokio::test]
     async fn test_prefilter_is_empty() {
         assert!(SearchPrefilter::default().is_empty());
@@ -0,0 +1,39 @@
+// crates/db/src/queries/sessions/display_name.rs
+// User-chosen project display names that survive reindexing.
+
+use crate::{Database, DbResult};
+
+/// `settings` key prefix for a project's display-name override; the full key
+/// is the prefix followed by the project id. The session_stats UPSERT reads
+/// it back so a reindex keeps the user's name instead of the derived one.
+pub const PROJECT_DISPLAY_NAME_KEY_PREFIX: &str = "project_display_name:";
+
+impl Database {
+    /// Rename project `project_id` to `name` in the UI.
+    ///
+    /// Rewrites `project_display_name` on every session of the project and
+    /// stores the override in `settings`, both in one transaction. Returns the
+    /// number of sessions updated.
+    pub async fn set_project_display_name(&self, project_id: &str, name: &str) -> DbResult<u64> {
+        let mut tx = self.pool().begin().await?;
+
+        let updated =
+            sqlx::query("UPDATE session_stats SET project_display_name = ?2 WHERE project_id = ?1")
+                .bind(project_id)
+                .bind(name)
+                .execute(&mut *tx)
+                .await?;
+
+        sqlx::query(
+            "INSERT INTO settings (key, value) VALUES (?1, ?2) \
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        )
+        .bind(format!("{PROJECT_DISPLAY_NAME_KEY_PREFIX}{project_id}"))
+        .bind(name)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(updated.rows_affected())
+    }
+}
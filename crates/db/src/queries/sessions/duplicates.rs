@@ -0,0 +1,53 @@
+// crates/db/src/queries/sessions/duplicates.rs
+// Duplicate-session detection for cleanup after backup recovery / re-indexing.
+
+use crate::queries::DuplicateSessionGroup;
+use crate::{Database, DbResult};
+
+impl Database {
+    /// Find groups of sessions with identical `(project_id, first_message_at,
+    /// preview)`. Sessions without a first-message time or preview are never
+    /// grouped. Groups are ordered newest first.
+    pub async fn duplicate_sessions(&self) -> DbResult<Vec<DuplicateSessionGroup>> {
+        let rows: Vec<(String, i64, String, String)> = sqlx::query_as(
+            r#"
+            SELECT s.project_id, s.first_message_at, s.preview, s.id
+            FROM valid_sessions s
+            JOIN (
+                SELECT project_id, first_message_at, preview
+                FROM valid_sessions
+                WHERE first_message_at IS NOT NULL AND first_message_at > 0
+                  AND preview <> ''
+                GROUP BY project_id, first_message_at, preview
+                HAVING COUNT(*) > 1
+            ) d ON d.project_id = s.project_id
+               AND d.first_message_at = s.first_message_at
+               AND d.preview = s.preview
+            ORDER BY s.first_message_at DESC, s.project_id, s.preview,
+                     s.last_message_at DESC, s.id
+            "#,
+        )
+        .fetch_all(self.pool())
+        .await?;
+
+        let mut groups: Vec<DuplicateSessionGroup> = Vec::new();
+        for (project_id, first_message_at, preview, id) in rows {
+            match groups.last_mut() {
+                Some(g)
+                    if g.project_id == project_id
+                        && g.first_message_at == first_message_at
+                        && g.preview == preview =>
+                {
+                    g.session_ids.push(id);
+                }
+                _ => groups.push(DuplicateSessionGroup {
+                    project_id,
+                    first_message_at,
+                    preview,
+                    session_ids: vec![id],
+                }),
+            }
+        }
+        Ok(groups)
+    }
+}
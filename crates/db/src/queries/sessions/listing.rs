@@ -3,10 +3,59 @@
 
 use crate::{Database, DbResult};
 use chrono::Utc;
-use claude_view_core::{ProjectInfo, SessionInfo};
-use std::collections::HashMap;
+use claude_view_core::{BranchFilter, ProjectInfo, SessionInfo, SessionsPage};
+use std::collections::{BTreeMap, HashMap};
 
 use super::super::row_types::SessionRow;
+use super::super::{ErrorSession, SessionCard, SessionsFilter, SessionsSort};
+
+/// Column list shared by the by-id session lookups.
+///
+/// CQRS Phase D.3 — category / classified fields join from
+/// `session_flags`; timestamp formatting matches the legacy RFC3339
+/// shape for API compatibility.
+const SESSION_BY_ID_SELECT: &str = r#"SELECT
+                s.session_id AS id, s.project_id, s.preview, s.turn_count,
+                s.last_message_at, s.file_path,
+                s.project_path, s.git_root, s.project_display_name,
+                s.size_bytes, s.last_message, s.files_touched, s.skills_used,
+                s.tool_counts_edit, s.tool_counts_read, s.tool_counts_bash, s.tool_counts_write,
+                s.message_count,
+                COALESCE(s.summary_text, s.summary) AS summary,
+                s.git_branch, s.is_sidechain, s.deep_indexed_at,
+                s.total_input_tokens,
+                s.total_output_tokens,
+                s.cache_read_tokens AS total_cache_read_tokens,
+                s.cache_creation_tokens AS total_cache_creation_tokens,
+                s.api_call_count AS turn_count_api,
+                s.primary_model,
+                s.user_prompt_count, s.api_call_count, s.tool_call_count,
+                s.files_read, s.files_edited,
+                s.files_read_count, s.files_edited_count, s.reedited_files_count,
+                s.duration_seconds, s.first_message_at, s.commit_count,
+                s.thinking_block_count, s.turn_duration_avg_ms, s.turn_duration_max_ms,
+                s.api_error_count, s.compaction_count, s.agent_spawn_count,
+                s.bash_progress_count, s.hook_progress_count, s.mcp_progress_count,
+                s.lines_added, s.lines_removed, s.loc_source,
+                s.summary_text, s.parse_version,
+                sf.category_l1 AS category_l1,
+                sf.category_l2 AS category_l2,
+                sf.category_l3 AS category_l3,
+                sf.category_confidence AS category_confidence,
+                sf.category_source AS category_source,
+                CASE
+                    WHEN sf.classified_at IS NULL THEN NULL
+                    ELSE strftime('%Y-%m-%dT%H:%M:%fZ', sf.classified_at / 1000.0, 'unixepoch')
+                END AS classified_at,
+                s.total_task_time_seconds, s.longest_task_seconds, s.longest_task_preview,
+                s.total_cost_usd,
+                s.slug,
+                s.entrypoint
+            FROM session_stats s
+            LEFT JOIN session_flags sf ON sf.session_id = s.session_id"#;
+
+/// Stay under SQLite's bound-variable limit when batching `IN (...)` lookups.
+const SQLITE_VARIABLE_LIMIT: usize = 900;
 
 impl Database {
     /// List all projects with their sessions, grouped by project_id.
@@ -123,60 +172,238 @@ impl Database {
         Ok(projects)
     }
 
+    /// One page of sessions across all projects, for the global session
+    /// browser: the paginated, filterable form of
+    /// [`list_all_sessions`](Self::list_all_sessions).
+    ///
+    /// Sidechains and archived sessions are excluded, as in
+    /// `valid_sessions`. `total` counts every session matching `filter`,
+    /// not just the page.
+    pub async fn sessions_page(
+        &self,
+        limit: i64,
+        offset: i64,
+        sort: SessionsSort,
+        filter: &SessionsFilter,
+    ) -> DbResult<SessionsPage> {
+        let (named_branch, no_branch) = match BranchFilter::from_param(filter.branch.as_deref()) {
+            BranchFilter::All => (None, false),
+            BranchFilter::Named(name) => (Some(name), false),
+            BranchFilter::NoBranch => (None, true),
+        };
+        let where_clause = r#"
+            WHERE s.is_sidechain = 0 AND sf.archived_at IS NULL
+              AND (?1 IS NULL OR s.project_id = ?1
+                   OR (s.git_root IS NOT NULL AND s.git_root <> '' AND s.git_root = ?1)
+                   OR (s.project_path IS NOT NULL AND s.project_path <> '' AND s.project_path = ?1))
+              AND (?2 IS NULL OR s.git_branch = ?2)
+              AND (?3 = 0 OR s.git_branch IS NULL)
+              AND (?4 IS NULL OR s.last_message_at >= ?4)
+              AND (?5 IS NULL OR s.last_message_at <= ?5)
+              AND (?6 IS NULL OR s.work_type = ?6)"#;
+        let order_by = match sort {
+            SessionsSort::Recent => "s.last_message_at DESC, s.session_id",
+            SessionsSort::Oldest => "s.last_message_at ASC, s.session_id",
+            SessionsSort::Messages => "s.message_count DESC, s.last_message_at DESC, s.session_id",
+        };
+
+        let count_sql = format!(
+            "SELECT COUNT(*) FROM session_stats s
+            LEFT JOIN session_flags sf ON sf.session_id = s.session_id{where_clause}"
+        );
+        let (total,): (i64,) = sqlx::query_as(&count_sql)
+            .bind(filter.project_id.as_deref())
+            .bind(named_branch)
+            .bind(no_branch)
+            .bind(filter.since)
+            .bind(filter.until)
+            .bind(filter.work_type.as_deref())
+            .fetch_one(self.pool())
+            .await?;
+
+        let sql = format!(
+            "{SESSION_BY_ID_SELECT}{where_clause}
+            ORDER BY {order_by}
+            LIMIT ?7 OFFSET ?8"
+        );
+        let rows = sqlx::query_as::<_, SessionRow>(&sql)
+            .bind(filter.project_id.as_deref())
+            .bind(named_branch)
+            .bind(no_branch)
+            .bind(filter.since)
+            .bind(filter.until)
+            .bind(filter.work_type.as_deref())
+            .bind(limit.max(1))
+            .bind(offset.max(0))
+            .fetch_all(self.pool())
+            .await?;
+
+        let sessions = rows
+            .into_iter()
+            .map(|r| {
+                let pid = r.project_id.clone();
+                r.into_session_info(&pid)
+            })
+            .collect();
+        Ok(SessionsPage {
+            sessions,
+            total: total as usize,
+        })
+    }
+
+    /// First and last activity per project in one pass:
+    /// `(project_id, first_at, last_at, session_count)`, most recently active
+    /// project first. Sidechains and archived sessions are excluded (via
+    /// `valid_sessions`), as are sessions with no message timestamp.
+    ///
+    /// `first_at` uses a session's `first_message_at` when known, falling back
+    /// to its `last_message_at`.
+    pub async fn project_activity_bounds(&self) -> DbResult<Vec<(String, i64, i64, i64)>> {
+        let rows: Vec<(String, i64, i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT
+                project_id,
+                MIN(CASE WHEN first_message_at > 0 THEN first_message_at
+                         ELSE last_message_at END) AS first_at,
+                MAX(last_message_at) AS last_at,
+                COUNT(*) AS session_count
+            FROM valid_sessions
+            WHERE last_message_at > 0
+            GROUP BY project_id
+            ORDER BY last_at DESC, project_id
+            "#,
+        )
+        .fetch_all(self.pool())
+        .await?;
+        Ok(rows)
+    }
+
+    /// Sessions with the most API errors, for triaging problematic runs.
+    ///
+    /// Ranked by `api_error_count`, then `compaction_count`, then recency.
+    /// Sessions with neither errors nor compactions are skipped. `project`
+    /// matches `project_id`, `git_root` or `project_path`; `from` / `to`
+    /// bound `last_message_at`.
+    pub async fn top_error_sessions(
+        &self,
+        from: Option<i64>,
+        to: Option<i64>,
+        project: Option<&str>,
+        limit: i64,
+    ) -> DbResult<Vec<ErrorSession>> {
+        type Row = (String, String, String, String, i64, i64, i64, i64);
+        let rows: Vec<Row> = sqlx::query_as(
+            r#"
+            SELECT id, project_id, project_display_name, preview,
+                   api_error_count, api_retry_count, compaction_count,
+                   last_message_at
+            FROM valid_sessions
+            WHERE (api_error_count > 0 OR compaction_count > 0)
+              AND last_message_at >= ?1
+              AND last_message_at <= ?2
+              AND (?3 IS NULL OR project_id = ?3
+                   OR (git_root IS NOT NULL AND git_root <> '' AND git_root = ?3)
+                   OR (project_path IS NOT NULL AND project_path <> '' AND project_path = ?3))
+            ORDER BY api_error_count DESC, compaction_count DESC, last_message_at DESC
+            LIMIT ?4
+            "#,
+        )
+        .bind(from.unwrap_or(i64::MIN))
+        .bind(to.unwrap_or(i64::MAX))
+        .bind(project)
+        .bind(limit)
+        .fetch_all(self.pool())
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(
+                    session_id,
+                    project_id,
+                    project_display_name,
+                    preview,
+                    api_error_count,
+                    api_retry_count,
+                    compaction_count,
+                    last_message_at,
+                )| ErrorSession {
+                    session_id,
+                    project_id,
+                    project_display_name,
+                    preview,
+                    api_error_count,
+                    api_retry_count,
+                    compaction_count,
+                    last_message_at,
+                },
+            )
+            .collect())
+    }
+
+    /// The `limit` most recently active sessions across all projects, newest
+    /// first, as lightweight cards for the homepage feed. Sidechains and
+    /// archived sessions are excluded (`valid_sessions`).
+    pub async fn get_recent_sessions(&self, limit: i64) -> DbResult<Vec<SessionCard>> {
+        type Row = (
+            String,
+            String,
+            String,
+            String,
+            Option<String>,
+            Option<String>,
+            i64,
+            i64,
+        );
+        let rows: Vec<Row> = sqlx::query_as(
+            r#"
+            SELECT id, project_id, project_display_name, preview,
+                   git_branch, primary_model, message_count, last_message_at
+            FROM valid_sessions
+            ORDER BY last_message_at DESC, id
+            LIMIT ?1
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(self.pool())
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(
+                    session_id,
+                    project_id,
+                    project_display_name,
+                    preview,
+                    git_branch,
+                    primary_model,
+                    message_count,
+                    last_message_at,
+                )| SessionCard {
+                    session_id,
+                    project_id,
+                    project_display_name,
+                    preview,
+                    git_branch,
+                    primary_model,
+                    message_count,
+                    last_message_at,
+                },
+            )
+            .collect())
+    }
+
     /// Look up a single session by its UUID.
     ///
     /// Used by the cost estimation endpoint to fetch session metadata
     /// (token counts, model, timestamps) for a specific session.
-    ///
-    /// CQRS Phase D.3 — category / classified fields join from
-    /// `session_flags`; timestamp formatting matches the legacy RFC3339
-    /// shape for API compatibility.
     pub async fn get_session_by_id(&self, id: &str) -> DbResult<Option<SessionInfo>> {
-        let row = sqlx::query_as::<_, SessionRow>(
-            r#"SELECT
-                s.session_id AS id, s.project_id, s.preview, s.turn_count,
-                s.last_message_at, s.file_path,
-                s.project_path, s.git_root, s.project_display_name,
-                s.size_bytes, s.last_message, s.files_touched, s.skills_used,
-                s.tool_counts_edit, s.tool_counts_read, s.tool_counts_bash, s.tool_counts_write,
-                s.message_count,
-                COALESCE(s.summary_text, s.summary) AS summary,
-                s.git_branch, s.is_sidechain, s.deep_indexed_at,
-                s.total_input_tokens,
-                s.total_output_tokens,
-                s.cache_read_tokens AS total_cache_read_tokens,
-                s.cache_creation_tokens AS total_cache_creation_tokens,
-                s.api_call_count AS turn_count_api,
-                s.primary_model,
-                s.user_prompt_count, s.api_call_count, s.tool_call_count,
-                s.files_read, s.files_edited,
-                s.files_read_count, s.files_edited_count, s.reedited_files_count,
-                s.duration_seconds, s.first_message_at, s.commit_count,
-                s.thinking_block_count, s.turn_duration_avg_ms, s.turn_duration_max_ms,
-                s.api_error_count, s.compaction_count, s.agent_spawn_count,
-                s.bash_progress_count, s.hook_progress_count, s.mcp_progress_count,
-                s.lines_added, s.lines_removed, s.loc_source,
-                s.summary_text, s.parse_version,
-                sf.category_l1 AS category_l1,
-                sf.category_l2 AS category_l2,
-                sf.category_l3 AS category_l3,
-                sf.category_confidence AS category_confidence,
-                sf.category_source AS category_source,
-                CASE
-                    WHEN sf.classified_at IS NULL THEN NULL
-                    ELSE strftime('%Y-%m-%dT%H:%M:%fZ', sf.classified_at / 1000.0, 'unixepoch')
-                END AS classified_at,
-                s.total_task_time_seconds, s.longest_task_seconds, s.longest_task_preview,
-                s.total_cost_usd,
-                s.slug,
-                s.entrypoint
-            FROM session_stats s
-            LEFT JOIN session_flags sf ON sf.session_id = s.session_id
-            WHERE s.session_id = ?1"#,
-        )
-        .bind(id)
-        .fetch_optional(self.pool())
-        .await?;
+        let sql = format!("{SESSION_BY_ID_SELECT}\n            WHERE s.session_id = ?1");
+        let row = sqlx::query_as::<_, SessionRow>(&sql)
+            .bind(id)
+            .fetch_optional(self.pool())
+            .await?;
 
         Ok(row.map(|r| {
             let pid = r.project_id.clone();
@@ -184,6 +411,33 @@ impl Database {
         }))
     }
 
+    /// Batch form of [`get_session_by_id`](Self::get_session_by_id) for
+    /// hydrating search results in one round-trip per 900 ids.
+    ///
+    /// Results follow the order of `ids`; unknown ids are skipped and
+    /// duplicates yield the session once per occurrence.
+    pub async fn get_sessions_by_ids(&self, ids: &[String]) -> DbResult<Vec<SessionInfo>> {
+        let mut by_id: HashMap<String, SessionInfo> = HashMap::with_capacity(ids.len());
+        for chunk in ids.chunks(SQLITE_VARIABLE_LIMIT) {
+            let placeholders: Vec<String> = (1..=chunk.len()).map(|i| format!("?{i}")).collect();
+            let sql = format!(
+                "{SESSION_BY_ID_SELECT}\n            WHERE s.session_id IN ({})",
+                placeholders.join(", ")
+            );
+            let mut query = sqlx::query_as::<_, SessionRow>(&sql);
+            for id in chunk {
+                query = query.bind(id);
+            }
+            for r in query.fetch_all(self.pool()).await? {
+                let pid = r.project_id.clone();
+                let info = r.into_session_info(&pid);
+                by_id.insert(info.id.clone(), info);
+            }
+        }
+
+        Ok(ids.iter().filter_map(|id| by_id.get(id).cloned()).collect())
+    }
+
     /// Look up a session's JSONL file path by session ID.
     ///
     /// Returns `None` if the session doesn't exist in the DB.
@@ -197,6 +451,54 @@ impl Database {
         Ok(row.map(|(p,)| p))
     }
 
+    /// How many times the user interrupted the model mid-turn in a session.
+    ///
+    /// Returns 0 if the session isn't indexed.
+    pub async fn get_session_interruption_count(&self, session_id: &str) -> DbResult<u32> {
+        let row: Option<(i64,)> =
+            sqlx::query_as("SELECT interruption_count FROM session_stats WHERE session_id = ?1")
+                .bind(session_id)
+                .fetch_optional(self.pool())
+                .await?;
+        Ok(row.map_or(0, |(n,)| n.max(0) as u32))
+    }
+
+    /// Bash calls in a session by command category (`"git"`, `"test"`,
+    /// `"build"`, `"misc"`).
+    ///
+    /// Empty if the session isn't indexed.
+    pub async fn get_session_bash_categories(
+        &self,
+        session_id: &str,
+    ) -> DbResult<BTreeMap<String, u32>> {
+        let row: Option<(String,)> =
+            sqlx::query_as("SELECT bash_categories FROM session_stats WHERE session_id = ?1")
+                .bind(session_id)
+                .fetch_optional(self.pool())
+                .await?;
+        Ok(row
+            .and_then(|(json,)| serde_json::from_str(&json).ok())
+            .unwrap_or_default())
+    }
+
+    /// Argument strings a session passed to each slash command or skill,
+    /// counted (`"/commit"` -> `"--amend"` -> 2).
+    ///
+    /// Empty if the session isn't indexed.
+    pub async fn get_session_invocation_args(
+        &self,
+        session_id: &str,
+    ) -> DbResult<BTreeMap<String, BTreeMap<String, u32>>> {
+        let row: Option<(String,)> =
+            sqlx::query_as("SELECT invocation_args FROM session_stats WHERE session_id = ?1")
+                .bind(session_id)
+                .fetch_optional(self.pool())
+                .await?;
+        Ok(row
+            .and_then(|(json,)| serde_json::from_str(&json).ok())
+            .unwrap_or_default())
+    }
+
     /// Get all session IDs in the database (for backup dedup).
     pub async fn get_all_session_ids(&self) -> DbResult<Vec<String>> {
         let rows: Vec<(String,)> = sqlx::query_as("SELECT session_id FROM session_stats")
@@ -0,0 +1,101 @@
+// crates/db/src/queries/sessions/merge.rs
+// Project consolidation after a repo is renamed or moved.
+
+use crate::{Database, DbResult};
+
+/// `settings` key prefix recording a merge; the full key is the prefix
+/// followed by the merged-away project id, and the value is the project it
+/// now belongs to. The session_stats writers read it back so a reindex files
+/// the sessions under the merged project instead of undoing the merge.
+pub const PROJECT_MERGE_KEY_PREFIX: &str = "project_merge:";
+
+impl Database {
+    /// Move every session of project `from_id` into project `to_id`.
+    ///
+    /// Moved sessions take the destination's display name and path (from its
+    /// most recently active session) so the sidebar shows a single entry. If
+    /// `to_id` has no sessions yet, only the id changes. Runs in one
+    /// transaction and returns the number of sessions moved.
+    ///
+    /// The merge is also recorded under [`PROJECT_MERGE_KEY_PREFIX`], so
+    /// sessions of `from_id` indexed later (new or reparsed) land in `to_id`.
+    /// Earlier merges into `from_id` are repointed at `to_id`.
+    ///
+    /// Per-session invocation data lives on `session_stats`, so it moves with
+    /// the row; the prompt search index is keyed by prompt history, not
+    /// `project_id`, and needs no update.
+    pub async fn merge_projects(&self, from_id: &str, to_id: &str) -> DbResult<u64> {
+        if from_id == to_id {
+            return Ok(0);
+        }
+
+        let mut tx = self.pool().begin().await?;
+
+        let target: Option<(String, String)> = sqlx::query_as(
+            r#"
+            SELECT project_display_name, project_path
+            FROM session_stats
+            WHERE project_id = ?1
+            ORDER BY last_message_at DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(to_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let moved = match target {
+            Some((display_name, path)) => {
+                sqlx::query(
+                    r#"
+                    UPDATE session_stats
+                    SET project_id = ?2, project_display_name = ?3, project_path = ?4
+                    WHERE project_id = ?1
+                    "#,
+                )
+                .bind(from_id)
+                .bind(to_id)
+                .bind(display_name)
+                .bind(path)
+                .execute(&mut *tx)
+                .await?
+            }
+            None => {
+                sqlx::query("UPDATE session_stats SET project_id = ?2 WHERE project_id = ?1")
+                    .bind(from_id)
+                    .bind(to_id)
+                    .execute(&mut *tx)
+                    .await?
+            }
+        };
+
+        // `to_id` is a destination now, not a redirect; earlier merges into
+        // `from_id` follow it to `to_id`.
+        sqlx::query("DELETE FROM settings WHERE key = ?1 || ?2")
+            .bind(PROJECT_MERGE_KEY_PREFIX)
+            .bind(to_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            "UPDATE settings SET value = ?3 \
+             WHERE substr(key, 1, length(?1)) = ?1 AND value = ?2",
+        )
+        .bind(PROJECT_MERGE_KEY_PREFIX)
+        .bind(from_id)
+        .bind(to_id)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query(
+            "INSERT INTO settings (key, value) VALUES (?1 || ?2, ?3) \
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        )
+        .bind(PROJECT_MERGE_KEY_PREFIX)
+        .bind(from_id)
+        .bind(to_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(moved.rows_affected())
+    }
+}
@@ -2,14 +2,19 @@
 // Session CRUD operations: insert, update, list, and indexer state management.
 
 mod archive;
+mod display_name;
+mod duplicates;
 mod indexer;
 mod listing;
+mod merge;
 #[cfg(test)]
 mod tests;
 mod update;
 mod upsert;
 mod upsert_stats;
 
+pub use display_name::PROJECT_DISPLAY_NAME_KEY_PREFIX;
+pub use merge::PROJECT_MERGE_KEY_PREFIX;
 pub use upsert::execute_upsert_parsed_session;
 pub use upsert_stats::{
     execute_upsert_session_stats_from_parsed, UPSERT_SESSION_STATS_FROM_PARSED_SQL,
@@ -29,6 +29,9 @@ mod upsert_tests {
             tool_counts_write: 0,
             files_touched: "[]".to_string(),
             skills_used: "[]".to_string(),
+            mcp_tools: "[]".to_string(),
+            bash_categories: "{}".to_string(),
+            invocation_args: "{}".to_string(),
             user_prompt_count: 5,
             api_call_count: 5,
             tool_call_count: 6,
@@ -44,6 +47,7 @@ mod upsert_tests {
             cache_read_tokens: 2000,
             cache_creation_tokens: 1000,
             thinking_block_count: 3,
+            thinking_output_tokens: 0,
             turn_duration_avg_ms: Some(5000),
             turn_duration_max_ms: Some(12000),
             turn_duration_total_ms: Some(25000),
@@ -51,11 +55,13 @@ mod upsert_tests {
             api_retry_count: 0,
             compaction_count: 0,
             hook_blocked_count: 0,
+            interruption_count: 0,
             agent_spawn_count: 0,
             bash_progress_count: 0,
             hook_progress_count: 0,
             mcp_progress_count: 0,
             summary_text: None,
+            summary_source: "unknown".to_string(),
             parse_version: CURRENT_PARSE_VERSION,
             file_size_at_index: 1024,
             file_mtime_at_index: 1700000000,
@@ -65,7 +71,9 @@ mod upsert_tests {
             ai_lines_added: 0,
             ai_lines_removed: 0,
             work_type: None,
+            work_type_alternates: None,
             primary_model: Some("claude-sonnet-4-5-20250929".to_string()),
+            model_turn_counts: None,
             total_task_time_seconds: Some(0),
             longest_task_seconds: Some(0),
             longest_task_preview: None,
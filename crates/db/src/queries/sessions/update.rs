@@ -240,6 +240,7 @@ impl Database {
             "l3": category_l3,
             "confidence": confidence,
             "source": source,
+            "version": claude_view_core::classification::TAXONOMY_VERSION,
         })
         .to_string();
         let actor = format!("classifier:{source}");
@@ -68,6 +68,9 @@ impl Database {
             tool_counts_write: session.tool_counts.write as i32,
             files_touched,
             skills_used,
+            mcp_tools: "[]".to_string(),
+            bash_categories: "{}".to_string(),
+            invocation_args: "{}".to_string(),
             user_prompt_count: session.user_prompt_count as i32,
             api_call_count: session.api_call_count as i32,
             tool_call_count: session.tool_call_count as i32,
@@ -83,6 +86,7 @@ impl Database {
             cache_read_tokens: session.total_cache_read_tokens.unwrap_or(0) as i64,
             cache_creation_tokens: session.total_cache_creation_tokens.unwrap_or(0) as i64,
             thinking_block_count: session.thinking_block_count as i32,
+            thinking_output_tokens: 0,
             turn_duration_avg_ms: session.turn_duration_avg_ms.map(|n| n as i64),
             turn_duration_max_ms: session.turn_duration_max_ms.map(|n| n as i64),
             turn_duration_total_ms: None,
@@ -90,11 +94,13 @@ impl Database {
             api_retry_count: 0,
             compaction_count: session.compaction_count as i32,
             hook_blocked_count: 0,
+            interruption_count: 0,
             agent_spawn_count: session.agent_spawn_count as i32,
             bash_progress_count: session.bash_progress_count as i32,
             hook_progress_count: session.hook_progress_count as i32,
             mcp_progress_count: session.mcp_progress_count as i32,
             summary_text: None,
+            summary_source: "unknown".to_string(),
             parse_version: if session.parse_version == 0 {
                 CURRENT_PARSE_VERSION
             } else {
@@ -108,7 +114,9 @@ impl Database {
             ai_lines_added: 0,
             ai_lines_removed: 0,
             work_type: None,
+            work_type_alternates: None,
             primary_model: session.primary_model.clone(),
+            model_turn_counts: None,
             total_task_time_seconds: session.total_task_time_seconds.map(|n| n as i64),
             longest_task_seconds: session.longest_task_seconds.map(|n| n as i64),
             longest_task_preview: session.longest_task_preview.clone(),
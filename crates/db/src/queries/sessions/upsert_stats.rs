@@ -6,6 +6,7 @@
 use chrono::Utc;
 use claude_view_session_parser::{PARSER_VERSION, STATS_VERSION};
 
+use super::{PROJECT_DISPLAY_NAME_KEY_PREFIX, PROJECT_MERGE_KEY_PREFIX};
 use crate::indexer_parallel::ParsedSession;
 
 /// Full-row `session_stats` UPSERT from a parsed session.
@@ -27,8 +28,18 @@ use crate::indexer_parallel::ParsedSession;
 ///   INSERT and DO NOT overwrite them in the ON CONFLICT clause, so whichever
 ///   writer got there first wins for those columns.
 ///
+/// `project_display_name` prefers a user override stored in `settings`
+/// under [`super::PROJECT_DISPLAY_NAME_KEY_PREFIX`] (bound as `?74`) over the
+/// derived name. A project merged away with `merge_projects` (recorded under
+/// [`super::PROJECT_MERGE_KEY_PREFIX`], bound as `?75`) is redirected to the
+/// merged project, taking its display name and path from that project's
+/// latest session, as the merge did.
+///
 /// 65 bind parameters (same shape as UPSERT_SESSION_SQL to keep mental model
-/// stable) plus one trailing bind for `stats_version`.
+/// stable) plus trailing binds for `stats_version`, `thinking_output_tokens`,
+/// `summary_source`, `work_type_alternates`, `model_turn_counts`,
+/// `interruption_count`, `mcp_tools`, `bash_categories`, the display-name
+/// and merge key prefixes, and `invocation_args`.
 pub const UPSERT_SESSION_STATS_FROM_PARSED_SQL: &str = r#"
     INSERT INTO session_stats (
         session_id, project_id, project_display_name, project_path,
@@ -53,7 +64,9 @@ pub const UPSERT_SESSION_STATS_FROM_PARSED_SQL: &str = r#"
         ai_lines_added, ai_lines_removed, work_type,
         primary_model, total_task_time_seconds,
         longest_task_seconds, longest_task_preview, total_cost_usd,
-        slug, entrypoint,
+        slug, entrypoint, thinking_output_tokens, summary_source,
+        work_type_alternates, model_turn_counts, interruption_count,
+        mcp_tools, bash_categories, invocation_args,
         -- session_stats header columns the StatsDelta writer owns. We set
         -- them to safe defaults on INSERT so the NOT NULL constraints are
         -- satisfied; ON CONFLICT DO NOT update them (coexistence contract).
@@ -62,7 +75,26 @@ pub const UPSERT_SESSION_STATS_FROM_PARSED_SQL: &str = r#"
         bash_count,
         source_mtime
     ) VALUES (
-        ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10,
+        ?1,
+        COALESCE((SELECT value FROM settings WHERE key = ?75 || ?2), ?2),
+        COALESCE(
+            (SELECT value FROM settings
+             WHERE key = ?74 || COALESCE(
+                 (SELECT value FROM settings WHERE key = ?75 || ?2), ?2)),
+            (SELECT ss.project_display_name FROM settings m
+             JOIN session_stats ss ON ss.project_id = m.value
+             WHERE m.key = ?75 || ?2 AND ss.session_id <> ?1
+             ORDER BY ss.last_message_at DESC LIMIT 1),
+            ?3
+        ),
+        COALESCE(
+            (SELECT ss.project_path FROM settings m
+             JOIN session_stats ss ON ss.project_id = m.value
+             WHERE m.key = ?75 || ?2 AND ss.session_id <> ?1
+             ORDER BY ss.last_message_at DESC LIMIT 1),
+            ?4
+        ),
+        ?5, ?6, ?7, ?8, ?9, ?10,
         NULLIF(TRIM(?11), ''), ?12, ?13, ?14,
         ?15, ?16, ?17, ?18, ?19, ?20, ?21,
         ?22, ?14, ?23, ?24, ?25,
@@ -71,7 +103,9 @@ pub const UPSERT_SESSION_STATS_FROM_PARSED_SQL: &str = r#"
         ?41, ?42, ?43, ?44, ?45, ?46, ?47, ?48,
         ?49, ?50, ?51, ?52, ?53, ?54, ?55,
         ?56, ?57, ?58, ?59, ?60, ?61, ?62, ?63,
-        ?64, ?65,
+        ?64, ?65, ?67, ?68,
+        ?69, ?70, ?71,
+        ?72, ?73, ?76,
         X'', ?13,
         ?23, ?66,
         ?20,
@@ -143,7 +177,15 @@ pub const UPSERT_SESSION_STATS_FROM_PARSED_SQL: &str = r#"
         total_cost_usd = excluded.total_cost_usd,
         slug = excluded.slug,
         entrypoint = COALESCE(excluded.entrypoint, session_stats.entrypoint),
-        bash_count = excluded.bash_count
+        bash_count = excluded.bash_count,
+        thinking_output_tokens = excluded.thinking_output_tokens,
+        summary_source = excluded.summary_source,
+        work_type_alternates = excluded.work_type_alternates,
+        model_turn_counts = excluded.model_turn_counts,
+        interruption_count = excluded.interruption_count,
+        mcp_tools = excluded.mcp_tools,
+        bash_categories = excluded.bash_categories,
+        invocation_args = excluded.invocation_args
 "#;
 
 /// Execute the session_stats full-row UPSERT from a ParsedSession.
@@ -226,6 +268,16 @@ where
         .bind(&s.slug) // ?64
         .bind(&s.entrypoint) // ?65
         .bind(i64::from(STATS_VERSION.0)) // ?66 stats_version default for INSERT
+        .bind(s.thinking_output_tokens) // ?67
+        .bind(&s.summary_source) // ?68
+        .bind(&s.work_type_alternates) // ?69
+        .bind(&s.model_turn_counts) // ?70
+        .bind(s.interruption_count) // ?71
+        .bind(&s.mcp_tools) // ?72
+        .bind(&s.bash_categories) // ?73
+        .bind(PROJECT_DISPLAY_NAME_KEY_PREFIX) // ?74
+        .bind(PROJECT_MERGE_KEY_PREFIX) // ?75
+        .bind(&s.invocation_args) // ?76
         .execute(executor)
         .await?;
 
@@ -1,9 +1,18 @@
 //! App settings CRUD queries.
+//!
+//! Two stores live here: the typed single-row `app_settings` table for LLM
+//! config, and the generic `settings` key-value table for everything else.
 
 use crate::{Database, DbResult};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
+/// `settings` key for the git sync interval in seconds.
+pub const GIT_SYNC_INTERVAL_KEY: &str = "git_sync_interval_secs";
+
+/// Git sync interval used when the setting is absent.
+pub const DEFAULT_GIT_SYNC_INTERVAL_SECS: u64 = 60;
+
 /// Application settings (single-row table).
 #[derive(Debug, Clone, Serialize, Deserialize, TS, utoipa::ToSchema)]
 #[cfg_attr(feature = "codegen", ts(export))]
@@ -47,6 +56,74 @@ impl Database {
         }
         self.get_app_settings().await
     }
+
+    /// Read a raw value from the `settings` key-value store.
+    pub async fn get_setting(&self, key: &str) -> DbResult<Option<String>> {
+        let row: Option<(String,)> = sqlx::query_as("SELECT value FROM settings WHERE key = ?")
+            .bind(key)
+            .fetch_optional(self.pool())
+            .await?;
+        Ok(row.map(|(value,)| value))
+    }
+
+    /// Insert or replace a raw value in the `settings` key-value store.
+    pub async fn set_setting(&self, key: &str, value: &str) -> DbResult<()> {
+        sqlx::query(
+            "INSERT INTO settings (key, value) VALUES (?1, ?2) \
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        )
+        .bind(key)
+        .bind(value)
+        .execute(self.pool())
+        .await?;
+        Ok(())
+    }
+
+    /// Read an integer setting, falling back to `default` when the key is
+    /// missing or its value doesn't parse.
+    pub async fn get_setting_i64(&self, key: &str, default: i64) -> DbResult<i64> {
+        Ok(self
+            .get_setting(key)
+            .await?
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap_or(default))
+    }
+
+    /// Store an integer setting.
+    pub async fn set_setting_i64(&self, key: &str, value: i64) -> DbResult<()> {
+        self.set_setting(key, &value.to_string()).await
+    }
+
+    /// Read a boolean setting (`true`/`false`, `1`/`0`), falling back to
+    /// `default` when the key is missing or its value doesn't parse.
+    pub async fn get_setting_bool(&self, key: &str, default: bool) -> DbResult<bool> {
+        let parsed = self.get_setting(key).await?.and_then(|v| match v.trim() {
+            "true" | "1" => Some(true),
+            "false" | "0" => Some(false),
+            _ => None,
+        });
+        Ok(parsed.unwrap_or(default))
+    }
+
+    /// Store a boolean setting.
+    pub async fn set_setting_bool(&self, key: &str, value: bool) -> DbResult<()> {
+        self.set_setting(key, if value { "true" } else { "false" })
+            .await
+    }
+
+    /// Get the git sync interval in seconds.
+    pub async fn get_git_sync_interval(&self) -> DbResult<u64> {
+        let secs = self
+            .get_setting_i64(GIT_SYNC_INTERVAL_KEY, DEFAULT_GIT_SYNC_INTERVAL_SECS as i64)
+            .await?;
+        Ok(secs.max(0) as u64)
+    }
+
+    /// Set the git sync interval in seconds.
+    pub async fn set_git_sync_interval(&self, seconds: u64) -> DbResult<()> {
+        self.set_setting_i64(GIT_SYNC_INTERVAL_KEY, seconds as i64)
+            .await
+    }
 }
 
 #[cfg(test)]
@@ -90,4 +167,56 @@ mod tests {
         assert_eq!(settings.llm_model, "opus");
         assert_eq!(settings.llm_timeout_secs, 180);
     }
+
+    #[tokio::test]
+    async fn test_setting_round_trip() {
+        let db = Database::new_in_memory().await.unwrap();
+        assert_eq!(db.get_setting("retention_days").await.unwrap(), None);
+
+        db.set_setting("retention_days", "30").await.unwrap();
+        assert_eq!(
+            db.get_setting("retention_days").await.unwrap().as_deref(),
+            Some("30")
+        );
+
+        db.set_setting_i64("retention_days", 90).await.unwrap();
+        assert_eq!(db.get_setting_i64("retention_days", 0).await.unwrap(), 90);
+
+        db.set_setting_bool("purge_orphans", true).await.unwrap();
+        assert!(db.get_setting_bool("purge_orphans", false).await.unwrap());
+        db.set_setting_bool("purge_orphans", false).await.unwrap();
+        assert!(!db.get_setting_bool("purge_orphans", true).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_setting_defaults_when_missing_or_unparsable() {
+        let db = Database::new_in_memory().await.unwrap();
+        assert_eq!(db.get_setting_i64("missing", 7).await.unwrap(), 7);
+        assert!(db.get_setting_bool("missing", true).await.unwrap());
+
+        db.set_setting("active_threshold", "soon").await.unwrap();
+        assert_eq!(db.get_setting_i64("active_threshold", 5).await.unwrap(), 5);
+        assert!(!db
+            .get_setting_bool("active_threshold", false)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_git_sync_interval_lives_in_settings() {
+        let db = Database::new_in_memory().await.unwrap();
+        // Seeded by the migration from index_metadata's column default.
+        assert_eq!(db.get_git_sync_interval().await.unwrap(), 60);
+
+        db.set_git_sync_interval(300).await.unwrap();
+        assert_eq!(db.get_git_sync_interval().await.unwrap(), 300);
+        assert_eq!(
+            db.get_setting_i64(super::GIT_SYNC_INTERVAL_KEY, 0)
+                .await
+                .unwrap(),
+            300
+        );
+        let meta = db.get_index_metadata().await.unwrap();
+        assert_eq!(meta.git_sync_interval_secs, 300);
+    }
 }
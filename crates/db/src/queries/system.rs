@@ -3,7 +3,8 @@
 
 use super::row_types::IndexRunIntegrityCountersRow;
 use super::{
-    ClassificationStatus, HealthStats, HealthStatus, IndexRunIntegrityCounters, SystemStorageStats,
+    ClassificationStatus, HealthStats, HealthStatus, IndexRunIntegrityCounters, ProjectHealth,
+    SystemStorageStats,
 };
 use crate::{Database, DbResult};
 use chrono::Utc;
@@ -153,6 +154,62 @@ impl Database {
         })
     }
 
+    /// Health rollup for one project: sessions, last activity, API-error
+    /// rate, unclassified ratio, and sessions with edits but no linked commit.
+    ///
+    /// `project_id` may be a project id, git root or project path. The status
+    /// applies [`Self::calculate_health_status`] to the project's error
+    /// sessions and the index's last sync, so a stale index downgrades every
+    /// project alike. An unknown project yields zero counts.
+    pub async fn project_health(&self, project_id: &str) -> DbResult<ProjectHealth> {
+        let (sessions_count, last_activity_at, error_sessions_count, unclassified, uncommitted): (
+            i64,
+            Option<i64>,
+            i64,
+            i64,
+            i64,
+        ) = sqlx::query_as(
+            r#"
+            SELECT
+                COUNT(*),
+                MAX(s.last_message_at),
+                COALESCE(SUM(CASE WHEN s.api_error_count > 0 THEN 1 ELSE 0 END), 0),
+                COALESCE(SUM(CASE WHEN sf.classified_at IS NULL THEN 1 ELSE 0 END), 0),
+                COALESCE(SUM(CASE WHEN s.files_edited_count > 0
+                    AND NOT EXISTS (SELECT 1 FROM session_commits sc WHERE sc.session_id = s.id)
+                    THEN 1 ELSE 0 END), 0)
+            FROM valid_sessions s
+            LEFT JOIN session_flags sf ON sf.session_id = s.id
+            WHERE s.project_id = ?1
+               OR (s.git_root IS NOT NULL AND s.git_root <> '' AND s.git_root = ?1)
+               OR (s.project_path IS NOT NULL AND s.project_path <> '' AND s.project_path = ?1)
+            "#,
+        )
+        .bind(project_id)
+        .fetch_one(self.pool())
+        .await?;
+
+        let ratio = |n: i64| {
+            if sessions_count > 0 {
+                n as f64 / sessions_count as f64
+            } else {
+                0.0
+            }
+        };
+        let last_sync_at = self.get_index_metadata().await?.last_indexed_at;
+
+        Ok(ProjectHealth {
+            project_id: project_id.to_string(),
+            sessions_count,
+            last_activity_at,
+            error_sessions_count,
+            error_rate: ratio(error_sessions_count),
+            unclassified_ratio: ratio(unclassified),
+            uncommitted_sessions_count: uncommitted,
+            status: Self::calculate_health_status(error_sessions_count, last_sync_at),
+        })
+    }
+
     /// Calculate health status based on errors and staleness.
     fn calculate_health_status(errors_count: i64, last_sync_at: Option<i64>) -> HealthStatus {
         // Error: 10+ errors or index stale > 24 hours
@@ -352,4 +409,83 @@ impl Database {
         tx.commit().await?;
         Ok(())
     }
+
+    /// Delete one project's indexed data: its sessions, their commit links
+    /// and indexer state, and the project's contribution snapshots. Runs in
+    /// one transaction and returns the number of sessions removed.
+    ///
+    /// Turns and invocations live on `session_stats` rows since the CQRS
+    /// cutover, so they go with the sessions. Commits themselves are kept;
+    /// other projects may link to them. The prompt search index is keyed by
+    /// project path — callers clear it with `PromptSearchIndex::delete_by_project`
+    /// for each of [`Self::project_paths`], read before the reset.
+    ///
+    /// The JSONL files are left alone, so the next scan indexes whatever of
+    /// the project is still on disk again.
+    pub async fn reset_project_data(&self, project_id: &str) -> DbResult<u64> {
+        let mut tx = self.pool().begin().await?;
+
+        sqlx::query(
+            r#"DELETE FROM session_commits WHERE session_id IN
+               (SELECT session_id FROM session_stats WHERE project_id = ?1)"#,
+        )
+        .bind(project_id)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query(
+            r#"DELETE FROM indexer_state WHERE file_path IN
+               (SELECT file_path FROM session_stats WHERE project_id = ?1)"#,
+        )
+        .bind(project_id)
+        .execute(&mut *tx)
+        .await?;
+        let removed = sqlx::query("DELETE FROM session_stats WHERE project_id = ?1")
+            .bind(project_id)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+        sqlx::query("DELETE FROM contribution_snapshots WHERE project_id = ?1")
+            .bind(project_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(removed)
+    }
+
+    /// Distinct non-empty `project_path` and `git_root` values of a project's
+    /// sessions, sorted — the keys the prompt search index files it under.
+    pub async fn project_paths(&self, project_id: &str) -> DbResult<Vec<String>> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            r#"SELECT project_path AS path FROM session_stats
+               WHERE project_id = ?1 AND project_path IS NOT NULL AND project_path <> ''
+               UNION
+               SELECT git_root FROM session_stats
+               WHERE project_id = ?1 AND git_root IS NOT NULL AND git_root <> ''
+               ORDER BY path"#,
+        )
+        .bind(project_id)
+        .fetch_all(self.pool())
+        .await?;
+        Ok(rows.into_iter().map(|(path,)| path).collect())
+    }
+
+    /// Delete `session_commits` links whose session no longer exists and
+    /// return how many were removed.
+    ///
+    /// The foreign key cascades on normal deletes, but manual edits (e.g.
+    /// the `sqlite3` shell, which runs with `foreign_keys` off) or partial
+    /// restores can leave links behind that inflate commit counts. The
+    /// `turns` and `invocations` tables were retired in migration 87, so
+    /// `session_commits` is the only per-session child table left to check.
+    pub async fn purge_orphan_session_commits(&self) -> DbResult<u64> {
+        let result = sqlx::query(
+            r#"DELETE FROM session_commits
+               WHERE NOT EXISTS
+                 (SELECT 1 FROM session_stats ss WHERE ss.session_id = session_commits.session_id)"#,
+        )
+        .execute(self.pool())
+        .await?;
+        Ok(result.rows_affected())
+    }
 }
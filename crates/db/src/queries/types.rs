@@ -12,6 +12,13 @@ pub struct BranchCount {
     pub branch: Option<String>,
     #[ts(type = "number")]
     pub count: i64,
+    /// Latest `last_message_at` (unix seconds) across the branch's sessions.
+    #[ts(type = "number")]
+    pub last_activity_at: i64,
+    /// `"active"`, `"merged"`, `"remote"` (gone locally, still on a remote)
+    /// or `"deleted"` as of the last git sync; `None` when never checked.
+    /// Across worktrees the most alive status wins.
+    pub status: Option<String>,
 }
 
 /// Indexer state entry returned from the database.
@@ -39,6 +46,19 @@ pub struct InvocableWithCount {
     pub last_used_at: Option<i64>,
 }
 
+/// Two invocables used in the same sessions, keyed as in
+/// `session_stats.invocation_counts` (e.g. `"Bash"`, `"Skill:commit"`).
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct InvocablePair {
+    pub first: String,
+    pub second: String,
+    /// Sessions that used both.
+    #[ts(type = "number")]
+    pub session_count: i64,
+}
+
 impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for InvocableWithCount {
     fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
         use sqlx::Row;
@@ -78,6 +98,45 @@ pub struct ModelWithStats {
     pub total_sessions: i64,
 }
 
+/// One model used in a session, with how many assistant turns it served.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct ModelTurnCount {
+    pub model_id: String,
+    #[ts(type = "number")]
+    pub turn_count: i64,
+}
+
+/// When a model was first and last used, with assistant turns per month
+/// (for the model-adoption chart).
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct ModelAdoption {
+    pub model_id: String,
+    pub family: Option<String>,
+    /// `last_message_at` of the earliest session that used the model.
+    #[ts(type = "number")]
+    pub first_seen: i64,
+    /// `last_message_at` of the latest session that used the model.
+    #[ts(type = "number")]
+    pub last_seen: i64,
+    /// Months with usage, oldest first.
+    pub months: Vec<ModelMonthTurns>,
+}
+
+/// Assistant turns served by one model in one calendar month (UTC).
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct ModelMonthTurns {
+    /// `YYYY-MM`.
+    pub month: String,
+    #[ts(type = "number")]
+    pub turn_count: i64,
+}
+
 impl<'r> sqlx::FromRow<'r, sqlx::sqlite::SqliteRow> for ModelWithStats {
     fn from_row(row: &'r sqlx::sqlite::SqliteRow) -> Result<Self, sqlx::Error> {
         use sqlx::Row;
@@ -117,6 +176,103 @@ pub struct TokenStats {
     pub sessions_count: u64,
 }
 
+/// One session's cost, priced per model it used (for the session detail view).
+#[derive(Debug, Clone, serde::Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct SessionModelCost {
+    /// The session's `primary_model`; `None` if unknown.
+    pub model: Option<String>,
+    pub cost: claude_view_core::pricing::CostBreakdown,
+}
+
+/// Sessions and tokens in one time-of-week bucket (a weekday or an hour).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct TimeBucketStats {
+    #[ts(type = "number")]
+    pub sessions: i64,
+    /// Input + output tokens (cache reads/writes excluded).
+    #[ts(type = "number")]
+    pub total_tokens: i64,
+}
+
+/// Sort order of `sessions_page`, named like the `sort` parameter of
+/// the project sessions endpoint.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SessionsSort {
+    /// Most recent `last_message_at` first.
+    #[default]
+    Recent,
+    /// Oldest `last_message_at` first.
+    Oldest,
+    /// Most messages first, then most recent.
+    Messages,
+}
+
+impl SessionsSort {
+    /// Parse a `sort` query value; unknown values fall back to `Recent`.
+    pub fn from_param(param: &str) -> Self {
+        match param {
+            "oldest" => Self::Oldest,
+            "messages" => Self::Messages,
+            _ => Self::Recent,
+        }
+    }
+}
+
+/// Filters of `sessions_page`. Unset fields match every session.
+#[derive(Debug, Clone, Default)]
+pub struct SessionsFilter {
+    /// Matches a session's project id, git root or project path.
+    pub project_id: Option<String>,
+    /// Branch in `BranchFilter` form: `None`/`""` for all, `"~"` for
+    /// sessions with no branch, else an exact `git_branch` match.
+    pub branch: Option<String>,
+    /// Inclusive `last_message_at` lower bound (Unix seconds).
+    pub since: Option<i64>,
+    /// Inclusive `last_message_at` upper bound (Unix seconds).
+    pub until: Option<i64>,
+    pub work_type: Option<String>,
+}
+
+/// A session ranked for error triage by `top_error_sessions`.
+#[derive(Debug, Clone, serde::Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorSession {
+    pub session_id: String,
+    pub project_id: String,
+    pub project_display_name: String,
+    pub preview: String,
+    #[ts(type = "number")]
+    pub api_error_count: i64,
+    #[ts(type = "number")]
+    pub api_retry_count: i64,
+    #[ts(type = "number")]
+    pub compaction_count: i64,
+    #[ts(type = "number")]
+    pub last_message_at: i64,
+}
+
+/// Lightweight session summary for the recent-sessions feed.
+#[derive(Debug, Clone, serde::Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct SessionCard {
+    pub session_id: String,
+    pub project_id: String,
+    pub project_display_name: String,
+    pub preview: String,
+    pub git_branch: Option<String>,
+    pub primary_model: Option<String>,
+    #[ts(type = "number")]
+    pub message_count: i64,
+    #[ts(type = "number")]
+    pub last_message_at: i64,
+}
+
 /// Token usage breakdown by model.
 #[derive(Debug, Clone, serde::Serialize, TS, utoipa::ToSchema)]
 #[cfg_attr(feature = "codegen", ts(export))]
@@ -129,6 +285,74 @@ pub struct TokensByModel {
     pub output_tokens: i64,
 }
 
+/// Full cost for one model, priced per token type including cache reads and
+/// cache writes (5m / 1h TTL split when recorded).
+#[derive(Debug, Clone, serde::Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct ModelCost {
+    pub model: String,
+    #[ts(type = "number")]
+    pub input_tokens: i64,
+    #[ts(type = "number")]
+    pub output_tokens: i64,
+    #[ts(type = "number")]
+    pub cache_read_tokens: i64,
+    #[ts(type = "number")]
+    pub cache_creation_tokens: i64,
+    pub input_cost_usd: f64,
+    pub output_cost_usd: f64,
+    pub cache_read_cost_usd: f64,
+    pub cache_creation_cost_usd: f64,
+    /// What the cache reads would have cost at the uncached input rate, minus
+    /// what they did cost.
+    pub cache_savings_usd: f64,
+    pub total_cost_usd: f64,
+    /// True when the model has no pricing entry; all costs are then 0.
+    pub has_unpriced_usage: bool,
+}
+
+/// One project's estimated cost for `project_cost_leaderboard`.
+#[derive(Debug, Clone, serde::Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectCost {
+    pub project_id: String,
+    pub project_display_name: String,
+    #[ts(type = "number")]
+    pub session_count: i64,
+    #[ts(type = "number")]
+    pub input_tokens: i64,
+    #[ts(type = "number")]
+    pub output_tokens: i64,
+    #[ts(type = "number")]
+    pub cache_read_tokens: i64,
+    #[ts(type = "number")]
+    pub cache_creation_tokens: i64,
+    /// Estimated cost in US cents, including cache reads and writes.
+    #[ts(type = "number")]
+    pub total_cost_cents: i64,
+    /// True when some tokens used a model with no pricing entry; those
+    /// tokens are counted but not costed.
+    pub has_unpriced_usage: bool,
+}
+
+/// A file edited more than once within sessions, for `files_most_reedited`.
+#[derive(Debug, Clone, serde::Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct FileChurn {
+    pub file_path: String,
+    /// Edits beyond the first in each session, summed across sessions.
+    #[ts(type = "number")]
+    pub reedit_count: i64,
+    /// Sessions that edited the file more than once.
+    #[ts(type = "number")]
+    pub session_count: i64,
+    /// Up to five of those sessions, most recent first.
+    pub session_ids: Vec<String>,
+}
+
 /// Token usage breakdown by project.
 #[derive(Debug, Clone, serde::Serialize, TS, utoipa::ToSchema)]
 #[cfg_attr(feature = "codegen", ts(export))]
@@ -196,6 +420,9 @@ pub struct AIGenerationStats {
     pub cache_read_tokens: i64,
     #[ts(type = "number")]
     pub cache_creation_tokens: i64,
+    /// Estimated share of `total_output_tokens` spent on thinking.
+    #[ts(type = "number")]
+    pub thinking_output_tokens: i64,
     pub tokens_by_model: Vec<TokensByModel>,
     pub tokens_by_project: Vec<TokensByProject>,
     /// Aggregate cost breakdown (computed server-side using pricing engine).
@@ -251,6 +478,29 @@ pub struct HealthStats {
     pub status: HealthStatus,
 }
 
+/// Health rollup for one project (per-project system view).
+#[derive(Debug, Clone, serde::Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectHealth {
+    pub project_id: String,
+    #[ts(type = "number")]
+    pub sessions_count: i64,
+    #[ts(type = "number | null")]
+    pub last_activity_at: Option<i64>,
+    /// Sessions with at least one API error.
+    #[ts(type = "number")]
+    pub error_sessions_count: i64,
+    /// `error_sessions_count / sessions_count` (0 with no sessions).
+    pub error_rate: f64,
+    /// Share of sessions not yet classified (0 with no sessions).
+    pub unclassified_ratio: f64,
+    /// Sessions that edited files but have no linked commit.
+    #[ts(type = "number")]
+    pub uncommitted_sessions_count: i64,
+    pub status: HealthStatus,
+}
+
 /// Classification status summary for the system page.
 #[derive(Debug, Clone, serde::Serialize, TS, utoipa::ToSchema)]
 #[cfg_attr(feature = "codegen", ts(export))]
@@ -310,3 +560,17 @@ pub struct StatsOverview {
     pub unique_invocables_used: i64,
     pub top_invocables: Vec<InvocableWithCount>,
 }
+
+/// Sessions sharing project, first-message time, and preview — usually the
+/// same conversation indexed twice (backup recovery, re-indexing).
+#[derive(Debug, Clone, serde::Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateSessionGroup {
+    pub project_id: String,
+    #[ts(type = "number")]
+    pub first_message_at: i64,
+    pub preview: String,
+    /// Session IDs in the group, most recently active first.
+    pub session_ids: Vec<String>,
+}
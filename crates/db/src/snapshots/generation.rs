@@ -5,6 +5,7 @@ use super::helpers::usd_opt_to_cents;
 use super::types::SnapshotStats;
 use crate::{Database, DbResult};
 use chrono::Local;
+use std::collections::BTreeMap;
 
 impl Database {
     /// Generate a daily snapshot for a specific date.
@@ -173,6 +174,126 @@ impl Database {
         Ok(count)
     }
 
+    /// Delete and regenerate daily snapshots for every local date in
+    /// `from..=to` (`YYYY-MM-DD`, inclusive).
+    ///
+    /// With `project` set, only that project's rows (`branch IS NULL`) are
+    /// rebuilt from its sessions; otherwise the global rows are. Use this to
+    /// repair history after a reclassification or LOC-estimation fix without
+    /// regenerating the whole year. Dates with no activity end up with no
+    /// row, matching [`Self::generate_missing_snapshots`].
+    ///
+    /// # Returns
+    /// * Number of snapshot rows written
+    pub async fn recompute_contribution_for_range(
+        &self,
+        from: &str,
+        to: &str,
+        project: Option<&str>,
+    ) -> DbResult<u32> {
+        let mut tx = self.pool().begin().await?;
+
+        let session_rows: Vec<(String, i64, i64, i64, i64, i64, Option<f64>)> = sqlx::query_as(
+            r#"
+            SELECT
+                date(last_message_at, 'unixepoch', 'localtime') as day,
+                COUNT(*) as sessions_count,
+                COALESCE(SUM(ai_lines_added), 0) as ai_lines_added,
+                COALESCE(SUM(ai_lines_removed), 0) as ai_lines_removed,
+                COALESCE(SUM(total_input_tokens + total_output_tokens), 0) as tokens_used,
+                COALESCE(SUM(files_edited_count), 0) as files_edited_count,
+                SUM(total_cost_usd) as total_cost_usd
+            FROM valid_sessions
+            WHERE date(last_message_at, 'unixepoch', 'localtime') BETWEEN ?1 AND ?2
+              AND (?3 IS NULL OR project_id = ?3 OR (git_root IS NOT NULL AND git_root <> '' AND git_root = ?3) OR (project_path IS NOT NULL AND project_path <> '' AND project_path = ?3))
+            GROUP BY day
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .bind(project)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let commit_rows: Vec<(String, i64, i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT
+                date(s.last_message_at, 'unixepoch', 'localtime') as day,
+                COUNT(DISTINCT c.hash) as commits_count,
+                COALESCE(SUM(c.insertions), 0) as commit_insertions,
+                COALESCE(SUM(c.deletions), 0) as commit_deletions
+            FROM session_commits sc
+            JOIN commits c ON sc.commit_hash = c.hash
+            JOIN valid_sessions s ON sc.session_id = s.id
+            WHERE date(s.last_message_at, 'unixepoch', 'localtime') BETWEEN ?1 AND ?2
+              AND (?3 IS NULL OR s.project_id = ?3 OR (s.git_root IS NOT NULL AND s.git_root <> '' AND s.git_root = ?3) OR (s.project_path IS NOT NULL AND s.project_path <> '' AND s.project_path = ?3))
+            GROUP BY day
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .bind(project)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        // day -> (session aggregates, commit aggregates)
+        let mut days: BTreeMap<String, ((i64, i64, i64, i64, i64, Option<f64>), (i64, i64, i64))> =
+            BTreeMap::new();
+        for (day, sessions, added, removed, tokens, files, cost) in session_rows {
+            days.entry(day).or_default().0 = (sessions, added, removed, tokens, files, cost);
+        }
+        for (day, commits, insertions, deletions) in commit_rows {
+            days.entry(day).or_default().1 = (commits, insertions, deletions);
+        }
+
+        // Daily rows only; `W:` rollup keys never fall inside a YYYY-MM-DD range.
+        sqlx::query(
+            r#"
+            DELETE FROM contribution_snapshots
+            WHERE date BETWEEN ?1 AND ?2
+              AND length(date) = 10
+              AND branch IS NULL
+              AND ((?3 IS NULL AND project_id IS NULL) OR project_id = ?3)
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .bind(project)
+        .execute(&mut *tx)
+        .await?;
+
+        let mut count = 0u32;
+        for (date, (session_agg, commit_agg)) in &days {
+            let cost_cents = usd_opt_to_cents(session_agg.5, session_agg.0);
+            sqlx::query(
+                r#"
+                INSERT INTO contribution_snapshots
+                    (date, project_id, branch, sessions_count, ai_lines_added, ai_lines_removed,
+                     commits_count, commit_insertions, commit_deletions, tokens_used, cost_cents, files_edited_count)
+                VALUES (?1, ?2, NULL, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+                "#,
+            )
+            .bind(date)
+            .bind(project)
+            .bind(session_agg.0)
+            .bind(session_agg.1)
+            .bind(session_agg.2)
+            .bind(commit_agg.0)
+            .bind(commit_agg.1)
+            .bind(commit_agg.2)
+            .bind(session_agg.3)
+            .bind(cost_cents)
+            .bind(session_agg.4)
+            .execute(&mut *tx)
+            .await?;
+
+            count += 1;
+        }
+
+        tx.commit().await?;
+        Ok(count)
+    }
+
     /// Roll up daily snapshots into weekly aggregates.
     ///
     /// This function aggregates daily snapshots older than `retention_days` into
@@ -300,6 +421,36 @@ impl Database {
         Ok(count)
     }
 
+    /// Local dates (`YYYY-MM-DD`, oldest first) that have sessions but no
+    /// global snapshot, for showing snapshot coverage.
+    ///
+    /// A date rolled up by [`Self::rollup_weekly_snapshots`] counts as
+    /// covered by its week's `W:<monday>` row.
+    pub async fn get_contribution_snapshot_gaps(&self) -> DbResult<Vec<String>> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            r#"
+            SELECT d.day
+            FROM (
+                SELECT DISTINCT date(last_message_at, 'unixepoch', 'localtime') AS day
+                FROM valid_sessions
+                WHERE last_message_at IS NOT NULL AND last_message_at > 0
+            ) d
+            WHERE NOT EXISTS (
+                SELECT 1 FROM contribution_snapshots cs
+                WHERE cs.project_id IS NULL AND cs.branch IS NULL
+                  AND (
+                      cs.date = d.day
+                      OR cs.date = 'W:' || date(d.day, 'weekday 0', '-6 days')
+                  )
+            )
+            ORDER BY d.day
+            "#,
+        )
+        .fetch_all(self.pool())
+        .await?;
+        Ok(rows.into_iter().map(|(day,)| day).collect())
+    }
+
     /// Get snapshot retention statistics.
     ///
     /// Returns counts of daily vs weekly snapshots for monitoring.
@@ -1,6 +1,8 @@
 // crates/db/src/snapshots/session_detail.rs
 //! Session contribution detail, linked commits, and file impact queries.
 
+use claude_view_core::WorkTypeScore;
+
 use super::types::{FileImpact, LinkedCommit, SessionContribution};
 use crate::{Database, DbResult};
 
@@ -10,31 +12,45 @@ impl Database {
         &self,
         session_id: &str,
     ) -> DbResult<Option<SessionContribution>> {
-        let row: Option<(String, Option<String>, i64, i64, i64, i64, i64, i64, i64)> =
-            sqlx::query_as(
-                r#"
+        type Row = (
+            String,
+            Option<String>,
+            Option<String>,
+            i64,
+            i64,
+            i64,
+            i64,
+            i64,
+            i64,
+            i64,
+        );
+        let row: Option<Row> = sqlx::query_as(
+            r#"
             SELECT
-                id,
-                work_type,
-                duration_seconds,
-                user_prompt_count,
-                ai_lines_added,
-                ai_lines_removed,
-                files_edited_count,
-                reedited_files_count,
-                commit_count
-            FROM valid_sessions
-            WHERE id = ?1
+                s.id,
+                s.work_type,
+                ss.work_type_alternates,
+                s.duration_seconds,
+                s.user_prompt_count,
+                s.ai_lines_added,
+                s.ai_lines_removed,
+                s.files_edited_count,
+                s.reedited_files_count,
+                s.commit_count
+            FROM valid_sessions s
+            JOIN session_stats ss ON ss.session_id = s.id
+            WHERE s.id = ?1
             "#,
-            )
-            .bind(session_id)
-            .fetch_optional(self.pool())
-            .await?;
+        )
+        .bind(session_id)
+        .fetch_optional(self.pool())
+        .await?;
 
         Ok(row.map(
             |(
                 session_id,
                 work_type,
+                alternates_json,
                 duration_seconds,
                 prompt_count,
                 ai_lines_added,
@@ -43,9 +59,17 @@ impl Database {
                 reedited_files_count,
                 commit_count,
             )| {
+                // Stored as `[{workType, score}]`; only the labels are shown.
+                let work_type_alternates = alternates_json
+                    .and_then(|json| serde_json::from_str::<Vec<WorkTypeScore>>(&json).ok())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|s| s.work_type.as_str().to_string())
+                    .collect();
                 SessionContribution {
                     session_id,
                     work_type,
+                    work_type_alternates,
                     duration_seconds,
                     prompt_count,
                     ai_lines_added,
@@ -270,6 +270,34 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_get_session_contribution_reads_work_type_alternates() {
+        let db = Database::new_in_memory().await.unwrap();
+
+        sqlx::query(
+            r#"
+            INSERT INTO session_stats (
+                session_id, source_content_hash, source_size, parser_version,
+                stats_version, indexed_at, project_id, file_path, preview,
+                work_type, work_type_alternates
+            )
+            VALUES
+                ('mixed', X'00', 0, 1, 4, 0, 'proj', '/tmp/mixed.jsonl', 'Mixed', 'planning',
+                 '[{"workType":"bug_fix","score":0.5},{"workType":"quick_ask","score":0.5}]'),
+                ('plain', X'00', 0, 1, 4, 0, 'proj', '/tmp/plain.jsonl', 'Plain', 'standard', NULL)
+            "#,
+        )
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+        let mixed = db.get_session_contribution("mixed").await.unwrap().unwrap();
+        assert_eq!(mixed.work_type_alternates, vec!["bug_fix", "quick_ask"]);
+
+        let plain = db.get_session_contribution("plain").await.unwrap().unwrap();
+        assert!(plain.work_type_alternates.is_empty());
+    }
+
     #[tokio::test]
     async fn test_get_session_commits_empty() {
         let db = Database::new_in_memory().await.unwrap();
@@ -681,6 +709,124 @@ mod tests {
         assert_eq!(stats.oldest_weekly, Some("2025-12-02".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_get_contribution_snapshot_gaps_reports_unsnapshotted_dates() {
+        use crate::test_support::SessionSeedBuilder;
+
+        let db = Database::new_in_memory().await.unwrap();
+        let day = |ts: i64| {
+            chrono::DateTime::from_timestamp(ts, 0)
+                .unwrap()
+                .with_timezone(&Local)
+                .format("%Y-%m-%d")
+                .to_string()
+        };
+        // Noon UTC, a week apart, so neither lands in the other's day or week.
+        let covered_ts = 1_767_960_000;
+        let gap_ts = covered_ts + 7 * 86400;
+        let rolled_ts = covered_ts - 14 * 86400;
+        for (id, ts) in [
+            ("covered", covered_ts),
+            ("gap", gap_ts),
+            ("rolled", rolled_ts),
+        ] {
+            SessionSeedBuilder::new(id)
+                .file_path(format!("/tmp/{id}.jsonl"))
+                .last_message_at(ts)
+                .seed(&db)
+                .await
+                .unwrap();
+        }
+        db.upsert_snapshot(&day(covered_ts), None, None, 1, 0, 0, 0, 0, 0, 0, 0, 0)
+            .await
+            .unwrap();
+        let (monday,): (String,) = sqlx::query_as("SELECT date(?1, 'weekday 0', '-6 days')")
+            .bind(day(rolled_ts))
+            .fetch_one(db.pool())
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO contribution_snapshots (date, sessions_count) VALUES (?1, 1)")
+            .bind(format!("W:{monday}"))
+            .execute(db.pool())
+            .await
+            .unwrap();
+
+        let gaps = db.get_contribution_snapshot_gaps().await.unwrap();
+        assert_eq!(gaps, vec![day(gap_ts)]);
+    }
+
+    #[tokio::test]
+    async fn test_recompute_contribution_for_range_refreshes_rows() {
+        use crate::test_support::SessionSeedBuilder;
+
+        let db = Database::new_in_memory().await.unwrap();
+        let day = |ts: i64| {
+            chrono::DateTime::from_timestamp(ts, 0)
+                .unwrap()
+                .with_timezone(&Local)
+                .format("%Y-%m-%d")
+                .to_string()
+        };
+        let in_range_ts = 1_767_960_000;
+        let outside_ts = in_range_ts + 7 * 86400;
+        for (id, project, ts, lines) in [
+            ("a1", "proj-a", in_range_ts, 10),
+            ("b1", "proj-b", in_range_ts, 5),
+            ("a2", "proj-a", outside_ts, 7),
+        ] {
+            SessionSeedBuilder::new(id)
+                .project_id(project)
+                .file_path(format!("/tmp/{id}.jsonl"))
+                .last_message_at(ts)
+                .ai_lines_added(lines)
+                .seed(&db)
+                .await
+                .unwrap();
+        }
+        let (from, to) = (day(in_range_ts), day(in_range_ts));
+        db.upsert_snapshot(&day(outside_ts), None, None, 1, 99, 0, 0, 0, 0, 0, 0, 0)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            db.recompute_contribution_for_range(&from, &to, None)
+                .await
+                .unwrap(),
+            1
+        );
+        db.recompute_contribution_for_range(&from, &to, Some("proj-a"))
+            .await
+            .unwrap();
+
+        // A LOC-estimation fix changes the underlying session.
+        sqlx::query("UPDATE session_stats SET ai_lines_added = 40 WHERE session_id = 'a1'")
+            .execute(db.pool())
+            .await
+            .unwrap();
+        db.recompute_contribution_for_range(&from, &to, None)
+            .await
+            .unwrap();
+        db.recompute_contribution_for_range(&from, &to, Some("proj-a"))
+            .await
+            .unwrap();
+
+        let rows: Vec<(String, Option<String>, i64, i64)> = sqlx::query_as(
+            "SELECT date, project_id, sessions_count, ai_lines_added FROM contribution_snapshots ORDER BY date, project_id",
+        )
+        .fetch_all(db.pool())
+        .await
+        .unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                (from.clone(), None, 2, 45),
+                (from.clone(), Some("proj-a".to_string()), 1, 40),
+                // Outside the window: left untouched.
+                (day(outside_ts), None, 1, 99),
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn test_snapshot_stats_serialization() {
         let stats = SnapshotStats {
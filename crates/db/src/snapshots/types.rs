@@ -188,6 +188,8 @@ pub struct BranchBreakdown {
 pub struct SessionContribution {
     pub session_id: String,
     pub work_type: Option<String>,
+    /// Secondary work types, best first.
+    pub work_type_alternates: Vec<String>,
     #[ts(type = "number")]
     pub duration_seconds: i64,
     #[ts(type = "number")]
@@ -42,6 +42,9 @@ impl SessionSeedBuilder {
                 tool_counts_write: 0,
                 files_touched: "[]".to_string(),
                 skills_used: "[]".to_string(),
+                mcp_tools: "[]".to_string(),
+                bash_categories: "{}".to_string(),
+                invocation_args: "{}".to_string(),
                 user_prompt_count: 0,
                 api_call_count: 0,
                 tool_call_count: 0,
@@ -57,6 +60,7 @@ impl SessionSeedBuilder {
                 cache_read_tokens: 0,
                 cache_creation_tokens: 0,
                 thinking_block_count: 0,
+                thinking_output_tokens: 0,
                 turn_duration_avg_ms: None,
                 turn_duration_max_ms: None,
                 turn_duration_total_ms: None,
@@ -64,11 +68,13 @@ impl SessionSeedBuilder {
                 api_retry_count: 0,
                 compaction_count: 0,
                 hook_blocked_count: 0,
+                interruption_count: 0,
                 agent_spawn_count: 0,
                 bash_progress_count: 0,
                 hook_progress_count: 0,
                 mcp_progress_count: 0,
                 summary_text: None,
+                summary_source: "unknown".to_string(),
                 parse_version: 0,
                 file_size_at_index: 0,
                 file_mtime_at_index: 0,
@@ -78,7 +84,9 @@ impl SessionSeedBuilder {
                 ai_lines_added: 0,
                 ai_lines_removed: 0,
                 work_type: None,
+                work_type_alternates: None,
                 primary_model: None,
+                model_turn_counts: None,
                 total_task_time_seconds: None,
                 longest_task_seconds: None,
                 longest_task_preview: None,
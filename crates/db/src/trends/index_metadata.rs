@@ -1,11 +1,13 @@
 //! Index metadata CRUD operations.
 
-use super::types::IndexMetadata;
+use super::types::{IndexMetadata, IndexRunHistoryEntry, SearchIndexRebuildProgress};
+use crate::queries::settings::GIT_SYNC_INTERVAL_KEY;
 use crate::{Database, DbResult};
 use chrono::Utc;
 
 impl Database {
-    /// Update index metadata after a successful index operation.
+    /// Update index metadata after a successful index operation and append
+    /// the run to `index_metadata_history`.
     ///
     /// Only call this when indexing completes successfully.
     /// Do NOT call on failure — preserve the last successful timestamp.
@@ -17,6 +19,8 @@ impl Database {
     ) -> DbResult<()> {
         let now = Utc::now().timestamp();
 
+        let mut tx = self.pool().begin().await?;
+
         sqlx::query(
             r#"
             UPDATE index_metadata SET
@@ -33,12 +37,64 @@ impl Database {
         .bind(sessions_indexed)
         .bind(projects_indexed)
         .bind(now)
-        .execute(self.pool())
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO index_metadata_history
+                (indexed_at, duration_ms, sessions_indexed, projects_indexed)
+            VALUES (?1, ?2, ?3, ?4)
+            "#,
+        )
+        .bind(now)
+        .bind(duration_ms)
+        .bind(sessions_indexed)
+        .bind(projects_indexed)
+        .execute(&mut *tx)
         .await?;
 
+        tx.commit().await?;
         Ok(())
     }
 
+    /// Get the last `limit` successful index runs, oldest first (ready to
+    /// plot as a sparkline).
+    pub async fn get_index_metadata_history(
+        &self,
+        limit: i64,
+    ) -> DbResult<Vec<IndexRunHistoryEntry>> {
+        let rows: Vec<(i64, i64, i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT indexed_at, duration_ms, sessions_indexed, projects_indexed
+            FROM (
+                SELECT id, indexed_at, duration_ms, sessions_indexed, projects_indexed
+                FROM index_metadata_history
+                ORDER BY id DESC
+                LIMIT ?1
+            )
+            ORDER BY id ASC
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(self.pool())
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(indexed_at, duration_ms, sessions_indexed, projects_indexed)| {
+                    IndexRunHistoryEntry {
+                        indexed_at,
+                        duration_ms,
+                        sessions_indexed,
+                        projects_indexed,
+                    }
+                },
+            )
+            .collect())
+    }
+
     /// Update git sync metadata after a successful git sync operation.
     ///
     /// Only call this when git sync completes successfully.
@@ -94,11 +150,16 @@ impl Database {
                 commits_found,
                 links_created,
                 updated_at,
-                git_sync_interval_secs
+                COALESCE(
+                    (SELECT CAST(value AS INTEGER) FROM settings
+                     WHERE key = ?1),
+                    git_sync_interval_secs
+                )
             FROM index_metadata
             WHERE id = 1
             "#,
         )
+        .bind(GIT_SYNC_INTERVAL_KEY)
         .fetch_one(self.pool())
         .await?;
 
@@ -115,24 +176,6 @@ impl Database {
         })
     }
 
-    /// Get the git sync interval in seconds.
-    pub async fn get_git_sync_interval(&self) -> DbResult<u64> {
-        let (interval,): (i64,) =
-            sqlx::query_as("SELECT git_sync_interval_secs FROM index_metadata WHERE id = 1")
-                .fetch_one(self.pool())
-                .await?;
-        Ok(interval as u64)
-    }
-
-    /// Set the git sync interval in seconds.
-    pub async fn set_git_sync_interval(&self, seconds: u64) -> DbResult<()> {
-        sqlx::query("UPDATE index_metadata SET git_sync_interval_secs = ?1 WHERE id = 1")
-            .bind(seconds as i64)
-            .execute(self.pool())
-            .await?;
-        Ok(())
-    }
-
     /// Get the stored registry fingerprint (if any).
     pub async fn get_registry_hash(&self) -> DbResult<Option<String>> {
         let (hash,): (Option<String>,) =
@@ -150,4 +193,97 @@ impl Database {
             .await?;
         Ok(())
     }
+
+    /// Start a full deep index rebuild, or resume an interrupted one.
+    ///
+    /// Returns the stored checkpoint when a previous rebuild never
+    /// completed (e.g. the server restarted mid-rebuild); the caller then
+    /// continues from it instead of starting over. Otherwise records a
+    /// fresh rebuild and returns `None`.
+    pub async fn begin_search_index_rebuild(&self) -> DbResult<Option<SearchIndexRebuildProgress>> {
+        if let Some(progress) = self.search_index_rebuild_progress().await? {
+            if progress.is_in_progress() {
+                return Ok(Some(progress));
+            }
+        }
+        let now = Utc::now().timestamp();
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO search_index_rebuild_progress
+                (id, started_at, last_session_id, sessions_processed, updated_at, completed_at)
+            VALUES (1, ?1, NULL, 0, ?1, NULL)
+            "#,
+        )
+        .bind(now)
+        .execute(self.pool())
+        .await?;
+        Ok(None)
+    }
+
+    /// Checkpoint the running rebuild: `last_session_id` is the session it
+    /// last finished, `sessions_processed` the running total.
+    pub async fn record_search_index_rebuild_progress(
+        &self,
+        last_session_id: &str,
+        sessions_processed: i64,
+    ) -> DbResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE search_index_rebuild_progress SET
+                last_session_id = ?1,
+                sessions_processed = ?2,
+                updated_at = ?3
+            WHERE id = 1 AND completed_at IS NULL
+            "#,
+        )
+        .bind(last_session_id)
+        .bind(sessions_processed)
+        .bind(Utc::now().timestamp())
+        .execute(self.pool())
+        .await?;
+        Ok(())
+    }
+
+    /// Mark the running rebuild as completed. No-op when none is running.
+    pub async fn complete_search_index_rebuild(&self) -> DbResult<()> {
+        let now = Utc::now().timestamp();
+        sqlx::query(
+            r#"
+            UPDATE search_index_rebuild_progress SET completed_at = ?1, updated_at = ?1
+            WHERE id = 1 AND completed_at IS NULL
+            "#,
+        )
+        .bind(now)
+        .execute(self.pool())
+        .await?;
+        Ok(())
+    }
+
+    /// Progress of the running or most recent rebuild; `None` if no
+    /// rebuild was ever started.
+    pub async fn search_index_rebuild_progress(
+        &self,
+    ) -> DbResult<Option<SearchIndexRebuildProgress>> {
+        let row: Option<(i64, Option<String>, i64, i64, Option<i64>)> = sqlx::query_as(
+            r#"
+            SELECT started_at, last_session_id, sessions_processed, updated_at, completed_at
+            FROM search_index_rebuild_progress
+            WHERE id = 1
+            "#,
+        )
+        .fetch_optional(self.pool())
+        .await?;
+
+        Ok(row.map(
+            |(started_at, last_session_id, sessions_processed, updated_at, completed_at)| {
+                SearchIndexRebuildProgress {
+                    started_at,
+                    last_session_id,
+                    sessions_processed,
+                    updated_at,
+                    completed_at,
+                }
+            },
+        ))
+    }
 }
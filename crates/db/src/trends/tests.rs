@@ -330,6 +330,77 @@ async fn test_metadata_updates_preserve_other_fields() {
     assert_eq!(second_metadata.commits_found, first_metadata.commits_found);
 }
 
+#[tokio::test]
+async fn test_successful_index_runs_append_history() {
+    let db = Database::new_in_memory().await.unwrap();
+    assert!(db.get_index_metadata_history(10).await.unwrap().is_empty());
+
+    db.update_index_metadata_on_success(1000, 30, 2)
+        .await
+        .unwrap();
+    db.update_index_metadata_on_success(2000, 60, 4)
+        .await
+        .unwrap();
+    db.update_index_metadata_on_success(1500, 90, 4)
+        .await
+        .unwrap();
+    // Git sync does not touch the index history.
+    db.update_git_sync_metadata_on_success(80, 15)
+        .await
+        .unwrap();
+
+    let history = db.get_index_metadata_history(10).await.unwrap();
+    let sessions: Vec<i64> = history.iter().map(|h| h.sessions_indexed).collect();
+    assert_eq!(sessions, vec![30, 60, 90], "oldest first");
+    assert_eq!(history[1].duration_ms, 2000);
+    assert!(history.iter().all(|h| h.indexed_at > 0));
+
+    // The limit keeps the most recent runs.
+    let last_two = db.get_index_metadata_history(2).await.unwrap();
+    let sessions: Vec<i64> = last_two.iter().map(|h| h.sessions_indexed).collect();
+    assert_eq!(sessions, vec![60, 90]);
+}
+
+#[tokio::test]
+async fn test_interrupted_rebuild_resumes_from_checkpoint_after_restart() {
+    let tmp = tempfile::tempdir().unwrap();
+    let db_path = tmp.path().join("rebuild.db");
+
+    let db = Database::new(&db_path).await.unwrap();
+    assert!(db.search_index_rebuild_progress().await.unwrap().is_none());
+    assert!(db.begin_search_index_rebuild().await.unwrap().is_none());
+    db.record_search_index_rebuild_progress("sess-040", 40)
+        .await
+        .unwrap();
+    // Simulate a restart mid-rebuild.
+    db.pool().close().await;
+    drop(db);
+
+    let db = Database::new(&db_path).await.unwrap();
+    let resumed = db
+        .begin_search_index_rebuild()
+        .await
+        .unwrap()
+        .expect("an interrupted rebuild resumes");
+    assert_eq!(resumed.last_session_id.as_deref(), Some("sess-040"));
+    assert_eq!(resumed.sessions_processed, 40);
+    assert!(resumed.is_in_progress());
+
+    db.record_search_index_rebuild_progress("sess-100", 100)
+        .await
+        .unwrap();
+    db.complete_search_index_rebuild().await.unwrap();
+    let done = db.search_index_rebuild_progress().await.unwrap().unwrap();
+    assert_eq!(done.sessions_processed, 100);
+    assert!(!done.is_in_progress());
+
+    // A completed rebuild is not resumed: the next one starts from zero.
+    assert!(db.begin_search_index_rebuild().await.unwrap().is_none());
+    let fresh = db.search_index_rebuild_progress().await.unwrap().unwrap();
+    assert_eq!(fresh.sessions_processed, 0);
+    assert_eq!(fresh.last_session_id, None);
+}
+
 #[tokio::test]
 async fn test_index_metadata_serializes_correctly() {
     let db = Database::new_in_memory().await.unwrap();
@@ -99,3 +99,51 @@ pub struct IndexMetadata {
     #[ts(type = "number")]
     pub git_sync_interval_secs: i64,
 }
+
+/// One successful index run, from `index_metadata_history`.
+#[derive(Debug, Clone, Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct IndexRunHistoryEntry {
+    /// Unix timestamp of the run's completion.
+    #[ts(type = "number")]
+    pub indexed_at: i64,
+    /// Run duration in milliseconds.
+    #[ts(type = "number")]
+    pub duration_ms: i64,
+    /// Number of sessions indexed in the run.
+    #[ts(type = "number")]
+    pub sessions_indexed: i64,
+    /// Number of projects indexed in the run.
+    #[ts(type = "number")]
+    pub projects_indexed: i64,
+}
+
+/// Checkpoint of a full deep index rebuild, from
+/// `search_index_rebuild_progress`.
+#[derive(Debug, Clone, PartialEq, Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct SearchIndexRebuildProgress {
+    /// Unix timestamp the rebuild started.
+    #[ts(type = "number")]
+    pub started_at: i64,
+    /// Last session the rebuild finished with, if any.
+    pub last_session_id: Option<String>,
+    /// Sessions processed so far, across restarts.
+    #[ts(type = "number")]
+    pub sessions_processed: i64,
+    /// Unix timestamp of the last checkpoint.
+    #[ts(type = "number")]
+    pub updated_at: i64,
+    /// Unix timestamp the rebuild completed; None while in progress.
+    #[ts(type = "number | null")]
+    pub completed_at: Option<i64>,
+}
+
+impl SearchIndexRebuildProgress {
+    /// Whether the rebuild has started but not completed.
+    pub fn is_in_progress(&self) -> bool {
+        self.completed_at.is_none()
+    }
+}
@@ -98,6 +98,9 @@ async fn parsed_session_roundtrips_through_db() {
         tool_counts_write: result.deep.tool_counts.write as i32,
         files_touched: "[]".into(),
         skills_used: "[]".into(),
+        mcp_tools: "[]".into(),
+        bash_categories: "{}".into(),
+        invocation_args: "{}".into(),
         user_prompt_count: result.deep.user_prompt_count as i32,
         api_call_count: 3,
         tool_call_count: result.deep.tool_call_count as i32,
@@ -113,6 +116,7 @@ async fn parsed_session_roundtrips_through_db() {
         cache_read_tokens: result.deep.cache_read_tokens as i64,
         cache_creation_tokens: result.deep.cache_creation_tokens as i64,
         thinking_block_count: 0,
+        thinking_output_tokens: 0,
         turn_duration_avg_ms: None,
         turn_duration_max_ms: None,
         turn_duration_total_ms: None,
@@ -120,11 +124,13 @@ async fn parsed_session_roundtrips_through_db() {
         api_retry_count: 0,
         compaction_count: 0,
         hook_blocked_count: 0,
+        interruption_count: 0,
         agent_spawn_count: 0,
         bash_progress_count: 0,
         hook_progress_count: 0,
         mcp_progress_count: 0,
         summary_text: None,
+        summary_source: "unknown".to_string(),
         parse_version: CURRENT_PARSE_VERSION,
         file_size_at_index: data.len() as i64,
         file_mtime_at_index: now,
@@ -134,7 +140,9 @@ async fn parsed_session_roundtrips_through_db() {
         ai_lines_added: 0,
         ai_lines_removed: 0,
         work_type: None,
+        work_type_alternates: None,
         primary_model: Some("claude-opus-4-6".into()),
+        model_turn_counts: None,
         total_task_time_seconds: None,
         longest_task_seconds: None,
         longest_task_preview: None,
@@ -529,3 +529,56 @@ async fn fold_lww_skipped_classify_emits_no_outbox_row() {
             .unwrap();
     assert_eq!(pending, 1, "only the applied classify must enqueue outbox");
 }
+
+#[tokio::test]
+async fn stale_taxonomy_classifications_are_reported_and_cleared() {
+    let db = Arc::new(Database::new_in_memory().await.unwrap());
+    for id in ["s-old", "s-new", "s-none"] {
+        seed_session(&db, id).await;
+    }
+    // Pre-versioning payload (no `version`) counts as taxonomy v1.
+    seed_action(
+        &db,
+        "s-old",
+        "classify",
+        r#"{"l1":"code_work","l2":"feature","l3":"new-component","confidence":0.9,"source":"claude-cli"}"#,
+        "classifier:claude-cli",
+        1_000,
+    )
+    .await;
+    seed_action(
+        &db,
+        "s-new",
+        "classify",
+        r#"{"l1":"support_work","l2":"docs","l3":"readme","confidence":0.8,"source":"claude-cli","version":2}"#,
+        "classifier:claude-cli",
+        1_000,
+    )
+    .await;
+    run_fold_batch(db.clone()).await.unwrap();
+
+    assert_eq!(
+        db.get_stale_classified_sessions(2).await.unwrap(),
+        vec!["s-old".to_string()]
+    );
+    assert!(db
+        .get_stale_classified_sessions(1)
+        .await
+        .unwrap()
+        .is_empty());
+
+    assert_eq!(db.reclassify_stale_categories(2).await.unwrap(), 1);
+    run_fold_batch(db.clone()).await.unwrap();
+
+    let old = get_flags(&db, "s-old").await.unwrap();
+    assert_eq!(old.3, None, "stale category cleared");
+    assert_eq!(old.8, None, "classified_at cleared");
+    let new = get_flags(&db, "s-new").await.unwrap();
+    assert_eq!(new.3.as_deref(), Some("support_work"));
+    assert!(db
+        .get_stale_classified_sessions(2)
+        .await
+        .unwrap()
+        .is_empty());
+    assert_eq!(db.count_unclassified_sessions().await.unwrap(), 2);
+}
@@ -132,6 +132,13 @@ async fn test_get_ai_generation_stats() {
     )
     .await;
 
+    db.pool()
+        .execute(sqlx::query(
+            "UPDATE session_stats SET thinking_output_tokens = 800 WHERE session_id = 'ai-gen-1'",
+        ))
+        .await
+        .unwrap();
+
     // Test all-time (no range filter)
     let stats = db
         .get_ai_generation_stats(None, None, None, None)
@@ -143,6 +150,7 @@ async fn test_get_ai_generation_stats() {
     // Total tokens from session_stats
     assert_eq!(stats.total_input_tokens, 4000, "3000 + 1000");
     assert_eq!(stats.total_output_tokens, 2500, "2000 + 500");
+    assert_eq!(stats.thinking_output_tokens, 800);
     // lines not tracked yet
     assert_eq!(stats.lines_added, 0);
     assert_eq!(stats.lines_removed, 0);
@@ -184,5 +192,158 @@ async fn test_get_ai_generation_stats() {
     assert_eq!(ranged.files_created, 4, "Only ai-gen-1 within range");
     assert_eq!(ranged.total_input_tokens, 3000);
     assert_eq!(ranged.total_output_tokens, 2000);
+    assert_eq!(ranged.thinking_output_tokens, 800);
     assert_eq!(ranged.tokens_by_model.len(), 1);
 }
+
+#[tokio::test]
+async fn test_cost_by_model_includes_cache_pricing() {
+    let db = Database::new_in_memory().await.unwrap();
+
+    claude_view_db::test_support::SessionSeedBuilder::new("cost-1")
+        .project_id("proj-cost")
+        .file_path("/tmp/cost1.jsonl")
+        .last_message_at(1000)
+        .seed(&db)
+        .await
+        .unwrap();
+    seed_session_stats_with_per_model(
+        &db,
+        "cost-1",
+        "proj-cost",
+        "/tmp/cost1.jsonl",
+        1000,
+        r#"{"claude-sonnet-4-20250514":{"inputTokens":1000,"outputTokens":500,"cacheReadTokens":100000,"cacheCreationTokens":20000,"cacheCreation5mTokens":20000,"cacheCreation1hrTokens":0,"totalTokens":121500}}"#,
+    )
+    .await;
+
+    let costs = db.cost_by_model(None, None, None, None).await.unwrap();
+    assert_eq!(costs.len(), 1);
+    let sonnet = &costs[0];
+    assert_eq!(sonnet.model, "claude-sonnet-4-20250514");
+    assert_eq!(sonnet.cache_read_tokens, 100_000);
+    assert_eq!(sonnet.cache_creation_tokens, 20_000);
+    assert!(!sonnet.has_unpriced_usage);
+
+    let pricing = claude_view_db::load_pricing();
+    let mp = claude_view_db::lookup_pricing("claude-sonnet-4-20250514", &pricing).unwrap();
+    let naive = 1000.0 * mp.input_cost_per_token + 500.0 * mp.output_cost_per_token;
+    let expected = naive
+        + 100_000.0 * mp.cache_read_cost_per_token
+        + 20_000.0 * mp.cache_creation_cost_per_token;
+    assert!(sonnet.total_cost_usd > naive, "cache tokens must be priced");
+    assert!((sonnet.total_cost_usd - expected).abs() < 1e-9);
+    assert!(sonnet.cache_savings_usd > 0.0);
+
+    // Project filter excludes everything for an unknown project.
+    let none = db
+        .cost_by_model(None, None, Some("other-proj"), None)
+        .await
+        .unwrap();
+    assert!(none.is_empty());
+}
+
+#[tokio::test]
+async fn test_project_cost_leaderboard_orders_by_cost() {
+    let db = Database::new_in_memory().await.unwrap();
+
+    // Cheap model, more tokens vs. expensive model, fewer tokens; plus an
+    // unpriced model that contributes tokens but no cost.
+    let sessions = [
+        (
+            "lb-haiku",
+            "proj-haiku",
+            r#"{"claude-3-5-haiku-20241022":{"inputTokens":200000,"outputTokens":20000,"cacheReadTokens":0,"cacheCreationTokens":0,"cacheCreation5mTokens":0,"cacheCreation1hrTokens":0,"totalTokens":220000}}"#,
+        ),
+        (
+            "lb-opus",
+            "proj-opus",
+            r#"{"claude-opus-4-20250514":{"inputTokens":50000,"outputTokens":10000,"cacheReadTokens":100000,"cacheCreationTokens":0,"cacheCreation5mTokens":0,"cacheCreation1hrTokens":0,"totalTokens":160000}}"#,
+        ),
+        (
+            "lb-mystery",
+            "proj-mystery",
+            r#"{"mystery-model-9":{"inputTokens":900000,"outputTokens":900000,"cacheReadTokens":0,"cacheCreationTokens":0,"cacheCreation5mTokens":0,"cacheCreation1hrTokens":0,"totalTokens":1800000}}"#,
+        ),
+    ];
+    for (sid, project, json) in sessions {
+        let file_path = format!("/tmp/{sid}.jsonl");
+        claude_view_db::test_support::SessionSeedBuilder::new(sid)
+            .project_id(project)
+            .file_path(file_path.clone())
+            .last_message_at(1000)
+            .seed(&db)
+            .await
+            .unwrap();
+        seed_session_stats_with_per_model(&db, sid, project, &file_path, 1000, json).await;
+    }
+
+    let board = db.project_cost_leaderboard(None, None, 10).await.unwrap();
+    let order: Vec<&str> = board.iter().map(|p| p.project_id.as_str()).collect();
+    assert_eq!(order, vec!["proj-opus", "proj-haiku", "proj-mystery"]);
+    assert!(board[0].total_cost_cents > board[1].total_cost_cents);
+    assert!(board[1].total_cost_cents > 0);
+    assert_eq!(board[0].cache_read_tokens, 100_000);
+    assert_eq!(board[0].session_count, 1);
+
+    let mystery = &board[2];
+    assert!(mystery.has_unpriced_usage);
+    assert_eq!(mystery.total_cost_cents, 0);
+    assert_eq!(mystery.input_tokens, 900_000);
+
+    // Top-N and the time filter.
+    let top = db.project_cost_leaderboard(None, None, 1).await.unwrap();
+    assert_eq!(top.len(), 1);
+    assert_eq!(top[0].project_id, "proj-opus");
+    assert!(db
+        .project_cost_leaderboard(Some(2000), None, 10)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_files_most_reedited() {
+    let db = Database::new_in_memory().await.unwrap();
+
+    // src/lib.rs is re-edited in three sessions (1 + 2 + 1 extra edits);
+    // src/main.rs is re-edited heavily but only in one; README.md never twice.
+    let sessions = [
+        (
+            "churn-1",
+            1_000,
+            r#"["src/lib.rs","src/lib.rs","README.md"]"#,
+        ),
+        (
+            "churn-2",
+            2_000,
+            r#"["src/lib.rs","src/lib.rs","src/lib.rs"]"#,
+        ),
+        (
+            "churn-3",
+            3_000,
+            r#"["src/main.rs","src/main.rs","src/main.rs","src/lib.rs","src/lib.rs"]"#,
+        ),
+    ];
+    for (id, ts, files) in sessions {
+        claude_view_db::test_support::SessionSeedBuilder::new(id)
+            .project_id("proj-churn")
+            .file_path(format!("/tmp/{id}.jsonl"))
+            .last_message_at(ts)
+            .with_parsed(|s| s.files_edited = files.to_string())
+            .seed(&db)
+            .await
+            .unwrap();
+    }
+
+    let files = db.files_most_reedited(10).await.unwrap();
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[0].file_path, "src/lib.rs");
+    assert_eq!(files[0].reedit_count, 4);
+    assert_eq!(files[0].session_count, 3);
+    assert_eq!(files[0].session_ids, vec!["churn-3", "churn-2", "churn-1"]);
+    assert_eq!(files[1].file_path, "src/main.rs");
+    assert_eq!(files[1].reedit_count, 2);
+
+    assert_eq!(db.files_most_reedited(1).await.unwrap().len(), 1);
+}
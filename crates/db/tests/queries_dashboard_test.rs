@@ -375,3 +375,106 @@ async fn test_get_dashboard_stats_with_range_and_project_filter() {
         .unwrap();
     assert_eq!(stats.total_sessions, 0);
 }
+
+#[tokio::test]
+async fn test_activity_streak_current_and_longest() {
+    let db = Database::new_in_memory().await.unwrap();
+
+    // 2026-03-10 12:00 UTC; "today" for the streak.
+    let now = 1_773_144_000;
+    let day = 86_400;
+    // Longest run: 3 days ending 5 days ago. Gap on day -4 and day -3.
+    // Current run: 2 days (yesterday and today).
+    let offsets = [-7, -6, -5, -1, 0];
+    for (i, d) in offsets.iter().enumerate() {
+        claude_view_db::test_support::SessionSeedBuilder::new(format!("streak-{i}"))
+            .project_id("proj-streak")
+            .file_path(format!("/tmp/streak-{i}.jsonl"))
+            .last_message_at(now + d * day)
+            .seed(&db)
+            .await
+            .unwrap();
+    }
+    // Second session on an already-active day doesn't extend anything.
+    claude_view_db::test_support::SessionSeedBuilder::new("streak-dup")
+        .project_id("proj-streak")
+        .file_path("/tmp/streak-dup.jsonl")
+        .last_message_at(now - 6 * day + 3600)
+        .seed(&db)
+        .await
+        .unwrap();
+
+    let streak = db.activity_streak(now, 0).await.unwrap();
+    assert_eq!(streak.current_days, 2);
+    assert_eq!(streak.longest_days, 3);
+
+    // A day with no activity breaks the current streak.
+    let streak = db.activity_streak(now + day, 0).await.unwrap();
+    assert_eq!(streak.current_days, 0);
+    assert_eq!(streak.longest_days, 3);
+
+    // At 23:30 UTC it's still the same day in UTC, but already tomorrow at
+    // UTC+1, where nothing has happened yet.
+    let late = now + 11 * 3600 + 1800;
+    let streak = db.activity_streak(late, 0).await.unwrap();
+    assert_eq!(streak.current_days, 2);
+    let streak = db.activity_streak(late, 3600).await.unwrap();
+    assert_eq!(streak.current_days, 0);
+    assert_eq!(streak.longest_days, 3);
+}
+
+#[tokio::test]
+async fn test_session_token_histogram_buckets_by_size() {
+    use claude_view_db::test_support::SessionSeedBuilder;
+
+    let db = Database::new_in_memory().await.unwrap();
+    // (id, project, last_message_at, input, output)
+    for (id, project, ts, input, output) in [
+        ("tiny", "proj-a", 1000, 400, 500),
+        ("edge", "proj-a", 1000, 600, 400),
+        ("mid", "proj-a", 2000, 30_000, 20_000),
+        ("big", "proj-a", 3000, 9_000_000, 2_000_000),
+        ("other", "proj-b", 1000, 50, 50),
+    ] {
+        SessionSeedBuilder::new(id)
+            .project_id(project)
+            .file_path(format!("/tmp/hist/{id}.jsonl"))
+            .last_message_at(ts)
+            .total_input_tokens(input)
+            .total_output_tokens(output)
+            .seed(&db)
+            .await
+            .unwrap();
+    }
+
+    let all = db.session_token_histogram(None, None, None).await.unwrap();
+    let edges: Vec<(i64, Option<i64>)> = all.iter().map(|b| (b.min_tokens, b.max_tokens)).collect();
+    assert_eq!(
+        edges,
+        vec![
+            (0, Some(1_000)),
+            (1_000, Some(10_000)),
+            (10_000, Some(100_000)),
+            (100_000, Some(1_000_000)),
+            (1_000_000, Some(10_000_000)),
+            (10_000_000, None),
+        ]
+    );
+    let counts: Vec<i64> = all.iter().map(|b| b.sessions).collect();
+    // "edge" has exactly 1,000 tokens, so it lands in 1k–10k.
+    assert_eq!(counts, vec![2, 1, 1, 0, 0, 1]);
+
+    let proj_a = db
+        .session_token_histogram(Some("proj-a"), None, None)
+        .await
+        .unwrap();
+    assert_eq!(proj_a[0].sessions, 1);
+    assert_eq!(proj_a.iter().map(|b| b.sessions).sum::<i64>(), 4);
+
+    let window = db
+        .session_token_histogram(None, Some(1500), Some(2500))
+        .await
+        .unwrap();
+    let counts: Vec<i64> = window.iter().map(|b| b.sessions).collect();
+    assert_eq!(counts, vec![0, 0, 1, 0, 0, 0]);
+}
@@ -95,6 +95,45 @@ async fn test_list_invocables_with_counts() {
     assert_eq!(items[2].last_used_at, None);
 }
 
+#[tokio::test]
+async fn test_search_invocables() {
+    let db = Database::new_in_memory().await.unwrap();
+    db.upsert_invocable(
+        "commit-commands:commit",
+        Some("commit-commands"),
+        "commit",
+        "skill",
+        "Create a git commit",
+    )
+    .await
+    .unwrap();
+    db.upsert_invocable(
+        "builtin:Bash",
+        None,
+        "Bash",
+        "tool",
+        "Run commands, e.g. to commit",
+    )
+    .await
+    .unwrap();
+    db.upsert_invocable("builtin:Read", None, "Read", "tool", "Read files")
+        .await
+        .unwrap();
+
+    let hits = db.search_invocables("commit").await.unwrap();
+    let ids: Vec<&str> = hits.iter().map(|i| i.id.as_str()).collect();
+    // Name match ranks above a description-only match; Read doesn't match.
+    assert_eq!(ids, vec!["commit-commands:commit", "builtin:Bash"]);
+
+    // Case-insensitive; a name prefix outranks the "Create" description hit.
+    let hits = db.search_invocables("REA").await.unwrap();
+    let ids: Vec<&str> = hits.iter().map(|i| i.id.as_str()).collect();
+    assert_eq!(ids, vec!["builtin:Read", "commit-commands:commit"]);
+
+    // Blank query returns the full list.
+    assert_eq!(db.search_invocables("  ").await.unwrap().len(), 3);
+}
+
 #[tokio::test]
 async fn test_batch_upsert_invocables() {
     let db = Database::new_in_memory().await.unwrap();
@@ -134,3 +173,52 @@ async fn test_batch_upsert_invocables() {
     assert_eq!(commit.plugin_name, Some("git".to_string()));
     assert_eq!(commit.kind, "skill");
 }
+
+#[tokio::test]
+async fn test_co_occurring_skills() {
+    let db = Database::new_in_memory().await.unwrap();
+
+    // Bash+Edit co-occur in 3 sessions, Bash+Read and Edit+Read in 2.
+    // Single-tool sessions and zero counts form no pairs.
+    let sessions = [
+        ("s1", r#"{"Edit":3,"Bash":2}"#),
+        ("s2", r#"{"Edit":1,"Bash":1,"Read":4}"#),
+        ("s3", r#"{"Edit":2,"Bash":5,"Read":1}"#),
+        ("s4", r#"{"Skill:commit":1}"#),
+        ("s5", r#"{"Read":1,"Grep":0}"#),
+    ];
+    for (sid, counts) in &sessions {
+        claude_view_db::test_support::SessionSeedBuilder::new(*sid)
+            .project_id("p")
+            .file_path(format!("/tmp/{}.jsonl", sid))
+            .modified_at(1000)
+            .seed(&db)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE session_stats SET invocation_counts = ? WHERE session_id = ?")
+            .bind(counts)
+            .bind(sid)
+            .execute(db.pool())
+            .await
+            .unwrap();
+    }
+
+    let pairs = db.co_occurring_skills(1, 10).await.unwrap();
+    assert_eq!(pairs.len(), 3);
+    assert_eq!(
+        (pairs[0].first.as_str(), pairs[0].second.as_str()),
+        ("Bash", "Edit")
+    );
+    assert_eq!(pairs[0].session_count, 3);
+    assert_eq!(
+        (pairs[1].first.as_str(), pairs[1].second.as_str()),
+        ("Bash", "Read")
+    );
+    assert_eq!(pairs[1].session_count, 2);
+    assert_eq!(pairs[2].session_count, 2);
+
+    // Rare combos are filtered out; limit caps the result.
+    let frequent = db.co_occurring_skills(3, 10).await.unwrap();
+    assert_eq!(frequent.len(), 1);
+    assert_eq!(db.co_occurring_skills(1, 1).await.unwrap().len(), 1);
+}
@@ -48,3 +48,440 @@ async fn test_get_stats_overview() {
     assert_eq!(stats.top_invocables[0].id, "builtin:Read");
     assert_eq!(stats.top_invocables[0].invocation_count, 2);
 }
+
+#[tokio::test]
+async fn test_get_stats_overview_filtered_vs_global() {
+    let db = Database::new_in_memory().await.unwrap();
+
+    let s1 = make_session("sess-a1", "project-a", 1000);
+    let s2 = make_session("sess-a2", "project-a", 5000);
+    let s3 = make_session("sess-b1", "project-b", 2000);
+    db.insert_session(&s1, "project-a", "Project A")
+        .await
+        .unwrap();
+    db.insert_session(&s2, "project-a", "Project A")
+        .await
+        .unwrap();
+    db.insert_session(&s3, "project-b", "Project B")
+        .await
+        .unwrap();
+
+    db.upsert_invocable("builtin:Read", None, "Read", "tool", "")
+        .await
+        .unwrap();
+    db.upsert_invocable("builtin:Bash", None, "Bash", "tool", "")
+        .await
+        .unwrap();
+
+    for (id, counts) in [
+        ("sess-a1", r#"{"Read":2}"#),
+        ("sess-a2", r#"{"Read":1}"#),
+        ("sess-b1", r#"{"Bash":7}"#),
+    ] {
+        sqlx::query(
+            r#"INSERT INTO session_stats (
+                   session_id, source_content_hash, source_size,
+                   parser_version, stats_version, indexed_at,
+                   invocation_counts
+               ) VALUES (?1, X'01', 0, 1, 1, 0, ?2)
+               ON CONFLICT(session_id) DO UPDATE SET
+                   invocation_counts = excluded.invocation_counts"#,
+        )
+        .bind(id)
+        .bind(counts)
+        .execute(db.pool())
+        .await
+        .unwrap();
+    }
+
+    let global = db.get_stats_overview().await.unwrap();
+    assert_eq!(global.total_sessions, 3);
+    assert_eq!(global.total_invocations, 10);
+    assert_eq!(global.unique_invocables_used, 2);
+
+    // No filters == global.
+    let unfiltered = db
+        .get_stats_overview_filtered(None, None, None, None)
+        .await
+        .unwrap();
+    assert_eq!(unfiltered.total_sessions, global.total_sessions);
+    assert_eq!(unfiltered.total_invocations, global.total_invocations);
+    assert_eq!(
+        unfiltered.unique_invocables_used,
+        global.unique_invocables_used
+    );
+
+    // Project-scoped: Bash from project-b must not leak in.
+    let project_a = db
+        .get_stats_overview_filtered(Some("project-a"), None, None, None)
+        .await
+        .unwrap();
+    assert_eq!(project_a.total_sessions, 2);
+    assert_eq!(project_a.total_invocations, 3);
+    assert_eq!(project_a.unique_invocables_used, 1);
+    assert_eq!(project_a.top_invocables[0].id, "builtin:Read");
+    assert_eq!(project_a.top_invocables[0].invocation_count, 3);
+    let bash = project_a
+        .top_invocables
+        .iter()
+        .find(|i| i.id == "builtin:Bash")
+        .unwrap();
+    assert_eq!(bash.invocation_count, 0);
+
+    // Project + time range: only sess-a2 (last_message_at = 5000).
+    let project_a_recent = db
+        .get_stats_overview_filtered(Some("project-a"), None, Some(3000), None)
+        .await
+        .unwrap();
+    assert_eq!(project_a_recent.total_sessions, 1);
+    assert_eq!(project_a_recent.total_invocations, 1);
+}
+
+#[tokio::test]
+async fn test_token_stats_by_day_of_week_and_hour() {
+    use claude_view_db::test_support::SessionSeedBuilder;
+
+    let db = Database::new_in_memory().await.unwrap();
+
+    // 2026-01-05 was a Monday. In UTC+2 local time:
+    //   a: Mon 09:30  (2026-01-05T07:30Z)
+    //   b: Mon 09:45  (2026-01-05T07:45Z)
+    //   c: Sat 23:30  (2026-01-10T21:30Z)
+    //   d: Sun 00:30  (2026-01-10T22:30Z) -- still Saturday in UTC
+    let monday_utc = 1_767_571_200; // 2026-01-05T00:00:00Z
+    let h = 3600;
+    let offset = 2 * h;
+    for (id, ts, tokens) in [
+        ("a", monday_utc + 7 * h + 1800, 100),
+        ("b", monday_utc + 7 * h + 2700, 50),
+        ("c", monday_utc + 5 * 24 * h + 21 * h + 1800, 10),
+        ("d", monday_utc + 5 * 24 * h + 22 * h + 1800, 1),
+    ] {
+        SessionSeedBuilder::new(id)
+            .file_path(format!("/tmp/tod/{id}.jsonl"))
+            .last_message_at(ts)
+            .total_input_tokens(tokens)
+            .total_output_tokens(tokens)
+            .seed(&db)
+            .await
+            .unwrap();
+    }
+
+    let by_day = db
+        .token_stats_by_day_of_week(0, i64::MAX, offset)
+        .await
+        .unwrap();
+    assert_eq!(by_day.len(), 7);
+    assert_eq!(by_day[0].sessions, 2, "Monday");
+    assert_eq!(by_day[0].total_tokens, 300);
+    assert_eq!(by_day[5].sessions, 1, "Saturday");
+    assert_eq!(by_day[5].total_tokens, 20);
+    assert_eq!(
+        by_day[6].sessions, 1,
+        "Sunday (local) despite Saturday in UTC"
+    );
+    assert_eq!(by_day.iter().map(|b| b.sessions).sum::<i64>(), 4);
+
+    let by_hour = db
+        .token_stats_by_hour_of_day(0, i64::MAX, offset)
+        .await
+        .unwrap();
+    assert_eq!(by_hour.len(), 24);
+    assert_eq!(by_hour[9].sessions, 2);
+    assert_eq!(by_hour[23].sessions, 1);
+    assert_eq!(by_hour[0].sessions, 1);
+    assert_eq!(by_hour[7].sessions, 0, "UTC hour is not used");
+
+    // Without an offset the buckets are UTC.
+    let by_hour_utc = db.token_stats_by_hour_of_day(0, i64::MAX, 0).await.unwrap();
+    assert_eq!(by_hour_utc[7].sessions, 2);
+    let by_day_utc = db.token_stats_by_day_of_week(0, i64::MAX, 0).await.unwrap();
+    assert_eq!(by_day_utc[5].sessions, 2);
+    assert_eq!(by_day_utc[6].sessions, 0);
+}
+
+#[tokio::test]
+async fn test_get_token_stats_filtered_vs_global() {
+    use claude_view_db::test_support::SessionSeedBuilder;
+
+    let db = Database::new_in_memory().await.unwrap();
+
+    for (id, project, branch, ts, tokens) in [
+        ("tok-a1", "project-a", "main", 1000, 100),
+        ("tok-a2", "project-a", "feature", 5000, 10),
+        ("tok-b1", "project-b", "main", 2000, 1),
+    ] {
+        SessionSeedBuilder::new(id)
+            .project_id(project)
+            .file_path(format!("/tmp/tok/{id}.jsonl"))
+            .git_branch(branch)
+            .last_message_at(ts)
+            .turn_count(2)
+            .total_input_tokens(tokens)
+            .total_output_tokens(tokens * 2)
+            .with_parsed(|s| {
+                s.cache_read_tokens = tokens * 3;
+                s.cache_creation_tokens = tokens;
+            })
+            .seed(&db)
+            .await
+            .unwrap();
+    }
+
+    let global = db.get_token_stats().await.unwrap();
+    assert_eq!(global.sessions_count, 3);
+    assert_eq!(global.total_input_tokens, 111);
+    assert_eq!(global.total_output_tokens, 222);
+    assert_eq!(global.turns_count, 6);
+    assert!((global.cache_hit_ratio - 0.75).abs() < 1e-9);
+
+    // No filters == global.
+    let unfiltered = db
+        .get_token_stats_filtered(None, None, None, None)
+        .await
+        .unwrap();
+    assert_eq!(unfiltered.sessions_count, global.sessions_count);
+    assert_eq!(unfiltered.total_input_tokens, global.total_input_tokens);
+    assert_eq!(
+        unfiltered.total_cache_read_tokens,
+        global.total_cache_read_tokens
+    );
+
+    // Project-scoped: project-b tokens must not leak in.
+    let project_a = db
+        .get_token_stats_filtered(None, None, Some("project-a"), None)
+        .await
+        .unwrap();
+    assert_eq!(project_a.sessions_count, 2);
+    assert_eq!(project_a.total_input_tokens, 110);
+    assert_eq!(project_a.total_output_tokens, 220);
+    assert_eq!(project_a.total_cache_read_tokens, 330);
+    assert_eq!(project_a.turns_count, 4);
+
+    // Project + branch.
+    let project_a_main = db
+        .get_token_stats_filtered(None, None, Some("project-a"), Some("main"))
+        .await
+        .unwrap();
+    assert_eq!(project_a_main.sessions_count, 1);
+    assert_eq!(project_a_main.total_input_tokens, 100);
+
+    // Time range: only tok-a2 (last_message_at = 5000).
+    let recent = db
+        .get_token_stats_filtered(Some(3000), None, None, None)
+        .await
+        .unwrap();
+    assert_eq!(recent.sessions_count, 1);
+    assert_eq!(recent.total_input_tokens, 10);
+}
+
+#[tokio::test]
+async fn test_session_model_cost_matches_manual_calculation() {
+    use claude_view_db::test_support::SessionSeedBuilder;
+
+    let db = Database::new_in_memory().await.unwrap();
+    SessionSeedBuilder::new("cost-1")
+        .file_path("/tmp/cost/cost-1.jsonl")
+        .primary_model("claude-sonnet-4-6")
+        .total_input_tokens(12_000)
+        .total_output_tokens(3_000)
+        .with_parsed(|s| {
+            s.cache_read_tokens = 400_000;
+            s.cache_creation_tokens = 20_000;
+        })
+        .seed(&db)
+        .await
+        .unwrap();
+
+    let session = db.session_model_cost("cost-1").await.unwrap().unwrap();
+    assert_eq!(session.model.as_deref(), Some("claude-sonnet-4-6"));
+
+    // Base rates even though cache reads exceed 200k: the tier is per call.
+    let rates = &claude_view_db::load_pricing()["claude-sonnet-4-6"];
+    let expected = 12_000.0 * rates.input_cost_per_token
+        + 3_000.0 * rates.output_cost_per_token
+        + 400_000.0 * rates.cache_read_cost_per_token
+        + 20_000.0 * rates.cache_creation_cost_per_token;
+    assert!(
+        (session.cost.total_usd - expected).abs() < 1e-9,
+        "{} != {expected}",
+        session.cost.total_usd
+    );
+    assert!(session.cost.cache_savings_usd > 0.0);
+    assert!(!session.cost.has_unpriced_usage);
+
+    assert!(db.session_model_cost("missing").await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_session_model_cost_prices_each_model_used() {
+    use claude_view_db::test_support::SessionSeedBuilder;
+
+    let db = Database::new_in_memory().await.unwrap();
+    SessionSeedBuilder::new("cost-switch")
+        .file_path("/tmp/cost/cost-switch.jsonl")
+        .primary_model("claude-sonnet-4-6")
+        .total_input_tokens(12_000)
+        .total_output_tokens(3_000)
+        .seed(&db)
+        .await
+        .unwrap();
+    sqlx::query(
+        r#"UPDATE session_stats SET per_model_tokens_json = ?1 WHERE session_id = 'cost-switch'"#,
+    )
+    .bind(
+        r#"{"claude-sonnet-4-6":{"inputTokens":10000,"outputTokens":2000,"cacheReadTokens":0,"cacheCreationTokens":0,"cacheCreation5mTokens":0,"cacheCreation1hrTokens":0,"totalTokens":12000},
+            "claude-opus-4-6":{"inputTokens":2000,"outputTokens":1000,"cacheReadTokens":0,"cacheCreationTokens":0,"cacheCreation5mTokens":0,"cacheCreation1hrTokens":0,"totalTokens":3000}}"#,
+    )
+    .execute(db.pool())
+    .await
+    .unwrap();
+
+    let session = db.session_model_cost("cost-switch").await.unwrap().unwrap();
+    assert_eq!(session.model.as_deref(), Some("claude-sonnet-4-6"));
+
+    // The opus share is priced at opus rates, not the primary model's.
+    let pricing = claude_view_db::load_pricing();
+    let sonnet = &pricing["claude-sonnet-4-6"];
+    let opus = &pricing["claude-opus-4-6"];
+    let expected = 10_000.0 * sonnet.input_cost_per_token
+        + 2_000.0 * sonnet.output_cost_per_token
+        + 2_000.0 * opus.input_cost_per_token
+        + 1_000.0 * opus.output_cost_per_token;
+    assert!(
+        (session.cost.total_usd - expected).abs() < 1e-9,
+        "{} != {expected}",
+        session.cost.total_usd
+    );
+    assert!(!session.cost.has_unpriced_usage);
+    assert_eq!(session.cost.priced_token_coverage, 1.0);
+}
+
+#[tokio::test]
+async fn test_models_used_in_session_reports_model_switch() {
+    use claude_view_db::indexer_parallel::{build_index_hints, scan_and_index_all};
+
+    let db = Database::new_in_memory().await.unwrap();
+    let tmp = tempfile::tempdir().unwrap();
+    let project_dir = tmp.path().join("projects").join("-Users-test-proj");
+    std::fs::create_dir_all(&project_dir).unwrap();
+    // Two Opus turns, then one Haiku turn.
+    let turn = |uuid: &str, parent: &str, ts: i64, model: &str| {
+        format!(
+            r#"{{"parentUuid":"{parent}","type":"assistant","uuid":"{uuid}","timestamp":{ts},"message":{{"id":"msg-{uuid}","model":"{model}","role":"assistant","content":[{{"type":"text","text":"ok"}}],"usage":{{"input_tokens":10,"output_tokens":20}}}}}}"#
+        )
+    };
+    let jsonl = [
+        r#"{"parentUuid":null,"type":"user","uuid":"u1","timestamp":1706200000,"message":{"role":"user","content":"Hello"}}"#.to_string(),
+        turn("a1", "u1", 1706200001, "claude-opus-4-6"),
+        turn("a2", "a1", 1706200002, "claude-opus-4-6"),
+        turn("a3", "a2", 1706200003, "claude-haiku-4-5-20251001"),
+    ]
+    .join("\n");
+    std::fs::write(project_dir.join("sess-switch.jsonl"), jsonl).unwrap();
+
+    let hints = build_index_hints(tmp.path());
+    scan_and_index_all(tmp.path(), &db, &hints, None, |_| {}, |_| {}, || {})
+        .await
+        .unwrap();
+
+    let models = db.models_used_in_session("sess-switch").await.unwrap();
+    let got: Vec<(&str, i64)> = models
+        .iter()
+        .map(|m| (m.model_id.as_str(), m.turn_count))
+        .collect();
+    assert_eq!(
+        got,
+        // Model ids are normalized (date suffix stripped) like primary_model.
+        vec![("claude-opus-4-6", 2), ("claude-haiku-4-5", 1)]
+    );
+
+    assert!(db
+        .models_used_in_session("missing")
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_model_adoption_timeline_tracks_migration() {
+    use claude_view_db::test_support::SessionSeedBuilder;
+
+    let db = Database::new_in_memory().await.unwrap();
+    let jan = 1_768_478_400; // 2026-01-15T12:00:00Z
+    let feb = 1_771_156_800; // 2026-02-15T12:00:00Z
+    let mar = 1_773_576_000; // 2026-03-15T12:00:00Z
+    for (id, ts, counts) in [
+        ("jan", jan, r#"{"claude-opus-4-6":3}"#),
+        ("feb", feb, r#"{"claude-opus-4-6":1,"claude-sonnet-4-6":4}"#),
+    ] {
+        SessionSeedBuilder::new(id)
+            .file_path(format!("/tmp/adoption/{id}.jsonl"))
+            .last_message_at(ts)
+            .with_parsed(|s| s.model_turn_counts = Some(counts.to_string()))
+            .seed(&db)
+            .await
+            .unwrap();
+    }
+    // Indexed before model_turn_counts: turns go to the primary model.
+    SessionSeedBuilder::new("mar")
+        .file_path("/tmp/adoption/mar.jsonl")
+        .last_message_at(mar)
+        .turn_count(2)
+        .primary_model("claude-sonnet-4-6")
+        .seed(&db)
+        .await
+        .unwrap();
+    db.batch_upsert_models(&["claude-sonnet-4-6".to_string()], 0)
+        .await
+        .unwrap();
+
+    let timeline = db.model_adoption_timeline().await.unwrap();
+    assert_eq!(timeline.len(), 2);
+
+    let opus = &timeline[0];
+    assert_eq!(opus.model_id, "claude-opus-4-6");
+    assert_eq!((opus.first_seen, opus.last_seen), (jan, feb));
+    let opus_months: Vec<(&str, i64)> = opus
+        .months
+        .iter()
+        .map(|m| (m.month.as_str(), m.turn_count))
+        .collect();
+    assert_eq!(opus_months, vec![("2026-01", 3), ("2026-02", 1)]);
+
+    let sonnet = &timeline[1];
+    assert_eq!(sonnet.model_id, "claude-sonnet-4-6");
+    assert_eq!(sonnet.family.as_deref(), Some("sonnet"));
+    assert_eq!((sonnet.first_seen, sonnet.last_seen), (feb, mar));
+    let sonnet_months: Vec<(&str, i64)> = sonnet
+        .months
+        .iter()
+        .map(|m| (m.month.as_str(), m.turn_count))
+        .collect();
+    assert_eq!(sonnet_months, vec![("2026-02", 4), ("2026-03", 2)]);
+}
+
+#[tokio::test]
+async fn test_session_primary_models() {
+    use claude_view_db::test_support::SessionSeedBuilder;
+
+    let db = Database::new_in_memory().await.unwrap();
+    SessionSeedBuilder::new("with-model")
+        .file_path("/tmp/with-model.jsonl")
+        .primary_model("claude-opus-4-6")
+        .seed(&db)
+        .await
+        .unwrap();
+    SessionSeedBuilder::new("no-model")
+        .file_path("/tmp/no-model.jsonl")
+        .seed(&db)
+        .await
+        .unwrap();
+
+    let models = db.session_primary_models().await.unwrap();
+    assert_eq!(models.len(), 1);
+    assert_eq!(
+        models.get("with-model").map(String::as_str),
+        Some("claude-opus-4-6")
+    );
+}
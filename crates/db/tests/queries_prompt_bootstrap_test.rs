@@ -0,0 +1,54 @@
+//! Integration tests for seeding the prompt search index from the database.
+
+use claude_view_db::test_support::SessionSeedBuilder;
+use claude_view_db::{Database, DB_PROMPT_ID_PREFIX};
+use claude_view_search::prompt_index::PromptSearchIndex;
+
+#[tokio::test]
+async fn test_rebuild_prompt_index_makes_previews_searchable() {
+    let db = Database::new_in_memory().await.unwrap();
+    SessionSeedBuilder::new("s1")
+        .project_id("proj")
+        .file_path("/tmp/s1.jsonl")
+        .project_display_name("Proj")
+        .git_branch("main")
+        .preview("Refactor the flamingo parser")
+        .first_message_at(1_700_000_000)
+        .seed(&db)
+        .await
+        .unwrap();
+    SessionSeedBuilder::new("s2")
+        .project_id("proj")
+        .file_path("/tmp/s2.jsonl")
+        .preview("flamingo in a sidechain")
+        .is_sidechain(true)
+        .seed(&db)
+        .await
+        .unwrap();
+    SessionSeedBuilder::new("s3")
+        .project_id("proj")
+        .file_path("/tmp/s3.jsonl")
+        .preview("")
+        .seed(&db)
+        .await
+        .unwrap();
+
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    assert_eq!(db.rebuild_prompt_index(&index).await.unwrap(), 1);
+
+    let response = index.search("flamingo", None, 10, 0).unwrap();
+    assert_eq!(response.total_matches, 1);
+    let hit = &response.prompts[0];
+    assert_eq!(hit.prompt_id, "db:s1");
+    assert_eq!(hit.session_id.as_deref(), Some("s1"));
+    assert_eq!(hit.project, "Proj");
+    assert_eq!(hit.branch, "main");
+    assert_eq!(hit.timestamp, 1_700_000_000);
+
+    // Once history is indexed, the seed documents are dropped.
+    index
+        .delete_by_prompt_id_prefix(DB_PROMPT_ID_PREFIX)
+        .unwrap();
+    index.commit().unwrap();
+    assert_eq!(index.num_docs(), 0);
+}
@@ -378,6 +378,49 @@ async fn test_get_session_file_path() {
     );
 }
 
+#[tokio::test]
+async fn test_get_session_bash_categories() {
+    let db = Database::new_in_memory().await.unwrap();
+    assert!(db
+        .get_session_bash_categories("nonexistent")
+        .await
+        .unwrap()
+        .is_empty());
+
+    claude_view_db::test_support::SessionSeedBuilder::new("bash-cats")
+        .file_path("/tmp/bash-cats.jsonl")
+        .with_parsed(|s| s.bash_categories = r#"{"git":3,"test":2}"#.to_string())
+        .seed(&db)
+        .await
+        .unwrap();
+
+    let counts = db.get_session_bash_categories("bash-cats").await.unwrap();
+    assert_eq!(counts.get("git"), Some(&3));
+    assert_eq!(counts.get("test"), Some(&2));
+    assert_eq!(counts.len(), 2);
+}
+
+#[tokio::test]
+async fn test_get_session_invocation_args() {
+    let db = Database::new_in_memory().await.unwrap();
+    assert!(db
+        .get_session_invocation_args("nonexistent")
+        .await
+        .unwrap()
+        .is_empty());
+
+    claude_view_db::test_support::SessionSeedBuilder::new("inv-args")
+        .file_path("/tmp/inv-args.jsonl")
+        .with_parsed(|s| s.invocation_args = r#"{"/commit":{"--amend":2}}"#.to_string())
+        .seed(&db)
+        .await
+        .unwrap();
+
+    let args = db.get_session_invocation_args("inv-args").await.unwrap();
+    assert_eq!(args["/commit"].get("--amend"), Some(&2));
+    assert_eq!(args.len(), 1);
+}
+
 #[tokio::test]
 async fn test_phase3_fields_default_to_zero() {
     let db = Database::new_in_memory().await.unwrap();
@@ -415,3 +458,422 @@ async fn test_phase3_fields_default_to_zero() {
     assert_eq!(session.duration_seconds, 0);
     assert_eq!(session.commit_count, 0);
 }
+
+#[tokio::test]
+async fn test_duplicate_sessions_groups_identical_sessions() {
+    let db = Database::new_in_memory().await.unwrap();
+
+    let mut original = make_session("orig", "project-a", 2000);
+    original.preview = "Fix the login bug".to_string();
+    original.first_message_at = Some(1500);
+    let mut restored = make_session("restored", "project-a", 2100);
+    restored.preview = "Fix the login bug".to_string();
+    restored.first_message_at = Some(1500);
+    // Same preview and start time, different project: not a duplicate.
+    let mut other_project = make_session("other", "project-b", 2000);
+    other_project.preview = "Fix the login bug".to_string();
+    other_project.first_message_at = Some(1500);
+    // Same project and start time, different preview: not a duplicate.
+    let mut different = make_session("different", "project-a", 2000);
+    different.first_message_at = Some(1500);
+
+    for (s, project) in [
+        (&original, "project-a"),
+        (&restored, "project-a"),
+        (&other_project, "project-b"),
+        (&different, "project-a"),
+    ] {
+        db.insert_session(s, project, project).await.unwrap();
+    }
+
+    let groups = db.duplicate_sessions().await.unwrap();
+    assert_eq!(groups.len(), 1, "groups: {groups:?}");
+    assert_eq!(groups[0].project_id, "project-a");
+    assert_eq!(groups[0].first_message_at, 1500);
+    assert_eq!(groups[0].preview, "Fix the login bug");
+    assert_eq!(groups[0].session_ids, vec!["restored", "orig"]);
+}
+
+#[tokio::test]
+async fn test_merge_projects_collapses_listings() {
+    let db = Database::new_in_memory().await.unwrap();
+
+    let old1 = make_session("old-1", "old-name", 1000);
+    let old2 = make_session("old-2", "old-name", 2000);
+    let new1 = make_session("new-1", "new-name", 3000);
+    db.insert_session(&old1, "old-name", "Old Name")
+        .await
+        .unwrap();
+    db.insert_session(&old2, "old-name", "Old Name")
+        .await
+        .unwrap();
+    db.insert_session(&new1, "new-name", "New Name")
+        .await
+        .unwrap();
+    assert_eq!(db.list_projects().await.unwrap().len(), 2);
+
+    let moved = db.merge_projects("old-name", "new-name").await.unwrap();
+    assert_eq!(moved, 2);
+
+    let projects = db.list_projects().await.unwrap();
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].name, "new-name");
+    assert_eq!(projects[0].display_name, "New Name");
+    assert_eq!(projects[0].sessions.len(), 3);
+
+    // Merging again (or into itself) is a no-op.
+    assert_eq!(db.merge_projects("old-name", "new-name").await.unwrap(), 0);
+    assert_eq!(db.merge_projects("new-name", "new-name").await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_merge_projects_survives_reindex() {
+    let db = Database::new_in_memory().await.unwrap();
+
+    let old1 = make_session("old-1", "old-name", 1000);
+    let new1 = make_session("new-1", "new-name", 3000);
+    db.insert_session(&old1, "old-name", "Old Name")
+        .await
+        .unwrap();
+    db.insert_session(&new1, "new-name", "New Name")
+        .await
+        .unwrap();
+    db.merge_projects("old-name", "new-name").await.unwrap();
+
+    // The indexer re-upserts the moved session and finds a new one, both
+    // still derived from the old project directory.
+    db.insert_session(&old1, "old-name", "Old Name")
+        .await
+        .unwrap();
+    let old2 = make_session("old-2", "old-name", 2000);
+    db.insert_session(&old2, "old-name", "Old Name")
+        .await
+        .unwrap();
+
+    let projects = db.list_projects().await.unwrap();
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].name, "new-name");
+    assert_eq!(projects[0].display_name, "New Name");
+    assert_eq!(projects[0].sessions.len(), 3);
+
+    // A later merge of the destination carries earlier merges along.
+    db.merge_projects("new-name", "final").await.unwrap();
+    db.insert_session(&old1, "old-name", "Old Name")
+        .await
+        .unwrap();
+    let projects = db.list_projects().await.unwrap();
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].name, "final");
+}
+
+#[tokio::test]
+async fn test_project_display_name_override_survives_reindex() {
+    let db = Database::new_in_memory().await.unwrap();
+
+    let s1 = make_session("sess-1", "proj", 1000);
+    let s2 = make_session("sess-2", "proj", 2000);
+    let other = make_session("sess-3", "other", 3000);
+    db.insert_session(&s1, "proj", "Derived").await.unwrap();
+    db.insert_session(&s2, "proj", "Derived").await.unwrap();
+    db.insert_session(&other, "other", "Other").await.unwrap();
+
+    let renamed = db
+        .set_project_display_name("proj", "My Project")
+        .await
+        .unwrap();
+    assert_eq!(renamed, 2);
+
+    // Reindexing existing and new sessions keeps the override. There is no
+    // `insert_session_from_index` any more; `insert_session` runs the same
+    // session_stats UPSERT the indexer writes through.
+    db.insert_session(&s1, "proj", "Derived").await.unwrap();
+    let s4 = make_session("sess-4", "proj", 4000);
+    db.insert_session(&s4, "proj", "Derived").await.unwrap();
+
+    let projects = db.list_projects().await.unwrap();
+    let proj = projects.iter().find(|p| p.name == "proj").unwrap();
+    assert_eq!(proj.display_name, "My Project");
+    assert_eq!(proj.sessions.len(), 3);
+    let other = projects.iter().find(|p| p.name == "other").unwrap();
+    assert_eq!(other.display_name, "Other");
+}
+
+#[tokio::test]
+async fn test_project_activity_bounds() {
+    use claude_view_db::test_support::SessionSeedBuilder;
+
+    let db = Database::new_in_memory().await.unwrap();
+    SessionSeedBuilder::new("alpha-early")
+        .project_id("alpha")
+        .file_path("/tmp/alpha-early.jsonl")
+        .first_message_at(1000)
+        .last_message_at(1500)
+        .seed(&db)
+        .await
+        .unwrap();
+    SessionSeedBuilder::new("alpha-late")
+        .project_id("alpha")
+        .file_path("/tmp/alpha-late.jsonl")
+        .first_message_at(4000)
+        .last_message_at(5000)
+        .seed(&db)
+        .await
+        .unwrap();
+    // Sidechains don't widen the bounds.
+    SessionSeedBuilder::new("alpha-side")
+        .project_id("alpha")
+        .file_path("/tmp/alpha-side.jsonl")
+        .first_message_at(100)
+        .last_message_at(9000)
+        .is_sidechain(true)
+        .seed(&db)
+        .await
+        .unwrap();
+    SessionSeedBuilder::new("beta-only")
+        .project_id("beta")
+        .file_path("/tmp/beta-only.jsonl")
+        .first_message_at(2000)
+        .last_message_at(2500)
+        .seed(&db)
+        .await
+        .unwrap();
+
+    let bounds = db.project_activity_bounds().await.unwrap();
+    assert_eq!(
+        bounds,
+        vec![
+            ("alpha".to_string(), 1000, 5000, 2),
+            ("beta".to_string(), 2000, 2500, 1),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_top_error_sessions_ranking() {
+    use claude_view_db::test_support::SessionSeedBuilder;
+
+    let db = Database::new_in_memory().await.unwrap();
+    // (id, project, api_errors, compactions, last_message_at)
+    for (id, project, errors, compactions, ts) in [
+        ("few-errors", "alpha", 2, 0, 1000),
+        ("many-errors", "alpha", 9, 1, 2000),
+        ("tie-more-compactions", "alpha", 2, 4, 1500),
+        ("clean", "alpha", 0, 0, 3000),
+        ("compaction-only", "alpha", 0, 2, 2500),
+        ("other-project", "beta", 20, 0, 2200),
+    ] {
+        SessionSeedBuilder::new(id)
+            .project_id(project)
+            .file_path(format!("/tmp/errors/{id}.jsonl"))
+            .preview(format!("preview of {id}"))
+            .last_message_at(ts)
+            .with_parsed(|s| {
+                s.api_error_count = errors;
+                s.compaction_count = compactions;
+            })
+            .seed(&db)
+            .await
+            .unwrap();
+    }
+
+    let ranked = db
+        .top_error_sessions(None, None, Some("alpha"), 10)
+        .await
+        .unwrap();
+    let ids: Vec<&str> = ranked.iter().map(|s| s.session_id.as_str()).collect();
+    assert_eq!(
+        ids,
+        vec![
+            "many-errors",
+            "tie-more-compactions",
+            "few-errors",
+            "compaction-only"
+        ]
+    );
+    assert_eq!(ranked[0].api_error_count, 9);
+    assert_eq!(ranked[0].compaction_count, 1);
+    assert_eq!(ranked[0].preview, "preview of many-errors");
+
+    // Unfiltered: the beta session leads; limit applies.
+    let top = db.top_error_sessions(None, None, None, 2).await.unwrap();
+    assert_eq!(top.len(), 2);
+    assert_eq!(top[0].session_id, "other-project");
+
+    // Time window drops sessions outside it.
+    let windowed = db
+        .top_error_sessions(Some(1200), Some(2100), Some("alpha"), 10)
+        .await
+        .unwrap();
+    let ids: Vec<&str> = windowed.iter().map(|s| s.session_id.as_str()).collect();
+    assert_eq!(ids, vec!["many-errors", "tie-more-compactions"]);
+}
+
+#[tokio::test]
+async fn test_get_sessions_by_ids_preserves_input_order() {
+    let db = Database::new_in_memory().await.unwrap();
+    for (i, id) in ["sess-a", "sess-b", "sess-c", "sess-d"].iter().enumerate() {
+        claude_view_db::test_support::SessionSeedBuilder::new(*id)
+            .project_id("proj-batch")
+            .file_path(format!("/tmp/{id}.jsonl"))
+            .preview(format!("Preview {id}"))
+            .last_message_at(1_700_000_000 + i as i64)
+            .seed(&db)
+            .await
+            .unwrap();
+    }
+
+    let ids: Vec<String> = ["sess-c", "missing", "sess-a", "sess-b"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let sessions = db.get_sessions_by_ids(&ids).await.unwrap();
+    let got: Vec<&str> = sessions.iter().map(|s| s.id.as_str()).collect();
+    assert_eq!(got, vec!["sess-c", "sess-a", "sess-b"]);
+    assert_eq!(sessions[0].preview, "Preview sess-c");
+    assert_eq!(sessions[1].project, "proj-batch");
+
+    assert!(db.get_sessions_by_ids(&[]).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_sessions_page_across_projects() {
+    use claude_view_db::{SessionsFilter, SessionsSort};
+
+    let db = Database::new_in_memory().await.unwrap();
+    let seeds = [
+        ("a1", "proj-a", Some("main"), 1_000, 3, "bug_fix"),
+        ("b1", "proj-b", None, 2_000, 9, "deep_work"),
+        ("a2", "proj-a", Some("feat"), 3_000, 5, "bug_fix"),
+        ("c1", "proj-c", Some("main"), 4_000, 1, "quick_ask"),
+        ("b2", "proj-b", Some("main"), 5_000, 7, "bug_fix"),
+    ];
+    for (id, project, branch, last_message_at, messages, work_type) in seeds {
+        let mut seed = claude_view_db::test_support::SessionSeedBuilder::new(id)
+            .project_id(project)
+            .file_path(format!("/tmp/{id}.jsonl"))
+            .last_message_at(last_message_at)
+            .message_count(messages)
+            .with_parsed(move |p| p.work_type = Some(work_type.to_string()));
+        if let Some(branch) = branch {
+            seed = seed.git_branch(branch);
+        }
+        seed.seed(&db).await.unwrap();
+    }
+
+    let ids = |page: &claude_view_core::SessionsPage| {
+        page.sessions
+            .iter()
+            .map(|s| s.id.clone())
+            .collect::<Vec<_>>()
+    };
+    let all = SessionsFilter::default();
+
+    let first = db
+        .sessions_page(2, 0, SessionsSort::Recent, &all)
+        .await
+        .unwrap();
+    assert_eq!(first.total, 5);
+    assert_eq!(ids(&first), vec!["b2", "c1"]);
+    let second = db
+        .sessions_page(2, 2, SessionsSort::Recent, &all)
+        .await
+        .unwrap();
+    assert_eq!(ids(&second), vec!["a2", "b1"]);
+    let last = db
+        .sessions_page(2, 4, SessionsSort::Recent, &all)
+        .await
+        .unwrap();
+    assert_eq!(ids(&last), vec!["a1"]);
+    assert_eq!(last.sessions[0].project, "proj-a");
+
+    let by_messages = db
+        .sessions_page(3, 0, SessionsSort::from_param("messages"), &all)
+        .await
+        .unwrap();
+    assert_eq!(ids(&by_messages), vec!["b1", "b2", "a2"]);
+
+    let main_bug_fixes = SessionsFilter {
+        branch: Some("main".to_string()),
+        work_type: Some("bug_fix".to_string()),
+        ..Default::default()
+    };
+    let page = db
+        .sessions_page(10, 0, SessionsSort::Oldest, &main_bug_fixes)
+        .await
+        .unwrap();
+    assert_eq!(page.total, 2);
+    assert_eq!(ids(&page), vec!["a1", "b2"]);
+
+    let no_branch = SessionsFilter {
+        branch: Some("~".to_string()),
+        ..Default::default()
+    };
+    let page = db
+        .sessions_page(10, 0, SessionsSort::Recent, &no_branch)
+        .await
+        .unwrap();
+    assert_eq!(ids(&page), vec!["b1"]);
+
+    let windowed = SessionsFilter {
+        project_id: Some("proj-a".to_string()),
+        since: Some(2_000),
+        until: Some(4_000),
+        ..Default::default()
+    };
+    let page = db
+        .sessions_page(10, 0, SessionsSort::Recent, &windowed)
+        .await
+        .unwrap();
+    assert_eq!(page.total, 1);
+    assert_eq!(ids(&page), vec!["a2"]);
+
+    // The sidebar filters by git root, not project id.
+    db.set_git_root("b2", "/repo/b").await.unwrap();
+    let by_root = SessionsFilter {
+        project_id: Some("/repo/b".to_string()),
+        ..Default::default()
+    };
+    let page = db
+        .sessions_page(10, 0, SessionsSort::Recent, &by_root)
+        .await
+        .unwrap();
+    assert_eq!(page.total, 1);
+    assert_eq!(ids(&page), vec!["b2"]);
+}
+
+#[tokio::test]
+async fn test_get_recent_sessions_newest_first_across_projects() {
+    use claude_view_db::test_support::SessionSeedBuilder;
+
+    let db = Database::new_in_memory().await.unwrap();
+    for (id, project, last_message_at, sidechain) in [
+        ("a1", "proj-a", 1_000, false),
+        ("b1", "proj-b", 4_000, false),
+        ("a2", "proj-a", 3_000, false),
+        ("c1", "proj-c", 2_000, false),
+        ("side", "proj-a", 9_000, true),
+    ] {
+        SessionSeedBuilder::new(id)
+            .project_id(project)
+            .file_path(format!("/tmp/{id}.jsonl"))
+            .preview(format!("preview {id}"))
+            .git_branch("main")
+            .message_count(4)
+            .last_message_at(last_message_at)
+            .is_sidechain(sidechain)
+            .seed(&db)
+            .await
+            .unwrap();
+    }
+
+    let recent = db.get_recent_sessions(3).await.unwrap();
+    let ids: Vec<&str> = recent.iter().map(|c| c.session_id.as_str()).collect();
+    assert_eq!(ids, vec!["b1", "a2", "c1"]);
+    assert_eq!(recent[0].project_id, "proj-b");
+    assert_eq!(recent[0].preview, "preview b1");
+    assert_eq!(recent[0].git_branch.as_deref(), Some("main"));
+    assert_eq!(recent[0].message_count, 4);
+    assert_eq!(recent[0].last_message_at, 4_000);
+
+    assert_eq!(db.get_recent_sessions(10).await.unwrap().len(), 4);
+}
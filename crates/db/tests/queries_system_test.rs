@@ -138,3 +138,210 @@ async fn test_get_latest_integrity_counters_defaults_and_latest_run() {
     assert_eq!(latest.derived_source_message_doc_count, 9);
     assert_eq!(latest.source_message_non_source_provenance_count, 10);
 }
+
+#[tokio::test]
+async fn test_reset_project_data_leaves_other_projects_intact() {
+    use claude_view_db::test_support::SessionSeedBuilder;
+
+    let db = Database::new_in_memory().await.unwrap();
+    for (id, project) in [("a-1", "proj-a"), ("a-2", "proj-a"), ("b-1", "proj-b")] {
+        let path = format!("/tmp/{id}.jsonl");
+        SessionSeedBuilder::new(id)
+            .project_id(project)
+            .file_path(path.as_str())
+            .last_message_at(1000)
+            .seed(&db)
+            .await
+            .unwrap();
+        db.update_indexer_state(&path, 100, 1000).await.unwrap();
+    }
+    sqlx::query(
+        "INSERT INTO commits (hash, repo_path, message, timestamp) VALUES ('c1', '/repo', 'msg', 1000)",
+    )
+    .execute(db.pool())
+    .await
+    .unwrap();
+    for session in ["a-1", "b-1"] {
+        sqlx::query(
+            "INSERT INTO session_commits (session_id, commit_hash, tier) VALUES (?, 'c1', 1)",
+        )
+        .bind(session)
+        .execute(db.pool())
+        .await
+        .unwrap();
+    }
+    for project in ["proj-a", "proj-b"] {
+        sqlx::query(
+            "INSERT INTO contribution_snapshots (date, project_id, sessions_count) VALUES ('2026-01-01', ?, 1)",
+        )
+        .bind(project)
+        .execute(db.pool())
+        .await
+        .unwrap();
+    }
+
+    let removed = db.reset_project_data("proj-a").await.unwrap();
+    assert_eq!(removed, 2);
+
+    let count = |sql: &'static str| {
+        let db = &db;
+        async move {
+            let (n,): (i64,) = sqlx::query_as(sql).fetch_one(db.pool()).await.unwrap();
+            n
+        }
+    };
+    assert_eq!(
+        count("SELECT COUNT(*) FROM session_stats WHERE project_id = 'proj-a'").await,
+        0
+    );
+    assert_eq!(
+        count("SELECT COUNT(*) FROM session_stats WHERE project_id = 'proj-b'").await,
+        1
+    );
+    assert_eq!(count("SELECT COUNT(*) FROM session_commits").await, 1);
+    assert_eq!(
+        count("SELECT COUNT(*) FROM session_commits WHERE session_id = 'b-1'").await,
+        1
+    );
+    assert_eq!(count("SELECT COUNT(*) FROM commits").await, 1);
+    assert_eq!(
+        count("SELECT COUNT(*) FROM contribution_snapshots").await,
+        1
+    );
+    assert!(db
+        .get_indexer_state("/tmp/a-1.jsonl")
+        .await
+        .unwrap()
+        .is_none());
+    assert!(db
+        .get_indexer_state("/tmp/b-1.jsonl")
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_purge_orphan_session_commits_keeps_valid_links() {
+    use claude_view_db::test_support::SessionSeedBuilder;
+
+    let db = Database::new_in_memory().await.unwrap();
+    for id in ["kept", "gone"] {
+        SessionSeedBuilder::new(id)
+            .file_path(format!("/tmp/{id}.jsonl"))
+            .last_message_at(1000)
+            .seed(&db)
+            .await
+            .unwrap();
+    }
+    sqlx::query(
+        "INSERT INTO commits (hash, repo_path, message, timestamp) VALUES ('c1', '/repo', 'msg', 1000)",
+    )
+    .execute(db.pool())
+    .await
+    .unwrap();
+    for session in ["kept", "gone"] {
+        sqlx::query(
+            "INSERT INTO session_commits (session_id, commit_hash, tier) VALUES (?, 'c1', 1)",
+        )
+        .bind(session)
+        .execute(db.pool())
+        .await
+        .unwrap();
+    }
+    // A manual delete with foreign keys off skips the cascade.
+    let mut conn = db.pool().acquire().await.unwrap();
+    sqlx::query("PRAGMA foreign_keys = OFF")
+        .execute(&mut *conn)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM session_stats WHERE session_id = 'gone'")
+        .execute(&mut *conn)
+        .await
+        .unwrap();
+    sqlx::query("PRAGMA foreign_keys = ON")
+        .execute(&mut *conn)
+        .await
+        .unwrap();
+    drop(conn);
+
+    assert_eq!(db.purge_orphan_session_commits().await.unwrap(), 1);
+    let remaining: Vec<(String,)> = sqlx::query_as("SELECT session_id FROM session_commits")
+        .fetch_all(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(remaining, vec![("kept".to_string(),)]);
+    assert_eq!(db.purge_orphan_session_commits().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_project_health_downgrades_with_errors() {
+    use claude_view_db::test_support::SessionSeedBuilder;
+    use claude_view_db::HealthStatus;
+
+    let db = Database::new_in_memory().await.unwrap();
+    // (id, project, last_message_at, api errors, files edited)
+    for (id, project, ts, errors, edited) in [
+        ("ok-1", "proj-ok", 1000, 0, 2),
+        ("ok-2", "proj-ok", 3000, 0, 0),
+        ("bad-1", "proj-bad", 2000, 3, 1),
+        ("bad-2", "proj-bad", 4000, 0, 1),
+    ] {
+        SessionSeedBuilder::new(id)
+            .project_id(project)
+            .file_path(format!("/tmp/{id}.jsonl"))
+            .last_message_at(ts)
+            .with_parsed(|s| {
+                s.api_error_count = errors;
+                s.files_edited_count = edited;
+            })
+            .seed(&db)
+            .await
+            .unwrap();
+    }
+    sqlx::query(
+        "INSERT INTO commits (hash, repo_path, message, timestamp) VALUES ('c1', '/repo', 'msg', 1000)",
+    )
+    .execute(db.pool())
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO session_commits (session_id, commit_hash, tier) VALUES ('ok-1', 'c1', 1)",
+    )
+    .execute(db.pool())
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO session_flags (session_id, classified_at) VALUES ('ok-1', 1000)")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let ok = db.project_health("proj-ok").await.unwrap();
+    assert_eq!(ok.sessions_count, 2);
+    assert_eq!(ok.last_activity_at, Some(3000));
+    assert_eq!(ok.error_sessions_count, 0);
+    assert_eq!(ok.error_rate, 0.0);
+    assert_eq!(ok.unclassified_ratio, 0.5);
+    assert_eq!(ok.uncommitted_sessions_count, 0);
+    assert_eq!(ok.status, HealthStatus::Healthy);
+
+    let bad = db.project_health("proj-bad").await.unwrap();
+    assert_eq!(bad.sessions_count, 2);
+    assert_eq!(bad.error_sessions_count, 1);
+    assert_eq!(bad.error_rate, 0.5);
+    assert_eq!(bad.unclassified_ratio, 1.0);
+    assert_eq!(bad.uncommitted_sessions_count, 2);
+    assert_eq!(bad.status, HealthStatus::Warning);
+
+    // The sidebar passes git roots, not project ids.
+    db.set_git_root("bad-1", "/repo/bad").await.unwrap();
+    db.set_git_root("bad-2", "/repo/bad").await.unwrap();
+    let by_root = db.project_health("/repo/bad").await.unwrap();
+    assert_eq!(by_root.sessions_count, 2);
+    assert_eq!(by_root.error_sessions_count, 1);
+
+    let unknown = db.project_health("proj-missing").await.unwrap();
+    assert_eq!(unknown.sessions_count, 0);
+    assert_eq!(unknown.last_activity_at, None);
+    assert_eq!(unknown.unclassified_ratio, 0.0);
+    assert_eq!(unknown.status, HealthStatus::Healthy);
+}
@@ -0,0 +1,83 @@
+use claude_view_db::indexer_parallel::{build_index_hints, scan_and_index_all};
+use claude_view_db::Database;
+use tempfile::tempdir;
+
+const SESSION_JSONL: &str = r#"{"parentUuid":null,"isFinal":false,"type":"user","uuid":"u1","message":{"role":"user","content":[{"type":"text","text":"Hello world"}]}}
+{"parentUuid":"u1","isFinal":false,"type":"assistant","uuid":"a1","timestamp":1706200000,"message":{"model":"claude-opus-4-5-20251101","role":"assistant","content":[{"type":"text","text":"Hi there!"},{"type":"tool_use","name":"Read","id":"t1","input":{"file_path":"/tmp/test.rs"}}],"usage":{"input_tokens":50,"output_tokens":200,"cache_read_input_tokens":5000,"cache_creation_input_tokens":1000,"service_tier":"standard"}}}
+{"parentUuid":"a1","isFinal":true,"type":"user","uuid":"u2","message":{"role":"user","content":[{"type":"text","text":"Thanks for reading that file"}]}}
+"#;
+
+async fn counts(db: &Database, session_id: &str) -> (i64, i64, i64) {
+    sqlx::query_as(
+        "SELECT message_count, total_output_tokens, tool_counts_read \
+         FROM session_stats WHERE session_id = ?1",
+    )
+    .bind(session_id)
+    .fetch_one(db.pool())
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn recompute_restores_corrupted_counts() {
+    let db = Database::new_in_memory().await.unwrap();
+    let tmp = tempdir().unwrap();
+    let project_dir = tmp.path().join("projects").join("-Users-test-proj");
+    std::fs::create_dir_all(&project_dir).unwrap();
+    std::fs::write(project_dir.join("sess-1.jsonl"), SESSION_JSONL).unwrap();
+
+    let hints = build_index_hints(tmp.path());
+    let (indexed, _) = scan_and_index_all(tmp.path(), &db, &hints, None, |_| {}, |_| {}, || {})
+        .await
+        .unwrap();
+    assert_eq!(indexed, 1);
+    let original = counts(&db, "sess-1").await;
+
+    sqlx::query(
+        "UPDATE session_stats SET message_count = 999, total_output_tokens = 0, \
+         tool_counts_read = 42 WHERE session_id = 'sess-1'",
+    )
+    .execute(db.pool())
+    .await
+    .unwrap();
+    assert_ne!(counts(&db, "sess-1").await, original);
+
+    assert!(db.recompute_session_aggregates("sess-1").await.unwrap());
+    assert_eq!(counts(&db, "sess-1").await, original);
+}
+
+#[tokio::test]
+async fn recompute_unknown_session_returns_false() {
+    let db = Database::new_in_memory().await.unwrap();
+    assert!(!db.recompute_session_aggregates("missing").await.unwrap());
+}
+
+#[tokio::test]
+async fn regenerate_previews_fills_blank_preview() {
+    let db = Database::new_in_memory().await.unwrap();
+    let tmp = tempdir().unwrap();
+    let project_dir = tmp.path().join("projects").join("-Users-test-proj");
+    std::fs::create_dir_all(&project_dir).unwrap();
+    std::fs::write(project_dir.join("sess-1.jsonl"), SESSION_JSONL).unwrap();
+
+    let hints = build_index_hints(tmp.path());
+    scan_and_index_all(tmp.path(), &db, &hints, None, |_| {}, |_| {}, || {})
+        .await
+        .unwrap();
+
+    sqlx::query("UPDATE session_stats SET preview = '' WHERE session_id = 'sess-1'")
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    assert_eq!(db.regenerate_previews().await.unwrap(), 1);
+    let (preview,): (String,) =
+        sqlx::query_as("SELECT preview FROM session_stats WHERE session_id = 'sess-1'")
+            .fetch_one(db.pool())
+            .await
+            .unwrap();
+    assert_eq!(preview, "Hello world");
+
+    // Nothing left to fill.
+    assert_eq!(db.regenerate_previews().await.unwrap(), 0);
+}
@@ -0,0 +1,111 @@
+// crates/search/src/attachments.rs
+//! `attachment:` qualifier support for session search.
+//!
+//! Attachments (image/document blocks in user messages) have no text the grep
+//! engine could match, so the qualifier is resolved by scanning each
+//! candidate session's user lines with the core parser's extractor.
+
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use claude_view_core::{extract_attachments, Attachment};
+
+use crate::grep::JsonlFile;
+use crate::query::{qualifier_value, retain_tokens};
+
+const QUALIFIER: &str = "attachment:";
+
+/// Split `attachment:<name>` tokens out of a query.
+///
+/// Returns the lowercased attachment needles and the remaining free text.
+pub fn split_attachment_qualifiers(query: &str) -> (Vec<String>, String) {
+    let mut needles = Vec::new();
+    let rest = retain_tokens(query, |token| match qualifier_value(token, QUALIFIER) {
+        Some(val) if !val.is_empty() => {
+            needles.push(val.to_lowercase());
+            false
+        }
+        _ => true,
+    });
+    (needles, rest)
+}
+
+/// Distinct attachments referenced by user messages in a session file.
+///
+/// Unreadable files and malformed lines yield an empty/partial list.
+pub fn session_attachments(path: &Path) -> Vec<Attachment> {
+    let mut out: Vec<Attachment> = Vec::new();
+    let Ok(file) = std::fs::File::open(path) else {
+        return out;
+    };
+    for line in BufReader::new(file).lines().map_while(Result::ok) {
+        // Cheap pre-check: skip lines that cannot hold an attachment block.
+        if !line.contains("\"image\"") && !line.contains("\"document\"") {
+            continue;
+        }
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(&line) else {
+            continue;
+        };
+        if value.get("type").and_then(|t| t.as_str()) != Some("user") {
+            continue;
+        }
+        let Some(blocks) = value
+            .get("message")
+            .and_then(|m| m.get("content"))
+            .and_then(|c| c.as_array())
+        else {
+            continue;
+        };
+        for attachment in extract_attachments(blocks) {
+            if !out.contains(&attachment) {
+                out.push(attachment);
+            }
+        }
+    }
+    out
+}
+
+/// Keep sessions with an attachment matching every needle (case-insensitive
+/// substring on the name), paired with their attachment names.
+pub fn filter_by_attachments(
+    files: &[JsonlFile],
+    needles: &[String],
+) -> Vec<(JsonlFile, Vec<String>)> {
+    files
+        .iter()
+        .filter_map(|file| {
+            let names: Vec<String> = session_attachments(&file.path)
+                .into_iter()
+                .map(|a| a.name)
+                .collect();
+            let all_match = needles
+                .iter()
+                .all(|n| names.iter().any(|name| name.to_lowercase().contains(n)));
+            all_match.then(|| (file.clone(), names))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_attachment_qualifiers() {
+        let (needles, rest) =
+            split_attachment_qualifiers("attachment:Screenshot.png diagram attachment:");
+        assert_eq!(needles, vec!["screenshot.png"]);
+        assert_eq!(rest, "diagram attachment:");
+    }
+
+    #[test]
+    fn test_split_attachment_qualifiers_keeps_literal_whitespace() {
+        assert_eq!(
+            split_attachment_qualifiers("foo  bar\tbaz"),
+            (Vec::new(), "foo  bar\tbaz".to_string())
+        );
+        let (needles, rest) = split_attachment_qualifiers("attachment:a.png foo  bar");
+        assert_eq!(needles, vec!["a.png"]);
+        assert_eq!(rest, "foo  bar");
+    }
+}
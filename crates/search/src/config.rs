@@ -0,0 +1,156 @@
+// crates/search/src/config.rs
+//! Tunables applied when a Tantivy-backed index is opened.
+
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+/// BM25 relevance parameters.
+///
+/// `k1` controls term-frequency saturation; `b` controls how strongly long
+/// documents are penalised (0 = no length normalisation, 1 = full). Defaults
+/// match Tantivy's built-in constants, so a default config scores exactly
+/// like stock Tantivy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bm25Params {
+    pub k1: f32,
+    pub b: f32,
+}
+
+impl Bm25Params {
+    /// Tantivy's hard-coded `K1`.
+    pub const TANTIVY_K1: f32 = 1.2;
+    /// Tantivy's hard-coded `B`.
+    pub const TANTIVY_B: f32 = 0.75;
+
+    /// True when these parameters match what Tantivy scores with natively,
+    /// i.e. no re-scoring pass is needed.
+    pub fn is_tantivy_default(&self) -> bool {
+        self.k1 == Self::TANTIVY_K1 && self.b == Self::TANTIVY_B
+    }
+}
+
+impl Default for Bm25Params {
+    fn default() -> Self {
+        Self {
+            k1: Self::TANTIVY_K1,
+            b: Self::TANTIVY_B,
+        }
+    }
+}
+
+/// When an idle index gets its segments merged.
+///
+/// Every commit adds a segment, and Tantivy's merge policy leaves a tail of
+/// small ones behind. Once nothing has been written for `idle_after` and the
+/// index holds more than `max_segments`, merging them into one keeps query
+/// latency stable over long uptimes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IdleOptimizeConfig {
+    pub idle_after: Duration,
+    pub max_segments: usize,
+}
+
+impl Default for IdleOptimizeConfig {
+    fn default() -> Self {
+        Self {
+            idle_after: Duration::from_secs(10 * 60),
+            max_segments: 8,
+        }
+    }
+}
+
+/// Words dropped from prompt text (`display` / `paste_text`), both when
+/// indexing and when parsing queries.
+///
+/// Empty by default, so every word stays searchable. `keep` opts words back
+/// in: start `words` from a generic list and keep the ones that carry
+/// meaning in this corpus ("test", "build"). Matching is case-insensitive.
+/// Changing either list requires a reindex; documents indexed under the old
+/// list keep their old terms.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StopWords {
+    pub words: Vec<String>,
+    pub keep: Vec<String>,
+}
+
+impl StopWords {
+    /// Lowercased `words` minus `keep`: what the token filter removes.
+    pub fn effective(&self) -> BTreeSet<String> {
+        let keep: BTreeSet<String> = self.keep.iter().map(|w| w.to_lowercase()).collect();
+        self.words
+            .iter()
+            .map(|w| w.to_lowercase())
+            .filter(|w| !keep.contains(w))
+            .collect()
+    }
+}
+
+/// Clock a prompt's timestamp is read on to derive its day-part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayPartClock {
+    /// The machine's local timezone, applying the offset in force at each
+    /// timestamp, so prompts on either side of a DST change both land in
+    /// the right day-part.
+    Local,
+    /// A fixed offset from UTC, in seconds.
+    FixedOffset(i32),
+}
+
+impl DayPartClock {
+    /// Hour of day (0–23) of a unix timestamp (seconds) on this clock.
+    pub fn hour_of(self, timestamp_secs: i64) -> u32 {
+        let offset = match self {
+            Self::Local => chrono::DateTime::from_timestamp(timestamp_secs, 0)
+                .map(|utc| utc.with_timezone(&chrono::Local).offset().local_minus_utc())
+                .unwrap_or(0),
+            Self::FixedOffset(secs) => secs,
+        };
+        ((timestamp_secs + i64::from(offset)).rem_euclid(86_400) / 3_600) as u32
+    }
+}
+
+impl Default for DayPartClock {
+    fn default() -> Self {
+        Self::FixedOffset(0)
+    }
+}
+
+/// Per-index configuration, fixed at open time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchIndexConfig {
+    /// BM25 parameters used to rank free-text matches.
+    pub bm25: Bm25Params,
+    /// Thresholds for merging segments while the index is idle.
+    pub idle_optimize: IdleOptimizeConfig,
+    /// Largest page a single search returns; bigger `limit`s are clamped.
+    pub max_results: usize,
+    /// Clock used to derive each prompt's day-part (`when:morning`); UTC by
+    /// default. Changing it requires a reindex.
+    pub daypart_clock: DayPartClock,
+    /// Stop words filtered from prompt text. Changing them requires a reindex.
+    pub stop_words: StopWords,
+    /// Longest prompt text (`display` / `paste_text`) indexed per document,
+    /// in bytes. Longer text is cut on a char boundary before indexing, so a
+    /// pasted log does not bloat the term index; terms past the cut are not
+    /// searchable. Results still return the full stored `display`. `None`
+    /// (the default) indexes everything. Changing it requires a reindex.
+    pub max_content_bytes: Option<usize>,
+}
+
+impl SearchIndexConfig {
+    /// Default for [`Self::max_results`].
+    pub const DEFAULT_MAX_RESULTS: usize = 1000;
+}
+
+impl Default for SearchIndexConfig {
+    fn default() -> Self {
+        Self {
+            bm25: Bm25Params::default(),
+            idle_optimize: IdleOptimizeConfig::default(),
+            max_results: Self::DEFAULT_MAX_RESULTS,
+            daypart_clock: DayPartClock::default(),
+            stop_words: StopWords::default(),
+            max_content_bytes: None,
+        }
+    }
+}
@@ -0,0 +1,117 @@
+// crates/search/src/fields.rs
+//! Field allowlists for lean search responses.
+//!
+//! Callers name the `SessionHit` / `MatchHit` fields they want in camelCase
+//! (`fields=sessionId,matchCount,topMatch,role`). One list covers both
+//! levels: hit objects keep only listed keys, and match objects inside
+//! `topMatch` / `matches` are trimmed the same way when any match field is
+//! listed, and kept whole otherwise.
+
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+
+use crate::types::SearchResponse;
+
+/// Serialized `SessionHit` keys.
+pub const SESSION_HIT_FIELDS: &[&str] = &[
+    "sessionId",
+    "project",
+    "branch",
+    "modifiedAt",
+    "matchCount",
+    "bestScore",
+    "topMatch",
+    "matches",
+    "matchTurns",
+    "engines",
+];
+
+/// Serialized `MatchHit` keys.
+pub const MATCH_HIT_FIELDS: &[&str] = &["role", "turnNumber", "snippet", "timestamp"];
+
+/// A validated allowlist of hit fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchFields {
+    fields: BTreeSet<String>,
+}
+
+impl SearchFields {
+    /// Build from field names, rejecting any that aren't `SessionHit` or
+    /// `MatchHit` keys. Blank names are ignored.
+    pub fn new<I, S>(names: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut fields = BTreeSet::new();
+        for name in names {
+            let name = name.as_ref().trim();
+            if name.is_empty() {
+                continue;
+            }
+            if !SESSION_HIT_FIELDS.contains(&name) && !MATCH_HIT_FIELDS.contains(&name) {
+                return Err(format!("unknown search field '{name}'"));
+            }
+            fields.insert(name.to_string());
+        }
+        Ok(Self { fields })
+    }
+
+    /// Parse a comma-separated list, e.g. `"sessionId,matchCount"`.
+    pub fn parse(csv: &str) -> Result<Self, String> {
+        Self::new(csv.split(','))
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.fields.contains(name)
+    }
+
+    fn trims_matches(&self) -> bool {
+        MATCH_HIT_FIELDS.iter().any(|f| self.contains(f))
+    }
+
+    /// Whether any snippet survives the allowlist, so search can skip
+    /// building them otherwise.
+    pub fn wants_snippets(&self) -> bool {
+        (self.contains("topMatch") || self.contains("matches"))
+            && (self.contains("snippet") || !self.trims_matches())
+    }
+
+    /// Whether any turn number survives the allowlist, so search can skip
+    /// mapping matched lines to turns otherwise.
+    pub fn wants_turns(&self) -> bool {
+        self.contains("matchTurns")
+            || ((self.contains("topMatch") || self.contains("matches"))
+                && (self.contains("turnNumber") || !self.trims_matches()))
+    }
+
+    /// Serialize `response` with every session hit reduced to the allowlist.
+    /// Response-level fields (`query`, `totalMatches`, ...) are always kept.
+    pub fn apply(&self, response: &SearchResponse) -> Value {
+        let mut value = serde_json::to_value(response).unwrap_or(Value::Null);
+        if let Some(sessions) = value.get_mut("sessions").and_then(Value::as_array_mut) {
+            for hit in sessions {
+                self.retain(hit, SESSION_HIT_FIELDS);
+                if !self.trims_matches() {
+                    continue;
+                }
+                if let Some(top) = hit.get_mut("topMatch") {
+                    self.retain(top, MATCH_HIT_FIELDS);
+                }
+                if let Some(matches) = hit.get_mut("matches").and_then(Value::as_array_mut) {
+                    for m in matches {
+                        self.retain(m, MATCH_HIT_FIELDS);
+                    }
+                }
+            }
+        }
+        value
+    }
+
+    fn retain(&self, object: &mut Value, known: &[&str]) {
+        if let Some(map) = object.as_object_mut() {
+            map.retain(|key, _| !known.contains(&key.as_str()) || self.contains(key));
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
 use grep_matcher::Matcher;
@@ -6,6 +8,7 @@ use grep_regex::RegexMatcherBuilder;
 use grep_searcher::{Searcher, SearcherBuilder, Sink, SinkMatch};
 
 use crate::grep_types::{GrepLineMatch, GrepResponse, GrepSessionHit};
+use crate::prompt_index::fxhash;
 
 /// Options for a grep search.
 pub struct GrepOptions {
@@ -16,12 +19,92 @@ pub struct GrepOptions {
 }
 
 /// Metadata for one JSONL file to search.
+#[derive(Debug, Clone)]
 pub struct JsonlFile {
     pub path: PathBuf,
     pub session_id: String,
     pub project: String,
     pub project_path: String,
     pub modified_at: i64,
+    /// Subagent transcript (`isSidechain: true`), per [`jsonl_is_sidechain`].
+    pub is_sidechain: bool,
+}
+
+/// Lines read before giving up on finding an `isSidechain` flag. Claude Code
+/// writes it on every message line, so the first few lines settle it.
+const SIDECHAIN_PROBE_LINES: usize = 16;
+
+/// Whether a session file is a subagent sidechain: the first line carrying
+/// `isSidechain` decides. Unreadable files and files without the flag count
+/// as primary sessions.
+pub fn jsonl_is_sidechain(path: &Path) -> bool {
+    let Ok(file) = std::fs::File::open(path) else {
+        return false;
+    };
+    for line in BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .take(SIDECHAIN_PROBE_LINES)
+    {
+        if !line.contains("\"isSidechain\"") {
+            continue;
+        }
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(&line) else {
+            continue;
+        };
+        if let Some(flag) = value.get("isSidechain").and_then(|v| v.as_bool()) {
+            return flag;
+        }
+    }
+    false
+}
+
+/// Whether a JSONL line is a user prompt (a new turn): a non-meta `user`
+/// message carrying text rather than only tool results.
+fn is_user_prompt_line(line: &str) -> bool {
+    if !line.contains("\"user\"") {
+        return false;
+    }
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(line) else {
+        return false;
+    };
+    if value.get("type").and_then(|v| v.as_str()) != Some("user")
+        || value.get("isMeta").and_then(|v| v.as_bool()) == Some(true)
+    {
+        return false;
+    }
+    match value.pointer("/message/content") {
+        Some(serde_json::Value::String(_)) => true,
+        Some(serde_json::Value::Array(blocks)) => blocks
+            .iter()
+            .any(|b| b.get("type").and_then(|t| t.as_str()) == Some("text")),
+        _ => false,
+    }
+}
+
+/// 1-based turn number of each 1-based `line_numbers` entry in a session
+/// file, in input order. A turn starts at each user prompt; lines before the
+/// first prompt belong to turn 1. Unreadable lines count as turn 1.
+pub fn turn_numbers_for_lines(path: &Path, line_numbers: &[usize]) -> Vec<u64> {
+    let Some(&last) = line_numbers.iter().max() else {
+        return Vec::new();
+    };
+    let mut turn_at_line = vec![1u64; last + 1];
+    if let Ok(file) = std::fs::File::open(path) {
+        let mut turn = 0u64;
+        for (idx, line) in BufReader::new(file)
+            .lines()
+            .map_while(Result::ok)
+            .take(last)
+            .enumerate()
+        {
+            if is_user_prompt_line(&line) {
+                turn += 1;
+            }
+            turn_at_line[idx + 1] = turn.max(1);
+        }
+    }
+    line_numbers.iter().map(|&n| turn_at_line[n]).collect()
 }
 
 /// Search raw JSONL files for a regex pattern using ripgrep core crates.
@@ -82,6 +165,7 @@ pub fn grep_files(files: &[JsonlFile], opts: &GrepOptions) -> Result<GrepRespons
                     }
 
                     let mut line_matches: Vec<GrepLineMatch> = Vec::new();
+                    let mut seen_lines: HashSet<u64> = HashSet::new();
 
                     let _ = SearcherBuilder::new()
                         .line_number(true)
@@ -91,6 +175,7 @@ pub fn grep_files(files: &[JsonlFile], opts: &GrepOptions) -> Result<GrepRespons
                             &file.path,
                             MatchCollector {
                                 matches: &mut line_matches,
+                                seen_lines: &mut seen_lines,
                                 matcher: &matcher,
                                 limit,
                                 total_matches,
@@ -129,8 +214,16 @@ pub fn grep_files(files: &[JsonlFile], opts: &GrepOptions) -> Result<GrepRespons
 }
 
 /// Sink implementation that collects grep matches.
+///
+/// Claude Code can append the same message to a JSONL file more than once
+/// (resume/re-parse writes a byte-identical copy, same `uuid` and all). Grep
+/// has no unique constraint, so `seen_lines` drops repeats within one file:
+/// the key is `fxhash` of the full raw line, which already encodes the
+/// message's role, uuid/parent chain, and content. Distinct messages with the
+/// same text differ in `uuid` and are still counted separately.
 struct MatchCollector<'a, M: Matcher> {
     matches: &'a mut Vec<GrepLineMatch>,
+    seen_lines: &'a mut HashSet<u64>,
     matcher: &'a M,
     limit: usize,
     total_matches: &'a AtomicUsize,
@@ -145,6 +238,13 @@ impl<'a, M: Matcher> Sink for MatchCollector<'a, M> {
             return Ok(false);
         }
 
+        let raw = mat.bytes();
+        let raw = raw.strip_suffix(b"\n").unwrap_or(raw);
+        let raw = raw.strip_suffix(b"\r").unwrap_or(raw);
+        if !self.seen_lines.insert(fxhash(raw)) {
+            return Ok(true);
+        }
+
         let line_content = String::from_utf8_lossy(mat.bytes());
         // UTF-8 safe truncation
         let content = if line_content.len() > 500 {
@@ -231,6 +331,7 @@ mod tests {
             project: "alpha".to_string(),
             project_path: tmp.path().to_string_lossy().to_string(),
             modified_at: 1000,
+            is_sidechain: false,
         }];
 
         let result = grep_files(&files, &opts).unwrap();
@@ -261,6 +362,7 @@ mod tests {
             project: "alpha".to_string(),
             project_path: tmp.path().to_string_lossy().to_string(),
             modified_at: 1000,
+            is_sidechain: false,
         }];
 
         let result = grep_files(&files, &opts).unwrap();
@@ -303,6 +405,7 @@ mod tests {
             project: "alpha".to_string(),
             project_path: tmp.path().to_string_lossy().to_string(),
             modified_at: 1000,
+            is_sidechain: false,
         }];
 
         let result = grep_files(&files, &opts);
@@ -333,6 +436,7 @@ mod tests {
             project: "alpha".to_string(),
             project_path: tmp.path().to_string_lossy().to_string(),
             modified_at: 1000,
+            is_sidechain: false,
         }];
 
         let result = grep_files(&files, &opts).unwrap();
@@ -360,6 +464,7 @@ mod tests {
                 project: "alpha".to_string(),
                 project_path: tmp.path().to_string_lossy().to_string(),
                 modified_at: 1000,
+                is_sidechain: false,
             },
             JsonlFile {
                 path: file2,
@@ -367,6 +472,7 @@ mod tests {
                 project: "alpha".to_string(),
                 project_path: tmp.path().to_string_lossy().to_string(),
                 modified_at: 2000,
+                is_sidechain: false,
             },
         ];
 
@@ -377,6 +483,35 @@ mod tests {
         assert_eq!(result.results[1].session_id, "old-session");
     }
 
+    #[test]
+    fn test_grep_dedups_identical_lines_within_session() {
+        let tmp = TempDir::new().unwrap();
+        let line = "{\"uuid\":\"u1\",\"type\":\"user\",\"message\":\"deploy now\"}\n";
+        let other = "{\"uuid\":\"u2\",\"type\":\"user\",\"message\":\"deploy now\"}\n";
+        let file = create_test_jsonl(tmp.path(), "test.jsonl", &format!("{line}{line}{other}"));
+
+        let opts = GrepOptions {
+            pattern: "deploy".to_string(),
+            case_sensitive: false,
+            whole_word: false,
+            limit: 100,
+        };
+
+        let files = vec![JsonlFile {
+            path: file,
+            session_id: "s1".to_string(),
+            project: "alpha".to_string(),
+            project_path: tmp.path().to_string_lossy().to_string(),
+            modified_at: 1000,
+            is_sidechain: false,
+        }];
+
+        let result = grep_files(&files, &opts).unwrap();
+        // The byte-identical copy of u1 is dropped; u2 has distinct identity.
+        assert_eq!(result.total_matches, 2);
+        assert_eq!(result.results[0].matches.len(), 2);
+    }
+
     #[test]
     fn test_grep_no_matches() {
         let tmp = TempDir::new().unwrap();
@@ -395,6 +530,7 @@ mod tests {
             project: "alpha".to_string(),
             project_path: tmp.path().to_string_lossy().to_string(),
             modified_at: 1000,
+            is_sidechain: false,
         }];
 
         let result = grep_files(&files, &opts).unwrap();
@@ -0,0 +1,229 @@
+// crates/search/src/has.rs
+//! `has:` qualifier support for session search.
+//!
+//! `has:attachment`, `has:thinking` and `has:code` keep sessions that contain
+//! an attachment, an extended-thinking block, or a fenced code block in a
+//! user message ("sessions where I pasted code"). Like `attachment:`, these
+//! are existence checks the grep engine cannot express, so each candidate
+//! session file is scanned once for all three.
+
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use claude_view_core::extract_attachments;
+use serde_json::Value;
+
+use crate::grep::JsonlFile;
+use crate::query::{qualifier_value, retain_tokens};
+
+const QUALIFIER: &str = "has:";
+
+/// Marker of a fenced code block.
+const CODE_FENCE: &str = "```";
+
+/// A session feature a `has:` qualifier checks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HasFeature {
+    /// An image or document block in a user message.
+    Attachment,
+    /// A `thinking` / `redacted_thinking` block in an assistant message.
+    Thinking,
+    /// A fenced code block in text the user typed or pasted.
+    Code,
+}
+
+impl HasFeature {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "attachment" => Some(Self::Attachment),
+            "thinking" => Some(Self::Thinking),
+            "code" => Some(Self::Code),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Attachment => "attachment",
+            Self::Thinking => "thinking",
+            Self::Code => "code",
+        }
+    }
+}
+
+/// Split `has:<feature>` tokens out of a query.
+///
+/// Returns the requested features (deduplicated, in query order) and the
+/// remaining free text. Tokens with an unknown feature stay in the text.
+pub fn split_has_qualifiers(query: &str) -> (Vec<HasFeature>, String) {
+    let mut features = Vec::new();
+    let rest = retain_tokens(query, |token| {
+        match qualifier_value(token, QUALIFIER).and_then(HasFeature::parse) {
+            Some(feature) => {
+                if !features.contains(&feature) {
+                    features.push(feature);
+                }
+                false
+            }
+            None => true,
+        }
+    });
+    (features, rest)
+}
+
+/// Which [`HasFeature`]s a session file contains.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SessionFeatures {
+    pub attachment: bool,
+    pub thinking: bool,
+    pub code: bool,
+}
+
+impl SessionFeatures {
+    pub fn has(&self, feature: HasFeature) -> bool {
+        match feature {
+            HasFeature::Attachment => self.attachment,
+            HasFeature::Thinking => self.thinking,
+            HasFeature::Code => self.code,
+        }
+    }
+
+    fn all(&self) -> bool {
+        self.attachment && self.thinking && self.code
+    }
+}
+
+/// Scan a session file for [`SessionFeatures`].
+///
+/// Unreadable files and malformed lines count as having none.
+pub fn session_features(path: &Path) -> SessionFeatures {
+    let mut found = SessionFeatures::default();
+    let Ok(file) = std::fs::File::open(path) else {
+        return found;
+    };
+    for line in BufReader::new(file).lines().map_while(Result::ok) {
+        if found.all() {
+            break;
+        }
+        // Cheap pre-check: skip lines that cannot add a missing feature.
+        let maybe_attachment =
+            !found.attachment && (line.contains("\"image\"") || line.contains("\"document\""));
+        let maybe_thinking = !found.thinking && line.contains("thinking\"");
+        let maybe_code = !found.code && line.contains(CODE_FENCE);
+        if !maybe_attachment && !maybe_thinking && !maybe_code {
+            continue;
+        }
+        let Ok(value) = serde_json::from_str::<Value>(&line) else {
+            continue;
+        };
+        let content = value.get("message").and_then(|m| m.get("content"));
+        match value.get("type").and_then(|t| t.as_str()) {
+            Some("user") => {
+                if let Some(blocks) = content.and_then(|c| c.as_array()) {
+                    found.attachment |= !extract_attachments(blocks).is_empty();
+                }
+                found.code |= user_text_has_code(content);
+            }
+            Some("assistant") => {
+                found.thinking |= content.and_then(|c| c.as_array()).is_some_and(|blocks| {
+                    blocks.iter().any(|b| {
+                        matches!(
+                            b.get("type").and_then(|t| t.as_str()),
+                            Some("thinking" | "redacted_thinking")
+                        )
+                    })
+                });
+            }
+            _ => {}
+        }
+    }
+    found
+}
+
+/// Whether user message `content` has a code fence in its text. Tool
+/// results are skipped: their output is not something the user wrote.
+fn user_text_has_code(content: Option<&Value>) -> bool {
+    match content {
+        Some(Value::String(text)) => text.contains(CODE_FENCE),
+        Some(Value::Array(blocks)) => blocks.iter().any(|b| {
+            b.get("type").and_then(|t| t.as_str()) == Some("text")
+                && b.get("text")
+                    .and_then(|t| t.as_str())
+                    .is_some_and(|t| t.contains(CODE_FENCE))
+        }),
+        _ => false,
+    }
+}
+
+/// Keep sessions that have every feature in `features`.
+pub fn filter_by_features(files: &[JsonlFile], features: &[HasFeature]) -> Vec<JsonlFile> {
+    files
+        .iter()
+        .filter(|file| {
+            let found = session_features(&file.path);
+            features.iter().all(|&f| found.has(f))
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_has_qualifiers() {
+        let (features, rest) =
+            split_has_qualifiers("has:code deploy has:Thinking has:code has:nothing");
+        assert_eq!(features, vec![HasFeature::Code, HasFeature::Thinking]);
+        assert_eq!(rest, "deploy has:nothing");
+
+        let (features, rest) = split_has_qualifiers("has:code fix  the\tparser");
+        assert_eq!(features, vec![HasFeature::Code]);
+        assert_eq!(rest, "fix  the\tparser");
+    }
+
+    #[test]
+    fn test_session_features() {
+        let tmp = tempfile::tempdir().unwrap();
+        let write = |name: &str, lines: &[&str]| {
+            let path = tmp.path().join(name);
+            std::fs::write(&path, lines.join("\n")).unwrap();
+            session_features(&path)
+        };
+
+        let attachment = write(
+            "a.jsonl",
+            &[
+                r#"{"type":"user","message":{"content":[{"type":"image","source":{"type":"base64","media_type":"image/png","data":"x"}}]}}"#,
+            ],
+        );
+        assert!(attachment.attachment && !attachment.thinking && !attachment.code);
+
+        let thinking = write(
+            "t.jsonl",
+            &[
+                r#"{"type":"user","message":{"content":"why?"}}"#,
+                r#"{"type":"assistant","message":{"content":[{"type":"thinking","thinking":"hmm"},{"type":"text","text":"because"}]}}"#,
+            ],
+        );
+        assert!(thinking.thinking && !thinking.attachment && !thinking.code);
+
+        let code = write(
+            "c.jsonl",
+            &[r#"{"type":"user","message":{"content":"fix this:\n```rust\nfn main() {}\n```"}}"#],
+        );
+        assert!(code.code && !code.attachment && !code.thinking);
+
+        // Fences in tool output or assistant text do not count as pasted code.
+        let none = write(
+            "n.jsonl",
+            &[
+                r#"{"type":"user","message":{"content":[{"type":"tool_result","content":"```\nls\n```"}]}}"#,
+                r#"{"type":"assistant","message":{"content":[{"type":"text","text":"```sh\nls\n```"}]}}"#,
+                r#"{"type":"user","message":{"content":"mentions an image and thinking"}}"#,
+            ],
+        );
+        assert_eq!(none, SessionFeatures::default());
+    }
+}
@@ -7,17 +7,35 @@
 //!
 //! - **Session runtime**: raw JSONL files -> ripgrep-core -> grouped snippets
 //! - **Prompt history**: `prompt_index::PromptSearchIndex`
+//! - **Related sessions**: `related::related_sessions` (term overlap)
+//! - **Attachments**: `attachments` resolves the `attachment:` qualifier
+//! - **Features**: `has` resolves `has:attachment` / `has:thinking` / `has:code`
 
+pub mod attachments;
+pub mod config;
+pub mod fields;
 pub mod grep;
 pub mod grep_types;
+pub mod has;
 pub mod prompt_index;
+pub mod query;
+pub mod related;
 pub mod types;
 pub mod unified;
 
-pub use grep::JsonlFile;
-pub use types::{MatchHit, SearchResponse, SessionHit};
+pub use config::{Bm25Params, DayPartClock, IdleOptimizeConfig, SearchIndexConfig, StopWords};
+pub use fields::SearchFields;
+pub use grep::{jsonl_is_sidechain, JsonlFile};
+pub use query::{
+    qualifier_value, session_qualifier_value, split_mcp_qualifiers, split_range_qualifiers,
+    split_session_qualifiers, split_sidechain_qualifier, tokenize_query, wildcard_prefix,
+    NumericRange, RangeQualifiers, MIN_WILDCARD_PREFIX_CHARS,
+};
+pub use related::related_sessions;
+pub use types::{MatchHit, SearchResponse, SearchTimings, SessionHit};
 pub use unified::{
-    unified_search, SearchEngine, UnifiedSearchError, UnifiedSearchOptions, UnifiedSearchResult,
+    unified_search, RoleBoosts, SearchEngine, UnifiedSearchError, UnifiedSearchOptions,
+    UnifiedSearchResult,
 };
 
 /// Writer heap size for bulk prompt-history indexing.
@@ -37,6 +55,15 @@ pub enum SearchError {
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
+    #[error("Invalid wildcard `{0}`: needs at least 2 letters or digits before a trailing `*`")]
+    InvalidWildcard(String),
+
+    #[error("Unknown day-part `{0}`: expected morning, afternoon, evening or night")]
+    InvalidDayPart(String),
+
     #[error("Index not ready")]
     NotReady,
+
+    #[error("No prompt in session {0} matches the query")]
+    NoMatch(String),
 }
@@ -0,0 +1,123 @@
+//! BM25 re-scoring with caller-supplied `k1` / `b`.
+//!
+//! Tantivy 0.22 hard-codes `K1 = 1.2` and `B = 0.75` inside `Bm25Weight`.
+//! When a [`Bm25Params`] differs from those, the search path re-scores each
+//! collected doc via `TopDocs::tweak_score`: the per-term `tf` comes from the
+//! segment postings, the field length from the fieldnorm reader, and `idf` /
+//! average length from the same `Bm25StatisticsProvider` stats Tantivy uses.
+
+use tantivy::postings::{Postings, SegmentPostings};
+use tantivy::query::{Bm25StatisticsProvider, Query};
+use tantivy::schema::{Field, IndexRecordOption};
+use tantivy::{DocId, DocSet, Score, Searcher, SegmentReader, Term};
+
+use crate::config::Bm25Params;
+use crate::SearchError;
+
+/// Per-term statistics resolved once per search, shared across segments.
+struct TermStats {
+    term: Term,
+    idf: Score,
+    avg_fieldnorm: Score,
+}
+
+/// Computes BM25 with custom parameters for the free-text terms of a query.
+pub(crate) struct Bm25Rescorer {
+    params: Bm25Params,
+    terms: Vec<TermStats>,
+}
+
+/// Per-segment cursor state for one query term.
+struct SegmentTerm {
+    postings: SegmentPostings,
+    fieldnorms: tantivy::fieldnorm::FieldNormReader,
+    idf: Score,
+    avg_fieldnorm: Score,
+}
+
+impl Bm25Rescorer {
+    /// Collect the terms of `query` that target one of `fields`.
+    pub(crate) fn new(
+        searcher: &Searcher,
+        query: &dyn Query,
+        fields: &[Field],
+        params: Bm25Params,
+    ) -> Result<Self, SearchError> {
+        let mut raw: Vec<Term> = Vec::new();
+        query.query_terms(&mut |term, _| {
+            if fields.contains(&term.field()) && !raw.contains(term) {
+                raw.push(term.clone());
+            }
+        });
+
+        let total_docs = searcher.total_num_docs()?;
+        let mut terms = Vec::with_capacity(raw.len());
+        for term in raw {
+            let total_tokens = searcher.total_num_tokens(term.field())?;
+            let doc_freq = Bm25StatisticsProvider::doc_freq(searcher, &term)?;
+            terms.push(TermStats {
+                idf: idf(doc_freq, total_docs),
+                avg_fieldnorm: if total_docs == 0 {
+                    1.0
+                } else {
+                    (total_tokens as Score / total_docs as Score).max(Score::EPSILON)
+                },
+                term,
+            });
+        }
+        Ok(Self { params, terms })
+    }
+
+    /// Build the per-segment scoring closure for `TopDocs::tweak_score`.
+    ///
+    /// Docs arrive in ascending `DocId` order within a segment, so each
+    /// term's postings cursor only ever moves forward.
+    pub(crate) fn segment_scorer(
+        &self,
+        segment_reader: &SegmentReader,
+    ) -> impl FnMut(DocId, Score) -> Score + 'static {
+        let Bm25Params { k1, b } = self.params;
+        let mut segment_terms: Vec<SegmentTerm> = self
+            .terms
+            .iter()
+            .filter_map(|t| {
+                let postings = segment_reader
+                    .inverted_index(t.term.field())
+                    .ok()?
+                    .read_postings(&t.term, IndexRecordOption::WithFreqs)
+                    .ok()??;
+                let fieldnorms = segment_reader.get_fieldnorms_reader(t.term.field()).ok()?;
+                Some(SegmentTerm {
+                    postings,
+                    fieldnorms,
+                    idf: t.idf,
+                    avg_fieldnorm: t.avg_fieldnorm,
+                })
+            })
+            .collect();
+
+        move |doc: DocId, _original: Score| {
+            let mut score: Score = 0.0;
+            for st in segment_terms.iter_mut() {
+                if st.postings.doc() < doc {
+                    st.postings.seek(doc);
+                }
+                if st.postings.doc() != doc {
+                    continue;
+                }
+                let tf = st.postings.term_freq() as Score;
+                let fieldnorm = st.fieldnorms.fieldnorm(doc) as Score;
+                let norm = k1 * (1.0 - b + b * fieldnorm / st.avg_fieldnorm);
+                score += st.idf * (k1 + 1.0) * tf / (tf + norm);
+            }
+            score
+        }
+    }
+}
+
+/// Same IDF formula as Tantivy's `bm25::idf`.
+fn idf(doc_freq: u64, doc_count: u64) -> Score {
+    let doc_freq = doc_freq.min(doc_count);
+    let x = ((doc_count - doc_freq) as Score + 0.5) / (doc_freq as Score + 0.5);
+    (1.0 + x).ln()
+}
@@ -2,25 +2,51 @@
 
 use std::path::Path;
 use std::sync::Mutex;
+use std::time::Instant;
 
-use tantivy::{doc, Index, ReloadPolicy};
+use tantivy::query::RegexQuery;
+use tantivy::{doc, Index, ReloadPolicy, Term};
 
 use claude_view_core::prompt_templates::normalize_to_template;
 
+use crate::config::SearchIndexConfig;
 use crate::{SearchError, BULK_WRITER_HEAP, INCREMENTAL_WRITER_HEAP};
 
 use super::types::{
-    build_prompt_schema, fxhash, PromptDocument, PromptSearchIndex, PROMPT_SCHEMA_VERSION,
+    build_prompt_schema, fxhash, prompt_text_analyzer, DayPart, IdleState, PromptDocument,
+    PromptSearchIndex, PROMPT_SCHEMA_VERSION, PROMPT_TEXT_TOKENIZER,
 };
 
+/// `text` cut to at most `max_bytes`, backing off to a char boundary.
+/// Unchanged when it fits or there is no limit.
+fn truncate_content(text: &str, max_bytes: Option<usize>) -> &str {
+    match max_bytes {
+        Some(max) if text.len() > max => {
+            let mut end = max;
+            while !text.is_char_boundary(end) {
+                end -= 1;
+            }
+            &text[..end]
+        }
+        _ => text,
+    }
+}
+
 impl PromptSearchIndex {
     /// Open or create a prompt index at the given path.
-    /// Schema version mismatch triggers a full wipe and rebuild.
+    /// Schema version mismatch triggers a full wipe and rebuild, as does an
+    /// existing index that fails to open (e.g. a crash mid-commit left
+    /// `meta.json` or a segment unreadable).
     pub fn open(path: &Path) -> Result<Self, SearchError> {
+        Self::open_with_config(path, SearchIndexConfig::default())
+    }
+
+    /// [`Self::open`] with explicit tunables (e.g. BM25 `k1` / `b`).
+    pub fn open_with_config(path: &Path, config: SearchIndexConfig) -> Result<Self, SearchError> {
         std::fs::create_dir_all(path)?;
 
         let version_path = path.join("schema_version");
-        let needs_rebuild = match std::fs::read_to_string(&version_path) {
+        let mut needs_rebuild = match std::fs::read_to_string(&version_path) {
             Ok(v) => v.trim().parse::<u32>().unwrap_or(0) != PROMPT_SCHEMA_VERSION,
             Err(_) => true,
         };
@@ -30,43 +56,47 @@ impl PromptSearchIndex {
                 path = %path.display(),
                 "Prompt index schema version mismatch — rebuilding"
             );
-            if let Ok(entries) = std::fs::read_dir(path) {
-                for entry in entries.flatten() {
-                    let p = entry.path();
-                    if p.file_name()
-                        .map(|n| n != "schema_version")
-                        .unwrap_or(false)
-                    {
-                        if p.is_dir() {
-                            let _ = std::fs::remove_dir_all(&p);
-                        } else {
-                            let _ = std::fs::remove_file(&p);
-                        }
-                    }
-                }
-            }
+            clear_index_dir(path);
         }
 
         let schema = build_prompt_schema();
-        let index = match Index::open_in_dir(path) {
-            Ok(idx) => {
-                tracing::info!(path = %path.display(), "opened existing prompt index");
-                idx
-            }
-            Err(_) => {
-                tracing::info!(path = %path.display(), "creating new prompt index");
-                Index::create_in_dir(path, schema.clone())?
+        let index = if !path.join("meta.json").exists() {
+            tracing::info!(path = %path.display(), "creating new prompt index");
+            Index::create_in_dir(path, schema.clone())?
+        } else {
+            // Opening a reader loads every segment, so a missing or truncated
+            // segment file surfaces here rather than on the first search.
+            match Index::open_in_dir(path).and_then(|idx| idx.reader().map(|_| idx)) {
+                Ok(idx) => {
+                    tracing::info!(path = %path.display(), "opened existing prompt index");
+                    idx
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        path = %path.display(),
+                        error = %e,
+                        "Prompt index is corrupted — wiping it; a full rebuild is needed"
+                    );
+                    clear_index_dir(path);
+                    needs_rebuild = true;
+                    Index::create_in_dir(path, schema.clone())?
+                }
             }
         };
 
-        Self::from_index(index, schema, needs_rebuild, Some(version_path))
+        Self::from_index(index, schema, needs_rebuild, Some(version_path), config)
     }
 
     /// Create a prompt index in RAM (for tests).
     pub fn open_in_ram() -> Result<Self, SearchError> {
+        Self::open_in_ram_with_config(SearchIndexConfig::default())
+    }
+
+    /// [`Self::open_in_ram`] with explicit tunables.
+    pub fn open_in_ram_with_config(config: SearchIndexConfig) -> Result<Self, SearchError> {
         let schema = build_prompt_schema();
         let index = Index::create_in_ram(schema.clone());
-        Self::from_index(index, schema, false, None)
+        Self::from_index(index, schema, false, None, config)
     }
 
     /// Internal: set up reader, writer, and field handles from an Index + Schema.
@@ -75,7 +105,12 @@ impl PromptSearchIndex {
         schema: tantivy::schema::Schema,
         needs_full_reindex: bool,
         version_file_path: Option<std::path::PathBuf>,
+        config: SearchIndexConfig,
     ) -> Result<Self, SearchError> {
+        index.tokenizers().register(
+            PROMPT_TEXT_TOKENIZER,
+            prompt_text_analyzer(&config.stop_words),
+        );
         let reader = index
             .reader_builder()
             .reload_policy(ReloadPolicy::OnCommitWithDelay)
@@ -85,6 +120,9 @@ impl PromptSearchIndex {
 
         let prompt_id_field = schema.get_field("prompt_id").expect("missing prompt_id");
         let display_field = schema.get_field("display").expect("missing display");
+        let display_stored_field = schema
+            .get_field("display_stored")
+            .expect("missing display_stored");
         let paste_text_field = schema.get_field("paste_text").expect("missing paste_text");
         let project_field = schema.get_field("project").expect("missing project");
         let session_id_field = schema.get_field("session_id").expect("missing session_id");
@@ -101,6 +139,11 @@ impl PromptSearchIndex {
         let is_template_field = schema
             .get_field("is_template")
             .expect("missing is_template");
+        let project_display_field = schema
+            .get_field("project_display")
+            .expect("missing project_display");
+        let daypart_field = schema.get_field("daypart").expect("missing daypart");
+        let skills_field = schema.get_field("skills").expect("missing skills");
 
         Ok(Self {
             index,
@@ -109,8 +152,11 @@ impl PromptSearchIndex {
             schema,
             needs_full_reindex,
             version_file_path,
+            config,
+            idle: Mutex::new(IdleState::new(Instant::now())),
             prompt_id_field,
             display_field,
+            display_stored_field,
             paste_text_field,
             project_field,
             session_id_field,
@@ -123,6 +169,9 @@ impl PromptSearchIndex {
             has_paste_field,
             template_id_field,
             is_template_field,
+            project_display_field,
+            daypart_field,
+            skills_field,
         })
     }
 
@@ -135,24 +184,34 @@ impl PromptSearchIndex {
         let writer = guard
             .as_ref()
             .ok_or_else(|| SearchError::Io(std::io::Error::other("writer missing after ensure")))?;
+        let max_bytes = self.config.max_content_bytes;
         for d in docs {
             let mut tantivy_doc = doc!(
                 self.prompt_id_field => d.prompt_id.as_str(),
-                self.display_field => d.display.as_str(),
+                self.display_field => truncate_content(&d.display, max_bytes),
+                self.display_stored_field => d.display.as_str(),
                 self.project_field => d.project.as_str(),
+                self.project_display_field => d.project_display.as_str(),
                 self.session_id_field => d.session_id.as_deref().unwrap_or(""),
                 self.branch_field => d.branch.as_str(),
                 self.model_field => d.model.as_str(),
+                self.skills_field => d.skills.as_str(),
                 self.git_root_field => d.git_root.as_str(),
                 self.intent_field => d.intent.as_str(),
                 self.complexity_field => d.complexity.as_str(),
                 self.timestamp_field => d.timestamp,
                 self.has_paste_field => if d.has_paste { "true" } else { "false" },
+                self.daypart_field => DayPart::from_timestamp(
+                    d.timestamp,
+                    self.config.daypart_clock,
+                )
+                .as_str(),
             );
             if let Some(ref paste) = d.paste_text {
-                tantivy_doc.add_text(self.paste_text_field, paste);
+                tantivy_doc.add_text(self.paste_text_field, truncate_content(paste, max_bytes));
             }
-            // Compute template classification: normalize display text to detect slots.
+            // Compute template classification: normalize the full (untruncated)
+            // display text to detect slots.
             // template_id field stores the stable hash of the normalized pattern (empty = unique).
             // is_template field stores "true"/"false" for fast TermQuery filtering.
             let normalized = normalize_to_template(&d.display);
@@ -168,9 +227,64 @@ impl PromptSearchIndex {
         Ok(())
     }
 
+    /// Delete every prompt whose `project` or `git_root` equals `project`
+    /// (the same match the `project:` scope filter uses). Takes effect on
+    /// the next [`commit`](Self::commit). An empty `project` is a no-op.
+    pub fn delete_by_project(&self, project: &str) -> Result<(), SearchError> {
+        // Many prompts have an empty git_root; never treat "" as a project.
+        if project.is_empty() {
+            return Ok(());
+        }
+        self.ensure_writer(INCREMENTAL_WRITER_HEAP)?;
+        let guard = self.writer.lock().map_err(|e| {
+            SearchError::Io(std::io::Error::other(format!("writer lock poisoned: {e}")))
+        })?;
+        let writer = guard
+            .as_ref()
+            .ok_or_else(|| SearchError::Io(std::io::Error::other("writer missing after ensure")))?;
+        writer.delete_term(Term::from_field_text(self.project_field, project));
+        writer.delete_term(Term::from_field_text(self.git_root_field, project));
+        Ok(())
+    }
+
+    /// Delete every prompt whose `prompt_id` starts with `prefix`. Takes
+    /// effect on the next [`commit`](Self::commit). An empty `prefix` is a
+    /// no-op.
+    pub fn delete_by_prompt_id_prefix(&self, prefix: &str) -> Result<(), SearchError> {
+        if prefix.is_empty() {
+            return Ok(());
+        }
+        self.ensure_writer(INCREMENTAL_WRITER_HEAP)?;
+        let guard = self.writer.lock().map_err(|e| {
+            SearchError::Io(std::io::Error::other(format!("writer lock poisoned: {e}")))
+        })?;
+        let writer = guard
+            .as_ref()
+            .ok_or_else(|| SearchError::Io(std::io::Error::other("writer missing after ensure")))?;
+        let pattern = format!("{}.*", escape_regex(prefix));
+        writer.delete_query(Box::new(RegexQuery::from_pattern(
+            &pattern,
+            self.prompt_id_field,
+        )?))?;
+        Ok(())
+    }
+
     /// Commit pending writes to disk.
     /// Call this after indexing a batch of prompts.
+    ///
+    /// Same as [`commit_and_reload`](Self::commit_and_reload): results are
+    /// searchable as soon as this returns.
     pub fn commit(&self) -> Result<(), SearchError> {
+        self.commit_and_reload().map(|_| ())
+    }
+
+    /// Commit pending writes and reload the reader before returning, so a
+    /// search issued right after sees every document committed here.
+    ///
+    /// The reader's own `OnCommitWithDelay` policy would also pick up the
+    /// commit, but only once Tantivy's meta-file watcher fires (typically
+    /// ~500ms later, longer on slow filesystems). Returns the commit opstamp.
+    pub fn commit_and_reload(&self) -> Result<u64, SearchError> {
         self.ensure_writer(INCREMENTAL_WRITER_HEAP)?;
         let mut guard = self.writer.lock().map_err(|e| {
             SearchError::Io(std::io::Error::other(format!("writer lock poisoned: {e}")))
@@ -178,10 +292,12 @@ impl PromptSearchIndex {
         let writer = guard
             .as_mut()
             .ok_or_else(|| SearchError::Io(std::io::Error::other("writer missing after ensure")))?;
-        writer.commit()?;
+        let opstamp = writer.commit()?;
+        // Synchronous: the next `searcher()` is built from the new generation.
         self.reader.reload()?;
-        tracing::info!("prompt index committed");
-        Ok(())
+        self.record_write(Instant::now());
+        tracing::info!(opstamp, "prompt index committed");
+        Ok(opstamp)
     }
 
     /// Ensure a writer exists. If `None`, create one with the given heap size.
@@ -221,3 +337,34 @@ impl PromptSearchIndex {
         }
     }
 }
+
+/// `text` with regex metacharacters backslash-escaped.
+fn escape_regex(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if r"\.+*?()|[]{}^$#&-~".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Remove everything in an index directory except the `schema_version`
+/// marker. Best-effort: entries that cannot be removed are left behind.
+fn clear_index_dir(path: &Path) {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let p = entry.path();
+        if p.file_name().is_some_and(|n| n == "schema_version") {
+            continue;
+        }
+        if p.is_dir() {
+            let _ = std::fs::remove_dir_all(&p);
+        } else {
+            let _ = std::fs::remove_file(&p);
+        }
+    }
+}
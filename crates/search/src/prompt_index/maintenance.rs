@@ -0,0 +1,89 @@
+//! Index upkeep: size stats and segment merging while idle.
+
+use std::time::Instant;
+
+use crate::{SearchError, INCREMENTAL_WRITER_HEAP};
+
+use super::types::{IdleState, PromptIndexStats, PromptSearchIndex};
+
+impl PromptSearchIndex {
+    /// Document and segment counts visible to the current reader.
+    pub fn stats(&self) -> PromptIndexStats {
+        let searcher = self.reader.searcher();
+        PromptIndexStats {
+            num_docs: searcher.num_docs(),
+            num_segments: searcher.segment_readers().len(),
+        }
+    }
+
+    /// Merge all searchable segments into one and drop the merged files.
+    ///
+    /// Uses the existing writer if there is one; otherwise opens one for the
+    /// merge and releases it afterwards so an idle index keeps its memory low.
+    pub fn optimize(&self) -> Result<(), SearchError> {
+        let segment_ids = self.index.searchable_segment_ids()?;
+        if segment_ids.len() <= 1 {
+            return Ok(());
+        }
+
+        let had_writer = self.writer_is_open()?;
+        self.ensure_writer(INCREMENTAL_WRITER_HEAP)?;
+        {
+            let mut guard = self.writer.lock().map_err(|e| {
+                SearchError::Io(std::io::Error::other(format!("writer lock poisoned: {e}")))
+            })?;
+            let writer = guard.as_mut().ok_or_else(|| {
+                SearchError::Io(std::io::Error::other("writer missing after ensure"))
+            })?;
+            writer.merge(&segment_ids).wait()?;
+            writer.garbage_collect_files().wait()?;
+        }
+        if !had_writer {
+            self.release_writer()?;
+        }
+        self.reader.reload()?;
+        tracing::info!(
+            merged_segments = segment_ids.len(),
+            "prompt index optimized"
+        );
+        Ok(())
+    }
+
+    /// Run [`optimize`](Self::optimize) once per idle stretch: when nothing
+    /// has been committed for `idle_optimize.idle_after` and the index holds
+    /// more than `idle_optimize.max_segments` segments. Returns whether it ran.
+    pub fn optimize_if_idle(&self, now: Instant) -> Result<bool, SearchError> {
+        let thresholds = self.config.idle_optimize;
+        let state = *self.idle_state()?;
+        if state.optimized
+            || now.saturating_duration_since(state.last_write_at) < thresholds.idle_after
+            || self.stats().num_segments <= thresholds.max_segments
+        {
+            return Ok(false);
+        }
+
+        self.optimize()?;
+        self.idle_state()?.optimized = true;
+        Ok(true)
+    }
+
+    /// Note a committed write; the next idle stretch may optimize again.
+    pub(crate) fn record_write(&self, now: Instant) {
+        if let Ok(mut state) = self.idle.lock() {
+            *state = IdleState::new(now);
+        }
+    }
+
+    fn idle_state(&self) -> Result<std::sync::MutexGuard<'_, IdleState>, SearchError> {
+        self.idle
+            .lock()
+            .map_err(|e| SearchError::Io(std::io::Error::other(format!("idle lock poisoned: {e}"))))
+    }
+
+    fn writer_is_open(&self) -> Result<bool, SearchError> {
+        let guard = self.writer.lock().map_err(|e| {
+            SearchError::Io(std::io::Error::other(format!("writer lock poisoned: {e}")))
+        })?;
+        Ok(guard.is_some())
+    }
+}
@@ -3,14 +3,17 @@
 //! Indexes `~/.claude/history.jsonl` entries into a Tantivy full-text index
 //! with per-prompt metadata for qualifier-based filtering.
 
+mod bm25;
 mod indexing;
+mod maintenance;
 mod search;
 mod types;
 
 #[cfg(test)]
 mod tests;
 
+pub(crate) use types::fxhash;
 pub use types::{
-    PromptDocument, PromptHit, PromptSearchIndex, PromptSearchParams, PromptSearchResponse,
-    PROMPT_SCHEMA_VERSION,
+    DayPart, PromptDocument, PromptHit, PromptIndexStats, PromptSearchIndex, PromptSearchParams,
+    PromptSearchResponse, PROMPT_SCHEMA_VERSION,
 };
@@ -1,20 +1,44 @@
 //! Search and query execution for the prompt index.
 
 use tantivy::collector::{Count, TopDocs};
-use tantivy::query::{BooleanQuery, Occur, QueryParser, RangeQuery, TermQuery};
+use tantivy::query::{
+    BooleanQuery, EmptyQuery, Occur, QueryParser, RangeQuery, RegexQuery, TermQuery,
+};
 use tantivy::schema::{Field, IndexRecordOption, Value};
 use tantivy::snippet::SnippetGenerator;
 use tantivy::Term;
 
+use crate::query::{
+    qualifier_value, session_qualifier_value, split_raw_segment, tokenize_query, wildcard_prefix,
+};
 use crate::SearchError;
 
-use super::types::{PromptHit, PromptSearchIndex, PromptSearchParams, PromptSearchResponse};
+use super::bm25::Bm25Rescorer;
+use super::types::{
+    DayPart, PromptHit, PromptSearchIndex, PromptSearchParams, PromptSearchResponse,
+};
 
 impl PromptSearchIndex {
     /// Search the prompt index with optional qualifier filtering.
     ///
-    /// Supports qualifiers: `project:`, `intent:`, `branch:`, `complexity:`.
-    /// Free-text searches both `display` and `paste_text` fields.
+    /// Supports qualifiers: `project:`, `intent:`, `branch:`, `complexity:`,
+    /// `when:` (`morning` / `afternoon` / `evening` / `night`, see
+    /// [`DayPart`]), `session:<id>` (exact session id), and
+    /// `project_display:`, `model:` and `skill:` (every word must appear in
+    /// the project name, model or invoked skills, so `project_display:app`
+    /// matches "My App").
+    /// Values containing spaces can be quoted: `project:"My App"`.
+    /// Free-text searches both `display` and `paste_text` fields. A trailing
+    /// `*` matches by prefix (`data*` matches "database").
+    /// `raw:(...)` (or `raw:` to the end of the query) is handed to Tantivy's
+    /// query parser as-is, e.g. `raw:(+foo -bar)`, and ANDed with the
+    /// rest; a syntax error there is [`SearchError::QueryParse`], and an
+    /// unknown `when:` value is [`SearchError::InvalidDayPart`].
+    ///
+    /// `limit` is clamped to the index's
+    /// [`max_results`](crate::config::SearchIndexConfig::max_results), and
+    /// `limit == 0` returns `total_matches` with no prompts, as does an
+    /// `offset` past the last match.
     pub fn search(
         &self,
         query: &str,
@@ -31,24 +55,228 @@ impl PromptSearchIndex {
         })
     }
 
-    /// Extended search with full filter support.
+    /// Extended search with full filter support. `limit` is handled as in
+    /// [`Self::search`].
     pub fn search_with(
         &self,
         params: PromptSearchParams<'_>,
     ) -> Result<PromptSearchResponse, SearchError> {
-        let query = params.query;
-        let scope = params.scope;
-        let limit = params.limit;
+        let limit = params.limit.min(self.config.max_results);
         let offset = params.offset;
         let start = std::time::Instant::now();
         let searcher = self.reader.searcher();
 
+        let (final_query, has_text) = self.build_query(&params)?;
+
+        // Build snippet generator when free-text query is present (TEXT field only).
+        let snippet_gen = if has_text {
+            SnippetGenerator::create(&searcher, &*final_query, self.display_field).ok()
+        } else {
+            None
+        };
+        // Prompts that matched on metadata rather than text show the
+        // matching field instead (see `metadata_snippet`).
+        let metadata_gens: Vec<(&str, SnippetGenerator)> =
+            [("model", self.model_field), ("skill", self.skills_field)]
+                .into_iter()
+                .filter_map(|(label, field)| {
+                    SnippetGenerator::create(&searcher, &*final_query, field)
+                        .ok()
+                        .map(|gen| (label, gen))
+                })
+                .collect();
+
+        let total_matches = searcher.search(&*final_query, &Count)?;
+        // Counts only: TopDocs panics on a zero limit. An offset past the end
+        // would also size TopDocs from the caller's number, so stop here.
+        if limit == 0 || offset >= total_matches {
+            return Ok(PromptSearchResponse {
+                prompts: Vec::new(),
+                total_matches,
+                elapsed_ms: start.elapsed().as_millis() as u64,
+            });
+        }
+        let fetch = limit.saturating_add(offset);
+        // Sort: newest (default) = descending timestamp, oldest = ascending timestamp.
+        // When a free-text query is present we keep relevance score as primary sort
+        // (matches user expectation for search results).
+        let sort_oldest = params.sort.map(|s| s == "oldest").unwrap_or(false);
+        let top_docs = if sort_oldest {
+            searcher
+                .search(
+                    &*final_query,
+                    &TopDocs::with_limit(fetch)
+                        .order_by_fast_field::<i64>("timestamp", tantivy::Order::Asc),
+                )?
+                .into_iter()
+                .map(|(_, addr)| (0.0f32, addr))
+                .collect::<Vec<_>>()
+        } else if !has_text {
+            // No text query — sort by newest first
+            searcher
+                .search(
+                    &*final_query,
+                    &TopDocs::with_limit(fetch)
+                        .order_by_fast_field::<i64>("timestamp", tantivy::Order::Desc),
+                )?
+                .into_iter()
+                .map(|(_, addr)| (0.0f32, addr))
+                .collect::<Vec<_>>()
+        } else if self.config.bm25.is_tantivy_default() {
+            // Text query present — use relevance score (BM25), newest as tiebreaker
+            searcher.search(&*final_query, &TopDocs::with_limit(fetch))?
+        } else {
+            // Custom k1/b — Tantivy can't take them natively, so re-score.
+            let rescorer = Bm25Rescorer::new(
+                &searcher,
+                &*final_query,
+                &[self.display_field, self.paste_text_field],
+                self.config.bm25,
+            )?;
+            searcher.search(
+                &*final_query,
+                &TopDocs::with_limit(fetch).tweak_score(
+                    move |segment_reader: &tantivy::SegmentReader| {
+                        rescorer.segment_scorer(segment_reader)
+                    },
+                ),
+            )?
+        };
+
+        let mut prompts = Vec::with_capacity(limit.min(top_docs.len()));
+        for (_score, doc_addr) in top_docs.into_iter().skip(offset) {
+            if prompts.len() >= limit {
+                break;
+            }
+            let retrieved = searcher.doc::<tantivy::TantivyDocument>(doc_addr)?;
+
+            let get_text = |field: Field| -> String {
+                retrieved
+                    .get_first(field)
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("")
+                    .to_string()
+            };
+
+            let session_id_str = get_text(self.session_id_field);
+            let timestamp = retrieved
+                .get_first(self.timestamp_field)
+                .and_then(|v| v.as_i64())
+                .unwrap_or(0);
+            let has_paste_str = get_text(self.has_paste_field);
+
+            let display = get_text(self.display_stored_field);
+            let text_snippet = snippet_gen
+                .as_ref()
+                .map(|gen| gen.snippet(&display).to_html())
+                .filter(|html| !html.is_empty());
+            let snippet = text_snippet.or_else(|| metadata_snippet(&metadata_gens, &retrieved));
+
+            let raw_template_id = get_text(self.template_id_field);
+            let template_id = if raw_template_id.is_empty() {
+                None
+            } else {
+                Some(raw_template_id)
+            };
+
+            prompts.push(PromptHit {
+                prompt_id: get_text(self.prompt_id_field),
+                display,
+                snippet,
+                template_id,
+                project: get_text(self.project_field),
+                session_id: if session_id_str.is_empty() {
+                    None
+                } else {
+                    Some(session_id_str)
+                },
+                branch: get_text(self.branch_field),
+                model: get_text(self.model_field),
+                git_root: get_text(self.git_root_field),
+                intent: get_text(self.intent_field),
+                complexity: get_text(self.complexity_field),
+                timestamp,
+                has_paste: has_paste_str == "true",
+            });
+        }
+
+        Ok(PromptSearchResponse {
+            prompts,
+            total_matches,
+            elapsed_ms: start.elapsed().as_millis() as u64,
+        })
+    }
+
+    /// Human-readable relevance breakdown for the best-scoring prompt of
+    /// `session_id` under `query` (same syntax as [`Self::search`]).
+    ///
+    /// Lists the query terms, then Tantivy's BM25 explanation tree, one
+    /// node per line as `score  description`. The tree uses Tantivy's
+    /// default k1/b even when the index is configured with custom
+    /// [`Bm25Params`](crate::config::Bm25Params).
+    pub fn explain(&self, query: &str, session_id: &str) -> Result<String, SearchError> {
+        let searcher = self.reader.searcher();
+        let (text_query, has_text) = self.build_query(&PromptSearchParams {
+            query,
+            ..Default::default()
+        })?;
+        let session_query = TermQuery::new(
+            Term::from_field_text(self.session_id_field, session_id),
+            IndexRecordOption::Basic,
+        );
+        let scoped = BooleanQuery::new(vec![
+            (Occur::Must, text_query.box_clone()),
+            (Occur::Must, Box::new(session_query)),
+        ]);
+        let Some((_, doc_addr)) = searcher
+            .search(&scoped, &TopDocs::with_limit(1))?
+            .into_iter()
+            .next()
+        else {
+            return Err(SearchError::NoMatch(session_id.to_string()));
+        };
+        let doc = searcher.doc::<tantivy::TantivyDocument>(doc_addr)?;
+        let prompt_id = doc
+            .get_first(self.prompt_id_field)
+            .and_then(|v| v.as_str())
+            .unwrap_or("");
+
+        let schema = self.index.schema();
+        let mut terms = Vec::new();
+        text_query.query_terms(&mut |term, _| {
+            let value = term.value();
+            let text = value.as_str().unwrap_or("?");
+            terms.push(format!("{}:{text}", schema.get_field_name(term.field())));
+        });
+
+        let mut out = format!("prompt {prompt_id} in session {session_id}\n");
+        out.push_str(&format!("terms: {}\n", terms.join(", ")));
+        if has_text {
+            let explanation = text_query.explain(&searcher, doc_addr)?;
+            out.push_str(&format!("BM25 score {:.4}:\n", explanation.value()));
+            let tree = serde_json::to_value(&explanation).unwrap_or_default();
+            render_explanation(&tree, 1, &mut out);
+        } else {
+            out.push_str("no free text: filter-only match, not scored by BM25\n");
+        }
+        Ok(out)
+    }
+
+    /// Query for `params` plus whether it has a free-text part (and so
+    /// ranks by relevance).
+    fn build_query(
+        &self,
+        params: &PromptSearchParams<'_>,
+    ) -> Result<(Box<dyn tantivy::query::Query>, bool), SearchError> {
         // Parse qualifiers from query string
         let mut free_text_parts = Vec::new();
+        let mut negated_parts = Vec::new();
+        let mut text_clauses: Vec<(Occur, Box<dyn tantivy::query::Query>)> = Vec::new();
         let mut qualifier_clauses: Vec<(Occur, Box<dyn tantivy::query::Query>)> = Vec::new();
 
-        for token in query.split_whitespace() {
-            if let Some(val) = token.strip_prefix("project:") {
+        let (raw_query, rest) = split_raw_segment(params.query);
+        for token in tokenize_query(&rest) {
+            if let Some(val) = qualifier_value(token, "project:") {
                 qualifier_clauses.push((
                     Occur::Must,
                     Box::new(TermQuery::new(
@@ -56,7 +284,16 @@ impl PromptSearchIndex {
                         IndexRecordOption::Basic,
                     )),
                 ));
-            } else if let Some(val) = token.strip_prefix("intent:") {
+            } else if let Some(val) = qualifier_value(token, "project_display:") {
+                qualifier_clauses.push((
+                    Occur::Must,
+                    self.words_query(self.project_display_field, val),
+                ));
+            } else if let Some(val) = qualifier_value(token, "model:") {
+                qualifier_clauses.push((Occur::Must, self.words_query(self.model_field, val)));
+            } else if let Some(val) = qualifier_value(token, "skill:") {
+                qualifier_clauses.push((Occur::Must, self.words_query(self.skills_field, val)));
+            } else if let Some(val) = qualifier_value(token, "intent:") {
                 qualifier_clauses.push((
                     Occur::Must,
                     Box::new(TermQuery::new(
@@ -64,7 +301,7 @@ impl PromptSearchIndex {
                         IndexRecordOption::Basic,
                     )),
                 ));
-            } else if let Some(val) = token.strip_prefix("branch:") {
+            } else if let Some(val) = qualifier_value(token, "branch:") {
                 qualifier_clauses.push((
                     Occur::Must,
                     Box::new(TermQuery::new(
@@ -72,7 +309,7 @@ impl PromptSearchIndex {
                         IndexRecordOption::Basic,
                     )),
                 ));
-            } else if let Some(val) = token.strip_prefix("complexity:") {
+            } else if let Some(val) = qualifier_value(token, "complexity:") {
                 qualifier_clauses.push((
                     Occur::Must,
                     Box::new(TermQuery::new(
@@ -80,13 +317,38 @@ impl PromptSearchIndex {
                         IndexRecordOption::Basic,
                     )),
                 ));
+            } else if let Some(val) = qualifier_value(token, "when:") {
+                let part = DayPart::parse(val)
+                    .ok_or_else(|| SearchError::InvalidDayPart(val.to_string()))?;
+                qualifier_clauses.push((
+                    Occur::Must,
+                    Box::new(TermQuery::new(
+                        Term::from_field_text(self.daypart_field, part.as_str()),
+                        IndexRecordOption::Basic,
+                    )),
+                ));
+            } else if let Some(id) = session_qualifier_value(token) {
+                qualifier_clauses.push((
+                    Occur::Must,
+                    Box::new(TermQuery::new(
+                        Term::from_field_text(self.session_id_field, id),
+                        IndexRecordOption::Basic,
+                    )),
+                ));
+            } else if let Some(negated) = negated_term(token) {
+                match wildcard_prefix(negated)? {
+                    Some(prefix) => text_clauses.push((Occur::MustNot, self.prefix_query(prefix)?)),
+                    None => negated_parts.push(negated),
+                }
+            } else if let Some(prefix) = wildcard_prefix(token)? {
+                text_clauses.push((Occur::Must, self.prefix_query(prefix)?));
             } else {
                 free_text_parts.push(token);
             }
         }
 
         // Scope filter (polymorphic: check both project and git_root)
-        if let Some(scope_val) = scope {
+        if let Some(scope_val) = params.scope {
             let project_term = TermQuery::new(
                 Term::from_field_text(self.project_field, scope_val),
                 IndexRecordOption::Basic,
@@ -156,130 +418,149 @@ impl PromptSearchIndex {
             _ => {}
         }
 
+        // Raw Tantivy syntax bypasses the qualifier layer; it is still ANDed
+        // with the qualifiers and free text around it.
+        if let Some(raw) = raw_query {
+            let parser = QueryParser::for_index(
+                &self.index,
+                vec![self.display_field, self.paste_text_field],
+            );
+            text_clauses.push((Occur::Must, parser.parse_query(raw)?));
+        }
+
         // Build final query
         let free_text = free_text_parts.join(" ");
+        if !free_text.is_empty() {
+            let mut parser = QueryParser::for_index(
+                &self.index,
+                vec![self.display_field, self.paste_text_field],
+            );
+            if params.default_operator == Some(Occur::Must) {
+                parser.set_conjunction_by_default();
+            }
+            // `MustNot` excludes prompts matching any of the terms.
+            let occur = match params.default_operator {
+                Some(Occur::MustNot) => Occur::MustNot,
+                _ => Occur::Must,
+            };
+            text_clauses.push((occur, parser.parse_query(&free_text)?));
+        }
+        if !negated_parts.is_empty() {
+            let parser = QueryParser::for_index(
+                &self.index,
+                vec![self.display_field, self.paste_text_field],
+            );
+            text_clauses.push((
+                Occur::MustNot,
+                parser.parse_query(&negated_parts.join(" "))?,
+            ));
+        }
+        // Only positive text is scored; exclusions alone leave a filter.
+        let has_text = text_clauses
+            .iter()
+            .any(|(occur, _)| *occur != Occur::MustNot);
+        let has_positive = has_text || !qualifier_clauses.is_empty();
         let final_query: Box<dyn tantivy::query::Query> =
-            if free_text.is_empty() && qualifier_clauses.is_empty() {
+            if text_clauses.is_empty() && qualifier_clauses.is_empty() {
                 Box::new(tantivy::query::AllQuery)
-            } else if free_text.is_empty() {
-                Box::new(BooleanQuery::new(qualifier_clauses))
+            } else if qualifier_clauses.is_empty() && text_clauses.len() == 1 && has_text {
+                text_clauses.pop().map(|(_, q)| q).unwrap()
             } else {
-                let parser = QueryParser::for_index(
-                    &self.index,
-                    vec![self.display_field, self.paste_text_field],
-                );
-                let text_query = parser.parse_query(&free_text)?;
-
-                if qualifier_clauses.is_empty() {
-                    text_query
-                } else {
-                    qualifier_clauses.push((Occur::Must, text_query));
-                    Box::new(BooleanQuery::new(qualifier_clauses))
+                // A boolean query of exclusions alone matches nothing, so
+                // exclude from every prompt instead.
+                if !has_positive {
+                    qualifier_clauses.push((Occur::Must, Box::new(tantivy::query::AllQuery)));
                 }
+                qualifier_clauses.extend(text_clauses);
+                Box::new(BooleanQuery::new(qualifier_clauses))
             };
+        Ok((final_query, has_text))
+    }
 
-        // Build snippet generator when free-text query is present (TEXT field only).
-        let snippet_gen = if !free_text.is_empty() {
-            SnippetGenerator::create(&searcher, &*final_query, self.display_field).ok()
-        } else {
-            None
-        };
+    /// Number of documents visible to the current reader.
+    pub fn num_docs(&self) -> u64 {
+        self.reader.searcher().num_docs()
+    }
 
-        let total_matches = searcher.search(&*final_query, &Count)?;
-        // Sort: newest (default) = descending timestamp, oldest = ascending timestamp.
-        // When a free-text query is present we keep relevance score as primary sort
-        // (matches user expectation for search results).
-        let sort_oldest = params.sort.map(|s| s == "oldest").unwrap_or(false);
-        let top_docs = if sort_oldest {
-            searcher
-                .search(
-                    &*final_query,
-                    &TopDocs::with_limit(limit + offset)
-                        .order_by_fast_field::<i64>("timestamp", tantivy::Order::Asc),
-                )?
-                .into_iter()
-                .map(|(_, addr)| (0.0f32, addr))
-                .collect::<Vec<_>>()
-        } else if free_text.is_empty() {
-            // No text query — sort by newest first
-            searcher
-                .search(
-                    &*final_query,
-                    &TopDocs::with_limit(limit + offset)
-                        .order_by_fast_field::<i64>("timestamp", tantivy::Order::Desc),
-                )?
+    /// All words of `value` (per the field's tokenizer) must occur in the
+    /// TEXT `field`. A value with no words matches nothing.
+    fn words_query(&self, field: Field, value: &str) -> Box<dyn tantivy::query::Query> {
+        let mut terms = Vec::new();
+        if let Ok(mut analyzer) = self.index.tokenizer_for_field(field) {
+            let mut stream = analyzer.token_stream(value);
+            stream.process(&mut |t| {
+                terms.push(Term::from_field_text(field, &t.text));
+            });
+        }
+        if terms.is_empty() {
+            return Box::new(EmptyQuery);
+        }
+        Box::new(BooleanQuery::new(
+            terms
                 .into_iter()
-                .map(|(_, addr)| (0.0f32, addr))
-                .collect::<Vec<_>>()
-        } else {
-            // Text query present — use relevance score (BM25), newest as tiebreaker
-            searcher.search(&*final_query, &TopDocs::with_limit(limit + offset))?
-        };
-
-        let mut prompts = Vec::with_capacity(limit.min(top_docs.len()));
-        for (_score, doc_addr) in top_docs.into_iter().skip(offset) {
-            if prompts.len() >= limit {
-                break;
-            }
-            let retrieved = searcher.doc::<tantivy::TantivyDocument>(doc_addr)?;
-
-            let get_text = |field: Field| -> String {
-                retrieved
-                    .get_first(field)
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("")
-                    .to_string()
-            };
+                .map(|term| {
+                    let q: Box<dyn tantivy::query::Query> =
+                        Box::new(TermQuery::new(term, IndexRecordOption::Basic));
+                    (Occur::Must, q)
+                })
+                .collect(),
+        ))
+    }
 
-            let session_id_str = get_text(self.session_id_field);
-            let timestamp = retrieved
-                .get_first(self.timestamp_field)
-                .and_then(|v| v.as_i64())
-                .unwrap_or(0);
-            let has_paste_str = get_text(self.has_paste_field);
+    /// Prefix match on `display` or `paste_text`. `prefix` is validated by
+    /// [`wildcard_prefix`] (letters and digits only), so it needs no regex
+    /// escaping; lowercased to match the default tokenizer.
+    fn prefix_query(&self, prefix: &str) -> Result<Box<dyn tantivy::query::Query>, SearchError> {
+        let pattern = format!("{}.*", prefix.to_lowercase());
+        Ok(Box::new(BooleanQuery::new(vec![
+            (
+                Occur::Should,
+                Box::new(RegexQuery::from_pattern(&pattern, self.display_field)?),
+            ),
+            (
+                Occur::Should,
+                Box::new(RegexQuery::from_pattern(&pattern, self.paste_text_field)?),
+            ),
+        ])))
+    }
+}
 
-            let snippet = snippet_gen.as_ref().and_then(|gen| {
-                let s = gen.snippet_from_doc(&retrieved);
-                let html = s.to_html();
-                if html.is_empty() {
-                    None
-                } else {
-                    Some(html)
-                }
-            });
+/// The term of a `-term` token, excluding `--flag`-like and quoted tokens
+/// (a `-"phrase"` is left to the query parser).
+fn negated_term(token: &str) -> Option<&str> {
+    token
+        .strip_prefix('-')
+        .filter(|t| !t.is_empty() && !t.starts_with('-') && !t.starts_with('"'))
+}
 
-            let raw_template_id = get_text(self.template_id_field);
-            let template_id = if raw_template_id.is_empty() {
-                None
-            } else {
-                Some(raw_template_id)
-            };
+/// `label: value` snippet of the first metadata field whose terms matched
+/// `doc`, with the matched words highlighted.
+fn metadata_snippet(
+    gens: &[(&str, SnippetGenerator)],
+    doc: &tantivy::TantivyDocument,
+) -> Option<String> {
+    gens.iter().find_map(|(label, gen)| {
+        let html = gen.snippet_from_doc(doc).to_html();
+        (!html.is_empty()).then(|| format!("{label}: {html}"))
+    })
+}
 
-            prompts.push(PromptHit {
-                prompt_id: get_text(self.prompt_id_field),
-                display: get_text(self.display_field),
-                snippet,
-                template_id,
-                project: get_text(self.project_field),
-                session_id: if session_id_str.is_empty() {
-                    None
-                } else {
-                    Some(session_id_str)
-                },
-                branch: get_text(self.branch_field),
-                model: get_text(self.model_field),
-                git_root: get_text(self.git_root_field),
-                intent: get_text(self.intent_field),
-                complexity: get_text(self.complexity_field),
-                timestamp,
-                has_paste: has_paste_str == "true",
-            });
+/// Append one explanation node per line, indented two spaces per level.
+fn render_explanation(node: &serde_json::Value, depth: usize, out: &mut String) {
+    let value = node["value"].as_f64().unwrap_or(0.0);
+    let description = node["description"].as_str().unwrap_or("");
+    out.push_str(&format!(
+        "{}{value:.4}  {description}\n",
+        "  ".repeat(depth)
+    ));
+    if let Some(context) = node["context"].as_array() {
+        for line in context.iter().filter_map(|c| c.as_str()) {
+            out.push_str(&format!("{}- {line}\n", "  ".repeat(depth + 1)));
+        }
+    }
+    if let Some(details) = node["details"].as_array() {
+        for child in details {
+            render_explanation(child, depth + 1, out);
         }
-
-        Ok(PromptSearchResponse {
-            prompts,
-            total_matches,
-            elapsed_ms: start.elapsed().as_millis() as u64,
-        })
     }
 }
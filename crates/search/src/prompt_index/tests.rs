@@ -1,11 +1,13 @@
 //! Tests for the prompt search index.
 
 use super::*;
+use crate::SearchError;
 
 #[test]
 fn create_prompt_index_in_ram() {
     let index = PromptSearchIndex::open_in_ram().unwrap();
     assert_eq!(index.search("test", None, 10, 0).unwrap().total_matches, 0);
+    assert_eq!(index.num_docs(), 0);
 }
 
 #[test]
@@ -16,9 +18,11 @@ fn index_and_search_prompt() {
         display: "fix the authentication error".into(),
         paste_text: None,
         project: "claude-view".into(),
+        project_display: String::new(),
         session_id: Some("abc-123".into()),
         branch: "main".into(),
         model: "claude-opus-4-6".into(),
+        skills: String::new(),
         git_root: "/Users/test/dev/claude-view".into(),
         intent: "fix".into(),
         complexity: "short".into(),
@@ -32,6 +36,7 @@ fn index_and_search_prompt() {
     let results = index.search("authentication", None, 10, 0).unwrap();
     assert_eq!(results.total_matches, 1);
     assert_eq!(results.prompts[0].display, "fix the authentication error");
+    assert_eq!(index.num_docs(), 1);
 }
 
 #[test]
@@ -42,9 +47,11 @@ fn search_paste_content() {
         display: "[Pasted text #1 +18 lines]".into(),
         paste_text: Some("NullPointerException in UserService.java".into()),
         project: "proj".into(),
+        project_display: String::new(),
         session_id: None,
         branch: "".into(),
         model: "".into(),
+        skills: String::new(),
         git_root: "".into(),
         intent: "other".into(),
         complexity: "short".into(),
@@ -68,9 +75,11 @@ fn search_with_intent_qualifier() {
             display: "fix the bug".into(),
             paste_text: None,
             project: "proj".into(),
+            project_display: String::new(),
             session_id: None,
             branch: "".into(),
             model: "".into(),
+            skills: String::new(),
             git_root: "".into(),
             intent: "fix".into(),
             complexity: "micro".into(),
@@ -82,9 +91,11 @@ fn search_with_intent_qualifier() {
             display: "create a new module".into(),
             paste_text: None,
             project: "proj".into(),
+            project_display: String::new(),
             session_id: None,
             branch: "".into(),
             model: "".into(),
+            skills: String::new(),
             git_root: "".into(),
             intent: "create".into(),
             complexity: "short".into(),
@@ -101,6 +112,103 @@ fn search_with_intent_qualifier() {
     assert_eq!(results.prompts[0].intent, "fix");
 }
 
+#[test]
+fn search_with_quoted_project_and_branch_qualifiers() {
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    let mut spaced = make_doc("p005", "fix the login flow", 100);
+    spaced.project = "My App".into();
+    spaced.branch = "feature/long name".into();
+    let mut plain = make_doc("p006", "fix the login flow", 200);
+    plain.project = "My".into();
+    plain.branch = "feature/long".into();
+    index.index_prompts(&[spaced, plain]).unwrap();
+    index.commit().unwrap();
+    index.reader.reload().unwrap();
+
+    let results = index
+        .search(r#"project:"My App" login"#, None, 10, 0)
+        .unwrap();
+    assert_eq!(results.total_matches, 1);
+    assert_eq!(results.prompts[0].project, "My App");
+
+    let results = index
+        .search(r#"branch:"feature/long name""#, None, 10, 0)
+        .unwrap();
+    assert_eq!(results.total_matches, 1);
+    assert_eq!(results.prompts[0].branch, "feature/long name");
+
+    // Unquoted values still work and match exactly.
+    let results = index.search("project:My", None, 10, 0).unwrap();
+    assert_eq!(results.total_matches, 1);
+    assert_eq!(results.prompts[0].project, "My");
+}
+
+#[test]
+fn search_project_display_matches_partial_name() {
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    let mut app = make_doc("p007", "fix the login flow", 100);
+    app.project_display = "My Shiny App".into();
+    let mut other = make_doc("p008", "fix the login flow", 200);
+    other.project_display = "Backend Service".into();
+    index.index_prompts(&[app, other]).unwrap();
+    index.commit().unwrap();
+    index.reader.reload().unwrap();
+
+    for query in [
+        "project_display:app",
+        r#"project_display:"my app""#,
+        r#"project_display:"Shiny App" login"#,
+    ] {
+        let results = index.search(query, None, 10, 0).unwrap();
+        assert_eq!(results.total_matches, 1, "query {query}");
+        assert_eq!(results.prompts[0].prompt_id, "p007");
+    }
+
+    let results = index
+        .search(r#"project_display:"my service""#, None, 10, 0)
+        .unwrap();
+    assert_eq!(results.total_matches, 0);
+}
+
+#[test]
+fn search_with_session_qualifier() {
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    let mut in_session = make_doc("p007", "tune the content cache", 100);
+    in_session.session_id = Some("abc".into());
+    let mut other = make_doc("p008", "content cache again", 200);
+    other.session_id = Some("abc-def".into());
+    index.index_prompts(&[in_session, other]).unwrap();
+    index.commit().unwrap();
+    index.reader.reload().unwrap();
+
+    let results = index.search("session:abc content", None, 10, 0).unwrap();
+    assert_eq!(results.total_matches, 1);
+    assert_eq!(results.prompts[0].session_id.as_deref(), Some("abc"));
+}
+
+#[test]
+fn explain_reports_bm25_breakdown_for_session() {
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    let mut hit = make_doc("p009", "migrate the billing schema", 100);
+    hit.session_id = Some("s1".into());
+    let mut other = make_doc("p010", "billing dashboard copy", 200);
+    other.session_id = Some("s2".into());
+    index.index_prompts(&[hit, other]).unwrap();
+    index.commit().unwrap();
+    index.reader.reload().unwrap();
+
+    let explanation = index.explain("schema", "s1").unwrap();
+    assert!(explanation.contains("prompt p009 in session s1"));
+    assert!(explanation.contains("display:schema"));
+    assert!(explanation.contains("BM25"));
+    assert!(explanation.contains("idf"));
+
+    assert!(matches!(
+        index.explain("schema", "s2"),
+        Err(SearchError::NoMatch(id)) if id == "s2"
+    ));
+}
+
 // ── template_match tests ────────────────────────────────────────────────
 
 fn make_doc(id: &str, display: &str, ts: i64) -> PromptDocument {
@@ -109,9 +217,11 @@ fn make_doc(id: &str, display: &str, ts: i64) -> PromptDocument {
         display: display.into(),
         paste_text: None,
         project: "proj".into(),
+        project_display: String::new(),
         session_id: None,
         branch: "".into(),
         model: "".into(),
+        skills: String::new(),
         git_root: "".into(),
         intent: "fix".into(),
         complexity: "short".into(),
@@ -120,6 +230,165 @@ fn make_doc(id: &str, display: &str, ts: i64) -> PromptDocument {
     }
 }
 
+#[test]
+fn search_limit_zero_returns_counts_only() {
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    let docs = vec![
+        make_doc("z1", "deploy the api", 100),
+        make_doc("z2", "deploy the docs", 200),
+    ];
+    index.index_prompts(&docs).unwrap();
+    index.commit().unwrap();
+    index.reader.reload().unwrap();
+
+    let results = index.search("deploy", None, 0, 0).unwrap();
+    assert_eq!(results.total_matches, 2);
+    assert!(results.prompts.is_empty());
+
+    // No text query takes the timestamp-sorted path.
+    let results = index.search("", Some("proj"), 0, 5).unwrap();
+    assert_eq!(results.total_matches, 2);
+    assert!(results.prompts.is_empty());
+}
+
+#[test]
+fn metadata_match_snippet_shows_matching_field() {
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    let docs = vec![
+        PromptDocument {
+            skills: "commit".into(),
+            model: "claude-opus-4-6".into(),
+            ..make_doc("m1", "/commit --amend", 100)
+        },
+        PromptDocument {
+            model: "claude-opus-4-6".into(),
+            ..make_doc("m2", "fix the login bug", 200)
+        },
+    ];
+    index.index_prompts(&docs).unwrap();
+    index.commit().unwrap();
+    index.reader.reload().unwrap();
+
+    let results = index.search("skill:commit", None, 10, 0).unwrap();
+    assert_eq!(results.total_matches, 1);
+    assert_eq!(
+        results.prompts[0].snippet.as_deref(),
+        Some("skill: <b>commit</b>")
+    );
+
+    let results = index.search("model:opus", None, 10, 0).unwrap();
+    assert_eq!(results.total_matches, 2);
+    assert_eq!(
+        results.prompts[0].snippet.as_deref(),
+        Some("model: claude-<b>opus</b>-4-6")
+    );
+
+    // A text match in the prompt itself still wins.
+    let results = index.search("model:opus login", None, 10, 0).unwrap();
+    assert_eq!(
+        results.prompts[0].snippet.as_deref(),
+        Some("fix the <b>login</b> bug")
+    );
+}
+
+#[test]
+fn qualifier_only_search_has_no_snippet() {
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    let docs = vec![
+        make_doc("q1", "deploy the api", 100),
+        make_doc("q2", "deploy the docs", 200),
+    ];
+    index.index_prompts(&docs).unwrap();
+    index.commit().unwrap();
+    index.reader.reload().unwrap();
+
+    let results = index
+        .search("project:proj intent:fix", None, 10, 0)
+        .unwrap();
+    assert_eq!(results.total_matches, 2);
+    assert!(results.prompts.iter().all(|p| p.snippet.is_none()));
+}
+
+#[test]
+fn raw_segment_is_parsed_as_tantivy_syntax_and_anded_with_qualifiers() {
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    let mut other_intent = make_doc("r4", "foo bar again", 400);
+    other_intent.intent = "create".into();
+    let docs = vec![
+        make_doc("r1", "foo bar", 100),
+        make_doc("r2", "foo baz", 200),
+        make_doc("r3", "bar qux", 300),
+        other_intent,
+    ];
+    index.index_prompts(&docs).unwrap();
+    index.commit().unwrap();
+    index.reader.reload().unwrap();
+
+    let ids = |q: &str| {
+        let mut ids: Vec<String> = index
+            .search(q, None, 10, 0)
+            .unwrap()
+            .prompts
+            .into_iter()
+            .map(|p| p.prompt_id)
+            .collect();
+        ids.sort();
+        ids
+    };
+    assert_eq!(ids("raw:(foo AND bar)"), vec!["r1", "r4"]);
+    assert_eq!(ids("raw:(+foo -bar)"), vec!["r2"]);
+    assert_eq!(ids("raw:(baz OR qux)"), vec!["r2", "r3"]);
+    // Qualifiers outside the segment still apply.
+    assert_eq!(ids("intent:fix raw:(foo AND bar)"), vec!["r1"]);
+    assert_eq!(ids("raw:(foo AND bar) intent:create"), vec!["r4"]);
+
+    assert!(matches!(
+        index.search("raw:(nosuchfield:foo)", None, 10, 0),
+        Err(SearchError::QueryParse(_))
+    ));
+}
+
+#[test]
+fn search_limit_above_cap_is_clamped() {
+    use crate::config::SearchIndexConfig;
+
+    let index = PromptSearchIndex::open_in_ram_with_config(SearchIndexConfig {
+        max_results: 3,
+        ..Default::default()
+    })
+    .unwrap();
+    let docs: Vec<_> = (0..5)
+        .map(|i| make_doc(&format!("c{i}"), "deploy again", 100 + i))
+        .collect();
+    index.index_prompts(&docs).unwrap();
+    index.commit().unwrap();
+    index.reader.reload().unwrap();
+
+    let results = index.search("deploy", None, usize::MAX, 0).unwrap();
+    assert_eq!(results.total_matches, 5);
+    assert_eq!(results.prompts.len(), 3);
+
+    let results = index.search("deploy", None, usize::MAX, 4).unwrap();
+    assert_eq!(results.prompts.len(), 1);
+}
+
+#[test]
+fn search_offset_past_end_returns_empty_page() {
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    let docs: Vec<_> = (0..3)
+        .map(|i| make_doc(&format!("o{i}"), "deploy again", 100 + i))
+        .collect();
+    index.index_prompts(&docs).unwrap();
+    index.commit().unwrap();
+    index.reader.reload().unwrap();
+
+    for offset in [3, 1_000_000_000_000_000_000, usize::MAX] {
+        let results = index.search("deploy", None, 10, offset).unwrap();
+        assert_eq!(results.total_matches, 3);
+        assert!(results.prompts.is_empty());
+    }
+}
+
 #[test]
 fn template_prompts_share_same_template_id() {
     // Three prompts that normalize to the same pattern get a non-empty, identical template_id.
@@ -273,6 +542,95 @@ fn template_match_any_returns_all_prompts() {
     assert_eq!(results.total_matches, 3);
 }
 
+#[test]
+fn default_operator_controls_multi_term_matching() {
+    use std::collections::HashSet;
+    use tantivy::query::Occur;
+
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    let docs: Vec<PromptDocument> = [
+        ("p1", "s1", "fix the login redirect"),
+        ("p2", "s2", "fix flaky tests"),
+        ("p3", "s3", "login page copy"),
+    ]
+    .into_iter()
+    .map(|(id, session, display)| {
+        let mut doc = make_doc(id, display, 100);
+        doc.session_id = Some(session.into());
+        doc
+    })
+    .collect();
+    index.index_prompts(&docs).unwrap();
+    index.commit().unwrap();
+    index.reader.reload().unwrap();
+
+    let sessions = |default_operator| {
+        let results = index
+            .search_with(PromptSearchParams {
+                query: "fix login",
+                default_operator,
+                limit: 10,
+                ..Default::default()
+            })
+            .unwrap();
+        results
+            .prompts
+            .into_iter()
+            .filter_map(|p| p.session_id)
+            .collect::<HashSet<_>>()
+    };
+
+    let all = sessions(Some(Occur::Must));
+    let any = sessions(Some(Occur::Should));
+    assert_eq!(all, HashSet::from(["s1".to_string()]));
+    assert_eq!(any.len(), 3);
+    assert!(any.len() > all.len());
+    assert_eq!(sessions(None), any, "unset keeps the parser's OR default");
+}
+
+#[test]
+fn negated_terms_exclude_matches() {
+    use tantivy::query::Occur;
+
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    let docs = vec![
+        make_doc("n1", "fix the login redirect", 100),
+        make_doc("n2", "fix flaky tests", 200),
+        make_doc("n3", "login page copy", 300),
+    ];
+    index.index_prompts(&docs).unwrap();
+    index.commit().unwrap();
+    index.reader.reload().unwrap();
+
+    let ids = |query: &str, default_operator| {
+        let mut ids: Vec<String> = index
+            .search_with(PromptSearchParams {
+                query,
+                default_operator,
+                limit: 10,
+                ..Default::default()
+            })
+            .unwrap()
+            .prompts
+            .into_iter()
+            .map(|p| p.prompt_id)
+            .collect();
+        ids.sort();
+        ids
+    };
+
+    assert_eq!(ids("fix -login", None), vec!["n2"]);
+    assert_eq!(ids("-login", None), vec!["n2"]);
+    assert_eq!(ids("project:proj -login", None), vec!["n2"]);
+    assert_eq!(ids("-log*", None), vec!["n2"]);
+    // The default operator can exclude every bare term.
+    assert_eq!(ids("login", Some(Occur::MustNot)), vec!["n2"]);
+    assert_eq!(
+        ids("login flaky", Some(Occur::MustNot)),
+        Vec::<String>::new()
+    );
+}
+
 #[test]
 fn search_pagination() {
     let index = PromptSearchIndex::open_in_ram().unwrap();
@@ -282,9 +640,11 @@ fn search_pagination() {
             display: format!("test prompt number {i}"),
             paste_text: None,
             project: "proj".into(),
+            project_display: String::new(),
             session_id: None,
             branch: "".into(),
             model: "".into(),
+            skills: String::new(),
             git_root: "".into(),
             intent: "other".into(),
             complexity: "short".into(),
@@ -303,3 +663,456 @@ fn search_pagination() {
     let page2 = index.search("test prompt", None, 2, 2).unwrap();
     assert_eq!(page2.prompts.len(), 2);
 }
+
+fn prompt_doc(id: &str, display: String) -> PromptDocument {
+    PromptDocument {
+        prompt_id: id.into(),
+        display,
+        paste_text: None,
+        project: "proj".into(),
+        project_display: String::new(),
+        session_id: None,
+        branch: "".into(),
+        model: "".into(),
+        skills: String::new(),
+        git_root: "".into(),
+        intent: "other".into(),
+        complexity: "short".into(),
+        timestamp: 0,
+        has_paste: false,
+    }
+}
+
+/// Lowering `b` removes the length penalty, so a long doc with more
+/// occurrences of the term overtakes a short doc with a single one.
+#[test]
+fn bm25_b_changes_long_vs_short_ranking() {
+    use crate::config::{Bm25Params, SearchIndexConfig};
+
+    let filler = (0..60)
+        .map(|i| format!("word{i}"))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let docs = vec![
+        prompt_doc("short", "deploy the app".into()),
+        prompt_doc("long", format!("deploy deploy {filler}")),
+        prompt_doc("other", "unrelated prompt text".into()),
+    ];
+
+    let top_with = |b: f32| -> String {
+        let index = PromptSearchIndex::open_in_ram_with_config(SearchIndexConfig {
+            bm25: Bm25Params { k1: 1.2, b },
+            ..Default::default()
+        })
+        .unwrap();
+        index.index_prompts(&docs).unwrap();
+        index.commit().unwrap();
+        index.reader.reload().unwrap();
+        let results = index.search("deploy", None, 10, 0).unwrap();
+        assert_eq!(results.total_matches, 2);
+        results.prompts[0].prompt_id.clone()
+    };
+
+    // Tantivy defaults (b = 0.75): the short doc wins.
+    assert_eq!(top_with(Bm25Params::TANTIVY_B), "short");
+    // No length normalisation: tf = 2 beats tf = 1.
+    assert_eq!(top_with(0.0), "long");
+}
+
+#[test]
+fn delete_by_project_removes_only_that_project() {
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    let doc = |id: &str, project: &str, git_root: &str| PromptDocument {
+        prompt_id: id.into(),
+        display: "refactor the parser".into(),
+        paste_text: None,
+        project: project.into(),
+        project_display: String::new(),
+        session_id: None,
+        branch: "".into(),
+        model: "".into(),
+        skills: String::new(),
+        git_root: git_root.into(),
+        intent: "other".into(),
+        complexity: "short".into(),
+        timestamp: 100,
+        has_paste: false,
+    };
+    index
+        .index_prompts(&[
+            doc("p1", "/dev/noisy", ""),
+            doc("p2", "/dev/noisy/sub", "/dev/noisy"),
+            doc("p3", "/dev/keep", ""),
+        ])
+        .unwrap();
+    index.commit().unwrap();
+    assert_eq!(index.num_docs(), 3);
+
+    index.delete_by_project("/dev/noisy").unwrap();
+    index.delete_by_project("").unwrap();
+    index.commit().unwrap();
+
+    let results = index.search("parser", None, 10, 0).unwrap();
+    assert_eq!(results.total_matches, 1);
+    assert_eq!(results.prompts[0].project, "/dev/keep");
+}
+
+#[test]
+fn delete_by_prompt_id_prefix_removes_only_prefixed_ids() {
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    index
+        .index_prompts(&[
+            make_doc("db:s1", "refactor the parser", 100),
+            make_doc("db:s2", "refactor the lexer", 200),
+            make_doc("1700000000000-0", "refactor the parser", 300),
+            make_doc("xdb:s3", "refactor the parser", 400),
+        ])
+        .unwrap();
+    index.commit().unwrap();
+
+    index.delete_by_prompt_id_prefix("db:").unwrap();
+    index.delete_by_prompt_id_prefix("").unwrap();
+    index.commit().unwrap();
+
+    assert_eq!(index.num_docs(), 2);
+    let results = index.search("refactor", None, 10, 0).unwrap();
+    let mut ids: Vec<&str> = results
+        .prompts
+        .iter()
+        .map(|p| p.prompt_id.as_str())
+        .collect();
+    ids.sort_unstable();
+    assert_eq!(ids, vec!["1700000000000-0", "xdb:s3"]);
+}
+
+#[test]
+fn wildcard_prefix_matches_and_bare_star_is_rejected() {
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    index
+        .index_prompts(&[
+            make_doc("p1", "migrate the Database schema", 100),
+            make_doc("p2", "clean up datasets folder", 200),
+            make_doc("p3", "fix the login form", 300),
+        ])
+        .unwrap();
+    index.commit().unwrap();
+    index.reader.reload().unwrap();
+
+    let results = index.search("data*", None, 10, 0).unwrap();
+    assert_eq!(results.total_matches, 2);
+    let mut ids: Vec<&str> = results
+        .prompts
+        .iter()
+        .map(|p| p.prompt_id.as_str())
+        .collect();
+    ids.sort();
+    assert_eq!(ids, vec!["p1", "p2"]);
+
+    // Combines with plain terms.
+    let results = index.search("data* schema", None, 10, 0).unwrap();
+    assert_eq!(results.total_matches, 1);
+
+    assert!(matches!(
+        index.search("*", None, 10, 0),
+        Err(SearchError::InvalidWildcard(_))
+    ));
+    assert!(matches!(
+        index.search("d*", None, 10, 0),
+        Err(SearchError::InvalidWildcard(_))
+    ));
+}
+
+#[test]
+fn optimize_if_idle_merges_segments_once() {
+    use crate::config::{IdleOptimizeConfig, SearchIndexConfig};
+    use std::time::{Duration, Instant};
+
+    let idle_after = Duration::from_secs(60);
+    let index = PromptSearchIndex::open_in_ram_with_config(SearchIndexConfig {
+        idle_optimize: IdleOptimizeConfig {
+            idle_after,
+            max_segments: 2,
+        },
+        ..Default::default()
+    })
+    .unwrap();
+    // One commit per batch -> one segment each.
+    for i in 0..4 {
+        let id = format!("p{i}");
+        index
+            .index_prompts(&[make_doc(&id, "deploy the app", i)])
+            .unwrap();
+        index.commit().unwrap();
+    }
+    index.release_writer().unwrap();
+    assert_eq!(index.stats().num_segments, 4);
+
+    // Not idle yet.
+    assert!(!index.optimize_if_idle(Instant::now()).unwrap());
+    assert_eq!(index.stats().num_segments, 4);
+
+    // Idle and over the threshold: merges into one segment.
+    let later = Instant::now() + idle_after;
+    assert!(index.optimize_if_idle(later).unwrap());
+    let stats = index.stats();
+    assert_eq!(stats.num_segments, 1);
+    assert_eq!(stats.num_docs, 4);
+    assert_eq!(
+        index.search("deploy", None, 10, 0).unwrap().total_matches,
+        4
+    );
+
+    // Runs once per idle stretch.
+    assert!(!index.optimize_if_idle(later).unwrap());
+}
+
+#[test]
+fn commit_and_reload_makes_new_doc_searchable_immediately() {
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    index
+        .index_prompts(&[make_doc("p1", "refactor the parser", 100)])
+        .unwrap();
+    index.commit_and_reload().unwrap();
+    // No manual `reader.reload()`.
+    assert_eq!(
+        index.search("parser", None, 10, 0).unwrap().total_matches,
+        1
+    );
+
+    index
+        .index_prompts(&[make_doc("p2", "parser error on empty input", 200)])
+        .unwrap();
+    let first = index.stats();
+    let opstamp = index.commit_and_reload().unwrap();
+    assert!(opstamp > 0);
+    assert_eq!(
+        index.search("parser", None, 10, 0).unwrap().total_matches,
+        2
+    );
+    assert_eq!(index.stats().num_docs, first.num_docs + 1);
+}
+
+#[test]
+fn open_recreates_corrupted_index() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    {
+        let index = PromptSearchIndex::open(tmp.path()).unwrap();
+        index
+            .index_prompts(&[make_doc("c1", "before the crash", 100)])
+            .unwrap();
+        index.commit().unwrap();
+        index.mark_schema_synced();
+    }
+    // Simulate a crash mid-commit leaving a truncated meta file.
+    std::fs::write(tmp.path().join("meta.json"), "{\"segments\": [").unwrap();
+
+    let index = PromptSearchIndex::open(tmp.path()).unwrap();
+    assert!(index.needs_full_reindex);
+    assert_eq!(index.num_docs(), 0);
+
+    index
+        .index_prompts(&[make_doc("c2", "after the rebuild", 200)])
+        .unwrap();
+    index.commit().unwrap();
+    index.reader.reload().unwrap();
+    assert_eq!(
+        index.search("rebuild", None, 10, 0).unwrap().total_matches,
+        1
+    );
+}
+
+#[test]
+fn when_qualifier_matches_daypart_of_timestamp() {
+    // 2026-01-05 (UTC midnight) plus an hour offset.
+    let at = |hour: i64| 1_767_571_200 + hour * 3_600;
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    index
+        .index_prompts(&[
+            make_doc("early", "deploy", at(7)),
+            make_doc("lunch", "deploy", at(13)),
+            make_doc("dinner", "deploy", at(19)),
+            make_doc("late", "deploy", at(23)),
+            make_doc("small-hours", "deploy", at(2)),
+        ])
+        .unwrap();
+    index.commit().unwrap();
+
+    let ids = |query: &str| {
+        let mut ids: Vec<String> = index
+            .search(query, None, 10, 0)
+            .unwrap()
+            .prompts
+            .into_iter()
+            .map(|p| p.prompt_id)
+            .collect();
+        ids.sort();
+        ids
+    };
+    assert_eq!(ids("when:morning"), vec!["early"]);
+    assert_eq!(ids("deploy when:Afternoon"), vec!["lunch"]);
+    assert_eq!(ids("when:evening"), vec!["dinner"]);
+    assert_eq!(ids("when:night"), vec!["late", "small-hours"]);
+}
+
+#[test]
+fn daypart_honours_utc_offset() {
+    use crate::config::DayPartClock;
+
+    // 03:00 UTC is night in UTC but morning at UTC+8 (11:00 local).
+    let ts = 1_767_571_200 + 3 * 3_600;
+    assert_eq!(
+        DayPart::from_timestamp(ts, DayPartClock::FixedOffset(0)),
+        DayPart::Night
+    );
+    assert_eq!(
+        DayPart::from_timestamp(ts, DayPartClock::FixedOffset(8 * 3_600)),
+        DayPart::Morning
+    );
+    assert_eq!(
+        DayPart::from_timestamp(ts, DayPartClock::FixedOffset(-5 * 3_600)),
+        DayPart::Night
+    );
+
+    let index = PromptSearchIndex::open_in_ram_with_config(crate::SearchIndexConfig {
+        daypart_clock: DayPartClock::FixedOffset(8 * 3_600),
+        ..Default::default()
+    })
+    .unwrap();
+    index.index_prompts(&[make_doc("p", "deploy", ts)]).unwrap();
+    index.commit().unwrap();
+    assert_eq!(
+        index
+            .search("when:morning", None, 10, 0)
+            .unwrap()
+            .total_matches,
+        1
+    );
+}
+
+#[test]
+fn local_daypart_uses_the_offset_in_force_at_each_timestamp() {
+    use chrono::{TimeZone, Timelike};
+
+    use crate::config::DayPartClock;
+
+    // A January and a July instant: wherever the zone observes DST, the
+    // two offsets differ and each must come from its own date.
+    for ts in [1_767_571_200 + 9 * 3_600, 1_783_296_000 + 9 * 3_600] {
+        let local_hour = chrono::Local.timestamp_opt(ts, 0).unwrap().hour();
+        assert_eq!(DayPartClock::Local.hour_of(ts), local_hour);
+    }
+}
+
+#[test]
+fn unknown_daypart_is_an_error() {
+    let index = PromptSearchIndex::open_in_ram().unwrap();
+    index
+        .index_prompts(&[make_doc("p", "deploy", 1_767_571_200)])
+        .unwrap();
+    index.commit().unwrap();
+    assert!(matches!(
+        index.search("deploy when:brunch", None, 10, 0),
+        Err(SearchError::InvalidDayPart(v)) if v == "brunch"
+    ));
+}
+
+#[test]
+fn stop_words_are_ignored_unless_kept() {
+    use crate::config::{SearchIndexConfig, StopWords};
+
+    let index = PromptSearchIndex::open_in_ram_with_config(SearchIndexConfig {
+        stop_words: StopWords {
+            words: vec!["Please".into(), "test".into(), "build".into()],
+            keep: vec!["build".into()],
+        },
+        ..Default::default()
+    })
+    .unwrap();
+    index
+        .index_prompts(&[
+            make_doc("suite", "please run the test suite", 100),
+            make_doc("build", "please fix the build", 200),
+        ])
+        .unwrap();
+    index.commit().unwrap();
+
+    let ids = |q: &str| -> Vec<String> {
+        let mut ids: Vec<String> = index
+            .search(q, None, 10, 0)
+            .unwrap()
+            .prompts
+            .into_iter()
+            .map(|p| p.prompt_id)
+            .collect();
+        ids.sort();
+        ids
+    };
+    // Stop words are not indexed, and dropped from the query.
+    assert!(ids("test").is_empty());
+    assert!(ids("please").is_empty());
+    assert_eq!(ids("test suite"), vec!["suite"]);
+    // `keep` opts "build" back in.
+    assert_eq!(ids("build"), vec!["build"]);
+    // Words outside the list are unaffected.
+    assert_eq!(ids("fix"), vec!["build"]);
+
+    // The default (empty) list keeps every word searchable.
+    let plain = PromptSearchIndex::open_in_ram().unwrap();
+    plain
+        .index_prompts(&[make_doc("suite", "please run the test suite", 100)])
+        .unwrap();
+    plain.commit().unwrap();
+    assert_eq!(plain.search("test", None, 10, 0).unwrap().total_matches, 1);
+}
+
+#[test]
+fn max_content_bytes_truncates_indexed_text() {
+    use crate::config::SearchIndexConfig;
+
+    let index = PromptSearchIndex::open_in_ram_with_config(SearchIndexConfig {
+        max_content_bytes: Some(64),
+        ..Default::default()
+    })
+    .unwrap();
+    // A multi-byte char straddles the cut, which must back off to a boundary.
+    let huge = format!(
+        "deploy failed with this log {} héllo {} sentinelword",
+        "x".repeat(33),
+        "line ".repeat(10_000)
+    );
+    let mut doc = make_doc("log", &huge, 100);
+    doc.paste_text = Some(format!("{} pastetail", "noise ".repeat(10_000)));
+    index.index_prompts(&[doc]).unwrap();
+    index.commit().unwrap();
+
+    // Results keep the full prompt; only the indexed text is capped.
+    let hits = index.search("deploy", None, 10, 0).unwrap();
+    assert_eq!(hits.total_matches, 1);
+    assert_eq!(hits.prompts[0].display, huge);
+    let snippet = hits.prompts[0].snippet.as_deref().unwrap();
+    assert!(snippet.contains("<b>deploy</b>"), "{snippet}");
+
+    // Terms past the cut are not indexed, in display or paste text.
+    assert_eq!(
+        index
+            .search("sentinelword", None, 10, 0)
+            .unwrap()
+            .total_matches,
+        0
+    );
+    assert_eq!(
+        index
+            .search("pastetail", None, 10, 0)
+            .unwrap()
+            .total_matches,
+        0
+    );
+
+    // Unlimited by default.
+    let plain = PromptSearchIndex::open_in_ram().unwrap();
+    plain.index_prompts(&[make_doc("log", &huge, 100)]).unwrap();
+    plain.commit().unwrap();
+    let hits = plain.search("sentinelword", None, 10, 0).unwrap();
+    assert_eq!(hits.total_matches, 1);
+    assert_eq!(hits.prompts[0].display, huge);
+}
@@ -3,12 +3,21 @@
 use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
 use std::sync::Mutex;
+use std::time::Instant;
 
 use serde::Serialize;
-use tantivy::schema::{Field, Schema, FAST, STORED, STRING, TEXT};
+use tantivy::query::Occur;
+use tantivy::schema::{
+    Field, IndexRecordOption, Schema, TextFieldIndexing, TextOptions, FAST, STORED, STRING, TEXT,
+};
+use tantivy::tokenizer::{
+    LowerCaser, RemoveLongFilter, SimpleTokenizer, StopWordFilter, TextAnalyzer,
+};
 use tantivy::{Index, IndexReader, IndexWriter};
 use ts_rs::TS;
 
+use crate::config::{DayPartClock, SearchIndexConfig, StopWords};
+
 /// Stable u64 hash of a byte slice using `DefaultHasher`.
 pub(crate) fn fxhash(data: &[u8]) -> u64 {
     use std::collections::hash_map::DefaultHasher;
@@ -21,7 +30,58 @@ pub(crate) fn fxhash(data: &[u8]) -> u64 {
 /// (field types, new fields, removed fields). A mismatch triggers auto-rebuild.
 // Version 1: Initial schema — 12 fields for prompt history
 // Version 2: Added `template_id` field (STRING | STORED) + snippet via SnippetGenerator
-pub const PROMPT_SCHEMA_VERSION: u32 = 2;
+// Version 3: Added `project_display` field (TEXT) for the `project_display:` qualifier
+// Version 4: Added `daypart` field (STRING) for the `when:` qualifier
+// Version 5: Added `skills` field (TEXT | STORED) for the `skill:` qualifier
+// Version 6: `display` / `paste_text` use the `prompt_text` tokenizer (stop words)
+// Version 7: `display` is indexed only (capped text); full text in `display_stored` (STORED)
+pub const PROMPT_SCHEMA_VERSION: u32 = 7;
+
+/// Tokenizer for `display` / `paste_text`: Tantivy's default pipeline plus
+/// the configured [`StopWords`](crate::config::StopWords). Tokenizers are not
+/// persisted, so it is registered each time an index is opened.
+pub(crate) const PROMPT_TEXT_TOKENIZER: &str = "prompt_text";
+
+/// Coarse time of day a prompt was sent, for the `when:` qualifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayPart {
+    /// 05:00–11:59
+    Morning,
+    /// 12:00–16:59
+    Afternoon,
+    /// 17:00–20:59
+    Evening,
+    /// 21:00–04:59
+    Night,
+}
+
+impl DayPart {
+    /// Day-part of a unix timestamp (seconds) read on `clock`.
+    pub fn from_timestamp(timestamp_secs: i64, clock: DayPartClock) -> Self {
+        match clock.hour_of(timestamp_secs) {
+            5..=11 => Self::Morning,
+            12..=16 => Self::Afternoon,
+            17..=20 => Self::Evening,
+            _ => Self::Night,
+        }
+    }
+
+    /// Parses a `when:` value, case-insensitively.
+    pub fn parse(value: &str) -> Option<Self> {
+        [Self::Morning, Self::Afternoon, Self::Evening, Self::Night]
+            .into_iter()
+            .find(|part| part.as_str().eq_ignore_ascii_case(value))
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Morning => "morning",
+            Self::Afternoon => "afternoon",
+            Self::Evening => "evening",
+            Self::Night => "night",
+        }
+    }
+}
 
 /// A document to be indexed into the prompt search index.
 pub struct PromptDocument {
@@ -29,9 +89,15 @@ pub struct PromptDocument {
     pub display: String,
     pub paste_text: Option<String>,
     pub project: String,
+    /// Human-readable project name, tokenized for partial matching via
+    /// `project_display:`.
+    pub project_display: String,
     pub session_id: Option<String>,
     pub branch: String,
     pub model: String,
+    /// Skills the prompt invoked (the slash command name), space-separated,
+    /// for `skill:`. Empty when none.
+    pub skills: String,
     pub git_root: String,
     pub intent: String,
     pub complexity: String,
@@ -46,8 +112,10 @@ pub struct PromptDocument {
 pub struct PromptHit {
     pub prompt_id: String,
     pub display: String,
-    /// HTML snippet with `<b>` tags around matched terms. Present when a
-    /// free-text query was used; `None` otherwise (browse/filter-only mode).
+    /// HTML snippet with `<b>` tags around matched terms when a free-text
+    /// query was used. When only `model` or `skills` matched, the matching
+    /// field as `model: <b>opus</b>` / `skill: <b>commit</b>`. `None`
+    /// otherwise (browse/filter-only mode).
     pub snippet: Option<String>,
     /// Stable hash of the normalized prompt pattern. Non-empty means the prompt
     /// matches a recurring template; empty means it is unique.
@@ -73,11 +141,29 @@ pub struct PromptSearchResponse {
     pub elapsed_ms: u64,
 }
 
+/// The [`PROMPT_TEXT_TOKENIZER`] pipeline. With no stop words it tokenizes
+/// exactly like Tantivy's `default` tokenizer.
+pub(crate) fn prompt_text_analyzer(stop_words: &StopWords) -> TextAnalyzer {
+    TextAnalyzer::builder(SimpleTokenizer::default())
+        .filter(RemoveLongFilter::limit(40))
+        .filter(LowerCaser)
+        .filter(StopWordFilter::remove(stop_words.effective()))
+        .build()
+}
+
 pub(crate) fn build_prompt_schema() -> Schema {
+    let prompt_text = TextOptions::default().set_indexing_options(
+        TextFieldIndexing::default()
+            .set_tokenizer(PROMPT_TEXT_TOKENIZER)
+            .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+    );
     let mut builder = Schema::builder();
     builder.add_text_field("prompt_id", STRING | STORED);
-    builder.add_text_field("display", TEXT | STORED);
-    builder.add_text_field("paste_text", TEXT);
+    // display: indexed text, capped at `max_content_bytes`; display_stored
+    // keeps the full prompt for results.
+    builder.add_text_field("display", prompt_text.clone());
+    builder.add_text_field("display_stored", STORED);
+    builder.add_text_field("paste_text", prompt_text);
     builder.add_text_field("project", STRING | STORED);
     builder.add_text_field("session_id", STRING | STORED);
     builder.add_text_field("branch", STRING | STORED);
@@ -91,6 +177,12 @@ pub(crate) fn build_prompt_schema() -> Schema {
     builder.add_text_field("template_id", STRING | STORED);
     // is_template: "true" if pattern detected, "false" if unique — used for TermQuery filtering.
     builder.add_text_field("is_template", STRING | STORED);
+    // project_display: tokenized project name for partial `project_display:` matches.
+    builder.add_text_field("project_display", TEXT);
+    // daypart: `DayPart` of the timestamp — used for `when:` TermQuery filtering.
+    builder.add_text_field("daypart", STRING);
+    // skills: slash commands the prompt invoked, for `skill:` matches.
+    builder.add_text_field("skills", TEXT | STORED);
     builder.build()
 }
 
@@ -98,7 +190,10 @@ pub(crate) fn build_prompt_schema() -> Schema {
 pub struct PromptSearchIndex {
     /// The underlying Tantivy index.
     pub index: Index,
-    /// Reader for executing queries. Automatically reloads on commit.
+    /// Reader for executing queries. Uses `ReloadPolicy::OnCommitWithDelay`,
+    /// which lags a commit by roughly 500ms; [`PromptSearchIndex::commit`]
+    /// and [`PromptSearchIndex::commit_and_reload`] reload it eagerly, so
+    /// callers never need to call `reload()` themselves.
     pub reader: IndexReader,
     /// Writer for indexing documents. Wrapped in Mutex because `IndexWriter`
     /// requires `&mut self` but may be used from different async contexts.
@@ -107,10 +202,15 @@ pub struct PromptSearchIndex {
     pub schema: Schema,
     pub needs_full_reindex: bool,
     pub(crate) version_file_path: Option<PathBuf>,
+    /// Tunables fixed at open time (BM25 parameters, ...).
+    pub(crate) config: SearchIndexConfig,
+    /// Write activity tracked for idle-time optimize.
+    pub(crate) idle: Mutex<IdleState>,
 
     // Pre-resolved field handles
     pub(crate) prompt_id_field: Field,
     pub(crate) display_field: Field,
+    pub(crate) display_stored_field: Field,
     pub(crate) paste_text_field: Field,
     pub(crate) project_field: Field,
     pub(crate) session_id_field: Field,
@@ -123,6 +223,32 @@ pub struct PromptSearchIndex {
     pub(crate) has_paste_field: Field,
     pub(crate) template_id_field: Field,
     pub(crate) is_template_field: Field,
+    pub(crate) project_display_field: Field,
+    pub(crate) daypart_field: Field,
+    pub(crate) skills_field: Field,
+}
+
+/// Last write time, and whether the index was optimized since.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct IdleState {
+    pub last_write_at: Instant,
+    pub optimized: bool,
+}
+
+impl IdleState {
+    pub fn new(now: Instant) -> Self {
+        Self {
+            last_write_at: now,
+            optimized: false,
+        }
+    }
+}
+
+/// Size of the prompt index as seen by the current reader.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PromptIndexStats {
+    pub num_docs: u64,
+    pub num_segments: usize,
 }
 
 /// Search parameters for the prompt index.
@@ -142,6 +268,11 @@ pub struct PromptSearchParams<'a> {
     pub sort: Option<&'a str>,
     /// Filter by template match: "template" = has template_id, "unique" = no template_id.
     pub template_match: Option<&'a str>,
+    /// How bare free-text terms combine: `Occur::Must` requires every term,
+    /// `Occur::Should` any of them, `Occur::MustNot` excludes prompts with
+    /// any of them. `None` keeps the query parser's default, which is OR.
+    /// Quoted phrases, `+`/`-` prefixes and qualifiers are unaffected.
+    pub default_operator: Option<Occur>,
     pub limit: usize,
     pub offset: usize,
 }
@@ -0,0 +1,544 @@
+// crates/search/src/query.rs
+//! Query tokenizing and numeric range qualifiers.
+//!
+//! [`tokenize_query`] splits a query on whitespace but keeps quoted qualifier
+//! values together (`project:"My App"`), and [`qualifier_value`] reads them
+//! back without the quotes. Every qualifier parser in this crate uses both;
+//! splitters rebuild the free text with [`retain_tokens`].
+//!
+//! [`wildcard_prefix`] recognizes trailing-`*` prefix terms (`data*`).
+//!
+//! [`split_raw_segment`] pulls out a `raw:` Tantivy query escape.
+//!
+//! [`split_sidechain_qualifier`] handles `is:sidechain` / `-is:sidechain`,
+//! and [`session_qualifier_value`] reads `session:<id>`.
+//!
+//! `duration:` and `tokens:` range qualifiers: session stats live in SQLite,
+//! not in the grep engine, so these are stripped from the query here and
+//! applied by the caller as a DB pre-filter. Supported forms: `>N`, `>=N`,
+//! `<N`, `<=N`, and exact `N`. Repeating a qualifier intersects the ranges
+//! (`duration:>60 duration:<600`).
+
+use crate::SearchError;
+
+/// Split a query into whitespace-separated tokens, keeping a quoted value
+/// right after `key:` in one token: `project:"My App" fix` yields
+/// `["project:\"My App\"", "fix"]`. Tokens are returned verbatim (quotes
+/// included). An unterminated quote runs to the end of the query.
+pub fn tokenize_query(query: &str) -> Vec<&str> {
+    token_spans(query)
+        .into_iter()
+        .map(|span| &query[span])
+        .collect()
+}
+
+/// Byte ranges of the [`tokenize_query`] tokens.
+fn token_spans(query: &str) -> Vec<std::ops::Range<usize>> {
+    let mut tokens = Vec::new();
+    let mut chars = query.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        let mut end = query.len();
+        let mut in_quotes = false;
+        while let Some(&(i, c)) = chars.peek() {
+            if c.is_whitespace() && !in_quotes {
+                end = i;
+                break;
+            }
+            if c == '"' {
+                if in_quotes {
+                    in_quotes = false;
+                } else if query[start..i].ends_with(':') {
+                    in_quotes = true;
+                }
+            }
+            chars.next();
+        }
+        tokens.push(start..end);
+    }
+    tokens
+}
+
+/// `query` with only the tokens `keep` accepts, for a splitter's remaining
+/// free text. Each kept token brings along the whitespace run before it, so
+/// a literal like `"foo  bar"` or one with tabs is not collapsed; leading and
+/// trailing whitespace is dropped.
+pub fn retain_tokens(query: &str, mut keep: impl FnMut(&str) -> bool) -> String {
+    let mut out = String::with_capacity(query.len());
+    for span in token_spans(query) {
+        let token = &query[span.clone()];
+        if !keep(token) {
+            continue;
+        }
+        if !out.is_empty() {
+            let gap_start = query[..span.start].trim_end().len();
+            out.push_str(&query[gap_start..span.start]);
+        }
+        out.push_str(token);
+    }
+    out
+}
+
+/// The value of qualifier `key` (including its colon, e.g. `"project:"`) in
+/// `token`, with surrounding quotes removed. `None` if `token` is not that
+/// qualifier.
+pub fn qualifier_value<'a>(token: &'a str, key: &str) -> Option<&'a str> {
+    let value = token.strip_prefix(key)?;
+    Some(match value.strip_prefix('"') {
+        Some(quoted) => quoted.strip_suffix('"').unwrap_or(quoted),
+        None => value,
+    })
+}
+
+/// Literal characters required before a trailing `*`. Shorter prefixes would
+/// expand to a large share of the term dictionary.
+pub const MIN_WILDCARD_PREFIX_CHARS: usize = 2;
+
+/// The literal prefix of a wildcard term: `data*` yields `Some("data")`.
+/// `Ok(None)` if `token` has no `*`. Errors unless the `*` is trailing and
+/// preceded by at least [`MIN_WILDCARD_PREFIX_CHARS`] letters or digits.
+pub fn wildcard_prefix(token: &str) -> Result<Option<&str>, SearchError> {
+    if !token.contains('*') {
+        return Ok(None);
+    }
+    match token.strip_suffix('*') {
+        Some(prefix)
+            if prefix.chars().count() >= MIN_WILDCARD_PREFIX_CHARS
+                && prefix.chars().all(char::is_alphanumeric) =>
+        {
+            Ok(Some(prefix))
+        }
+        _ => Err(SearchError::InvalidWildcard(token.to_string())),
+    }
+}
+
+/// Inclusive numeric bounds. `None` means unbounded on that side.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NumericRange {
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+}
+
+impl NumericRange {
+    /// Parse a comparison value such as `>600`, `<=5000`, or `42`.
+    pub fn parse(value: &str) -> Option<Self> {
+        let (op, digits) = if let Some(rest) = value.strip_prefix(">=") {
+            (">=", rest)
+        } else if let Some(rest) = value.strip_prefix("<=") {
+            ("<=", rest)
+        } else if let Some(rest) = value.strip_prefix('>') {
+            (">", rest)
+        } else if let Some(rest) = value.strip_prefix('<') {
+            ("<", rest)
+        } else {
+            ("=", value.strip_prefix('=').unwrap_or(value))
+        };
+        let n: i64 = digits.parse().ok()?;
+        let range = match op {
+            ">=" => Self {
+                min: Some(n),
+                max: None,
+            },
+            "<=" => Self {
+                min: None,
+                max: Some(n),
+            },
+            ">" => Self {
+                min: n.checked_add(1),
+                max: None,
+            },
+            "<" => Self {
+                min: None,
+                max: n.checked_sub(1),
+            },
+            _ => Self {
+                min: Some(n),
+                max: Some(n),
+            },
+        };
+        Some(range)
+    }
+
+    /// Narrow this range by another (logical AND).
+    pub fn intersect(self, other: Self) -> Self {
+        let pick = |a: Option<i64>, b: Option<i64>, f: fn(i64, i64) -> i64| match (a, b) {
+            (Some(x), Some(y)) => Some(f(x, y)),
+            (x, None) => x,
+            (None, y) => y,
+        };
+        Self {
+            min: pick(self.min, other.min, i64::max),
+            max: pick(self.max, other.max, i64::min),
+        }
+    }
+}
+
+/// Range qualifiers extracted from a search query.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeQualifiers {
+    /// Session wall-clock duration in seconds.
+    pub duration: Option<NumericRange>,
+    /// Total input + output tokens.
+    pub tokens: Option<NumericRange>,
+}
+
+impl RangeQualifiers {
+    pub fn is_empty(&self) -> bool {
+        self.duration.is_none() && self.tokens.is_none()
+    }
+}
+
+/// Split `duration:` / `tokens:` qualifiers out of a query.
+///
+/// Returns the parsed ranges and the remaining free text. Tokens with an
+/// unparseable value (e.g. `duration:long`) are left in the free text.
+pub fn split_range_qualifiers(query: &str) -> (RangeQualifiers, String) {
+    let mut quals = RangeQualifiers::default();
+    let rest = retain_tokens(query, |token| {
+        let (slot, value) = if let Some(v) = qualifier_value(token, "duration:") {
+            (&mut quals.duration, v)
+        } else if let Some(v) = qualifier_value(token, "tokens:") {
+            (&mut quals.tokens, v)
+        } else {
+            return true;
+        };
+        match NumericRange::parse(value) {
+            Some(range) => {
+                *slot = Some(slot.map_or(range, |prev| prev.intersect(range)));
+                false
+            }
+            None => true,
+        }
+    });
+    (quals, rest)
+}
+
+/// Longest value accepted by [`session_qualifier_value`].
+const MAX_SESSION_ID_LEN: usize = 128;
+
+/// The id in a `session:<id>` token. The shape check is loose — ASCII
+/// letters, digits, `-` and `_`, up to [`MAX_SESSION_ID_LEN`] — so UUIDs and
+/// `agent-*` ids pass while stray text like `session:` or `session:a/b` is
+/// left as free text.
+pub fn session_qualifier_value(token: &str) -> Option<&str> {
+    let id = qualifier_value(token, "session:")?;
+    let plausible = !id.is_empty()
+        && id.len() <= MAX_SESSION_ID_LEN
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    plausible.then_some(id)
+}
+
+/// Split `session:<id>` tokens out of a query. Returns the ids (a result may
+/// come from any of them) and the remaining free text.
+pub fn split_session_qualifiers(query: &str) -> (Vec<String>, String) {
+    let mut ids = Vec::new();
+    let rest = retain_tokens(query, |token| match session_qualifier_value(token) {
+        Some(id) => {
+            ids.push(id.to_string());
+            false
+        }
+        None => true,
+    });
+    (ids, rest)
+}
+
+/// Split `mcp:<server>` tokens out of a query. Returns the server names (a
+/// result must have used every one of them) and the remaining free text.
+/// An empty value (`mcp:`) stays in the free text.
+pub fn split_mcp_qualifiers(query: &str) -> (Vec<String>, String) {
+    let mut servers = Vec::new();
+    let rest = retain_tokens(query, |token| {
+        match qualifier_value(token, "mcp:").filter(|s| !s.is_empty()) {
+            Some(server) => {
+                servers.push(server.to_string());
+                false
+            }
+            None => true,
+        }
+    });
+    (servers, rest)
+}
+
+const RAW_PREFIX: &str = "raw:";
+
+/// Split a `raw:` segment out of a query for passing to Tantivy's
+/// `QueryParser` untouched.
+///
+/// `raw:(...)` ends at the matching `)` (parentheses inside double quotes
+/// don't count) and keeps the parentheses; any other `raw:` value runs to
+/// the end of the query. Only the first `raw:` token is split out. Returns
+/// the raw query (`None` if absent or empty) and the remaining text, whose
+/// qualifiers the caller ANDs with the raw query.
+pub fn split_raw_segment(query: &str) -> (Option<&str>, String) {
+    let start = query.match_indices(RAW_PREFIX).map(|(i, _)| i).find(|&i| {
+        query[..i]
+            .chars()
+            .next_back()
+            .is_none_or(char::is_whitespace)
+    });
+    let Some(start) = start else {
+        return (None, query.to_string());
+    };
+    let value_start = start + RAW_PREFIX.len();
+    let value = &query[value_start..];
+    let mut end = query.len();
+    if value.starts_with('(') {
+        let (mut depth, mut in_quotes) = (0usize, false);
+        for (i, c) in value.char_indices() {
+            match c {
+                '"' => in_quotes = !in_quotes,
+                '(' if !in_quotes => depth += 1,
+                ')' if !in_quotes => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = value_start + i + 1;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    let raw = query[value_start..end].trim();
+    let rest = format!("{} {}", &query[..start], &query[end..]);
+    (
+        (!raw.is_empty()).then_some(raw),
+        rest.split_whitespace().collect::<Vec<_>>().join(" "),
+    )
+}
+
+const SIDECHAIN_QUALIFIER: &str = "is:sidechain";
+
+/// Split `is:sidechain` / `-is:sidechain` out of a query.
+///
+/// Returns `Some(true)` to keep only subagent sidechains, `Some(false)` to
+/// exclude them, or `None` if neither appears (the last one wins), plus the
+/// remaining free text.
+pub fn split_sidechain_qualifier(query: &str) -> (Option<bool>, String) {
+    let mut sidechain = None;
+    let rest = retain_tokens(query, |token| {
+        if token.eq_ignore_ascii_case(SIDECHAIN_QUALIFIER) {
+            sidechain = Some(true);
+        } else if token
+            .strip_prefix('-')
+            .is_some_and(|t| t.eq_ignore_ascii_case(SIDECHAIN_QUALIFIER))
+        {
+            sidechain = Some(false);
+        } else {
+            return true;
+        }
+        false
+    });
+    (sidechain, rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_greater_than() {
+        let (q, rest) = split_range_qualifiers("duration:>600 deploy");
+        assert_eq!(
+            q.duration,
+            Some(NumericRange {
+                min: Some(601),
+                max: None
+            })
+        );
+        assert_eq!(q.tokens, None);
+        assert_eq!(rest, "deploy");
+    }
+
+    #[test]
+    fn test_less_than_and_inclusive_forms() {
+        let (q, _) = split_range_qualifiers("tokens:<100000");
+        assert_eq!(
+            q.tokens,
+            Some(NumericRange {
+                min: None,
+                max: Some(99_999)
+            })
+        );
+        let (q, _) = split_range_qualifiers("tokens:>=5 duration:<=10");
+        assert_eq!(q.tokens.unwrap().min, Some(5));
+        assert_eq!(q.duration.unwrap().max, Some(10));
+    }
+
+    #[test]
+    fn test_exact_value() {
+        let (q, rest) = split_range_qualifiers("tokens:1500");
+        assert_eq!(
+            q.tokens,
+            Some(NumericRange {
+                min: Some(1500),
+                max: Some(1500)
+            })
+        );
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn test_repeated_qualifier_intersects() {
+        let (q, _) = split_range_qualifiers("duration:>60 duration:<600");
+        assert_eq!(
+            q.duration,
+            Some(NumericRange {
+                min: Some(61),
+                max: Some(599)
+            })
+        );
+    }
+
+    #[test]
+    fn test_tokenize_keeps_quoted_qualifier_values_together() {
+        assert_eq!(
+            tokenize_query(r#"project:"My App"  fix  branch:"feature/long name""#),
+            vec![
+                r#"project:"My App""#,
+                "fix",
+                r#"branch:"feature/long name""#
+            ]
+        );
+        // Quotes outside a qualifier value do not group words.
+        assert_eq!(tokenize_query(r#""two words""#), vec!["\"two", "words\""]);
+        // Unterminated quote runs to the end.
+        assert_eq!(
+            tokenize_query(r#"project:"My App"#),
+            vec![r#"project:"My App"#]
+        );
+    }
+
+    #[test]
+    fn test_qualifier_value_strips_quotes() {
+        assert_eq!(
+            qualifier_value(r#"project:"My App""#, "project:"),
+            Some("My App")
+        );
+        assert_eq!(qualifier_value("project:web", "project:"), Some("web"));
+        assert_eq!(
+            qualifier_value(r#"project:"open"#, "project:"),
+            Some("open")
+        );
+        assert_eq!(qualifier_value("branch:main", "project:"), None);
+    }
+
+    #[test]
+    fn test_wildcard_prefix() {
+        assert_eq!(wildcard_prefix("data*").unwrap(), Some("data"));
+        assert_eq!(wildcard_prefix("data").unwrap(), None);
+        for rejected in ["*", "d*", "*data", "da*ta", "data**", "fo-o*"] {
+            assert!(
+                matches!(
+                    wildcard_prefix(rejected),
+                    Err(SearchError::InvalidWildcard(_))
+                ),
+                "{rejected} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_value_stays_in_text() {
+        let (q, rest) = split_range_qualifiers("duration:long fix");
+        assert!(q.is_empty());
+        assert_eq!(rest, "duration:long fix");
+    }
+
+    #[test]
+    fn test_range_split_keeps_literal_whitespace() {
+        let (q, rest) = split_range_qualifiers("fix  the\tbuild tokens:>100");
+        assert!(!q.is_empty());
+        assert_eq!(rest, "fix  the\tbuild");
+    }
+
+    #[test]
+    fn test_sidechain_qualifier() {
+        assert_eq!(
+            split_sidechain_qualifier("is:sidechain deploy"),
+            (Some(true), "deploy".to_string())
+        );
+        assert_eq!(
+            split_sidechain_qualifier("deploy -is:sidechain"),
+            (Some(false), "deploy".to_string())
+        );
+        assert_eq!(
+            split_sidechain_qualifier("is:sidechainy"),
+            (None, "is:sidechainy".to_string())
+        );
+        assert_eq!(
+            split_sidechain_qualifier("is:sidechain fix  the\tbuild"),
+            (Some(true), "fix  the\tbuild".to_string())
+        );
+    }
+
+    #[test]
+    fn test_mcp_qualifier() {
+        assert_eq!(
+            split_mcp_qualifiers("mcp:playwright screenshot mcp:supabase"),
+            (
+                vec!["playwright".to_string(), "supabase".to_string()],
+                "screenshot".to_string()
+            )
+        );
+        assert_eq!(
+            split_mcp_qualifiers("mcp: x"),
+            (vec![], "mcp: x".to_string())
+        );
+        assert_eq!(
+            split_mcp_qualifiers("take  a\tscreenshot mcp:playwright"),
+            (
+                vec!["playwright".to_string()],
+                "take  a\tscreenshot".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_split_raw_segment() {
+        assert_eq!(
+            split_raw_segment("intent:fix raw:(foo AND bar) branch:main"),
+            (Some("(foo AND bar)"), "intent:fix branch:main".to_string())
+        );
+        assert_eq!(
+            split_raw_segment(r#"raw:(display:"a (b" OR c)"#),
+            (Some(r#"(display:"a (b" OR c)"#), String::new())
+        );
+        // Unparenthesized: the remainder of the query.
+        assert_eq!(
+            split_raw_segment("project:x raw:foo -bar"),
+            (Some("foo -bar"), "project:x".to_string())
+        );
+        // Not at a token start, or empty.
+        assert_eq!(
+            split_raw_segment("draw:foo"),
+            (None, "draw:foo".to_string())
+        );
+        assert_eq!(split_raw_segment("x raw:"), (None, "x".to_string()));
+    }
+
+    #[test]
+    fn test_session_qualifier() {
+        assert_eq!(
+            split_session_qualifiers("session:3f2a-9c_b1 deploy"),
+            (vec!["3f2a-9c_b1".to_string()], "deploy".to_string())
+        );
+        assert_eq!(session_qualifier_value(r#"session:"abc""#), Some("abc"));
+        // Implausible ids stay in the free text.
+        assert_eq!(session_qualifier_value("session:"), None);
+        assert_eq!(session_qualifier_value("session:a/b"), None);
+        assert_eq!(
+            split_session_qualifiers("session:a/b"),
+            (vec![], "session:a/b".to_string())
+        );
+        assert_eq!(
+            split_session_qualifiers("deploy  the\tapi session:abc"),
+            (vec!["abc".to_string()], "deploy  the\tapi".to_string())
+        );
+    }
+}
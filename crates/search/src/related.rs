@@ -0,0 +1,195 @@
+// crates/search/src/related.rs
+//! "Related sessions" ranking over raw JSONL files.
+//!
+//! Session search no longer keeps a Tantivy index, so similarity is a
+//! term-overlap query: take the source session's top terms by frequency and
+//! score every other session by the IDF-weighted sum of the terms it shares.
+//! Rare shared terms (file names, crate names, skills) dominate; terms that
+//! appear in every session contribute almost nothing.
+
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use crate::grep::JsonlFile;
+use crate::types::{MatchHit, SessionHit};
+
+/// How many of the source session's most frequent terms form the query.
+pub const RELATED_QUERY_TERMS: usize = 32;
+
+/// Terms shorter than this are ignored (mostly punctuation fragments).
+const MIN_TERM_LEN: usize = 3;
+
+/// Common English and JSONL-noise words that carry no topical signal.
+const STOP_WORDS: &[&str] = &[
+    "the", "and", "for", "that", "this", "with", "you", "are", "was", "but", "not", "have", "has",
+    "can", "will", "from", "all", "any", "its", "it's", "let", "now", "use", "our", "out", "get",
+    "one", "also", "into", "then", "than", "them", "they", "there", "what", "when", "which",
+    "would", "should", "could", "just", "like", "need", "make", "file", "true", "false", "null",
+];
+
+/// Term frequencies for one session's user/assistant text.
+pub type TermCounts = HashMap<String, u32>;
+
+/// Read a session JSONL file and count terms in message text.
+///
+/// Only `message.content` text (plain strings and `text` blocks) is counted —
+/// tool payloads and JSON keys would otherwise make every session look alike.
+/// Unreadable files and malformed lines yield an empty/partial count.
+pub fn session_terms(path: &Path) -> TermCounts {
+    let mut counts = TermCounts::new();
+    let Ok(file) = std::fs::File::open(path) else {
+        return counts;
+    };
+    for line in BufReader::new(file).lines().map_while(Result::ok) {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(&line) else {
+            continue;
+        };
+        let Some(content) = value.get("message").and_then(|m| m.get("content")) else {
+            continue;
+        };
+        match content {
+            serde_json::Value::String(text) => count_terms(text, &mut counts),
+            serde_json::Value::Array(blocks) => {
+                for block in blocks {
+                    if block.get("type").and_then(|t| t.as_str()) == Some("text") {
+                        if let Some(text) = block.get("text").and_then(|t| t.as_str()) {
+                            count_terms(text, &mut counts);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    counts
+}
+
+fn count_terms(text: &str, counts: &mut TermCounts) {
+    for raw in text.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '\'')) {
+        let term = raw.trim_matches('\'').to_lowercase();
+        if term.chars().count() < MIN_TERM_LEN || STOP_WORDS.contains(&term.as_str()) {
+            continue;
+        }
+        if term.chars().all(|c| c.is_ascii_digit()) {
+            continue;
+        }
+        *counts.entry(term).or_default() += 1;
+    }
+}
+
+/// The `n` most frequent terms, ties broken alphabetically for stable output.
+pub fn top_terms(counts: &TermCounts, n: usize) -> Vec<String> {
+    let mut terms: Vec<(&String, &u32)> = counts.iter().collect();
+    terms.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    terms.into_iter().take(n).map(|(t, _)| t.clone()).collect()
+}
+
+/// Rank `candidates` by similarity to `source`, excluding the source itself.
+///
+/// Sessions sharing no query terms are dropped. `best_score` carries the
+/// similarity score and `match_count` the number of shared query terms;
+/// `top_match.snippet` lists the shared terms, best first.
+///
+/// Caller is responsible for wrapping this in `spawn_blocking` if needed.
+pub fn related_sessions(
+    source: &JsonlFile,
+    candidates: &[JsonlFile],
+    limit: usize,
+) -> Vec<SessionHit> {
+    if limit == 0 {
+        return Vec::new();
+    }
+    let query = top_terms(&session_terms(&source.path), RELATED_QUERY_TERMS);
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let others: Vec<(&JsonlFile, HashSet<String>)> = candidates
+        .iter()
+        .filter(|f| f.session_id != source.session_id)
+        .map(|f| {
+            let terms = session_terms(&f.path).into_keys().collect();
+            (f, terms)
+        })
+        .collect();
+
+    // Document frequency across the candidate set (+1 for the source).
+    let n_docs = others.len() as f32 + 1.0;
+    let idf: HashMap<&str, f32> = query
+        .iter()
+        .map(|t| {
+            let df = 1.0 + others.iter().filter(|(_, ts)| ts.contains(t)).count() as f32;
+            (t.as_str(), (1.0 + n_docs / df).ln())
+        })
+        .collect();
+
+    let mut hits: Vec<SessionHit> = others
+        .into_iter()
+        .filter_map(|(file, terms)| {
+            let mut shared: Vec<&str> = query
+                .iter()
+                .map(String::as_str)
+                .filter(|t| terms.contains(*t))
+                .collect();
+            if shared.is_empty() {
+                return None;
+            }
+            shared.sort_by(|a, b| idf[b].total_cmp(&idf[a]).then_with(|| a.cmp(b)));
+            let score: f32 = shared.iter().map(|t| idf[t]).sum();
+            Some(SessionHit {
+                session_id: file.session_id.clone(),
+                project: file.project.clone(),
+                branch: None,
+                modified_at: file.modified_at,
+                match_count: shared.len(),
+                best_score: score,
+                top_match: MatchHit {
+                    role: "unknown".to_string(),
+                    turn_number: 0,
+                    snippet: shared.join(", "),
+                    timestamp: file.modified_at,
+                },
+                matches: Vec::new(),
+                match_turns: Vec::new(),
+                engines: vec!["related".to_string()],
+            })
+        })
+        .collect();
+
+    hits.sort_by(|a, b| {
+        b.best_score
+            .total_cmp(&a.best_score)
+            .then_with(|| b.modified_at.cmp(&a.modified_at))
+    });
+    hits.truncate(limit);
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_terms_skips_stop_words_and_short_tokens() {
+        let mut counts = TermCounts::new();
+        count_terms(
+            "The tantivy index is in the tantivy crate, ok? 2024",
+            &mut counts,
+        );
+        assert_eq!(counts.get("tantivy"), Some(&2));
+        assert_eq!(counts.get("index"), Some(&1));
+        assert!(!counts.contains_key("the"));
+        assert!(!counts.contains_key("ok"));
+        assert!(!counts.contains_key("2024"));
+    }
+
+    #[test]
+    fn test_top_terms_orders_by_frequency_then_alpha() {
+        let mut counts = TermCounts::new();
+        counts.insert("beta".into(), 2);
+        counts.insert("alpha".into(), 2);
+        counts.insert("gamma".into(), 5);
+        assert_eq!(top_terms(&counts, 2), vec!["gamma", "alpha"]);
+    }
+}
@@ -2,7 +2,7 @@ use serde::Serialize;
 use ts_rs::TS;
 
 /// Response from a grep search query across all sessions.
-#[derive(Debug, Clone, Serialize, TS)]
+#[derive(Debug, Clone, Serialize, TS, utoipa::ToSchema)]
 #[cfg_attr(feature = "codegen", ts(export))]
 #[serde(rename_all = "camelCase")]
 pub struct SearchResponse {
@@ -14,12 +14,28 @@ pub struct SearchResponse {
     pub total_matches: usize,
     /// Time spent executing the search, in milliseconds.
     pub elapsed_ms: f64,
+    /// Per-phase breakdown of `elapsed_ms`.
+    pub timings: SearchTimings,
     /// Session-grouped results, sorted by session recency descending.
     pub sessions: Vec<SessionHit>,
 }
 
+/// Sub-timings of a search, in milliseconds. The phases run back to back,
+/// so together they never exceed `elapsed_ms`.
+#[derive(Debug, Clone, Copy, Default, Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct SearchTimings {
+    /// Query parsing: qualifier extraction and attachment filtering setup.
+    pub parse_ms: f64,
+    /// Running the match engine (grep, or the attachment scan).
+    pub query_ms: f64,
+    /// Building snippets and session groups, sorting, and pagination.
+    pub snippet_ms: f64,
+}
+
 /// A session that contains one or more search matches.
-#[derive(Debug, Clone, Serialize, TS)]
+#[derive(Debug, Clone, Serialize, TS, utoipa::ToSchema)]
 #[cfg_attr(feature = "codegen", ts(export))]
 #[serde(rename_all = "camelCase")]
 pub struct SessionHit {
@@ -31,19 +47,24 @@ pub struct SessionHit {
     pub modified_at: i64,
     /// How many individual messages matched in this session.
     pub match_count: usize,
-    /// Search score. Grep-backed session search sets this to 0.
+    /// Search score: the best match's role boost (see
+    /// [`crate::RoleBoosts`]), or 0 when no role boosts are set.
     pub best_score: f32,
     /// The single best-scoring match (for collapsed view).
     pub top_match: MatchHit,
     /// All matches in this session (for expanded view).
     pub matches: Vec<MatchHit>,
+    /// Every turn with a match, sorted and deduped (for a scrollbar
+    /// mini-map). Unlike `matches`, never collapsed by snippet dedupe.
+    #[ts(type = "number[]")]
+    pub match_turns: Vec<u64>,
     /// Which search engines contributed to this session's results.
     /// Session search currently returns ["grep"].
     pub engines: Vec<String>,
 }
 
 /// A single message-level search match with a highlighted snippet.
-#[derive(Debug, Clone, Serialize, TS)]
+#[derive(Debug, Clone, Serialize, TS, utoipa::ToSchema)]
 #[cfg_attr(feature = "codegen", ts(export))]
 #[serde(rename_all = "camelCase")]
 pub struct MatchHit {
@@ -4,8 +4,18 @@
 //! Grep is the session text search engine: exact substring matching, CJK-safe,
 //! and no persistent index lifecycle to manage.
 
-use crate::grep::{grep_files, GrepOptions, JsonlFile};
-use crate::types::{MatchHit, SearchResponse, SessionHit};
+use crate::attachments::{filter_by_attachments, split_attachment_qualifiers};
+use crate::fields::SearchFields;
+use crate::grep::{grep_files, turn_numbers_for_lines, GrepOptions, JsonlFile};
+use crate::grep_types::GrepLineMatch;
+use crate::has::{filter_by_features, split_has_qualifiers};
+use crate::query::{split_session_qualifiers, split_sidechain_qualifier};
+use crate::types::{MatchHit, SearchResponse, SearchTimings, SessionHit};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+use std::time::{Duration, Instant};
 
 /// Which engine produced the search results.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,7 +26,12 @@ pub enum SearchEngine {
 
 /// Options for unified search.
 pub struct UnifiedSearchOptions {
-    /// The raw query string.
+    /// The raw query string. `attachment:<name>` tokens restrict results to
+    /// sessions with a matching attachment, `has:attachment` / `has:thinking`
+    /// / `has:code` to sessions containing one, `is:sidechain` / `-is:sidechain`
+    /// keeps or drops subagent transcripts, and `session:<id>` limits the
+    /// search to that session; the rest is grepped literally. With no rest,
+    /// the scoped sessions are returned without grepping.
     pub query: String,
     /// Optional scope filter (e.g. `"project:claude-view"`).
     pub scope: Option<String>,
@@ -26,6 +41,94 @@ pub struct UnifiedSearchOptions {
     pub offset: usize,
     /// Skip snippet generation — retained for API compatibility.
     pub skip_snippets: bool,
+    /// Only `<mark>` whole-word occurrences of the query in snippets, so
+    /// "auth" is not highlighted inside "authorization". Matching itself is
+    /// unchanged (still substring). Defaults to substring highlighting.
+    pub whole_word_highlight: bool,
+    /// Collapse near-identical snippets within a session's `matches` (same
+    /// words once highlight tags, case, and digit-bearing tokens such as
+    /// UUIDs and timestamps are ignored). The first match in file order is
+    /// kept; `match_count` still counts every matching message.
+    pub dedupe_snippets: bool,
+    /// Hit fields the caller will keep (see [`SearchFields`]). Snippets are
+    /// skipped when none survive it; `None` returns everything.
+    pub fields: Option<SearchFields>,
+    /// Score matches by the role of the message they are in, ranking
+    /// sessions by their best match (ties by recency) and reporting each
+    /// match's role. `None` (the default) keeps plain recency order with
+    /// every match's role `"unknown"`.
+    pub role_boosts: Option<RoleBoosts>,
+}
+
+/// Per-role score of a session-search match, for ranking "what did I ask
+/// about" queries toward user prompts. A session scores its best match.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoleBoosts {
+    /// Text the user typed.
+    pub user: f32,
+    /// Assistant replies (text, thinking, tool calls).
+    pub assistant: f32,
+    /// Tool results.
+    pub tool: f32,
+}
+
+impl RoleBoosts {
+    /// Parse comma-separated `role:weight` pairs, e.g. `"user:2,tool:0.5"`.
+    /// Roles left out score 1.0.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut boosts = Self::default();
+        for pair in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (role, weight) = pair
+                .split_once(':')
+                .ok_or_else(|| format!("invalid role boost '{pair}', expected role:weight"))?;
+            let weight = weight
+                .trim()
+                .parse::<f32>()
+                .ok()
+                .filter(|w| w.is_finite() && *w >= 0.0)
+                .ok_or_else(|| format!("invalid weight in role boost '{pair}'"))?;
+            match role.trim() {
+                "user" => boosts.user = weight,
+                "assistant" => boosts.assistant = weight,
+                "tool" => boosts.tool = weight,
+                other => return Err(format!("unknown role '{other}' in role boost")),
+            }
+        }
+        Ok(boosts)
+    }
+
+    /// Score of a match in a message with `role`; unknown roles score 1.0.
+    pub fn boost(&self, role: &str) -> f32 {
+        match role {
+            "user" => self.user,
+            "assistant" => self.assistant,
+            "tool" => self.tool,
+            _ => 1.0,
+        }
+    }
+}
+
+impl Default for RoleBoosts {
+    /// No boost: every role scores 1.0.
+    fn default() -> Self {
+        Self {
+            user: 1.0,
+            assistant: 1.0,
+            tool: 1.0,
+        }
+    }
+}
+
+impl UnifiedSearchOptions {
+    /// Whether snippets are dropped, either explicitly or by `fields`.
+    fn skips_snippets(&self) -> bool {
+        self.skip_snippets || self.fields.as_ref().is_some_and(|f| !f.wants_snippets())
+    }
+
+    /// Whether matched lines need mapping to turn numbers.
+    fn wants_turns(&self) -> bool {
+        self.fields.as_ref().is_none_or(SearchFields::wants_turns)
+    }
 }
 
 /// Extended search response with engine metadata.
@@ -54,15 +157,93 @@ pub fn unified_search(
                 total_sessions: 0,
                 total_matches: 0,
                 elapsed_ms: 0.0,
+                timings: SearchTimings::default(),
                 sessions: vec![],
             },
             engine: SearchEngine::Grep,
         });
     }
 
+    let start = Instant::now();
+    let mut lap = Lap(start);
+    let mut timings = SearchTimings::default();
+
+    let (sidechain, text_query) = split_sidechain_qualifier(&opts.query);
+    let (session_ids, text_query) = split_session_qualifiers(&text_query);
+    let scoped_files;
+    let jsonl_files: &[JsonlFile] = if sidechain.is_none() && session_ids.is_empty() {
+        jsonl_files
+    } else {
+        scoped_files = jsonl_files
+            .iter()
+            .filter(|f| sidechain.is_none_or(|want| f.is_sidechain == want))
+            .filter(|f| session_ids.is_empty() || session_ids.contains(&f.session_id))
+            .cloned()
+            .collect::<Vec<_>>();
+        &scoped_files
+    };
+
+    let (features, text_query) = split_has_qualifiers(&text_query);
+    let (attachment_needles, text_query) = split_attachment_qualifiers(&text_query);
+    let feature_matches;
+    let jsonl_files: &[JsonlFile] = if features.is_empty() {
+        jsonl_files
+    } else {
+        timings.parse_ms += lap.next();
+        feature_matches = filter_by_features(jsonl_files, &features);
+        timings.query_ms += lap.next();
+        if text_query.is_empty() && attachment_needles.is_empty() {
+            let labels: Vec<String> = features.iter().map(|f| f.as_str().to_string()).collect();
+            let matched = feature_matches
+                .into_iter()
+                .map(|f| (f, labels.clone()))
+                .collect();
+            let mut result = filter_only_result(opts, matched, "has");
+            timings.snippet_ms += lap.next();
+            result.response.timings = timings;
+            result.response.elapsed_ms = as_ms(start.elapsed());
+            return Ok(result);
+        }
+        &feature_matches
+    };
+
+    let attachment_matches;
+    let jsonl_files: &[JsonlFile] = if attachment_needles.is_empty() {
+        jsonl_files
+    } else {
+        timings.parse_ms += lap.next();
+        let matched = filter_by_attachments(jsonl_files, &attachment_needles);
+        timings.query_ms += lap.next();
+        if text_query.is_empty() {
+            let mut result = filter_only_result(opts, matched, "attachment");
+            timings.snippet_ms += lap.next();
+            result.response.timings = timings;
+            result.response.elapsed_ms = as_ms(start.elapsed());
+            return Ok(result);
+        }
+        attachment_matches = matched.into_iter().map(|(f, _)| f).collect::<Vec<_>>();
+        &attachment_matches
+    };
+
+    // Only qualifiers (here, or already applied by the caller, e.g.
+    // `duration:>600`): an empty grep pattern would match every line, so
+    // return the scoped sessions as they are.
+    if text_query.trim().is_empty() {
+        timings.parse_ms += lap.next();
+        let matched = jsonl_files
+            .iter()
+            .map(|f| (f.clone(), Vec::new()))
+            .collect();
+        let mut result = filter_only_result(opts, matched, "filter");
+        timings.snippet_ms += lap.next();
+        result.response.timings = timings;
+        result.response.elapsed_ms = as_ms(start.elapsed());
+        return Ok(result);
+    }
+
     if !jsonl_files.is_empty() {
         let grep_opts = GrepOptions {
-            pattern: regex_escape_for_literal(&opts.query),
+            pattern: regex_escape_for_literal(&text_query),
             case_sensitive: false,
             whole_word: false,
             limit: opts
@@ -72,27 +253,61 @@ pub fn unified_search(
                 .min(100_000),
         };
 
+        timings.parse_ms += lap.next();
         let grep_resp = grep_files(jsonl_files, &grep_opts)?;
-        let mut sessions: Vec<SessionHit> = grep_resp
+        timings.query_ms += lap.next();
+
+        let snippet = |m: &GrepLineMatch| {
+            if opts.whole_word_highlight {
+                truncate_and_highlight_words(&m.content, m.match_start, m.match_end, &text_query)
+            } else {
+                truncate_and_highlight(&m.content, m.match_start, m.match_end)
+            }
+        };
+        let paths: HashMap<&str, &Path> = jsonl_files
+            .iter()
+            .map(|f| (f.session_id.as_str(), f.path.as_path()))
+            .collect();
+        let mut sessions: Vec<(SessionHit, Vec<usize>, usize)> = grep_resp
             .results
             .into_iter()
             .map(|hit| {
                 let match_count = hit.matches.len();
-                let top_match = if opts.skip_snippets {
+                let lines: Vec<usize> = hit.matches.iter().map(|m| m.line_number).collect();
+                // Messages carry their own time; fall back to the file's.
+                let timestamp = |m: &GrepLineMatch| match_timestamp(&m.content, hit.modified_at);
+                // Without role boosts every match scores the same, so the
+                // first one (in file order) is the top match.
+                let roles: Vec<&str> = match &opts.role_boosts {
+                    Some(_) => hit.matches.iter().map(|m| match_role(&m.content)).collect(),
+                    None => vec!["unknown"; match_count],
+                };
+                let (best, best_score) = match &opts.role_boosts {
+                    Some(boosts) => roles.iter().enumerate().fold((0, 0.0f32), |acc, (i, r)| {
+                        let score = boosts.boost(r);
+                        if score > acc.1 {
+                            (i, score)
+                        } else {
+                            acc
+                        }
+                    }),
+                    None => (0, 0.0),
+                };
+                let top_match = if opts.skips_snippets() {
                     MatchHit {
-                        role: "unknown".to_string(),
+                        role: roles.get(best).copied().unwrap_or("unknown").to_string(),
                         turn_number: 0,
                         snippet: String::new(),
-                        timestamp: hit.modified_at,
+                        timestamp: hit.matches.get(best).map_or(hit.modified_at, timestamp),
                     }
                 } else {
                     hit.matches
-                        .first()
+                        .get(best)
                         .map(|m| MatchHit {
-                            role: "unknown".to_string(),
+                            role: roles[best].to_string(),
                             turn_number: 0,
-                            snippet: truncate_and_highlight(&m.content, m.match_start, m.match_end),
-                            timestamp: hit.modified_at,
+                            snippet: snippet(m),
+                            timestamp: timestamp(m),
                         })
                         .unwrap_or_else(|| MatchHit {
                             role: "unknown".to_string(),
@@ -101,66 +316,229 @@ pub fn unified_search(
                             timestamp: 0,
                         })
                 };
-                let matches: Vec<MatchHit> = if opts.skip_snippets {
+                let matches: Vec<MatchHit> = if opts.skips_snippets() {
                     Vec::new()
                 } else {
                     hit.matches
                         .iter()
-                        .map(|m| MatchHit {
-                            role: "unknown".to_string(),
+                        .zip(&roles)
+                        .map(|(m, role)| MatchHit {
+                            role: role.to_string(),
                             turn_number: 0,
-                            snippet: truncate_and_highlight(&m.content, m.match_start, m.match_end),
-                            timestamp: hit.modified_at,
+                            snippet: snippet(m),
+                            timestamp: timestamp(m),
                         })
                         .collect()
                 };
-                SessionHit {
+                let session = SessionHit {
                     session_id: hit.session_id,
                     project: hit.project,
                     branch: None,
                     modified_at: hit.modified_at,
                     match_count,
-                    best_score: 0.0,
+                    best_score,
                     top_match,
                     matches,
+                    match_turns: Vec::new(),
                     engines: vec!["grep".to_string()],
-                }
+                };
+                (session, lines, best)
             })
             .collect();
 
-        sessions.sort_by(|a, b| b.modified_at.cmp(&a.modified_at));
+        // Best score first (all 0 without role boosts), then most recent.
+        sessions.sort_by(|(a, ..), (b, ..)| {
+            b.best_score
+                .total_cmp(&a.best_score)
+                .then_with(|| b.modified_at.cmp(&a.modified_at))
+        });
         let total_sessions = sessions.len();
-        let total_matches: usize = sessions.iter().map(|s| s.match_count).sum();
+        let total_matches: usize = sessions.iter().map(|(s, ..)| s.match_count).sum();
+        // Turn mapping re-reads the JSONL, so only do it for the returned
+        // page. Slim requests (no snippets) still get `match_turns`.
+        let map_turns = opts.wants_turns();
         let sessions = sessions
             .into_iter()
             .skip(opts.offset)
             .take(opts.limit)
+            .map(|(mut session, lines, best)| {
+                if map_turns {
+                    if let Some(path) = paths.get(session.session_id.as_str()) {
+                        let turns = turn_numbers_for_lines(path, &lines);
+                        session.top_match.turn_number = turns.get(best).copied().unwrap_or(0);
+                        for (m, &turn) in session.matches.iter_mut().zip(&turns) {
+                            m.turn_number = turn;
+                        }
+                        let mut match_turns: Vec<u64> =
+                            turns.into_iter().filter(|&t| t > 0).collect();
+                        match_turns.sort_unstable();
+                        match_turns.dedup();
+                        session.match_turns = match_turns;
+                    }
+                }
+                if opts.dedupe_snippets {
+                    dedupe_matches(&mut session.matches);
+                }
+                session
+            })
             .collect();
+        timings.snippet_ms += lap.next();
 
         return Ok(UnifiedSearchResult {
             response: SearchResponse {
                 query: opts.query.clone(),
                 total_sessions,
                 total_matches,
-                elapsed_ms: 0.0,
+                elapsed_ms: as_ms(start.elapsed()),
+                timings,
                 sessions,
             },
             engine: SearchEngine::Grep,
         });
     }
 
+    // Every session was scoped out before the grep.
+    timings.parse_ms += lap.next();
     Ok(UnifiedSearchResult {
         response: SearchResponse {
             query: opts.query.clone(),
             total_sessions: 0,
             total_matches: 0,
-            elapsed_ms: 0.0,
+            elapsed_ms: as_ms(start.elapsed()),
+            timings,
             sessions: vec![],
         },
         engine: SearchEngine::Grep,
     })
 }
 
+/// Results for a query made only of `attachment:` or `has:` qualifiers: one
+/// hit per matching session, newest first, with the matched attachment names
+/// (or features) as snippet. `engine` names the qualifier that matched.
+fn filter_only_result(
+    opts: &UnifiedSearchOptions,
+    matched: Vec<(JsonlFile, Vec<String>)>,
+    engine: &str,
+) -> UnifiedSearchResult {
+    let mut sessions: Vec<SessionHit> = matched
+        .into_iter()
+        .map(|(file, names)| {
+            let snippet = if opts.skips_snippets() {
+                String::new()
+            } else {
+                names.join(", ")
+            };
+            SessionHit {
+                session_id: file.session_id,
+                project: file.project,
+                branch: None,
+                modified_at: file.modified_at,
+                match_count: names.len(),
+                best_score: 0.0,
+                top_match: MatchHit {
+                    role: "user".to_string(),
+                    turn_number: 0,
+                    snippet,
+                    timestamp: file.modified_at,
+                },
+                matches: Vec::new(),
+                match_turns: Vec::new(),
+                engines: vec![engine.to_string()],
+            }
+        })
+        .collect();
+
+    sessions.sort_by(|a, b| b.modified_at.cmp(&a.modified_at));
+    let total_sessions = sessions.len();
+    let total_matches: usize = sessions.iter().map(|s| s.match_count).sum();
+    let sessions = sessions
+        .into_iter()
+        .skip(opts.offset)
+        .take(opts.limit)
+        .collect();
+
+    UnifiedSearchResult {
+        response: SearchResponse {
+            query: opts.query.clone(),
+            total_sessions,
+            total_matches,
+            elapsed_ms: 0.0,
+            timings: SearchTimings::default(),
+            sessions,
+        },
+        engine: SearchEngine::Grep,
+    }
+}
+
+/// Drop matches whose snippet fingerprint was already seen, keeping the
+/// earliest. Grep matches carry no score, so file order stands in for rank.
+fn dedupe_matches(matches: &mut Vec<MatchHit>) {
+    let mut seen = HashSet::new();
+    matches.retain(|m| seen.insert(snippet_fingerprint(&m.snippet)));
+}
+
+/// Hash of a snippet's normalized text: `<mark>` tags removed, lowercased,
+/// punctuation ignored, and tokens containing digits (UUIDs, timestamps,
+/// line numbers) skipped so raw-JSONL noise doesn't keep duplicates apart.
+fn snippet_fingerprint(snippet: &str) -> u64 {
+    let text = snippet.replace("<mark>", "").replace("</mark>", "");
+    let mut hasher = DefaultHasher::new();
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty() && !w.chars().any(|c| c.is_ascii_digit()))
+        .for_each(|w| w.to_lowercase().hash(&mut hasher));
+    hasher.finish()
+}
+
+/// Role of the message on a matched JSONL line: `"user"`, `"assistant"`,
+/// `"tool"` (a user line carrying tool results) or `"unknown"`. Works on the
+/// truncated line grep reports, so it checks markers rather than parsing.
+fn match_role(line: &str) -> &'static str {
+    if line.contains("\"type\":\"assistant\"") || line.contains("\"role\":\"assistant\"") {
+        "assistant"
+    } else if line.contains("\"tool_result\"") {
+        "tool"
+    } else if line.contains("\"type\":\"user\"") || line.contains("\"role\":\"user\"") {
+        "user"
+    } else {
+        "unknown"
+    }
+}
+
+/// Unix seconds of a JSONL line's `timestamp` (RFC 3339, epoch seconds or
+/// epoch millis), or `file_mtime` when it is missing or unparseable. The last
+/// occurrence is used: the top-level field follows `message`, which may hold
+/// tool input with its own `timestamp` key.
+fn match_timestamp(line: &str, file_mtime: i64) -> i64 {
+    const KEY: &str = "\"timestamp\":";
+    let value = line.rfind(KEY).and_then(|pos| {
+        let rest = line[pos + KEY.len()..].trim_start();
+        let raw = match rest.strip_prefix('"') {
+            Some(s) => &rest[..s.find('"')? + 2],
+            None => &rest[..rest.find([',', '}']).unwrap_or(rest.len())],
+        };
+        serde_json::from_str::<serde_json::Value>(raw).ok()
+    });
+    claude_view_core::normalize_timestamp_or_mtime(value.as_ref(), file_mtime)
+}
+
+/// Splits the time since a search started into back-to-back phases: each
+/// `next()` returns the milliseconds since the previous one, so the phases
+/// sum to (at most) the total elapsed time.
+struct Lap(Instant);
+
+impl Lap {
+    fn next(&mut self) -> f64 {
+        let now = Instant::now();
+        let ms = as_ms(now - self.0);
+        self.0 = now;
+        ms
+    }
+}
+
+fn as_ms(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
 /// Escape regex metacharacters for literal grep search.
 /// When the user types plain text, we want grep to find it literally.
 fn regex_escape_for_literal(input: &str) -> String {
@@ -196,6 +574,57 @@ fn truncate_and_highlight(content: &str, match_start: usize, match_end: usize) -
     format!("{prefix}{before}<mark>{matched}</mark>{after}{suffix}")
 }
 
+/// Like [`truncate_and_highlight`], but marks every whole-word,
+/// case-insensitive occurrence of `term` inside the snippet window instead of
+/// the raw grep match. A window with no whole-word occurrence is returned
+/// without any `<mark>`.
+fn truncate_and_highlight_words(
+    content: &str,
+    match_start: usize,
+    match_end: usize,
+    term: &str,
+) -> String {
+    let chars: Vec<char> = content.chars().collect();
+    let total = chars.len();
+
+    let context_before = 80;
+    let context_after = 150;
+    let start = match_start.saturating_sub(context_before);
+    let end = (match_end + context_after).min(total);
+
+    let prefix = if start > 0 { "..." } else { "" };
+    let suffix = if end < total { "..." } else { "" };
+
+    let needle: Vec<char> = term.chars().collect();
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let eq_ci = |a: char, b: char| a == b || a.to_lowercase().eq(b.to_lowercase());
+
+    let mut out = String::with_capacity(end - start + 16);
+    out.push_str(prefix);
+    let mut i = start;
+    while i < end {
+        let fits = !needle.is_empty() && i + needle.len() <= end;
+        let hit = fits
+            && needle
+                .iter()
+                .enumerate()
+                .all(|(k, &n)| eq_ci(chars[i + k], n))
+            && (i == 0 || !is_word(chars[i - 1]))
+            && chars.get(i + needle.len()).is_none_or(|&c| !is_word(c));
+        if hit {
+            out.push_str("<mark>");
+            out.extend(&chars[i..i + needle.len()]);
+            out.push_str("</mark>");
+            i += needle.len();
+        } else {
+            out.push(chars[i]);
+            i += 1;
+        }
+    }
+    out.push_str(suffix);
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -213,12 +642,14 @@ mod tests {
             .map(|(session_id, content, modified_at)| {
                 let path = dir.join(format!("{session_id}.jsonl"));
                 fs::write(&path, content).unwrap();
+                let is_sidechain = crate::grep::jsonl_is_sidechain(&path);
                 JsonlFile {
                     path,
                     session_id: session_id.to_string(),
                     project: "test-project".to_string(),
                     project_path: dir.to_string_lossy().to_string(),
                     modified_at: *modified_at,
+                    is_sidechain,
                 }
             })
             .collect()
@@ -238,6 +669,10 @@ mod tests {
             limit: 10,
             offset: 0,
             skip_snippets: false,
+            whole_word_highlight: false,
+            dedupe_snippets: false,
+            fields: None,
+            role_boosts: None,
         };
         let result = unified_search(&files, &opts).unwrap();
 
@@ -247,6 +682,368 @@ mod tests {
         assert_eq!(result.response.sessions[0].engines, vec!["grep"]);
     }
 
+    #[test]
+    fn test_has_qualifiers_filter_sessions() {
+        let tmp = TempDir::new().unwrap();
+        let files = create_test_jsonl_files(
+            tmp.path(),
+            &[
+                (
+                    "attached",
+                    "{\"type\":\"user\",\"message\":{\"content\":[{\"type\":\"image\",\"source\":{\"media_type\":\"image/png\"}},{\"type\":\"text\",\"text\":\"deploy this\"}]}}\n",
+                    1710000001,
+                ),
+                (
+                    "thought",
+                    "{\"type\":\"user\",\"message\":{\"content\":\"deploy?\"}}\n{\"type\":\"assistant\",\"message\":{\"content\":[{\"type\":\"thinking\",\"thinking\":\"hmm\"}]}}\n",
+                    1710000002,
+                ),
+                (
+                    "pasted",
+                    "{\"type\":\"user\",\"message\":{\"content\":\"deploy fails:\\n```\\nerror\\n```\"}}\n",
+                    1710000003,
+                ),
+            ],
+        );
+        let search = |query: &str| {
+            let opts = UnifiedSearchOptions {
+                query: query.to_string(),
+                scope: None,
+                limit: 10,
+                offset: 0,
+                skip_snippets: false,
+                whole_word_highlight: false,
+                dedupe_snippets: false,
+                fields: None,
+                role_boosts: None,
+            };
+            let response = unified_search(&files, &opts).unwrap().response;
+            response
+                .sessions
+                .into_iter()
+                .map(|s| (s.session_id, s.engines))
+                .collect::<Vec<_>>()
+        };
+        let has = |id: &str| vec![(id.to_string(), vec!["has".to_string()])];
+
+        assert_eq!(search("has:attachment"), has("attached"));
+        assert_eq!(search("has:thinking"), has("thought"));
+        assert_eq!(search("has:code"), has("pasted"));
+        assert!(search("has:code has:thinking").is_empty());
+        // Combined with free text, the remaining sessions are grepped.
+        assert_eq!(
+            search("has:code deploy"),
+            vec![("pasted".to_string(), vec!["grep".to_string()])]
+        );
+    }
+
+    #[test]
+    fn test_user_role_boost_outranks_assistant_match() {
+        let tmp = TempDir::new().unwrap();
+        let files = create_test_jsonl_files(
+            tmp.path(),
+            &[
+                (
+                    "asked",
+                    "{\"type\":\"user\",\"message\":{\"role\":\"user\",\"content\":\"how do I rotate keys\"}}\n",
+                    1710000000,
+                ),
+                // Newer, so it wins on recency when nothing is boosted.
+                (
+                    "answered",
+                    "{\"type\":\"assistant\",\"message\":{\"role\":\"assistant\",\"content\":[{\"type\":\"text\",\"text\":\"rotate keys with the cli\"}]}}\n",
+                    1710000100,
+                ),
+            ],
+        );
+        let search = |role_boosts: Option<RoleBoosts>| {
+            let opts = UnifiedSearchOptions {
+                query: "rotate keys".to_string(),
+                scope: None,
+                limit: 10,
+                offset: 0,
+                skip_snippets: false,
+                whole_word_highlight: false,
+                dedupe_snippets: false,
+                fields: None,
+                role_boosts,
+            };
+            unified_search(&files, &opts).unwrap().response.sessions
+        };
+
+        let plain = search(None);
+        assert_eq!(plain[0].session_id, "answered");
+        assert_eq!(plain[0].best_score, 0.0);
+        assert_eq!(plain[0].top_match.role, "unknown");
+
+        // Neutral boosts score every role alike, so recency still decides.
+        let neutral = search(Some(RoleBoosts::default()));
+        assert_eq!(neutral[0].session_id, "answered");
+
+        let boosted = search(Some(RoleBoosts {
+            user: 2.0,
+            ..RoleBoosts::default()
+        }));
+        let order: Vec<&str> = boosted.iter().map(|s| s.session_id.as_str()).collect();
+        assert_eq!(order, vec!["asked", "answered"]);
+        assert_eq!(boosted[0].best_score, 2.0);
+        assert_eq!(boosted[0].top_match.role, "user");
+        assert_eq!(boosted[1].top_match.role, "assistant");
+    }
+
+    #[test]
+    fn test_matches_carry_role_and_message_timestamp() {
+        let tmp = TempDir::new().unwrap();
+        let files = create_test_jsonl_files(
+            tmp.path(),
+            &[(
+                "s1",
+                concat!(
+                    "{\"type\":\"user\",\"message\":{\"role\":\"user\",\"content\":\"ship it\"},\"timestamp\":\"2024-03-09T16:00:00Z\"}\n",
+                    "{\"type\":\"assistant\",\"message\":{\"role\":\"assistant\",\"content\":[{\"type\":\"tool_use\",\"input\":{\"timestamp\":\"2001-01-01T00:00:00Z\"}},{\"type\":\"text\",\"text\":\"shipping\"}]},\"timestamp\":\"2024-03-09T16:00:30Z\"}\n",
+                    "{\"content\":\"ship without a timestamp\"}\n",
+                    "{\"content\":\"ship in epoch millis\",\"timestamp\":1710000060000}\n",
+                ),
+                1700000000,
+            )],
+        );
+        let opts = UnifiedSearchOptions {
+            query: "ship".to_string(),
+            scope: None,
+            limit: 10,
+            offset: 0,
+            skip_snippets: false,
+            whole_word_highlight: false,
+            dedupe_snippets: false,
+            fields: None,
+            role_boosts: Some(RoleBoosts::default()),
+        };
+        let response = unified_search(&files, &opts).unwrap().response;
+        let matches: Vec<(&str, i64)> = response.sessions[0]
+            .matches
+            .iter()
+            .map(|m| (m.role.as_str(), m.timestamp))
+            .collect();
+        assert_eq!(
+            matches,
+            vec![
+                ("user", 1710000000),
+                ("assistant", 1710000030),
+                ("unknown", 1700000000),
+                ("unknown", 1710000060),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_role_boosts_parse() {
+        assert_eq!(
+            RoleBoosts::parse("user:2, tool:0.5").unwrap(),
+            RoleBoosts {
+                user: 2.0,
+                assistant: 1.0,
+                tool: 0.5,
+            }
+        );
+        assert_eq!(RoleBoosts::parse("").unwrap(), RoleBoosts::default());
+        assert!(RoleBoosts::parse("user").is_err());
+        assert!(RoleBoosts::parse("user:-1").is_err());
+        assert!(RoleBoosts::parse("system:2").is_err());
+    }
+
+    #[test]
+    fn test_fields_allowlist_omits_snippets_when_not_requested() {
+        let tmp = TempDir::new().unwrap();
+        let files = create_test_jsonl_files(
+            tmp.path(),
+            &[("s1", "{\"content\":\"deploy to production\"}\n", 1710000000)],
+        );
+        let search = |fields: &str| {
+            let opts = UnifiedSearchOptions {
+                query: "deploy".to_string(),
+                scope: None,
+                limit: 10,
+                offset: 0,
+                skip_snippets: false,
+                whole_word_highlight: false,
+                dedupe_snippets: false,
+                fields: Some(SearchFields::parse(fields).unwrap()),
+                role_boosts: None,
+            };
+            let response = unified_search(&files, &opts).unwrap().response;
+            (opts.fields.unwrap().apply(&response), response)
+        };
+
+        let (json, response) = search("sessionId,matchCount,topMatch,role");
+        assert!(response.sessions[0].top_match.snippet.is_empty());
+        assert!(response.sessions[0].matches.is_empty());
+        let hit = &json["sessions"][0];
+        assert_eq!(hit["sessionId"], "s1");
+        assert_eq!(hit["matchCount"], 1);
+        assert!(hit.get("matches").is_none());
+        assert!(hit.get("project").is_none());
+        assert_eq!(hit["topMatch"]["role"], "unknown");
+        assert!(hit["topMatch"].get("snippet").is_none());
+
+        let (json, _) = search("sessionId,topMatch,snippet");
+        assert!(json["sessions"][0]["topMatch"]["snippet"]
+            .as_str()
+            .unwrap()
+            .contains("<mark>deploy</mark>"));
+
+        assert!(SearchFields::parse("sessionId,nope").is_err());
+    }
+
+    #[test]
+    fn test_sidechain_qualifier_includes_and_excludes() {
+        let tmp = TempDir::new().unwrap();
+        let files = create_test_jsonl_files(
+            tmp.path(),
+            &[
+                (
+                    "main",
+                    "{\"isSidechain\":false,\"content\":\"deploy to production\"}\n",
+                    1710000000,
+                ),
+                (
+                    "agent-1",
+                    "{\"isSidechain\":true,\"content\":\"deploy helper\"}\n",
+                    1710000100,
+                ),
+            ],
+        );
+        let search = |query: &str| {
+            let opts = UnifiedSearchOptions {
+                query: query.to_string(),
+                scope: None,
+                limit: 10,
+                offset: 0,
+                skip_snippets: true,
+                whole_word_highlight: false,
+                dedupe_snippets: false,
+                fields: None,
+                role_boosts: None,
+            };
+            unified_search(&files, &opts)
+                .unwrap()
+                .response
+                .sessions
+                .into_iter()
+                .map(|s| s.session_id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(search("deploy"), vec!["agent-1", "main"]);
+        assert_eq!(search("is:sidechain deploy"), vec!["agent-1"]);
+        assert_eq!(search("deploy -is:sidechain"), vec!["main"]);
+    }
+
+    #[test]
+    fn test_qualifier_only_query_skips_grep() {
+        let tmp = TempDir::new().unwrap();
+        let files = create_test_jsonl_files(
+            tmp.path(),
+            &[
+                ("main", "{\"isSidechain\":false}\n", 1710000000),
+                ("agent-1", "{\"isSidechain\":true}\n", 1710000100),
+            ],
+        );
+        let search = |query: &str| {
+            let opts = UnifiedSearchOptions {
+                query: query.to_string(),
+                scope: None,
+                limit: 10,
+                offset: 0,
+                skip_snippets: false,
+                whole_word_highlight: false,
+                dedupe_snippets: false,
+                fields: None,
+                role_boosts: None,
+            };
+            unified_search(&files, &opts).unwrap().response
+        };
+
+        let response = search("is:sidechain");
+        assert_eq!(response.total_sessions, 1);
+        assert_eq!(response.total_matches, 0);
+        let hit = &response.sessions[0];
+        assert_eq!(hit.session_id, "agent-1");
+        assert_eq!(hit.engines, vec!["filter"]);
+        assert!(hit.top_match.snippet.is_empty());
+
+        // Qualifiers the caller already applied leave nothing to grep.
+        let response = search("");
+        assert_eq!(response.total_sessions, 2);
+        assert_eq!(response.sessions[0].session_id, "agent-1");
+        assert_eq!(response.total_matches, 0);
+    }
+
+    #[test]
+    fn test_session_qualifier_restricts_to_session() {
+        let tmp = TempDir::new().unwrap();
+        let files = create_test_jsonl_files(
+            tmp.path(),
+            &[
+                ("abc", "{\"content\":\"shared content here\"}\n", 1710000000),
+                ("def", "{\"content\":\"more shared content\"}\n", 1710000100),
+            ],
+        );
+        let opts = UnifiedSearchOptions {
+            query: "session:abc content".to_string(),
+            scope: None,
+            limit: 10,
+            offset: 0,
+            skip_snippets: false,
+            whole_word_highlight: false,
+            dedupe_snippets: false,
+            fields: None,
+            role_boosts: None,
+        };
+        let result = unified_search(&files, &opts).unwrap();
+
+        assert_eq!(result.response.total_sessions, 1);
+        assert_eq!(result.response.sessions[0].session_id, "abc");
+        // The qualifier is not grepped as text.
+        assert!(result.response.sessions[0]
+            .top_match
+            .snippet
+            .contains("<mark>content</mark>"));
+    }
+
+    #[test]
+    fn test_timings_breakdown_sums_to_elapsed() {
+        let tmp = TempDir::new().unwrap();
+        let content = "{\"content\":\"deploy to production\"}\n".repeat(200);
+        let files = create_test_jsonl_files(tmp.path(), &[("s1", &content, 1710000000)]);
+
+        // Plain text, a feature scan, and qualifier-only queries each take a
+        // different path through the phases.
+        for query in ["deploy", "has:code deploy", "has:code", "session:s1"] {
+            let opts = UnifiedSearchOptions {
+                query: query.to_string(),
+                scope: None,
+                limit: 10,
+                offset: 0,
+                skip_snippets: false,
+                whole_word_highlight: false,
+                dedupe_snippets: false,
+                fields: None,
+                role_boosts: None,
+            };
+            let resp = unified_search(&files, &opts).unwrap().response;
+            let t = resp.timings;
+
+            assert!(t.parse_ms >= 0.0 && t.query_ms >= 0.0 && t.snippet_ms >= 0.0);
+            let sum = t.parse_ms + t.query_ms + t.snippet_ms;
+            assert!(resp.elapsed_ms > 0.0, "{query}");
+            assert!(
+                sum <= resp.elapsed_ms && sum >= resp.elapsed_ms * 0.5,
+                "{query}: timings {t:?} vs elapsed {}",
+                resp.elapsed_ms
+            );
+        }
+    }
+
     /// CJK search works via grep over the raw JSONL line.
     #[test]
     fn test_cjk_found_by_grep() {
@@ -266,6 +1063,10 @@ mod tests {
             limit: 10,
             offset: 0,
             skip_snippets: false,
+            whole_word_highlight: false,
+            dedupe_snippets: false,
+            fields: None,
+            role_boosts: None,
         };
         let result = unified_search(&files, &opts).unwrap();
 
@@ -297,6 +1098,10 @@ mod tests {
             limit: 10,
             offset: 0,
             skip_snippets: false,
+            whole_word_highlight: false,
+            dedupe_snippets: false,
+            fields: None,
+            role_boosts: None,
         };
         let result = unified_search(&files, &opts).unwrap();
 
@@ -329,6 +1134,10 @@ mod tests {
             limit: 1,
             offset: 1,
             skip_snippets: false,
+            whole_word_highlight: false,
+            dedupe_snippets: false,
+            fields: None,
+            role_boosts: None,
         };
         let result = unified_search(&files, &opts).unwrap();
 
@@ -338,6 +1147,58 @@ mod tests {
         assert_eq!(result.response.sessions[0].session_id, "s2");
     }
 
+    #[test]
+    fn test_match_turns_lists_every_matching_turn() {
+        let tmp = TempDir::new().unwrap();
+        let content = concat!(
+            "{\"type\":\"user\",\"message\":{\"role\":\"user\",\"content\":\"deploy the api\"}}\n",
+            "{\"type\":\"assistant\",\"message\":{\"role\":\"assistant\",\"content\":[{\"type\":\"text\",\"text\":\"deploying now\"}]}}\n",
+            "{\"type\":\"user\",\"message\":{\"role\":\"user\",\"content\":[{\"type\":\"tool_result\",\"content\":\"ok\"}]}}\n",
+            "{\"type\":\"user\",\"message\":{\"role\":\"user\",\"content\":\"now the docs\"}}\n",
+            "{\"type\":\"user\",\"message\":{\"role\":\"user\",\"content\":[{\"type\":\"text\",\"text\":\"deploy the docs too\"}]}}\n",
+            "{\"type\":\"user\",\"message\":{\"role\":\"user\",\"content\":\"and the worker\"}}\n",
+            "{\"type\":\"assistant\",\"message\":{\"role\":\"assistant\",\"content\":[{\"type\":\"text\",\"text\":\"deploy done\"}]}}\n",
+        );
+        let files = create_test_jsonl_files(tmp.path(), &[("s1", content, 100)]);
+        let opts = UnifiedSearchOptions {
+            query: "deploy".to_string(),
+            scope: None,
+            limit: 10,
+            offset: 0,
+            skip_snippets: false,
+            whole_word_highlight: false,
+            dedupe_snippets: false,
+            fields: None,
+            role_boosts: None,
+        };
+        let hit = &unified_search(&files, &opts).unwrap().response.sessions[0];
+
+        assert_eq!(hit.match_count, 4);
+        assert_eq!(hit.match_turns, vec![1, 3, 4]);
+        assert_eq!(hit.top_match.turn_number, 1);
+        let turns: Vec<u64> = hit.matches.iter().map(|m| m.turn_number).collect();
+        assert_eq!(turns, vec![1, 1, 3, 4]);
+
+        // Slim calls skip snippets but still get the matching turns.
+        let opts = UnifiedSearchOptions {
+            skip_snippets: true,
+            ..opts
+        };
+        let hit = &unified_search(&files, &opts).unwrap().response.sessions[0];
+        assert_eq!(hit.match_count, 4);
+        assert!(hit.matches.is_empty());
+        assert_eq!(hit.match_turns, vec![1, 3, 4]);
+        assert_eq!(hit.top_match.turn_number, 1);
+
+        let opts = UnifiedSearchOptions {
+            skip_snippets: false,
+            fields: Some(SearchFields::parse("sessionId,matchTurns").unwrap()),
+            ..opts
+        };
+        let hit = &unified_search(&files, &opts).unwrap().response.sessions[0];
+        assert_eq!(hit.match_turns, vec![1, 3, 4]);
+    }
+
     /// `regex_escape_for_literal` correctly escapes regex metacharacters.
     #[test]
     fn test_regex_escape_for_literal() {
@@ -349,4 +1210,89 @@ mod tests {
         // CJK passes through unchanged
         assert_eq!(regex_escape_for_literal("部署"), "部署");
     }
+
+    #[test]
+    fn test_dedupe_snippets_collapses_near_identical_matches() {
+        let tmp = TempDir::new().unwrap();
+        let content = concat!(
+            "{\"uuid\":\"a1b2c3d4-0001\",\"content\":\"Please fix the login redirect bug\"}\n",
+            "{\"uuid\":\"e5f6a7b8-0002\",\"content\":\"please fix the Login redirect bug!\"}\n",
+            "{\"uuid\":\"c9d0e1f2-0003\",\"content\":\"the redirect bug is in middleware\"}\n",
+        );
+        let files = create_test_jsonl_files(tmp.path(), &[("s1", content, 100)]);
+        let search = |dedupe_snippets: bool| {
+            let opts = UnifiedSearchOptions {
+                query: "redirect bug".to_string(),
+                scope: None,
+                limit: 10,
+                offset: 0,
+                skip_snippets: false,
+                whole_word_highlight: false,
+                dedupe_snippets,
+                fields: None,
+                role_boosts: None,
+            };
+            unified_search(&files, &opts).unwrap().response.sessions[0].clone()
+        };
+
+        let all = search(false);
+        assert_eq!(all.matches.len(), 3);
+
+        let deduped = search(true);
+        assert_eq!(
+            deduped.match_count, 3,
+            "match_count still counts every message"
+        );
+        assert_eq!(deduped.matches.len(), 2);
+        assert!(deduped.matches[0].snippet.contains("Please fix the login"));
+        assert!(deduped.matches[1].snippet.contains("middleware"));
+    }
+
+    /// Whole-word mode skips "auth" inside "authorization" but still marks a
+    /// standalone "Auth"; the default substring mode marks both.
+    #[test]
+    fn test_whole_word_highlight_skips_sub_tokens() {
+        let tmp = TempDir::new().unwrap();
+        let files = create_test_jsonl_files(
+            tmp.path(),
+            &[(
+                "s1",
+                "{\"content\":\"authorization header; Auth flow\"}\n",
+                100,
+            )],
+        );
+        let search = |whole_word_highlight: bool| {
+            let opts = UnifiedSearchOptions {
+                query: "auth".to_string(),
+                scope: None,
+                limit: 10,
+                offset: 0,
+                skip_snippets: false,
+                whole_word_highlight,
+                dedupe_snippets: false,
+                fields: None,
+                role_boosts: None,
+            };
+            unified_search(&files, &opts).unwrap().response.sessions[0]
+                .top_match
+                .snippet
+                .clone()
+        };
+
+        let substring = search(false);
+        assert!(
+            substring.contains("<mark>auth</mark>orization"),
+            "{substring}"
+        );
+
+        let whole = search(true);
+        assert!(!whole.contains("<mark>auth</mark>orization"), "{whole}");
+        assert!(whole.contains("<mark>Auth</mark> flow"), "{whole}");
+    }
+
+    #[test]
+    fn test_highlight_words_without_whole_word_hit_has_no_mark() {
+        let snippet = truncate_and_highlight_words("authorization only", 0, 4, "auth");
+        assert_eq!(snippet, "authorization only");
+    }
 }
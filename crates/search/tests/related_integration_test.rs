@@ -0,0 +1,82 @@
+use claude_view_search::{related_sessions, JsonlFile};
+use std::fs;
+use tempfile::TempDir;
+
+fn make_session(dir: &std::path::Path, session_id: &str, texts: &[&str]) -> JsonlFile {
+    let path = dir.join(format!("{session_id}.jsonl"));
+    let body: String = texts
+        .iter()
+        .map(|t| {
+            format!(
+                "{{\"type\":\"user\",\"message\":{{\"role\":\"user\",\"content\":{}}}}}\n",
+                serde_json::to_string(t).unwrap()
+            )
+        })
+        .collect();
+    fs::write(&path, body).unwrap();
+    JsonlFile {
+        path,
+        session_id: session_id.to_string(),
+        project: "integration-test".to_string(),
+        project_path: dir.to_string_lossy().to_string(),
+        modified_at: 1710000000,
+        is_sidechain: false,
+    }
+}
+
+/// The session sharing skills/files with the source ranks above one that
+/// only shares generic vocabulary, and the source itself is excluded.
+#[test]
+fn test_similar_session_ranks_first() {
+    let tmp = TempDir::new().unwrap();
+    let source = make_session(
+        tmp.path(),
+        "source",
+        &[
+            "Refactor the tantivy indexer in crates/search/src/indexing.rs",
+            "Run cargo clippy on the tantivy indexer and fix the warnings",
+        ],
+    );
+    let similar = make_session(
+        tmp.path(),
+        "similar",
+        &["The tantivy indexer in indexing.rs panics; run cargo clippy"],
+    );
+    let dissimilar = make_session(
+        tmp.path(),
+        "dissimilar",
+        &["Update the React dashboard colors and run cargo fmt"],
+    );
+
+    // The caller passes every session in scope, including the source.
+    let candidates = vec![
+        JsonlFile {
+            path: source.path.clone(),
+            session_id: source.session_id.clone(),
+            project: source.project.clone(),
+            project_path: source.project_path.clone(),
+            modified_at: source.modified_at,
+            is_sidechain: source.is_sidechain,
+        },
+        similar,
+        dissimilar,
+    ];
+
+    let hits = related_sessions(&source, &candidates, 10);
+    let ids: Vec<&str> = hits.iter().map(|h| h.session_id.as_str()).collect();
+
+    assert!(!ids.contains(&"source"), "source must be excluded: {ids:?}");
+    assert_eq!(ids.first(), Some(&"similar"), "ranking: {ids:?}");
+    if let Some(dissimilar) = hits.iter().find(|h| h.session_id == "dissimilar") {
+        assert!(hits[0].best_score > dissimilar.best_score);
+    }
+    assert_eq!(hits[0].engines, vec!["related"]);
+}
+
+#[test]
+fn test_zero_limit_returns_nothing() {
+    let tmp = TempDir::new().unwrap();
+    let source = make_session(tmp.path(), "a", &["tantivy indexer"]);
+    let other = make_session(tmp.path(), "b", &["tantivy indexer"]);
+    assert!(related_sessions(&source, &[other], 0).is_empty());
+}
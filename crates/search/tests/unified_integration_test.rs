@@ -17,6 +17,7 @@ fn make_jsonl_file(
         project: "integration-test".to_string(),
         project_path: dir.to_string_lossy().to_string(),
         modified_at,
+        is_sidechain: false,
     }
 }
 
@@ -36,6 +37,10 @@ fn test_session_search_uses_grep() {
         limit: 10,
         offset: 0,
         skip_snippets: false,
+        whole_word_highlight: false,
+        dedupe_snippets: false,
+        fields: None,
+        role_boosts: None,
     };
 
     let result = unified_search(&files, &opts).unwrap();
@@ -61,6 +66,10 @@ fn test_cjk_grep_search() {
         limit: 10,
         offset: 0,
         skip_snippets: false,
+        whole_word_highlight: false,
+        dedupe_snippets: false,
+        fields: None,
+        role_boosts: None,
     };
 
     let result = unified_search(&files, &opts).unwrap();
@@ -96,6 +105,10 @@ fn test_grep_sorted_by_recency() {
         limit: 10,
         offset: 0,
         skip_snippets: false,
+        whole_word_highlight: false,
+        dedupe_snippets: false,
+        fields: None,
+        role_boosts: None,
     };
 
     let result = unified_search(&files, &opts).unwrap();
@@ -104,3 +117,80 @@ fn test_grep_sorted_by_recency() {
     assert_eq!(result.response.sessions[0].session_id, "s-new");
     assert_eq!(result.response.sessions[1].session_id, "s-old");
 }
+
+/// A message appended twice to the JSONL (resume/re-parse) counts once.
+#[test]
+fn test_duplicated_message_counted_once() {
+    let tmp = TempDir::new().unwrap();
+    let line = "{\"uuid\":\"u1\",\"type\":\"user\",\"content\":\"deploy to production\"}\n";
+    let files = vec![make_jsonl_file(
+        tmp.path(),
+        "s1",
+        &format!("{line}{line}"),
+        1710000000,
+    )];
+
+    let opts = UnifiedSearchOptions {
+        query: "deploy".to_string(),
+        scope: None,
+        limit: 10,
+        offset: 0,
+        skip_snippets: false,
+        whole_word_highlight: false,
+        dedupe_snippets: false,
+        fields: None,
+        role_boosts: None,
+    };
+
+    let result = unified_search(&files, &opts).unwrap();
+    assert_eq!(result.response.total_sessions, 1);
+    assert_eq!(result.response.sessions[0].match_count, 1);
+    assert_eq!(result.response.total_matches, 1);
+}
+
+/// `attachment:` matches sessions by attached file name, alone or combined
+/// with free text.
+#[test]
+fn test_attachment_qualifier() {
+    let tmp = TempDir::new().unwrap();
+    let with_image = concat!(
+        r#"{"type":"user","message":{"content":[{"type":"text","text":"here is the diagram"},"#,
+        r#"{"type":"image","source":{"type":"url","media_type":"image/png","url":"https://example.com/screenshot.png"}}]}}"#,
+        "\n",
+    );
+    let files = vec![
+        make_jsonl_file(tmp.path(), "with-image", with_image, 1710000000),
+        make_jsonl_file(
+            tmp.path(),
+            "text-only",
+            "{\"type\":\"user\",\"message\":{\"content\":\"screenshot.png diagram\"}}\n",
+            1710000001,
+        ),
+    ];
+
+    let search = |query: &str| {
+        let opts = UnifiedSearchOptions {
+            query: query.to_string(),
+            scope: None,
+            limit: 10,
+            offset: 0,
+            skip_snippets: false,
+            whole_word_highlight: false,
+            dedupe_snippets: false,
+            fields: None,
+            role_boosts: None,
+        };
+        unified_search(&files, &opts).unwrap().response
+    };
+
+    let only = search("attachment:screenshot.png");
+    assert_eq!(only.total_sessions, 1);
+    assert_eq!(only.sessions[0].session_id, "with-image");
+    assert_eq!(only.sessions[0].top_match.snippet, "screenshot.png");
+
+    let combined = search("attachment:SCREENSHOT diagram");
+    assert_eq!(combined.total_sessions, 1);
+    assert_eq!(combined.sessions[0].session_id, "with-image");
+
+    assert_eq!(search("attachment:missing.gif").total_sessions, 0);
+}
@@ -0,0 +1,120 @@
+//! Rolling token burn rate (tokens per minute) for live sessions.
+
+use std::collections::VecDeque;
+
+/// How far back the burn rate looks.
+pub const BURN_RATE_WINDOW_SECS: i64 = 120;
+
+/// A gap without token growth longer than this clears the window, so a
+/// session resuming after a pause starts from a fresh baseline instead of
+/// averaging over the idle stretch.
+pub const BURN_RATE_IDLE_RESET_SECS: i64 = 60;
+
+/// Timestamped cumulative token totals over the last [`BURN_RATE_WINDOW_SECS`].
+///
+/// Runtime-only: not serialized, not persisted in snapshots.
+#[derive(Debug, Clone, Default)]
+pub struct BurnRateWindow {
+    /// `(unix_seconds, cumulative_tokens)`, oldest first.
+    samples: VecDeque<(i64, u64)>,
+}
+
+impl BurnRateWindow {
+    /// Record the session's cumulative token total at `now` (unix seconds).
+    ///
+    /// Unchanged totals are ignored so the idle gap is measured from the last
+    /// real growth. A total lower than the previous sample (e.g. `/clear`)
+    /// resets the window.
+    pub fn record(&mut self, now: i64, total_tokens: u64) {
+        if let Some(&(last_at, last_total)) = self.samples.back() {
+            if total_tokens == last_total {
+                return;
+            }
+            if total_tokens < last_total || now - last_at > BURN_RATE_IDLE_RESET_SECS {
+                self.samples.clear();
+            }
+        }
+        self.samples.push_back((now, total_tokens));
+
+        // Keep one sample at or before the window start so the rate spans the
+        // full window rather than just the samples inside it.
+        let window_start = now - BURN_RATE_WINDOW_SECS;
+        while self.samples.len() > 2 && self.samples[1].0 <= window_start {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Tokens per minute across the window. 0 until two samples exist.
+    pub fn tokens_per_minute(&self) -> f64 {
+        let (Some(&(first_at, first_total)), Some(&(last_at, last_total))) =
+            (self.samples.front(), self.samples.back())
+        else {
+            return 0.0;
+        };
+        let span = last_at - first_at;
+        if span <= 0 {
+            return 0.0;
+        }
+        (last_total - first_total) as f64 * 60.0 / span as f64
+    }
+
+    pub fn reset(&mut self) {
+        self.samples.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steady_increments_give_expected_rate() {
+        let mut window = BurnRateWindow::default();
+        // 500 tokens every 10s = 3000 tokens/min.
+        for i in 0..10 {
+            window.record(1_000 + i * 10, 10_000 + i as u64 * 500);
+        }
+        let rate = window.tokens_per_minute();
+        assert!((rate - 3000.0).abs() < 1.0, "rate was {rate}");
+    }
+
+    #[test]
+    fn old_samples_fall_out_of_window() {
+        let mut window = BurnRateWindow::default();
+        // Fast burst: 6000 tokens in the first 30s.
+        window.record(0, 0);
+        window.record(30, 6_000);
+        // Then 100 tokens every 20s for well over a window.
+        for i in 1..=12 {
+            window.record(30 + i * 20, 6_000 + i as u64 * 100);
+        }
+        // Only the slow phase is within the window: 100 per 20s = 300/min.
+        let rate = window.tokens_per_minute();
+        assert!((rate - 300.0).abs() < 30.0, "rate was {rate}");
+    }
+
+    #[test]
+    fn idle_gap_resets_window() {
+        let mut window = BurnRateWindow::default();
+        window.record(0, 0);
+        window.record(10, 5_000);
+        assert!(window.tokens_per_minute() > 0.0);
+
+        // Resumes after a long pause: the new sample is the only baseline.
+        window.record(10 + BURN_RATE_IDLE_RESET_SECS + 1, 5_200);
+        assert_eq!(window.tokens_per_minute(), 0.0);
+
+        window.record(10 + BURN_RATE_IDLE_RESET_SECS + 31, 5_700);
+        let rate = window.tokens_per_minute();
+        assert!((rate - 1000.0).abs() < 1.0, "rate was {rate}");
+    }
+
+    #[test]
+    fn decreasing_total_resets_window() {
+        let mut window = BurnRateWindow::default();
+        window.record(0, 10_000);
+        window.record(10, 12_000);
+        window.record(20, 100);
+        assert_eq!(window.tokens_per_minute(), 0.0);
+    }
+}
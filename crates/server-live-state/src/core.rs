@@ -227,6 +227,8 @@ pub fn test_live_session(id: &str) -> LiveSession {
             effective_branch: None,
             tokens: TokenUsage::default(),
             cost: CostBreakdown::default(),
+            token_burn_rate: 0.0,
+            burn_window: Default::default(),
             cache_status: CacheStatus::Unknown,
             last_turn_task_seconds: None,
             last_cache_hit_at: None,
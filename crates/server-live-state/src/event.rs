@@ -18,6 +18,37 @@ pub enum SessionEvent {
         session_id: String,
         session: LiveSession,
     },
+    /// Session is over for good — emitted exactly once, by the reaper, after
+    /// the `SessionRemove` for the same id. Lets clients finalize the card
+    /// without inferring end-of-session from status transitions.
+    SessionEnded {
+        #[serde(rename = "sessionId")]
+        session_id: String,
+        reason: SessionEndReason,
+    },
+}
+
+/// Why a live session ended. Carried on [`SessionEvent::SessionEnded`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[cfg_attr(
+    feature = "codegen",
+    ts(
+        export,
+        export_to = "../../../../../packages/shared/src/types/generated/"
+    )
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionEndReason {
+    /// The process detector lost the session's PID (exit, kqueue, polling).
+    ProcessExited,
+    /// The sessions watcher reported the process crashed.
+    Crashed,
+    /// No PID and no transcript writes for the idle timeout.
+    Idle,
+    /// A new session reused the PID; the old entry was evicted.
+    Evicted,
+    /// Killed on request via the CLI sessions API.
+    Killed,
 }
 
 /// A single hook lifecycle event, captured for the event log.
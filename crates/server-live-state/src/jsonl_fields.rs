@@ -6,6 +6,7 @@ use claude_view_core::pricing::{CacheStatus, CostBreakdown, TokenUsage};
 use serde::Serialize;
 use ts_rs::TS;
 
+use super::burn_rate::BurnRateWindow;
 use super::field_types::{is_zero_u32, ToolUsed, VerifiedFile};
 
 /// JSONL-watcher-sourced fields, grouped for decomposition clarity.
@@ -43,6 +44,13 @@ pub struct JsonlFields {
     pub tokens: TokenUsage,
     /// Computed cost breakdown in USD.
     pub cost: CostBreakdown,
+    /// Current token burn rate in tokens per minute, over a rolling window.
+    /// 0 when the session is idle or has too few samples.
+    pub token_burn_rate: f64,
+    /// Samples behind `token_burn_rate`. Runtime-only.
+    #[serde(skip)]
+    #[ts(skip)]
+    pub burn_window: BurnRateWindow,
     /// Whether the Anthropic prompt cache is likely warm or cold.
     pub cache_status: CacheStatus,
     /// Seconds the agent spent on the last completed turn (frozen on Working->Paused).
@@ -109,6 +117,8 @@ impl Default for JsonlFields {
             effective_branch: None,
             tokens: TokenUsage::default(),
             cost: CostBreakdown::default(),
+            token_burn_rate: 0.0,
+            burn_window: BurnRateWindow::default(),
             cache_status: CacheStatus::Unknown,
             last_turn_task_seconds: None,
             last_cache_hit_at: None,
@@ -126,3 +136,17 @@ impl Default for JsonlFields {
         }
     }
 }
+
+impl JsonlFields {
+    /// Sample the current cumulative token total and refresh `token_burn_rate`.
+    pub fn record_token_sample(&mut self, now: i64) {
+        self.burn_window.record(now, self.tokens.total_tokens);
+        self.token_burn_rate = self.burn_window.tokens_per_minute();
+    }
+
+    /// Drop the burn-rate window (session went idle).
+    pub fn reset_burn_rate(&mut self) {
+        self.burn_window.reset();
+        self.token_burn_rate = 0.0;
+    }
+}
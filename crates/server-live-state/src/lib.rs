@@ -5,6 +5,7 @@
 //!
 //! Decomposed into domain-specific submodules:
 //! - `agent` — AgentState, AgentStateGroup, status_from_agent_state
+//! - `burn_rate` — BurnRateWindow (rolling tokens/minute)
 //! - `classify` — LiveSessionAction, classify_live_session
 //! - `core` — LiveSession, SessionStatus, ControlBinding, snapshots
 //! - `event` — SessionEvent, HookEvent, append helpers
@@ -14,6 +15,7 @@
 //! - `statusline_fields` — StatuslineFields, StatuslineDebugEntry
 
 pub mod agent;
+pub mod burn_rate;
 pub mod classify;
 pub mod core;
 pub mod event;
@@ -30,6 +32,9 @@ pub mod statusline_fields;
 // agent
 pub use agent::{status_from_agent_state, AgentState, AgentStateGroup};
 
+// burn_rate
+pub use burn_rate::BurnRateWindow;
+
 // classify
 pub use classify::{classify_live_session, LiveSessionAction};
 
@@ -45,7 +50,7 @@ pub use core::{
 
 // event
 pub use event::{append_capped_hook_event, MAX_HOOK_EVENTS_PER_SESSION};
-pub use event::{HookEvent, SessionEvent};
+pub use event::{HookEvent, SessionEndReason, SessionEvent};
 
 // field_types
 pub use field_types::{FileSourceKind, ToolUsed, VerifiedFile};
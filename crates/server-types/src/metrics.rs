@@ -50,7 +50,7 @@ fn describe_metrics() {
     // Request metrics
     describe_counter!(
         "dashboard_requests_total",
-        "Total number of API requests to dashboard endpoints"
+        "Total number of API requests, by route template and status"
     );
     describe_histogram!(
         "dashboard_request_duration_seconds",
@@ -210,6 +210,11 @@ impl RequestTimer {
         }
     }
 
+    /// Time since the timer was created.
+    pub fn elapsed(&self) -> std::time::Duration {
+        self.start.elapsed()
+    }
+
     /// Finish timing with a successful status.
     pub fn finish_ok(self) {
         record_request(&self.endpoint, "200", self.start.elapsed());
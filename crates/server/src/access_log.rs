@@ -0,0 +1,73 @@
+// crates/server/src/access_log.rs
+//! Per-request tracing span and structured access log.
+//!
+//! The request id is assigned (or taken from the client) by
+//! [`claude_view_observability::apply_request_id_layers`], which wraps the
+//! whole router, so by the time these layers run the `x-request-id` header
+//! is always set. [`make_request_span`] puts it on the `TraceLayer` span;
+//! [`log_request`] emits one `access` log line per request and records it
+//! through [`RequestTimer`] under the matched route template. It is the only
+//! place request metrics are recorded, so handlers must not time themselves.
+
+use axum::body::Body;
+use axum::extract::{MatchedPath, Request};
+use axum::http::HeaderMap;
+use axum::middleware::Next;
+use axum::response::Response;
+use tracing::Span;
+
+use crate::metrics::RequestTimer;
+
+/// Header carrying the request id (set by the observability layers).
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Metrics label for requests that matched no route (static files, 404s),
+/// so raw paths never become label values.
+const UNMATCHED_ENDPOINT: &str = "unmatched";
+
+fn request_id(headers: &HeaderMap) -> &str {
+    headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("-")
+}
+
+/// `make_span_with` hook for `TraceLayer`: the default span fields plus the
+/// request id.
+pub fn make_request_span(req: &Request<Body>) -> Span {
+    tracing::info_span!(
+        "request",
+        method = %req.method(),
+        uri = %req.uri(),
+        version = ?req.version(),
+        request_id = %request_id(req.headers()),
+    )
+}
+
+/// Middleware logging method, path, status and latency for every request.
+pub async fn log_request(req: Request, next: Next) -> Response {
+    let method = req.method().clone();
+    let path = req.uri().path().to_string();
+    let request_id = request_id(req.headers()).to_string();
+    let endpoint = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|m| format!("{method} {}", m.as_str()))
+        .unwrap_or_else(|| UNMATCHED_ENDPOINT.to_string());
+    let timer = RequestTimer::new(endpoint);
+
+    let response = next.run(req).await;
+
+    let status = response.status();
+    tracing::info!(
+        target: "access",
+        request_id = %request_id,
+        method = %method,
+        path = %path,
+        status = status.as_u16(),
+        latency_ms = timer.elapsed().as_secs_f64() * 1000.0,
+        "request completed"
+    );
+    timer.finish(status.as_str());
+    response
+}
@@ -4,13 +4,17 @@
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use axum::http::HeaderValue;
+use axum::extract::Request;
+use axum::http::{header, HeaderValue};
+use axum::middleware::{self, Next};
+use axum::response::Response;
 use axum::Router;
 use tower_http::compression::CompressionLayer;
 use tower_http::cors::{AllowOrigin, Any, CorsLayer};
 use tower_http::services::{ServeDir, ServeFile};
 use tower_http::trace::TraceLayer;
 
+use crate::access_log;
 use crate::auth;
 use crate::cache;
 use crate::classify_state;
@@ -32,34 +36,58 @@ use crate::terminal_state;
 
 use claude_view_db::Database;
 
-/// Create a CORS layer that only allows localhost origins.
+/// Create a CORS layer that allows localhost origins plus any listed in
+/// `CLAUDE_VIEW_CORS_ORIGINS`.
 ///
 /// This prevents cross-origin attacks where a malicious website could exfiltrate
 /// Claude Code session data via `fetch()` to `localhost:47892`.
 fn cors_layer() -> CorsLayer {
+    let extra = parse_cors_origins(std::env::var("CLAUDE_VIEW_CORS_ORIGINS").ok().as_deref());
+    cors_layer_with_origins(extra)
+}
+
+/// Like [`cors_layer`], but with an explicit list of extra allowed origins
+/// instead of reading the environment.
+pub(crate) fn cors_layer_with_origins(extra: Vec<String>) -> CorsLayer {
     CorsLayer::new()
         .allow_origin(AllowOrigin::predicate(
-            |origin: &HeaderValue, _req_parts: &axum::http::request::Parts| {
-                if let Ok(origin) = origin.to_str() {
-                    origin.starts_with("http://localhost:")
-                        || origin.starts_with("http://127.0.0.1:")
-                        || origin == "http://localhost"
-                        || origin == "http://127.0.0.1"
-                } else {
-                    false
-                }
+            move |origin: &HeaderValue, _req_parts: &axum::http::request::Parts| {
+                origin
+                    .to_str()
+                    .is_ok_and(|origin| is_allowed_origin(origin, &extra))
             },
         ))
         .allow_methods(Any)
         .allow_headers(Any)
 }
 
+/// Parse a comma-separated origin list (e.g. for a reverse proxy on
+/// `http://claude.local`). Entries are trimmed and a trailing `/` dropped,
+/// since browsers never send one in `Origin`.
+pub(crate) fn parse_cors_origins(raw: Option<&str>) -> Vec<String> {
+    raw.unwrap_or_default()
+        .split(',')
+        .map(|o| o.trim().trim_end_matches('/'))
+        .filter(|o| !o.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Localhost on any port, or an exact match against `extra`.
+fn is_allowed_origin(origin: &str, extra: &[String]) -> bool {
+    origin.starts_with("http://localhost:")
+        || origin.starts_with("http://127.0.0.1:")
+        || origin == "http://localhost"
+        || origin == "http://127.0.0.1"
+        || extra.iter().any(|allowed| allowed == origin)
+}
+
 /// Create the Axum application with all routes and middleware (API-only mode).
 ///
 /// This sets up:
 /// - API routes (health, projects, sessions)
-/// - CORS restricted to localhost origins
-/// - Request tracing
+/// - CORS restricted to localhost origins (plus `CLAUDE_VIEW_CORS_ORIGINS`)
+/// - Request tracing, request ids (`x-request-id`) and access logs
 pub fn create_app(db: Database) -> Router {
     create_app_with_static(db, None)
 }
@@ -836,17 +864,64 @@ pub fn create_app_full(
         .merge(routes::api_routes(state))
         .layer(CompressionLayer::new())
         .layer(cors_layer())
-        .layer(TraceLayer::new_for_http());
+        .layer(middleware::from_fn(access_log::log_request))
+        .layer(TraceLayer::new_for_http().make_span_with(access_log::make_request_span));
     let mut app = claude_view_observability::apply_request_id_layers(app);
 
     if let Some(dir) = static_dir {
-        let index = dir.join("index.html");
-        app = app.fallback_service(ServeDir::new(&dir).fallback(ServeFile::new(&index)));
+        app = app.fallback_service(static_files(&dir));
     }
 
     (app, local_llm_service)
 }
 
+/// `Cache-Control` for Vite's content-hashed `assets/*` bundles: a new build
+/// means new file names, so they never need revalidating.
+const ASSET_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// `Cache-Control` for `index.html` (direct or SPA fallback), so a deploy is
+/// picked up on the next load instead of pointing at stale bundle names.
+const HTML_CACHE_CONTROL: &str = "no-store";
+
+/// Static file service for `dir` with SPA fallback to `index.html`.
+///
+/// Uses `.fallback()` rather than `.not_found_service()` so client-side
+/// routes return 200 (not_found_service returns 404). Other files such as
+/// `favicon.ico` keep default caching.
+fn static_files(dir: &std::path::Path) -> Router {
+    let index = dir.join("index.html");
+    Router::new()
+        .fallback_service(ServeDir::new(dir).fallback(ServeFile::new(index)))
+        .layer(middleware::from_fn(static_cache_headers))
+}
+
+async fn static_cache_headers(req: Request, next: Next) -> Response {
+    let is_asset = req.uri().path().starts_with("/assets/");
+    let mut response = next.run(req).await;
+    if !response.status().is_success() {
+        return response;
+    }
+    let is_html = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.starts_with("text/html"));
+    // HTML first: a missing `/assets/*` file falls back to `index.html`,
+    // which must not be cached as an immutable asset.
+    let cache_control = if is_html {
+        HTML_CACHE_CONTROL
+    } else if is_asset {
+        ASSET_CACHE_CONTROL
+    } else {
+        return response;
+    };
+    response.headers_mut().insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_static(cache_control),
+    );
+    response
+}
+
 /// Register Claude Code hooks for the given port.
 ///
 /// Must be called AFTER binding the actual port (which may differ from the
@@ -885,15 +960,13 @@ pub fn create_app_with_indexing_and_static(
         .merge(routes::api_routes(state))
         .layer(CompressionLayer::new())
         .layer(cors_layer())
-        .layer(TraceLayer::new_for_http());
+        .layer(middleware::from_fn(access_log::log_request))
+        .layer(TraceLayer::new_for_http().make_span_with(access_log::make_request_span));
     let mut app = claude_view_observability::apply_request_id_layers(app);
 
-    // Serve static files with SPA fallback
-    // Use .fallback() instead of .not_found_service() to return 200 for SPA routing
-    // (not_found_service returns 404, which is incorrect for client-side routing)
+    // Serve static files with SPA fallback and cache headers.
     if let Some(dir) = static_dir {
-        let index = dir.join("index.html");
-        app = app.fallback_service(ServeDir::new(&dir).fallback(ServeFile::new(&index)));
+        app = app.fallback_service(static_files(&dir));
     }
 
     app
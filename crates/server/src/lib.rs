@@ -4,6 +4,7 @@
 //! This crate provides the Axum-based HTTP server for the claude-view application.
 //! It serves a REST API for listing Claude Code projects and retrieving session data.
 
+pub mod access_log;
 pub mod app_factory;
 pub mod auth;
 pub mod backfill;
@@ -50,9 +50,19 @@ pub fn apply_mutation_to_session(
             }
             None
         }
-        SessionMutation::Lifecycle(event) => apply_lifecycle(&mut session.hook, event, now),
+        SessionMutation::Lifecycle(event) => {
+            let status = apply_lifecycle(&mut session.hook, event, now);
+            // Burn rate only means something while the agent is working.
+            if matches!(status, Some(SessionStatus::Paused | SessionStatus::Done)) {
+                session.jsonl.reset_burn_rate();
+            }
+            status
+        }
         SessionMutation::Reconcile(data) => {
             apply_reconcile(&mut session.jsonl, data);
+            if data.tokens.is_some() {
+                session.jsonl.record_token_sample(now);
+            }
             // Cross-source: model (only if newer than current)
             if let Some(ref m) = data.model {
                 if now >= session.model_set_at {
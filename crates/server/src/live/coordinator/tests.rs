@@ -64,6 +64,49 @@ mod tests {
         assert_eq!(session.context_window_tokens, 200_000);
     }
 
+    #[test]
+    fn reconcile_tokens_update_burn_rate_and_stop_resets_it() {
+        let mut session =
+            create_session_from_start("burn", &Some("/tmp".into()), &None, &None, 1700000000);
+        // 1000 tokens every 15s = 4000 tokens/min.
+        for i in 0..5u64 {
+            let tokens = claude_view_core::pricing::TokenUsage {
+                total_tokens: 1000 * (i + 1),
+                ..Default::default()
+            };
+            let data = ReconcileData {
+                project: None,
+                project_display_name: None,
+                project_path: None,
+                model: None,
+                model_display_name: None,
+                tokens: Some(tokens),
+                context_window_tokens: None,
+                cost: None,
+                turn_count: None,
+                edit_count: None,
+                phase: None,
+            };
+            let mutation = SessionMutation::Reconcile(Box::new(data));
+            apply_mutation_to_session(&mut session, &mutation, 1700000000 + i as i64 * 15);
+        }
+        let rate = session.jsonl.token_burn_rate;
+        assert!((rate - 4000.0).abs() < 1.0, "rate was {rate}");
+
+        let stop = SessionMutation::Lifecycle(LifecycleEvent::Stop {
+            agent_state: crate::live::state::AgentState {
+                group: crate::live::state::AgentStateGroup::NeedsYou,
+                state: "idle".into(),
+                label: "Waiting".into(),
+                context: None,
+            },
+            last_assistant_message: None,
+            pid: None,
+        });
+        apply_mutation_to_session(&mut session, &stop, 1700000100);
+        assert_eq!(session.jsonl.token_burn_rate, 0.0);
+    }
+
     #[test]
     fn plan_side_effects_for_end_captures_events() {
         let mut session = create_session_from_start("end-test", &None, &None, &None, 1700000000);
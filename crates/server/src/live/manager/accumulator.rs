@@ -440,6 +440,9 @@ pub(super) fn apply_jsonl_metadata(
         }
     }
     session.jsonl.tokens = m.tokens.clone();
+    session
+        .jsonl
+        .record_token_sample(super::helpers::unix_now());
     session.context_window_tokens = m.context_window_tokens;
     session.jsonl.cost = m.cost.clone();
     session.jsonl.cache_status = m.cache_status.clone();
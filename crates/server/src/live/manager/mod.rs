@@ -51,6 +51,39 @@ use helpers::{extract_project_info, pid_snapshot_path, save_session_snapshot};
 /// Type alias for the shared session map used by both the manager and route handlers.
 pub type LiveSessionMap = Arc<RwLock<HashMap<String, LiveSession>>>;
 
+/// Whether a live session belongs to `project`, matched like the DB's
+/// project filters: the encoded project directory name (same as
+/// `session_stats.project_id`), the decoded project path, or a git root the
+/// project path sits under.
+pub fn is_project_session(session: &LiveSession, project: &str) -> bool {
+    if session.jsonl.project == project || session.jsonl.project_path == project {
+        return true;
+    }
+    let root = project.trim_end_matches('/');
+    !root.is_empty()
+        && session
+            .jsonl
+            .project_path
+            .strip_prefix(root)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Live sessions in `map`, most recently active first. `project_id` limits
+/// them to one project (see [`is_project_session`]); `None` keeps all of
+/// them.
+pub fn sessions_for_project(
+    map: &HashMap<String, LiveSession>,
+    project_id: Option<&str>,
+) -> Vec<LiveSession> {
+    let mut sessions: Vec<LiveSession> = map
+        .values()
+        .filter(|s| project_id.is_none_or(|p| is_project_session(s, p)))
+        .cloned()
+        .collect();
+    sessions.sort_by(|a, b| b.hook.last_activity_at.cmp(&a.hook.last_activity_at));
+    sessions
+}
+
 /// Type alias for the transcript path -> session ID dedup map.
 /// Shared between `LiveSessionManager` (cleanup on PID death) and `AppState` (statusline handler).
 pub type TranscriptMap = Arc<RwLock<HashMap<PathBuf, String>>>;
@@ -670,6 +703,7 @@ impl LiveSessionManager {
                     Ok(SessionEvent::SessionUpsert { .. } | SessionEvent::SessionRemove { .. }) => {
                         publish(&sessions, &pids_tx).await;
                     }
+                    Ok(SessionEvent::SessionEnded { .. }) => {}
                     Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
                         tracing::warn!(skipped = n, "pid_publisher lagged, re-syncing");
                         publish(&sessions, &pids_tx).await;
@@ -846,6 +880,11 @@ impl LiveSessionManager {
             .collect()
     }
 
+    /// Live sessions for one project, most recently active first.
+    pub async fn live_sessions_for_project(&self, project: &str) -> Vec<LiveSession> {
+        sessions_for_project(&*self.sessions.read().await, Some(project))
+    }
+
     /// Total number of Claude processes detected on the system.
     pub fn process_count(&self) -> u32 {
         self.process_count.load(Ordering::Relaxed)
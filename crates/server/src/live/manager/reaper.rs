@@ -7,10 +7,15 @@ use std::sync::Arc;
 
 use tracing::info;
 
-use crate::live::state::SessionEvent;
+use crate::live::state::{SessionEndReason, SessionEvent};
 
 use super::LiveSessionManager;
 
+/// A session with no PID whose last activity is older than this is ended as
+/// [`SessionEndReason::Idle`] by the reconciler. Sessions that still have a
+/// PID are never idle-ended — the process detector owns their lifecycle.
+pub(crate) const IDLE_END_TIMEOUT_SECS: i64 = 30 * 60;
+
 /// Result of a reap attempt.
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) enum ReapResult {
@@ -31,8 +36,15 @@ impl LiveSessionManager {
     ///
     /// Cleans: live_sessions, transcript_to_session, hook_event_channels,
     ///         accumulators, and signals drain_loop to drop its dirty entry.
-    #[tracing::instrument(skip_all, fields(%session_id))]
-    pub(crate) async fn reap_session(self: &Arc<Self>, session_id: &str) -> ReapResult {
+    ///
+    /// Broadcasts `SessionRemove` then `SessionEnded { reason }`. A second
+    /// reap of the same id returns `NotFound`, so `SessionEnded` fires once.
+    #[tracing::instrument(skip_all, fields(%session_id, ?reason))]
+    pub(crate) async fn reap_session(
+        self: &Arc<Self>,
+        session_id: &str,
+        reason: SessionEndReason,
+    ) -> ReapResult {
         // Phase 1: Remove from live_sessions, capture for closed_ring.
         let (cleanup_tp, closed_session) = {
             let mut sessions = self.sessions.write().await;
@@ -105,6 +117,10 @@ impl LiveSessionManager {
             session_id: session_id.to_string(),
             session: closed_session,
         });
+        let _ = self.tx.send(SessionEvent::SessionEnded {
+            session_id: session_id.to_string(),
+            reason,
+        });
 
         // Phase 4: Persist clean snapshot (closed_ring NOT included).
         self.request_snapshot_save();
@@ -116,15 +132,40 @@ impl LiveSessionManager {
 
     /// Reap multiple sessions. Returns count of successfully reaped sessions.
     #[tracing::instrument(skip_all)]
-    pub(crate) async fn reap_sessions(self: &Arc<Self>, session_ids: &[String]) -> u32 {
+    pub(crate) async fn reap_sessions(
+        self: &Arc<Self>,
+        session_ids: &[String],
+        reason: SessionEndReason,
+    ) -> u32 {
         let mut count = 0u32;
         for id in session_ids {
-            if matches!(self.reap_session(id).await, ReapResult::Reaped) {
+            if matches!(self.reap_session(id, reason).await, ReapResult::Reaped) {
                 count += 1;
             }
         }
         count
     }
+
+    /// Reap PID-less sessions with no activity for `timeout_secs` as of `now`.
+    ///
+    /// Without a PID there is no process death to observe, so transcript
+    /// silence is the only end signal. Returns the number of sessions reaped.
+    pub(crate) async fn reap_idle_sessions(self: &Arc<Self>, now: i64, timeout_secs: i64) -> u32 {
+        let idle_ids: Vec<String> = {
+            let sessions = self.sessions.read().await;
+            sessions
+                .iter()
+                .filter(|(_, s)| {
+                    s.hook.pid.is_none() && now - s.hook.last_activity_at >= timeout_secs
+                })
+                .map(|(id, _)| id.clone())
+                .collect()
+        };
+        if idle_ids.is_empty() {
+            return 0;
+        }
+        self.reap_sessions(&idle_ids, SessionEndReason::Idle).await
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -148,8 +189,8 @@ mod tests {
     use crate::live::manager::LiveSessionManager;
     use crate::live::process_oracle;
     use crate::live::state::{
-        AgentState, AgentStateGroup, HookEvent, HookFields, JsonlFields, LiveSession, SessionEvent,
-        SessionStatus, StatuslineFields,
+        AgentState, AgentStateGroup, HookEvent, HookFields, JsonlFields, LiveSession,
+        SessionEndReason, SessionEvent, SessionStatus, StatuslineFields,
     };
 
     use super::ReapResult;
@@ -298,7 +339,9 @@ mod tests {
             .insert(session_id.to_string(), SessionAccumulator::new());
 
         // Reap.
-        let result = mgr.reap_session(session_id).await;
+        let result = mgr
+            .reap_session(session_id, SessionEndReason::ProcessExited)
+            .await;
         assert_eq!(result, ReapResult::Reaped, "should reap a dead session");
 
         // Verify ALL maps are clean.
@@ -329,7 +372,9 @@ mod tests {
     #[tokio::test]
     async fn test_reap_returns_not_found_for_missing_session() {
         let (mgr, _rx, _snap_rx) = make_test_manager().await;
-        let result = mgr.reap_session("no-such-session").await;
+        let result = mgr
+            .reap_session("no-such-session", SessionEndReason::ProcessExited)
+            .await;
         assert_eq!(result, ReapResult::NotFound);
     }
 
@@ -346,7 +391,9 @@ mod tests {
             .await
             .insert(session_id.to_string(), session);
 
-        let result = mgr.reap_session(session_id).await;
+        let result = mgr
+            .reap_session(session_id, SessionEndReason::ProcessExited)
+            .await;
         assert_eq!(
             result,
             ReapResult::StillAlive,
@@ -368,7 +415,8 @@ mod tests {
             .await
             .insert(session_id.to_string(), session);
 
-        mgr.reap_session(session_id).await;
+        mgr.reap_session(session_id, SessionEndReason::ProcessExited)
+            .await;
 
         // Should have received a SessionRemove event (moved to recently closed).
         let event = rx
@@ -409,13 +457,100 @@ mod tests {
             .await
             .insert(session_id.to_string(), session);
 
-        mgr.reap_session(session_id).await;
+        mgr.reap_session(session_id, SessionEndReason::ProcessExited)
+            .await;
 
         // Snapshot channel should have a message.
         let msg = snap_rx.try_recv();
         assert!(msg.is_ok(), "expected snapshot save request after reap");
     }
 
+    #[tokio::test]
+    async fn test_reap_emits_session_ended_with_reason() {
+        let (mgr, mut rx, _snap_rx) = make_test_manager().await;
+        let session_id = "sess-ended";
+        let session = make_dead_session(session_id, DEAD_PID, "/tmp/ended.jsonl");
+        mgr.sessions
+            .write()
+            .await
+            .insert(session_id.to_string(), session);
+
+        mgr.reap_session(session_id, SessionEndReason::Evicted)
+            .await;
+
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(SessionEvent::SessionRemove { .. })
+        ));
+        match rx.try_recv() {
+            Ok(SessionEvent::SessionEnded {
+                session_id: sid,
+                reason,
+            }) => {
+                assert_eq!(sid, session_id);
+                assert_eq!(reason, SessionEndReason::Evicted);
+            }
+            other => panic!("expected SessionEnded, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_idle_session_ends_exactly_once() {
+        let (mgr, mut rx, _snap_rx) = make_test_manager().await;
+        let session_id = "sess-idle";
+        let mut session = make_dead_session(session_id, DEAD_PID, "/tmp/idle.jsonl");
+        session.hook.pid = None;
+        session.hook.last_activity_at = 1_000;
+        mgr.sessions
+            .write()
+            .await
+            .insert(session_id.to_string(), session);
+
+        let timeout = super::IDLE_END_TIMEOUT_SECS;
+
+        // Just short of the timeout — still live.
+        assert_eq!(
+            mgr.reap_idle_sessions(1_000 + timeout - 1, timeout).await,
+            0
+        );
+        assert!(mgr.sessions.read().await.contains_key(session_id));
+
+        // Timeout reached — ended; further ticks are no-ops.
+        assert_eq!(mgr.reap_idle_sessions(1_000 + timeout, timeout).await, 1);
+        assert_eq!(
+            mgr.reap_idle_sessions(1_000 + timeout * 2, timeout).await,
+            0
+        );
+
+        let mut ended = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            if let SessionEvent::SessionEnded { session_id, reason } = event {
+                ended.push((session_id, reason));
+            }
+        }
+        assert_eq!(
+            ended,
+            vec![(session_id.to_string(), SessionEndReason::Idle)],
+            "SessionEnded must fire exactly once with reason Idle"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_idle_reap_skips_sessions_with_pid() {
+        let (mgr, _rx, _snap_rx) = make_test_manager().await;
+        let session_id = "sess-has-pid";
+        let mut session = make_dead_session(session_id, DEAD_PID, "/tmp/has-pid.jsonl");
+        session.hook.last_activity_at = 0;
+        mgr.sessions
+            .write()
+            .await
+            .insert(session_id.to_string(), session);
+
+        // PID-tracked sessions are the process detector's job, not idle's.
+        assert_eq!(mgr.reap_idle_sessions(i64::MAX / 2, 60).await, 0);
+        assert!(mgr.sessions.read().await.contains_key(session_id));
+    }
+
     #[tokio::test]
     async fn test_reap_sessions_batch_returns_count() {
         let (mgr, _rx, _snap_rx) = make_test_manager().await;
@@ -429,7 +564,9 @@ mod tests {
         }
 
         let ids: Vec<String> = (0..3).map(|i| format!("batch-{}", i)).collect();
-        let count = mgr.reap_sessions(&ids).await;
+        let count = mgr
+            .reap_sessions(&ids, SessionEndReason::ProcessExited)
+            .await;
         assert_eq!(count, 3, "should have reaped all 3 sessions");
         assert!(
             mgr.sessions.read().await.is_empty(),
@@ -451,7 +588,7 @@ mod tests {
             let id = format!("ring-{i}");
             let session = make_dead_session(&id, DEAD_PID + i as u32, &format!("/tmp/r{i}.jsonl"));
             mgr.sessions.write().await.insert(id.clone(), session);
-            mgr.reap_session(&id).await;
+            mgr.reap_session(&id, SessionEndReason::ProcessExited).await;
         }
         assert_eq!(mgr.closed_ring.read().await.len(), cap);
 
@@ -462,7 +599,8 @@ mod tests {
             .write()
             .await
             .insert(overflow_id.to_string(), session);
-        mgr.reap_session(overflow_id).await;
+        mgr.reap_session(overflow_id, SessionEndReason::ProcessExited)
+            .await;
 
         let ring = mgr.closed_ring.read().await;
         assert_eq!(ring.len(), cap, "ring should stay at capacity");
@@ -499,7 +637,11 @@ mod tests {
             .write()
             .await
             .insert(session_id.to_string(), s1);
-        assert_eq!(mgr.reap_session(session_id).await, ReapResult::Reaped);
+        assert_eq!(
+            mgr.reap_session(session_id, SessionEndReason::ProcessExited)
+                .await,
+            ReapResult::Reaped
+        );
 
         // Resurrection: the same id re-enters the live map, then dies again → reap #2.
         let mut s2 = make_dead_session(session_id, DEAD_PID, "/tmp/res.jsonl");
@@ -508,7 +650,11 @@ mod tests {
             .write()
             .await
             .insert(session_id.to_string(), s2);
-        assert_eq!(mgr.reap_session(session_id).await, ReapResult::Reaped);
+        assert_eq!(
+            mgr.reap_session(session_id, SessionEndReason::ProcessExited)
+                .await,
+            ReapResult::Reaped
+        );
 
         // Invariant: the ring holds this id AT MOST ONCE.
         let ring = mgr.closed_ring.read().await;
@@ -609,7 +755,9 @@ mod tests {
             .insert(session_id.to_string(), session);
 
         // Reap the session.
-        let result = mgr.reap_session(session_id).await;
+        let result = mgr
+            .reap_session(session_id, SessionEndReason::ProcessExited)
+            .await;
         assert_eq!(result, ReapResult::Reaped);
 
         // Check closed_ring: Running agents should now be Error.
@@ -10,7 +10,7 @@ use std::time::Duration;
 use tracing::info;
 
 use crate::live::process::count_claude_processes;
-use crate::live::state::SessionStatus;
+use crate::live::state::{SessionEndReason, SessionStatus};
 
 use super::LiveSessionManager;
 
@@ -61,12 +61,24 @@ impl LiveSessionManager {
                 };
 
                 if !dead_session_ids.is_empty() {
-                    let count = manager.reap_sessions(&dead_session_ids).await;
+                    let count = manager
+                        .reap_sessions(&dead_session_ids, SessionEndReason::ProcessExited)
+                        .await;
                     if count > 0 {
                         info!(reaped = count, "Reconciliation: reaped dead sessions");
                     }
                 }
 
+                // Phase 1a: PID-less sessions have no death to observe — end
+                // them once the transcript has been silent for the timeout.
+                let now = chrono::Utc::now().timestamp();
+                let idle = manager
+                    .reap_idle_sessions(now, super::reaper::IDLE_END_TIMEOUT_SECS)
+                    .await;
+                if idle > 0 {
+                    info!(reaped = idle, "Reconciliation: ended idle sessions");
+                }
+
                 // =============================================================
                 // Phase 1b: Sidecar health observation (passive — never acts)
                 // =============================================================
@@ -260,7 +272,9 @@ impl LiveSessionManager {
                         pid = pid,
                         "kqueue: PID death -> reaping session"
                     );
-                    manager.reap_session(&session_id).await;
+                    manager
+                        .reap_session(&session_id, SessionEndReason::ProcessExited)
+                        .await;
                 }
             }
         });
@@ -9,6 +9,7 @@ use tokio::sync::mpsc;
 use tracing::info;
 
 use crate::live::sessions_watcher::SessionLifecycleEvent;
+use crate::live::state::SessionEndReason;
 
 use super::LiveSessionManager;
 
@@ -106,7 +107,9 @@ impl LiveSessionManager {
                         };
 
                         if let Some(id) = session_id {
-                            manager.reap_session(&id).await;
+                            manager
+                                .reap_session(&id, SessionEndReason::ProcessExited)
+                                .await;
                         }
                     }
 
@@ -124,7 +127,9 @@ impl LiveSessionManager {
                         };
 
                         if has_session {
-                            manager.reap_session(&session_id).await;
+                            manager
+                                .reap_session(&session_id, SessionEndReason::Crashed)
+                                .await;
                         }
                     }
                 }
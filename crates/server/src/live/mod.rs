@@ -15,6 +15,7 @@ pub mod process;
 pub mod process_death;
 pub mod process_oracle;
 pub use claude_view_server_process_tree as process_tree;
+pub mod registry_watcher;
 pub mod relay_client;
 pub mod session_ws;
 pub mod sessions_watcher;
@@ -0,0 +1,211 @@
+//! File watcher for the invocable registry sources under `~/.claude`.
+//!
+//! Watches the directories [`build_registry`] reads (`plugins/`, `skills/`,
+//! `commands/`, `agents/`) and, once changes go quiet, rebuilds the
+//! [`Registry`] and swaps it into the shared [`RegistryHolder`]. A skill or
+//! plugin installed mid-session is then visible to the API and the next
+//! periodic re-scan without waiting for a restart.
+//!
+//! A source directory created after startup is picked up too: `~/.claude`
+//! itself is watched non-recursively, and every rebuild re-checks which
+//! source directories exist and adds a recursive watch for new ones.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use claude_view_core::build_registry;
+use claude_view_core::registry::Registry;
+use claude_view_db::Database;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::mpsc;
+use tracing::{info, warn};
+
+use crate::state::RegistryHolder;
+
+/// Subdirectories of `~/.claude` that [`build_registry`] scans.
+pub const REGISTRY_SOURCE_DIRS: &[&str] = &["plugins", "skills", "commands", "agents"];
+
+/// Quiet period after the last change before rebuilding, so a plugin
+/// install that writes many files triggers a single rebuild.
+const REBUILD_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Whether `path` lies in (or is) one of the [`REGISTRY_SOURCE_DIRS`] of
+/// `claude_dir`.
+pub fn is_registry_source(claude_dir: &Path, path: &Path) -> bool {
+    path.strip_prefix(claude_dir)
+        .ok()
+        .and_then(|rel| rel.components().next())
+        .and_then(|first| first.as_os_str().to_str())
+        .is_some_and(|first| REGISTRY_SOURCE_DIRS.contains(&first))
+}
+
+/// Upsert every invocable of `registry` into the DB so invocations can
+/// reference them (FK constraint). Failures are logged, not returned.
+pub async fn seed_invocables(db: &Database, registry: &Registry) {
+    let invocable_tuples: Vec<(String, Option<String>, String, String, String)> = registry
+        .all_invocables()
+        .map(|info| {
+            (
+                info.id.clone(),
+                info.plugin_name.clone(),
+                info.name.clone(),
+                info.kind.to_string(),
+                info.description.clone(),
+            )
+        })
+        .collect();
+    if !invocable_tuples.is_empty() {
+        if let Err(e) = db.batch_upsert_invocables(&invocable_tuples).await {
+            warn!(error = %e, "Failed to seed invocables");
+        }
+    }
+}
+
+/// Rebuild the registry from `claude_dir`, seed its invocables when `db` is
+/// given, and swap it into `holder`.
+pub async fn rebuild_registry(claude_dir: &Path, holder: &RegistryHolder, db: Option<&Database>) {
+    let registry = build_registry(claude_dir).await;
+    if let Some(db) = db {
+        seed_invocables(db, &registry).await;
+    }
+    info!(
+        invocables = registry.len(),
+        "Registry rebuilt after source change"
+    );
+    *holder.write().unwrap() = Some(registry);
+}
+
+/// Start watching the registry sources of `claude_dir` and spawn the task
+/// that rebuilds `holder` on change.
+///
+/// The spawned task owns the watcher, so the watch lasts as long as the
+/// task. Fails when `claude_dir` cannot be watched (e.g. it does not exist).
+pub fn spawn_registry_watcher(
+    claude_dir: PathBuf,
+    holder: RegistryHolder,
+    db: Option<Database>,
+) -> notify::Result<tokio::task::JoinHandle<()>> {
+    // Event paths are reported canonicalized on some platforms.
+    let claude_dir = claude_dir.canonicalize().unwrap_or(claude_dir);
+    // One pending signal is enough: the rebuild reads the whole tree.
+    let (tx, mut rx) = mpsc::channel::<()>(1);
+    let filter_dir = claude_dir.clone();
+
+    let mut watcher =
+        notify::recommended_watcher(move |res: Result<notify::Event, notify::Error>| match res {
+            Ok(event) => {
+                if matches!(event.kind, EventKind::Access(_)) {
+                    return;
+                }
+                // `Other` is a queue overflow: rebuild to be safe.
+                if event.kind == EventKind::Other
+                    || event
+                        .paths
+                        .iter()
+                        .any(|p| is_registry_source(&filter_dir, p))
+                {
+                    let _ = tx.try_send(());
+                }
+            }
+            Err(e) => warn!("Registry watcher error: {e}"),
+        })?;
+
+    watcher.watch(&claude_dir, RecursiveMode::NonRecursive)?;
+    let mut watched = HashSet::new();
+    watch_source_dirs(&mut watcher, &claude_dir, &mut watched);
+    info!(
+        "Watching {} for registry source changes",
+        claude_dir.display()
+    );
+
+    Ok(tokio::spawn(async move {
+        while rx.recv().await.is_some() {
+            while let Ok(Some(())) = tokio::time::timeout(REBUILD_DEBOUNCE, rx.recv()).await {}
+            watch_source_dirs(&mut watcher, &claude_dir, &mut watched);
+            rebuild_registry(&claude_dir, &holder, db.as_ref()).await;
+        }
+    }))
+}
+
+/// Add a recursive watch for each existing source directory not yet in
+/// `watched`.
+fn watch_source_dirs(
+    watcher: &mut RecommendedWatcher,
+    claude_dir: &Path,
+    watched: &mut HashSet<PathBuf>,
+) {
+    for name in REGISTRY_SOURCE_DIRS {
+        let dir = claude_dir.join(name);
+        if watched.contains(&dir) || !dir.is_dir() {
+            continue;
+        }
+        match watcher.watch(&dir, RecursiveMode::Recursive) {
+            Ok(()) => {
+                watched.insert(dir);
+            }
+            Err(e) => warn!("Failed to watch {}: {e}", dir.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, RwLock};
+
+    #[test]
+    fn test_is_registry_source_matches_source_dirs_only() {
+        let claude = Path::new("/home/u/.claude");
+        assert!(is_registry_source(
+            claude,
+            Path::new("/home/u/.claude/skills/foo/SKILL.md")
+        ));
+        assert!(is_registry_source(
+            claude,
+            Path::new("/home/u/.claude/plugins/installed_plugins.json")
+        ));
+        assert!(is_registry_source(
+            claude,
+            Path::new("/home/u/.claude/agents")
+        ));
+        assert!(!is_registry_source(
+            claude,
+            Path::new("/home/u/.claude/history.jsonl")
+        ));
+        assert!(!is_registry_source(
+            claude,
+            Path::new("/home/u/.claude/projects/p/s.jsonl")
+        ));
+        assert!(!is_registry_source(claude, Path::new("/tmp/skills/x")));
+    }
+
+    #[tokio::test]
+    async fn test_new_user_skill_triggers_rebuild() {
+        let tmp = tempfile::tempdir().unwrap();
+        let claude_dir = tmp.path().to_path_buf();
+        std::fs::create_dir_all(claude_dir.join("skills")).unwrap();
+
+        let holder: RegistryHolder = Arc::new(RwLock::new(Some(build_registry(&claude_dir).await)));
+        let lookup = |holder: &RegistryHolder| {
+            holder
+                .read()
+                .unwrap()
+                .as_ref()
+                .and_then(|r| r.lookup("user:hot-skill").map(|i| i.name.clone()))
+        };
+        assert_eq!(lookup(&holder), None);
+
+        let task = spawn_registry_watcher(claude_dir.clone(), holder.clone(), None).unwrap();
+        let skill_dir = claude_dir.join("skills/hot-skill");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(skill_dir.join("SKILL.md"), "Reload me\n").unwrap();
+
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(10);
+        while lookup(&holder).is_none() && tokio::time::Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        task.abort();
+        assert_eq!(lookup(&holder).as_deref(), Some("hot-skill"));
+    }
+}
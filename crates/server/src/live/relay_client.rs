@@ -185,6 +185,9 @@ async fn run_session(
                             let _ = push_session(&mut sink, &s, peer, &box_secret).await;
                         }
                     }
+                    // Ended carries no snapshot; the preceding Remove already
+                    // pushed the final state.
+                    Ok(SessionEvent::SessionEnded { .. }) => {}
                     Err(broadcast::error::RecvError::Lagged(n)) => {
                         warn!(skipped = n, "relay client lagged; resyncing");
                         let sessions_map = sessions.read().await;
@@ -26,11 +26,17 @@ use utoipa::OpenApi;
         crate::routes::sessions::estimate_cost,
         crate::routes::sessions::archive_session_handler,
         crate::routes::sessions::unarchive_session_handler,
+        crate::routes::sessions::refresh_session_handler,
+        crate::routes::sessions::get_session_models,
         crate::routes::sessions::bulk_archive_handler,
         crate::routes::sessions::bulk_unarchive_handler,
         crate::routes::sessions::get_session_hook_events,
         crate::routes::sessions::get_subagent_messages,
         crate::routes::sessions::session_activity_rich,
+        crate::routes::sessions::session_activity_streak,
+        crate::routes::sessions::session_token_histogram,
+        crate::routes::sessions::get_related_sessions,
+        crate::routes::sessions::export_session_transcript,
         // Multi-provider
         crate::routes::providers::list_providers,
         crate::routes::providers::providers_usage,
@@ -72,6 +78,7 @@ use utoipa::OpenApi;
         crate::routes::stats::dashboard_stats,
         crate::routes::stats::storage_stats,
         crate::routes::stats::ai_generation_stats,
+        crate::routes::stats::cost_by_model,
         crate::routes::trends::get_trends,
         crate::routes::score::get_fluency_score,
         crate::routes::invocables::list_invocables,
@@ -92,8 +99,11 @@ use utoipa::OpenApi;
         crate::routes::coaching::apply_rule,
         crate::routes::coaching::remove_rule,
         crate::routes::search::search_handler,
+        crate::routes::search::search_export_handler,
+        crate::routes::search::search_cards_handler,
         crate::routes::export::export_sessions,
         crate::routes::turns::get_session_turns,
+        crate::routes::turns::get_session_token_timeline,
         crate::routes::plans::get_session_plans,
         crate::routes::prompts::list_prompts,
         crate::routes::prompts::get_prompt_stats,
@@ -154,6 +164,7 @@ use utoipa::OpenApi;
         crate::routes::system::clear_cache,
         crate::routes::system::trigger_git_resync,
         crate::routes::system::reset_all,
+        crate::routes::system::reset_project,
         crate::routes::system::check_path,
         crate::routes::jobs::list_jobs,
         crate::routes::jobs::stream_jobs,
@@ -204,6 +215,10 @@ use utoipa::OpenApi;
         crate::routes::sessions::DerivedMetrics,
         crate::routes::sessions::EstimateRequest,
         crate::routes::sessions::CostEstimate,
+        crate::routes::sessions::TranscriptExport,
+        crate::routes::sessions::TranscriptMetadata,
+        crate::routes::sessions::TranscriptTurn,
+        claude_view_core::Role,
         claude_view_core::SessionInfo,
         claude_view_core::ToolCounts,
         claude_view_core::task_files::TaskItem,
@@ -274,6 +289,8 @@ use utoipa::OpenApi;
         claude_view_db::InvocableWithCount,
         claude_view_db::StatsOverview,
         claude_view_db::TokenStats,
+        claude_view_db::ModelCost,
+        claude_view_db::SessionModelCost,
         // Batch 4: Classify, Facets, Coaching, Search, Export, Turns, Plans, Models, Prompts
         crate::routes::classify::ClassifyResponse,
         crate::routes::classify::CancelResponse,
@@ -289,11 +306,19 @@ use utoipa::OpenApi;
         crate::routes::coaching::CoachingRule,
         crate::routes::coaching::ListRulesResponse,
         crate::routes::coaching::RemoveRuleResponse,
+        claude_view_search::types::SearchResponse,
+        claude_view_search::types::SearchTimings,
+        claude_view_search::types::SessionHit,
+        claude_view_search::types::MatchHit,
+        crate::search_service::SearchSessionCard,
+        crate::search_service::SearchCardsResponse,
         crate::routes::export::ExportedSession,
         crate::routes::export::ExportResponse,
         crate::routes::turns::TurnInfo,
+        crate::routes::turns::TurnTokens,
         claude_view_core::plan_files::PlanDocument,
         claude_view_db::ModelWithStats,
+        claude_view_db::ModelTurnCount,
         crate::routes::prompts::PromptInfo,
         crate::routes::prompts::PromptListResponse,
         crate::routes::prompts::PromptTemplateInfo,
@@ -406,6 +431,8 @@ use utoipa::OpenApi;
         claude_view_core::pricing::TokenUsage,
         claude_view_core::pricing::CostBreakdown,
         claude_view_db::RichActivityResponse,
+        claude_view_db::ActivityStreak,
+        claude_view_db::TokenHistogramBucket,
         // Batch 6: Memory, MCP, Settings
         claude_view_core::memory_files::MemoryIndex,
         claude_view_core::memory_files::MemoryEntry,
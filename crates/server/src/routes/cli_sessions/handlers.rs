@@ -442,7 +442,9 @@ pub async fn kill_session(
                     tokio::time::sleep(std::time::Duration::from_millis(50)).await;
                 }
             }
-            let result = manager.reap_session(key).await;
+            let result = manager
+                .reap_session(key, crate::live::state::SessionEndReason::Killed)
+                .await;
             tracing::debug!(
                 tmux_session = %id,
                 session_id = %key,
@@ -396,6 +396,7 @@ pub async fn get_session_contribution(
     let response = SessionContributionResponse {
         session_id: contribution.session_id,
         work_type: contribution.work_type,
+        work_type_alternates: contribution.work_type_alternates,
         duration: contribution.duration_seconds,
         prompt_count: contribution.prompt_count,
         ai_lines_added: contribution.ai_lines_added,
@@ -187,6 +187,8 @@ pub struct SessionContributionResponse {
     pub session_id: String,
     /// Work type classification
     pub work_type: Option<String>,
+    /// Secondary work types, best first
+    pub work_type_alternates: Vec<String>,
     /// Duration in seconds
     #[ts(type = "number")]
     pub duration: i64,
@@ -211,7 +211,7 @@ fn build_csv(sessions: &[&SessionInfo]) -> String {
 ///
 /// If the field contains comma, double quote, newline, or pipe, wrap in double quotes
 /// and escape any internal double quotes by doubling them.
-fn escape_csv_field(field: &str) -> String {
+pub(crate) fn escape_csv_field(field: &str) -> String {
     if field.contains(',')
         || field.contains('"')
         || field.contains('\n')
@@ -3,10 +3,13 @@
 //! Scans `~/.claude/projects/` for session JSONL files.
 //! Used by `search_service::execute_search()` for session search.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use claude_view_core::discovery::{claude_projects_dir, resolve_project_path_with_cwd};
-use claude_view_search::JsonlFile;
+use claude_view_core::discovery::{
+    claude_projects_dir, resolve_project_path_with_cwd, ResolvedProject,
+};
+use claude_view_db::SearchFileMeta;
+use claude_view_search::{jsonl_is_sidechain, JsonlFile};
 
 use crate::error::ApiError;
 
@@ -15,11 +18,16 @@ use crate::error::ApiError;
 ///
 /// Used by `search_service::execute_search()`.
 ///
+/// Project labels and sidechain flags come from `known` (see
+/// `Database::search_file_meta`). Only a directory holding a not-yet-indexed
+/// session is decoded, once, and only such files are probed for `isSidechain`.
+///
 /// NOTE: project filter checks BOTH display_name AND full_path to match
 /// the polymorphic project filter pattern (CLAUDE.md Hard Rule).
 pub fn collect_jsonl_files(
     project_filter: Option<&str>,
     session_ids: Option<&HashSet<String>>,
+    known: &HashMap<String, SearchFileMeta>,
 ) -> Result<Vec<JsonlFile>, ApiError> {
     let projects_dir =
         claude_projects_dir().map_err(|e| ApiError::Internal(format!("Projects dir: {e}")))?;
@@ -34,13 +42,7 @@ pub fn collect_jsonl_files(
             }
 
             let dir_name = entry.file_name().to_string_lossy().to_string();
-            let resolved = resolve_project_path_with_cwd(&dir_name, None);
-
-            if let Some(proj) = project_filter {
-                if resolved.display_name != proj && resolved.full_path != proj {
-                    continue;
-                }
-            }
+            let mut resolved: Option<ResolvedProject> = None;
 
             if let Ok(sessions) = std::fs::read_dir(&project_dir) {
                 for session in sessions.flatten() {
@@ -56,6 +58,24 @@ pub fn collect_jsonl_files(
                                 continue;
                             }
                         }
+                        let meta = known.get(&session_id);
+                        let (project, project_path) = match meta {
+                            Some(meta) if !meta.project_path.is_empty() => {
+                                (&meta.project_display_name, &meta.project_path)
+                            }
+                            _ => {
+                                let r = resolved.get_or_insert_with(|| {
+                                    resolve_project_path_with_cwd(&dir_name, None)
+                                });
+                                (&r.display_name, &r.full_path)
+                            }
+                        };
+                        if let Some(proj) = project_filter {
+                            if project.as_str() != proj && project_path.as_str() != proj {
+                                continue;
+                            }
+                        }
+
                         let modified_at = path
                             .metadata()
                             .and_then(|m| m.modified())
@@ -66,12 +86,17 @@ pub fn collect_jsonl_files(
                             })
                             .unwrap_or(0);
 
+                        let is_sidechain = match meta {
+                            Some(meta) => meta.is_sidechain,
+                            None => jsonl_is_sidechain(&path),
+                        };
                         files.push(JsonlFile {
+                            project: project.clone(),
+                            project_path: project_path.clone(),
                             path,
                             session_id,
-                            project: resolved.display_name.clone(),
-                            project_path: resolved.full_path.clone(),
                             modified_at,
+                            is_sidechain,
                         });
                     }
                 }
@@ -18,11 +18,17 @@ pub struct HealthResponse {
     pub version: String,
     #[ts(type = "number")]
     pub uptime_secs: u64,
+    /// Whether the prompt search index is open. `false` before the index is
+    /// built or after it failed to open, in which case search returns nothing.
+    pub search_available: bool,
+    /// Documents in the search index (0 when unavailable).
+    #[ts(type = "number")]
+    pub search_doc_count: u64,
 }
 
 /// GET /api/health - Health check endpoint.
 ///
-/// Returns server status, version, and uptime.
+/// Returns server status, version, uptime, and search index availability.
 #[utoipa::path(
     get,
     path = "/api/health",
@@ -32,10 +38,18 @@ pub struct HealthResponse {
     )
 )]
 pub async fn health_check(State(state): State<Arc<AppState>>) -> Json<HealthResponse> {
+    let search_doc_count = state
+        .prompt_index
+        .read()
+        .unwrap()
+        .as_ref()
+        .map(|index| index.num_docs());
     Json(HealthResponse {
         status: "ok".to_string(),
         version: env!("CARGO_PKG_VERSION").to_string(),
         uptime_secs: state.uptime_secs(),
+        search_available: search_doc_count.is_some(),
+        search_doc_count: search_doc_count.unwrap_or(0),
     })
 }
 
@@ -54,11 +68,15 @@ mod tests {
             status: "ok".to_string(),
             version: "0.1.0".to_string(),
             uptime_secs: 42,
+            search_available: true,
+            search_doc_count: 7,
         };
 
         let json = serde_json::to_string(&response).unwrap();
         assert!(json.contains("\"status\":\"ok\""));
         assert!(json.contains("\"version\":\"0.1.0\""));
         assert!(json.contains("\"uptime_secs\":42"));
+        assert!(json.contains("\"search_available\":true"));
+        assert!(json.contains("\"search_doc_count\":7"));
     }
 }
@@ -3,7 +3,7 @@
 //! One PID = one active session. When a new session starts on the same PID
 //! (e.g. session resume), stale entries are immediately reaped.
 
-use crate::live::state::SessionStatus;
+use crate::live::state::{SessionEndReason, SessionStatus};
 use crate::state::AppState;
 
 /// Evict all stale sessions sharing the same PID as a new SessionStart.
@@ -37,7 +37,9 @@ pub(super) async fn evict_stale_sessions_for_pid(
 
     // Phase 2: Reap via the unified reaper.
     if let Some(ref mgr) = state.live_manager {
-        let count = mgr.reap_sessions(&evict_ids).await;
+        let count = mgr
+            .reap_sessions(&evict_ids, SessionEndReason::Evicted)
+            .await;
         if count > 0 {
             tracing::info!(
                 pid = pid,
@@ -3,25 +3,43 @@
 
 use std::sync::Arc;
 
-use axum::{extract::State, routing::get, Json, Router};
+use axum::{
+    extract::{Query, State},
+    routing::get,
+    Json, Router,
+};
 use claude_view_db::{InvocableWithCount, StatsOverview, TokenStats};
+use serde::Deserialize;
 
 use crate::error::ApiResult;
 use crate::state::AppState;
 
+#[derive(Debug, Deserialize, Default, utoipa::IntoParams)]
+#[serde(default)]
+pub struct InvocablesQuery {
+    /// Case-insensitive search over name, plugin name and description.
+    pub q: Option<String>,
+}
+
 /// GET /api/invocables - List all invocables with their usage counts.
 #[utoipa::path(get, path = "/api/invocables", tag = "insights",
+    params(InvocablesQuery),
     responses(
         (status = 200, description = "All invocables (tools, skills, MCPs) sorted by usage", body = Vec<claude_view_db::InvocableWithCount>),
     )
 )]
 ///
 /// Returns a list of all known invocables (tools, skills, MCPs) ordered by
-/// invocation count descending, then name ascending.
+/// invocation count descending, then name ascending. With `?q=`, only
+/// matches are returned, most relevant first.
 pub async fn list_invocables(
     State(state): State<Arc<AppState>>,
+    Query(query): Query<InvocablesQuery>,
 ) -> ApiResult<Json<Vec<InvocableWithCount>>> {
-    let invocables = state.db.list_invocables_with_counts().await?;
+    let invocables = state
+        .db
+        .search_invocables(query.q.as_deref().unwrap_or_default())
+        .await?;
     Ok(Json(invocables))
 }
 
@@ -39,14 +57,42 @@ pub async fn stats_overview(State(state): State<Arc<AppState>>) -> ApiResult<Jso
     Ok(Json(stats))
 }
 
+#[derive(Debug, Deserialize, Default, utoipa::IntoParams)]
+#[serde(default)]
+pub struct TokenStatsQuery {
+    /// Period start timestamp (Unix seconds, inclusive).
+    pub from: Option<i64>,
+    /// Period end timestamp (Unix seconds, inclusive).
+    pub to: Option<i64>,
+    /// Optional project filter (matches project_id, git_root or project_path).
+    pub project: Option<String>,
+    /// Optional branch filter (matches sessions.git_branch).
+    pub branch: Option<String>,
+}
+
 /// GET /api/stats/tokens - Aggregate token usage statistics.
+///
+/// With no query params, totals cover every session; `from`/`to`,
+/// `project` and `branch` narrow them like the dashboard filters.
 #[utoipa::path(get, path = "/api/stats/tokens", tag = "stats",
+    params(TokenStatsQuery),
     responses(
         (status = 200, description = "Aggregate token usage with cache ratios", body = claude_view_db::TokenStats),
     )
 )]
-pub async fn stats_tokens(State(state): State<Arc<AppState>>) -> ApiResult<Json<TokenStats>> {
-    let stats = state.db.get_token_stats().await?;
+pub async fn stats_tokens(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<TokenStatsQuery>,
+) -> ApiResult<Json<TokenStats>> {
+    let stats = state
+        .db
+        .get_token_stats_filtered(
+            query.from,
+            query.to,
+            query.project.as_deref(),
+            query.branch.as_deref(),
+        )
+        .await?;
     Ok(Json(stats))
 }
 
@@ -168,6 +214,42 @@ mod tests {
         assert_eq!(json["sessionsCount"], 0);
     }
 
+    #[tokio::test]
+    async fn test_stats_tokens_endpoint_filters() {
+        use claude_view_db::test_support::SessionSeedBuilder;
+
+        let db = test_db().await;
+        for (id, project, branch, ts, tokens) in [
+            ("sess-a1", "project-a", "main", 1000, 100),
+            ("sess-a2", "project-a", "feature", 5000, 10),
+            ("sess-b1", "project-b", "main", 2000, 1),
+        ] {
+            SessionSeedBuilder::new(id)
+                .project_id(project)
+                .file_path(format!("/tmp/{id}.jsonl"))
+                .git_branch(branch)
+                .last_message_at(ts)
+                .total_input_tokens(tokens)
+                .seed(&db)
+                .await
+                .unwrap();
+        }
+        let app = build_app(db);
+
+        for (uri, sessions, input) in [
+            ("/api/stats/tokens", 3, 111),
+            ("/api/stats/tokens?project=project-a", 2, 110),
+            ("/api/stats/tokens?branch=main", 2, 101),
+            ("/api/stats/tokens?from=1500&to=6000", 2, 11),
+        ] {
+            let (status, body) = get(app.clone(), uri).await;
+            assert_eq!(status, StatusCode::OK, "{uri}");
+            let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+            assert_eq!(json["sessionsCount"], sessions, "{uri}");
+            assert_eq!(json["totalInputTokens"], input, "{uri}");
+        }
+    }
+
     #[tokio::test]
     async fn test_stats_overview_endpoint_with_data() {
         let db = test_db().await;
@@ -1,7 +1,7 @@
 //! Live session monitoring endpoints (SSE + REST).
 //!
-//! - `GET /api/live/stream`              -- SSE stream of real-time session events
-//! - `GET /api/live/sessions`            -- List all live sessions
+//! - `GET /api/live/stream`              -- SSE stream of real-time session events (`?project=`)
+//! - `GET /api/live/sessions`            -- List live sessions (`?project=`)
 //! - `GET /api/live/sessions/:id`        -- Get a single live session
 //! - `GET /api/live/sessions/:id/messages` -- Get recent messages for a live session
 //! - `POST /api/live/sessions/:id/kill`   -- Send SIGTERM to a session's process
@@ -37,7 +37,7 @@ pub use sse::live_stream;
 pub use summary::{get_live_summary, get_pricing};
 
 // ---- Re-export request/response types ----
-pub use types::{BindControlRequest, MessagesQuery, UnbindControlRequest};
+pub use types::{BindControlRequest, LiveProjectQuery, MessagesQuery, UnbindControlRequest};
 
 // ---- Re-export utoipa hidden path types ----
 pub use actions::__path_bind_control;
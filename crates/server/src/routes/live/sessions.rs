@@ -7,23 +7,37 @@ use axum::{
     response::{IntoResponse, Json, Response},
 };
 
+use crate::live::manager::{is_project_session, sessions_for_project};
 use crate::state::AppState;
 
-use super::types::MessagesQuery;
+use super::types::{LiveProjectQuery, MessagesQuery};
 
-/// GET /api/live/sessions -- List all live sessions, sorted by most recent activity.
+/// GET /api/live/sessions?project= -- List live sessions, sorted by most
+/// recent activity. `project` limits both lists to one project.
 #[utoipa::path(get, path = "/api/live/sessions", tag = "live",
+    params(LiveProjectQuery),
     responses(
         (status = 200, description = "Active live sessions", body = serde_json::Value),
     )
 )]
-pub async fn list_live_sessions(State(state): State<Arc<AppState>>) -> Json<serde_json::Value> {
+pub async fn list_live_sessions(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<LiveProjectQuery>,
+) -> Json<serde_json::Value> {
     let map = state.live_sessions.read().await;
-    let mut sessions: Vec<_> = map.values().cloned().collect();
-    sessions.sort_by(|a, b| b.hook.last_activity_at.cmp(&a.hook.last_activity_at));
+    let sessions = sessions_for_project(&map, query.project.as_deref());
     let recently_closed: Vec<_> = {
         let ring = state.closed_ring.read().await;
-        ring.iter().rev().cloned().collect() // newest first
+        ring.iter()
+            .rev() // newest first
+            .filter(|s| {
+                query
+                    .project
+                    .as_deref()
+                    .is_none_or(|p| is_project_session(s, p))
+            })
+            .cloned()
+            .collect()
     };
     let process_count = state
         .live_manager
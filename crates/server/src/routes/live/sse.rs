@@ -1,18 +1,75 @@
 //! SSE stream endpoint for real-time session events.
 
+use std::collections::{HashMap, HashSet};
 use std::convert::Infallible;
 use std::sync::Arc;
 use std::time::Duration;
 
 use axum::{
-    extract::State,
+    extract::{Query, State},
     response::sse::{Event, Sse},
 };
 
-use crate::live::state::SessionEvent;
+use crate::live::manager::is_project_session;
+use crate::live::state::{LiveSession, SessionEvent};
 use crate::state::AppState;
 
 use super::summary::build_summary;
+use super::types::LiveProjectQuery;
+
+/// `snapshot` payload: summary, active sessions, and recently closed ones,
+/// limited to `project` when set. Active session ids are added to `seen` so
+/// later `session_ended` events (which carry no project) can be matched.
+fn build_snapshot(
+    map: &HashMap<String, LiveSession>,
+    closed: &[LiveSession],
+    process_count: u32,
+    project: Option<&str>,
+    seen: &mut HashSet<String>,
+) -> serde_json::Value {
+    let in_scope = |s: &LiveSession| project.is_none_or(|p| is_project_session(s, p));
+    let scoped: HashMap<String, LiveSession>;
+    let map = match project {
+        Some(_) => {
+            scoped = map
+                .iter()
+                .filter(|(_, s)| in_scope(s))
+                .map(|(id, s)| (id.clone(), s.clone()))
+                .collect();
+            &scoped
+        }
+        None => map,
+    };
+    seen.extend(map.keys().cloned());
+    let active: Vec<&LiveSession> = map.values().collect();
+    let closed: Vec<&LiveSession> = closed.iter().filter(|s| in_scope(s)).collect();
+    serde_json::json!({
+        "summary": build_summary(map, process_count),
+        "sessions": active,
+        "recentlyClosed": closed,
+    })
+}
+
+/// Whether a broadcast event should reach a stream scoped to `project`.
+pub(super) fn event_in_scope(
+    event: &SessionEvent,
+    project: Option<&str>,
+    seen: &mut HashSet<String>,
+) -> bool {
+    let Some(project) = project else {
+        return true;
+    };
+    match event {
+        SessionEvent::SessionUpsert { session } | SessionEvent::SessionRemove { session, .. } => {
+            let in_scope = is_project_session(session, project);
+            if in_scope {
+                seen.insert(session.id.clone());
+            }
+            in_scope
+        }
+        SessionEvent::SessionEnded { session_id, .. } => seen.remove(session_id),
+    }
+}
 
 /// GET /api/live/stream -- SSE stream of real-time session events.
 ///
@@ -28,35 +85,38 @@ use super::summary::build_summary;
 /// On initial connection the server sends a single `snapshot` event containing
 /// the summary, all active sessions, and recently closed sessions so the client
 /// can hydrate immediately without a separate REST call.
+///
+/// `?project=<id>` scopes the snapshot and every event to that project's
+/// sessions, so clients don't filter the global broadcast themselves.
 #[utoipa::path(get, path = "/api/live/stream", tag = "live",
+    params(LiveProjectQuery),
     responses(
         (status = 200, description = "SSE stream of live session events", content_type = "text/event-stream"),
     )
 )]
 pub async fn live_stream(
     State(state): State<Arc<AppState>>,
+    Query(query): Query<LiveProjectQuery>,
 ) -> Sse<impl tokio_stream::Stream<Item = Result<Event, Infallible>>> {
     let mut rx = state.live_tx.subscribe();
+    let project = query.project;
     let sessions = state.live_sessions.clone();
     let live_manager = state.live_manager.clone();
     let mut shutdown = state.shutdown.clone();
 
     let stream = async_stream::stream! {
+        // Session ids this stream has sent, for scoping `session_ended`.
+        let mut seen: HashSet<String> = HashSet::new();
+
         // 1. On connect: send snapshot (summary + all active sessions + recently closed)
         {
             let map = sessions.read().await;
             let pc = live_manager.as_ref().map(|m| m.process_count()).unwrap_or(0);
-            let summary = build_summary(&map, pc);
-            let active: Vec<&crate::live::state::LiveSession> = map.values().collect();
-            let closed: Vec<crate::live::state::LiveSession> = {
+            let closed: Vec<LiveSession> = {
                 let ring = state.closed_ring.read().await;
                 ring.iter().cloned().collect()
             };
-            let snapshot = serde_json::json!({
-                "summary": summary,
-                "sessions": active,
-                "recentlyClosed": closed,
-            });
+            let snapshot = build_snapshot(&map, &closed, pc, project.as_deref(), &mut seen);
             match serde_json::to_string(&snapshot) {
                 Ok(data) => yield Ok(Event::default().event("snapshot").data(data)),
                 Err(e) => tracing::error!("failed to serialize snapshot: {e}"),
@@ -70,9 +130,13 @@ pub async fn live_stream(
                 event = rx.recv() => {
                     match event {
                         Ok(session_event) => {
+                            if !event_in_scope(&session_event, project.as_deref(), &mut seen) {
+                                continue;
+                            }
                             let event_name = match &session_event {
                                 SessionEvent::SessionUpsert { .. } => "session_upsert",
                                 SessionEvent::SessionRemove { .. } => "session_remove",
+                                SessionEvent::SessionEnded { .. } => "session_ended",
                             };
                             // No enrichment needed — ownership is a stored field
                             // in the session record. Just serialize directly.
@@ -90,17 +154,12 @@ pub async fn live_stream(
                             // client recovers from any missed events.
                             let map = sessions.read().await;
                             let pc = live_manager.as_ref().map(|m| m.process_count()).unwrap_or(0);
-                            let summary = build_summary(&map, pc);
-                            let active: Vec<&crate::live::state::LiveSession> = map.values().collect();
-                            let closed: Vec<crate::live::state::LiveSession> = {
+                            let closed: Vec<LiveSession> = {
                                 let ring = state.closed_ring.read().await;
                                 ring.iter().cloned().collect()
                             };
-                            let snapshot = serde_json::json!({
-                                "summary": summary,
-                                "sessions": active,
-                                "recentlyClosed": closed,
-                            });
+                            let snapshot =
+                                build_snapshot(&map, &closed, pc, project.as_deref(), &mut seen);
                             match serde_json::to_string(&snapshot) {
                                 Ok(data) => yield Ok(Event::default().event("snapshot").data(data)),
                                 Err(e) => tracing::error!("failed to serialize snapshot: {e}"),
@@ -94,3 +94,81 @@ fn test_build_summary_empty_map() {
     assert_eq!(summary["totalCostTodayUsd"], 0.0);
     assert_eq!(summary["totalTokensToday"], 0);
 }
+
+fn project_session(id: &str, project: &str, last_activity_at: i64) -> LiveSession {
+    let mut s = test_session(id, false);
+    s.jsonl.project = project.to_string();
+    s.hook.last_activity_at = last_activity_at;
+    s
+}
+
+#[test]
+fn test_sessions_for_project_filters_and_sorts() {
+    use crate::live::manager::sessions_for_project;
+
+    let mut map = HashMap::new();
+    for s in [
+        project_session("a-old", "-proj-a", 100),
+        project_session("a-new", "-proj-a", 300),
+        project_session("b-1", "-proj-b", 200),
+    ] {
+        map.insert(s.id.clone(), s);
+    }
+
+    let ids = |project: Option<&str>| -> Vec<String> {
+        sessions_for_project(&map, project)
+            .into_iter()
+            .map(|s| s.id)
+            .collect()
+    };
+    assert_eq!(ids(Some("-proj-a")), vec!["a-new", "a-old"]);
+    assert_eq!(ids(Some("-proj-b")), vec!["b-1"]);
+    assert!(ids(Some("-proj-c")).is_empty());
+    assert_eq!(ids(None), vec!["a-new", "b-1", "a-old"]);
+}
+
+#[test]
+fn test_is_project_session_matches_path_and_git_root() {
+    use crate::live::manager::is_project_session;
+
+    let mut s = test_session("s", false);
+    s.jsonl.project = "-repo-app".to_string();
+    s.jsonl.project_path = "/repo/app".to_string();
+
+    assert!(is_project_session(&s, "-repo-app"));
+    assert!(is_project_session(&s, "/repo/app"));
+    assert!(is_project_session(&s, "/repo"));
+    assert!(is_project_session(&s, "/repo/"));
+    assert!(!is_project_session(&s, "/rep"));
+    assert!(!is_project_session(&s, "/repo/app-2"));
+    assert!(!is_project_session(&s, ""));
+}
+
+#[test]
+fn test_stream_scope_drops_other_projects_events() {
+    use std::collections::HashSet;
+
+    use crate::live::state::{SessionEndReason, SessionEvent};
+
+    use super::sse::event_in_scope;
+
+    let ended = |id: &str| SessionEvent::SessionEnded {
+        session_id: id.to_string(),
+        reason: SessionEndReason::ProcessExited,
+    };
+    let mut seen = HashSet::new();
+    let upsert_a = SessionEvent::SessionUpsert {
+        session: project_session("a-1", "-proj-a", 1),
+    };
+    let upsert_b = SessionEvent::SessionUpsert {
+        session: project_session("b-1", "-proj-b", 1),
+    };
+
+    assert!(event_in_scope(&upsert_a, Some("-proj-a"), &mut seen));
+    assert!(!event_in_scope(&upsert_b, Some("-proj-a"), &mut seen));
+    // `session_ended` carries no project: only ids already sent pass.
+    assert!(event_in_scope(&ended("a-1"), Some("-proj-a"), &mut seen));
+    assert!(!event_in_scope(&ended("b-1"), Some("-proj-a"), &mut seen));
+    // Unscoped streams see everything.
+    assert!(event_in_scope(&upsert_b, None, &mut seen));
+}
@@ -14,6 +14,14 @@ fn default_limit() -> usize {
     20
 }
 
+/// Query parameters narrowing live endpoints to a single project.
+#[derive(Debug, Default, Deserialize, utoipa::IntoParams)]
+pub struct LiveProjectQuery {
+    /// Encoded project id, project path or git root; only that project's
+    /// sessions are returned.
+    pub project: Option<String>,
+}
+
 /// Request body for binding sidecar control to a session.
 #[derive(Deserialize, utoipa::ToSchema)]
 #[serde(rename_all = "camelCase")]
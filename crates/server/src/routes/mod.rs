@@ -77,6 +77,7 @@ use crate::state::AppState;
 /// - GET /api/session/:project_dir/:session_id - Get a specific session (full JSONL parse)
 /// - GET /api/sessions - List all sessions with filter/sort
 /// - GET /api/sessions/:id - Get extended session detail with commits
+/// - POST /api/sessions/:id/refresh - Re-parse one session and rewrite its metrics
 /// - GET /api/indexing/progress - SSE stream of indexing progress
 /// - GET /api/indexing/status - JSON snapshot of indexing progress (polling)
 /// - GET /api/invocables - List all invocables with usage counts
@@ -119,6 +120,7 @@ use crate::state::AppState;
 /// - GET  /api/live/pricing             - Model pricing table
 /// - GET /api/sessions/:id/turns - Per-turn breakdown for a session
 /// - GET /api/search?q=...&scope=...&limit=...&offset=... - Grep session search
+/// - GET /api/search/export?q=...&format=csv|jsonl - All matches for a query as a file
 /// - GET /api/settings - Read current app settings (model, timeout)
 /// - PUT /api/settings - Update app settings (partial, validates model + timeout)
 /// - GET /api/oauth/usage - OAuth usage (reads credentials, fetches from Anthropic API)
@@ -111,6 +111,16 @@ fn default_sort() -> String {
     "recent".to_string()
 }
 
+/// Query parameters for the project branches endpoint.
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct BranchesQuery {
+    /// Drop branches git sync found deleted (gone locally and on every
+    /// remote). Branches that only survive on a remote are kept.
+    #[serde(default)]
+    pub hide_deleted: bool,
+}
+
 /// Response from GET /api/projects/:id/branches
 #[derive(Debug, Clone, Serialize, TS, utoipa::ToSchema)]
 #[cfg_attr(feature = "codegen", ts(export))]
@@ -203,20 +213,32 @@ pub async fn list_project_sessions(
     Ok(Json(SessionsPage { sessions, total }))
 }
 
-/// GET /api/projects/:id/branches - List distinct branches with session counts.
+/// GET /api/projects/:id/branches - List distinct branches with session counts
+/// and last activity, most recently active first.
 #[utoipa::path(get, path = "/api/projects/{id}/branches", tag = "projects",
-    params(("id" = String, Path, description = "Project ID or git root path (URL-encoded)")),
+    params(
+        ("id" = String, Path, description = "Project ID or git root path (URL-encoded)"),
+        BranchesQuery,
+    ),
     responses(
-        (status = 200, description = "Distinct branches with session counts", body = BranchesResponse),
+        (status = 200, description = "Distinct branches with session counts and last activity", body = BranchesResponse),
     )
 )]
 pub async fn list_project_branches(
     State(state): State<Arc<AppState>>,
     Path(project_id): Path<String>,
+    Query(params): Query<BranchesQuery>,
 ) -> ApiResult<Json<BranchesResponse>> {
     // Branches require git_branch column data that isn't derivable from the
     // catalog yet. Kept DB-backed until branch extraction lands in session_stats.
-    let branches = state.db.list_branches_for_project(&project_id).await?;
+    let branches = if params.hide_deleted {
+        state
+            .db
+            .list_active_branches_for_project(&project_id)
+            .await?
+    } else {
+        state.db.list_branches_for_project(&project_id).await?
+    };
     Ok(Json(BranchesResponse { branches }))
 }
 
@@ -321,6 +343,136 @@ mod tests {
         assert_eq!(parsed[1].last_activity_at, Some(1_700_000_000));
     }
 
+    /// Branches come back most recently active first, each carrying its
+    /// latest `last_message_at` — even when an older branch has more sessions.
+    #[tokio::test]
+    async fn list_project_branches_orders_by_last_activity() {
+        let db = Database::new_in_memory().await.unwrap();
+
+        // (session_id, branch, last_message_at). "main" has more sessions
+        // but "feature/new" was touched last.
+        for (idx, (sid, branch, last_ts)) in [
+            ("b1", "main", 1_800_000_000_i64),
+            ("b2", "main", 1_800_000_100_i64),
+            ("b3", "feature/new", 1_800_000_900_i64),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            sqlx::query(
+                r#"INSERT INTO session_stats (
+                       session_id, source_content_hash, source_size,
+                       parser_version, stats_version, indexed_at,
+                       last_message_at,
+                       project_id, file_path, is_compressed, source_mtime,
+                       git_branch
+                   ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+            )
+            .bind(sid)
+            .bind(vec![idx as u8])
+            .bind(1_i64)
+            .bind(1_i64)
+            .bind(1_i64)
+            .bind(1_i64)
+            .bind(last_ts)
+            .bind("proj-recency")
+            .bind(format!("/tmp/proj-recency/{sid}.jsonl"))
+            .bind(0_i64)
+            .bind(last_ts)
+            .bind(branch)
+            .execute(db.pool())
+            .await
+            .unwrap();
+        }
+
+        let app = crate::create_app(db);
+        let (status, body) = do_get(app, "/api/projects/proj-recency/branches").await;
+        assert_eq!(status, StatusCode::OK);
+
+        let parsed: serde_json::Value = serde_json::from_str(&body).unwrap();
+        let branches = parsed["branches"].as_array().unwrap();
+        assert_eq!(branches.len(), 2);
+        assert_eq!(branches[0]["branch"], "feature/new");
+        assert_eq!(branches[0]["count"], 1);
+        assert_eq!(branches[0]["lastActivityAt"], 1_800_000_900_i64);
+        assert_eq!(branches[1]["branch"], "main");
+        assert_eq!(branches[1]["count"], 2);
+        assert_eq!(branches[1]["lastActivityAt"], 1_800_000_100_i64);
+    }
+
+    /// `hideDeleted=true` drops branches gone everywhere but keeps ones that
+    /// only survive on a remote.
+    #[tokio::test]
+    async fn list_project_branches_hide_deleted_keeps_remote_only() {
+        use claude_view_db::git_correlation::BranchStatus;
+
+        let db = Database::new_in_memory().await.unwrap();
+        for (idx, (sid, branch)) in [
+            ("h1", "main"),
+            ("h2", "feature/pushed"),
+            ("h3", "feature/gone"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            sqlx::query(
+                r#"INSERT INTO session_stats (
+                       session_id, source_content_hash, source_size,
+                       parser_version, stats_version, indexed_at,
+                       last_message_at,
+                       project_id, project_path, file_path, is_compressed,
+                       source_mtime, git_branch
+                   ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+            )
+            .bind(sid)
+            .bind(vec![idx as u8])
+            .bind(1_i64)
+            .bind(1_i64)
+            .bind(1_i64)
+            .bind(1_i64)
+            .bind(1_800_000_000_i64 + idx as i64)
+            .bind("proj-hide")
+            .bind("/repo/hide")
+            .bind(format!("/tmp/proj-hide/{sid}.jsonl"))
+            .bind(0_i64)
+            .bind(1_800_000_000_i64)
+            .bind(branch)
+            .execute(db.pool())
+            .await
+            .unwrap();
+        }
+        db.upsert_branch_statuses(
+            "/repo/hide",
+            &[
+                ("main".to_string(), BranchStatus::Active),
+                ("feature/pushed".to_string(), BranchStatus::Remote),
+                ("feature/gone".to_string(), BranchStatus::Deleted),
+            ],
+        )
+        .await
+        .unwrap();
+
+        let app = crate::create_app(db);
+        let names = |body: &str| -> Vec<String> {
+            let parsed: serde_json::Value = serde_json::from_str(body).unwrap();
+            parsed["branches"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|b| b["branch"].as_str().unwrap().to_string())
+                .collect()
+        };
+
+        let (status, body) = do_get(app.clone(), "/api/projects/proj-hide/branches").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(names(&body).len(), 3);
+
+        let (status, body) = do_get(app, "/api/projects/proj-hide/branches?hideDeleted=true").await;
+        assert_eq!(status, StatusCode::OK);
+        let visible = names(&body);
+        assert_eq!(visible, vec!["feature/pushed", "main"]);
+    }
+
     /// Regression: the `branch` query param on the per-project sessions
     /// endpoint must actually filter (it was a declared-but-ignored no-op).
     /// Seeds three sessions in one project — two on named branches, one with
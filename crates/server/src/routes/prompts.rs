@@ -4,10 +4,12 @@ use axum::{
     Json, Router,
 };
 use claude_view_search::prompt_index::PromptSearchParams;
+use claude_view_search::SearchError;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use ts_rs::TS;
 
+use crate::error::{ApiError, ApiResult};
 use crate::state::AppState;
 
 #[derive(Debug, Deserialize, Default, utoipa::IntoParams)]
@@ -68,26 +70,30 @@ pub struct PromptTemplateInfo {
 }
 
 /// GET /api/prompts - List prompt history with optional search/filter.
+///
+/// A malformed query (bad syntax, wildcard or `when:` value) is a 400 with the
+/// parse error.
 #[utoipa::path(get, path = "/api/prompts", tag = "prompts",
     params(PromptsListQuery),
     responses(
         (status = 200, description = "Paginated prompt history", body = crate::routes::prompts::PromptListResponse),
+        (status = 400, description = "Malformed search query"),
     )
 )]
 pub async fn list_prompts(
     State(state): State<Arc<AppState>>,
     Query(params): Query<PromptsListQuery>,
-) -> Json<PromptListResponse> {
+) -> ApiResult<Json<PromptListResponse>> {
     let limit = params.limit.unwrap_or(50).min(200);
     let offset = params.offset.unwrap_or(0);
 
     let index_guard = state.prompt_index.read().unwrap();
     let Some(index) = index_guard.as_ref() else {
-        return Json(PromptListResponse {
+        return Ok(Json(PromptListResponse {
             prompts: vec![],
             total: 0,
             has_more: false,
-        });
+        }));
     };
 
     // Build search query from params — free-text + qualifier tokens
@@ -118,6 +124,7 @@ pub async fn list_prompts(
         time_before: params.time_before,
         sort: params.sort.as_deref(),
         template_match: params.template_match.as_deref(),
+        default_operator: None,
         limit,
         offset,
     };
@@ -163,19 +170,24 @@ pub async fn list_prompts(
                 })
                 .collect();
             let total = result.total_matches;
-            Json(PromptListResponse {
+            Ok(Json(PromptListResponse {
                 has_more: offset + prompts.len() < total,
                 prompts,
                 total,
-            })
+            }))
         }
+        Err(
+            e @ (SearchError::QueryParse(_)
+            | SearchError::InvalidWildcard(_)
+            | SearchError::InvalidDayPart(_)),
+        ) => Err(ApiError::BadRequest(e.to_string())),
         Err(e) => {
             tracing::warn!(error = %e, "prompt search failed");
-            Json(PromptListResponse {
+            Ok(Json(PromptListResponse {
                 prompts: vec![],
                 total: 0,
                 has_more: false,
-            })
+            }))
         }
     }
 }
@@ -266,9 +278,36 @@ mod tests {
             time_before: None,
             sort: None,
             template_match: q.template_match.as_deref(),
+            default_operator: None,
             limit: 20,
             offset: 0,
         };
         assert_eq!(search_params.template_match, Some("template"));
     }
+
+    #[tokio::test]
+    async fn invalid_wildcard_is_a_bad_request() {
+        let db = claude_view_db::Database::new_in_memory().await.unwrap();
+        let state = AppState::new(db);
+        let index = claude_view_search::prompt_index::PromptSearchIndex::open_in_ram().unwrap();
+        *state.prompt_index.write().unwrap() = Some(Arc::new(index));
+
+        let query = |q: &str| PromptsListQuery {
+            q: Some(q.to_string()),
+            ..Default::default()
+        };
+        match list_prompts(State(state.clone()), Query(query("d*"))).await {
+            Err(ApiError::BadRequest(msg)) => assert!(msg.contains("wildcard"), "{msg}"),
+            Err(other) => panic!("expected BadRequest, got {other:?}"),
+            Ok(_) => panic!("bare short wildcard should be rejected"),
+        }
+        match list_prompts(State(state.clone()), Query(query("when:brunch"))).await {
+            Err(ApiError::BadRequest(msg)) => assert!(msg.contains("day-part"), "{msg}"),
+            Err(other) => panic!("expected BadRequest, got {other:?}"),
+            Ok(_) => panic!("unknown day-part should be rejected"),
+        }
+
+        let ok = list_prompts(State(state), Query(query("data*"))).await;
+        assert!(ok.is_ok());
+    }
 }
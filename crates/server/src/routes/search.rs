@@ -1,21 +1,38 @@
-//! Session search endpoint.
+//! Session search endpoints.
 //!
-//! GET /search?q=...&limit=...&offset=...&project=...&branch=...&model=...&after=...&before=...
+//! GET /search?q=...&limit=...&offset=...&project=...&branch=...&model=...&after=...&before=...&fields=...&whole_word=...&dedupe=...&boost=...
+//! GET /search/export?q=...&format=csv|jsonl&project=...&branch=...&model=...&after=...&before=...
+//! GET /search/cards?q=...&limit=...&offset=...&project=...&branch=...&model=...&after=...&before=...&whole_word=...&dedupe=...&boost=...
 //!
-//! Thin wrapper around `search_service::execute_search()`.
+//! Thin wrappers around `search_service::execute_search()`.
 
 use crate::error::{ApiError, ApiResult};
-use crate::search_service::{execute_search, SearchFilters};
+use crate::routes::export::escape_csv_field;
+use crate::search_service::{
+    execute_search, execute_search_cards, SearchCardsResponse, SearchDisplay, SearchFilters,
+};
 use crate::state::AppState;
 use axum::{
+    body::Body,
     extract::{Query, State},
+    http::{header, HeaderMap},
+    response::{IntoResponse, Response},
     routing::get,
     Json, Router,
 };
-use claude_view_search::types::SearchResponse;
-use serde::Deserialize;
+use claude_view_search::types::{MatchHit, SearchResponse};
+use claude_view_search::{RoleBoosts, SearchFields};
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
 use std::sync::Arc;
 
+/// Most sessions an export will search. Grep itself stops at 100k matching
+/// lines, so this mostly bounds the session grouping work.
+const MAX_EXPORT_SESSIONS: usize = 10_000;
+
+/// Most rows an export will emit, whatever the match count.
+const MAX_EXPORT_ROWS: usize = 50_000;
+
 #[derive(Debug, Deserialize, Default, utoipa::IntoParams)]
 #[serde(default)]
 pub struct SearchQuery {
@@ -27,24 +44,89 @@ pub struct SearchQuery {
     pub model: Option<String>,
     pub after: Option<String>,
     pub before: Option<String>,
+    /// Comma-separated `SessionHit` / `MatchHit` fields to return, e.g.
+    /// `sessionId,matchCount`. Omitted returns every field.
+    pub fields: Option<String>,
+    /// Only highlight whole-word occurrences of the query in snippets.
+    pub whole_word: Option<bool>,
+    /// Collapse near-identical snippets within a session's matches.
+    pub dedupe: Option<bool>,
+    /// Per-role score multipliers, e.g. `user:2,tool:0.5` (roles: user,
+    /// assistant, tool; unlisted roles score 1). Sessions rank by their
+    /// best-scoring match.
+    pub boost: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default, utoipa::IntoParams)]
+#[serde(default)]
+pub struct SearchCardsQuery {
+    pub q: Option<String>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+    pub project: Option<String>,
+    pub branch: Option<String>,
+    pub model: Option<String>,
+    pub after: Option<String>,
+    pub before: Option<String>,
+    /// Only highlight whole-word occurrences of the query in snippets.
+    pub whole_word: Option<bool>,
+    /// Collapse near-identical snippets within a session's matches.
+    pub dedupe: Option<bool>,
+    /// Per-role score multipliers, e.g. `user:2,tool:0.5` (roles: user,
+    /// assistant, tool; unlisted roles score 1). Sessions rank by their
+    /// best-scoring match.
+    pub boost: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default, utoipa::IntoParams)]
+#[serde(default)]
+pub struct SearchExportQuery {
+    pub q: Option<String>,
+    /// "csv" or "jsonl". Falls back to the `Accept` header, then JSONL.
+    pub format: Option<String>,
+    pub project: Option<String>,
+    pub branch: Option<String>,
+    pub model: Option<String>,
+    pub after: Option<String>,
+    pub before: Option<String>,
+}
+
+/// One exported search match.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SearchExportRow<'a> {
+    session_id: &'a str,
+    turn_number: u64,
+    role: &'a str,
+    snippet: &'a str,
+    timestamp: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExportFormat {
+    Csv,
+    Jsonl,
 }
 
 pub fn router() -> Router<Arc<AppState>> {
-    Router::new().route("/search", get(search_handler))
+    Router::new()
+        .route("/search", get(search_handler))
+        .route("/search/export", get(search_export_handler))
+        .route("/search/cards", get(search_cards_handler))
 }
 
 /// GET /api/search - Search raw session JSONL files with grep.
 #[utoipa::path(get, path = "/api/search", tag = "search",
     params(SearchQuery),
     responses(
-        (status = 200, description = "Search results", body = serde_json::Value),
-        (status = 400, description = "Missing or empty query"),
+        (status = 200, description = "Search results; only the requested keys when `fields` is set", body = SearchResponse),
+        (status = 400, description = "Missing or empty query, unknown field, or invalid boost"),
     )
 )]
 pub async fn search_handler(
     State(state): State<Arc<AppState>>,
     Query(query): Query<SearchQuery>,
-) -> ApiResult<Json<SearchResponse>> {
+) -> ApiResult<Response> {
     let q = query.q.as_deref().unwrap_or("").trim();
     if q.is_empty() {
         return Err(ApiError::BadRequest(
@@ -52,6 +134,13 @@ pub async fn search_handler(
         ));
     }
 
+    let fields = query
+        .fields
+        .as_deref()
+        .map(SearchFields::parse)
+        .transpose()
+        .map_err(ApiError::BadRequest)?;
+
     let limit = query.limit.unwrap_or(20);
     let offset = query.offset.unwrap_or(0);
 
@@ -63,6 +152,354 @@ pub async fn search_handler(
         before: query.before,
     };
 
-    let response = execute_search(&state, q, &filters, limit, offset, false).await?;
-    Ok(Json(response))
+    let display = SearchDisplay {
+        fields: fields.clone(),
+        ..display_options(query.whole_word, query.dedupe, query.boost.as_deref())?
+    };
+    let response = execute_search(&state, q, &filters, limit, offset, display).await?;
+    // Only a field projection needs the untyped form.
+    Ok(match fields {
+        Some(fields) => Json(fields.apply(&response)).into_response(),
+        None => Json(response).into_response(),
+    })
+}
+
+/// GET /api/search/cards - Search, with each hit merged with its session.
+///
+/// Same search as `/api/search`, but every hit also carries the session's
+/// indexed `SessionInfo` (`session`, `null` if not indexed yet), so list
+/// views need no second request.
+#[utoipa::path(get, path = "/api/search/cards", tag = "search",
+    params(SearchCardsQuery),
+    responses(
+        (status = 200, description = "Search hits hydrated with session info", body = SearchCardsResponse),
+        (status = 400, description = "Missing or empty query, or invalid boost"),
+    )
+)]
+pub async fn search_cards_handler(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<SearchCardsQuery>,
+) -> ApiResult<Json<SearchCardsResponse>> {
+    let q = query.q.as_deref().unwrap_or("").trim();
+    if q.is_empty() {
+        return Err(ApiError::BadRequest(
+            "query parameter 'q' is required".to_string(),
+        ));
+    }
+
+    let filters = SearchFilters {
+        project: query.project,
+        branch: query.branch,
+        model: query.model,
+        after: query.after,
+        before: query.before,
+    };
+    let display = display_options(query.whole_word, query.dedupe, query.boost.as_deref())?;
+    let limit = query.limit.unwrap_or(20);
+    let offset = query.offset.unwrap_or(0);
+    Ok(Json(
+        execute_search_cards(&state, q, &filters, limit, offset, display).await?,
+    ))
+}
+
+/// Snippet and ranking options from the `whole_word`, `dedupe` and `boost`
+/// query params.
+fn display_options(
+    whole_word: Option<bool>,
+    dedupe: Option<bool>,
+    boost: Option<&str>,
+) -> ApiResult<SearchDisplay> {
+    let role_boosts = boost
+        .map(RoleBoosts::parse)
+        .transpose()
+        .map_err(ApiError::BadRequest)?;
+    Ok(SearchDisplay {
+        whole_word_highlight: whole_word.unwrap_or(false),
+        dedupe_snippets: dedupe.unwrap_or(false),
+        role_boosts,
+        ..Default::default()
+    })
+}
+
+/// GET /api/search/export - Dump every match for a query as CSV or JSONL.
+///
+/// Unlike `/api/search` this is not paginated: it searches up to 10,000
+/// sessions and emits at most 50,000 rows (`sessionId`, `turnNumber`,
+/// `role`, `snippet`, `timestamp`), one per match, newest session first.
+#[utoipa::path(get, path = "/api/search/export", tag = "search",
+    params(SearchExportQuery),
+    responses(
+        (status = 200, description = "Matches as CSV or JSONL", content_type = "text/csv"),
+        (status = 400, description = "Missing query or unknown format"),
+    )
+)]
+pub async fn search_export_handler(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Query(query): Query<SearchExportQuery>,
+) -> ApiResult<Response> {
+    let q = query.q.as_deref().unwrap_or("").trim();
+    if q.is_empty() {
+        return Err(ApiError::BadRequest(
+            "query parameter 'q' is required".to_string(),
+        ));
+    }
+    let accept = headers.get(header::ACCEPT).and_then(|v| v.to_str().ok());
+    let format = export_format(query.format.as_deref(), accept)?;
+
+    let filters = SearchFilters {
+        project: query.project,
+        branch: query.branch,
+        model: query.model,
+        after: query.after,
+        before: query.before,
+    };
+    // Neutral boosts report each match's role without changing the order.
+    let display = SearchDisplay {
+        role_boosts: Some(RoleBoosts::default()),
+        ..Default::default()
+    };
+    let response = execute_search(&state, q, &filters, MAX_EXPORT_SESSIONS, 0, display).await?;
+    Ok(export_response(response, format, MAX_EXPORT_ROWS))
+}
+
+/// `format` wins over `Accept`; neither set means JSONL.
+fn export_format(format: Option<&str>, accept: Option<&str>) -> ApiResult<ExportFormat> {
+    match format.map(str::trim) {
+        Some("csv") => return Ok(ExportFormat::Csv),
+        Some("jsonl") => return Ok(ExportFormat::Jsonl),
+        Some(other) if !other.is_empty() => {
+            return Err(ApiError::BadRequest(format!(
+                "Invalid format '{other}'. Valid options: csv, jsonl"
+            )))
+        }
+        _ => {}
+    }
+    if accept.is_some_and(|a| a.contains("text/csv")) {
+        Ok(ExportFormat::Csv)
+    } else {
+        Ok(ExportFormat::Jsonl)
+    }
+}
+
+/// Render one line per match, capped at `max_rows`, as a streamed body.
+/// Lines are formatted as the body is polled, not buffered up front.
+fn export_response(response: SearchResponse, format: ExportFormat, max_rows: usize) -> Response {
+    let (content_type, filename, header_line) = match format {
+        ExportFormat::Csv => (
+            "text/csv; charset=utf-8",
+            "search-export.csv",
+            Some("sessionId,turnNumber,role,snippet,timestamp\n".to_string()),
+        ),
+        ExportFormat::Jsonl => ("application/x-ndjson", "search-export.jsonl", None),
+    };
+    let rows = response
+        .sessions
+        .into_iter()
+        .flat_map(move |s| {
+            // Grep hits without snippets only carry `top_match`.
+            let matches = if s.matches.is_empty() {
+                vec![s.top_match]
+            } else {
+                s.matches
+            };
+            let session_id = s.session_id;
+            matches
+                .into_iter()
+                .map(move |m| export_line(format, &session_id, &m))
+        })
+        .take(max_rows);
+
+    let body = Body::from_stream(futures_util::stream::iter(
+        header_line.into_iter().chain(rows).map(Ok::<_, Infallible>),
+    ));
+    (
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{filename}\""),
+            ),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+/// One export line for match `m`, with the snippet's `<mark>` tags removed.
+fn export_line(format: ExportFormat, session_id: &str, m: &MatchHit) -> String {
+    let snippet = m.snippet.replace("<mark>", "").replace("</mark>", "");
+    let row = SearchExportRow {
+        session_id,
+        turn_number: m.turn_number,
+        role: &m.role,
+        snippet: &snippet,
+        timestamp: m.timestamp,
+    };
+    match format {
+        ExportFormat::Csv => format!(
+            "{},{},{},{},{}\n",
+            escape_csv_field(row.session_id),
+            row.turn_number,
+            escape_csv_field(row.role),
+            escape_csv_field(row.snippet),
+            row.timestamp,
+        ),
+        ExportFormat::Jsonl => {
+            let mut line = serde_json::to_string(&row).unwrap_or_default();
+            line.push('\n');
+            line
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::{Request, StatusCode};
+    use claude_view_db::Database;
+    use claude_view_search::{unified_search, JsonlFile, UnifiedSearchOptions};
+    use tower::ServiceExt;
+
+    async fn body_string(response: Response) -> String {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    fn search_fixture(dir: &std::path::Path) -> SearchResponse {
+        let path = dir.join("sess-export.jsonl");
+        std::fs::write(
+            &path,
+            concat!(
+                r#"{"type":"user","message":{"role":"user","content":"fix the flaky websocket, reconnect"}}"#,
+                "\n",
+                r#"{"type":"assistant","message":{"role":"assistant","content":[{"type":"text","text":"The websocket reconnect loop is fixed"}]}}"#,
+                "\n",
+                r#"{"type":"user","message":{"role":"user","content":"unrelated"}}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+        let files = vec![JsonlFile {
+            path,
+            session_id: "sess-export".to_string(),
+            project: "proj".to_string(),
+            project_path: dir.to_string_lossy().to_string(),
+            modified_at: 1_700_000_000,
+            is_sidechain: false,
+        }];
+        let opts = UnifiedSearchOptions {
+            query: "websocket".to_string(),
+            scope: None,
+            limit: MAX_EXPORT_SESSIONS,
+            offset: 0,
+            skip_snippets: false,
+            whole_word_highlight: false,
+            dedupe_snippets: false,
+            fields: None,
+            role_boosts: Some(RoleBoosts::default()),
+        };
+        unified_search(&files, &opts).unwrap().response
+    }
+
+    #[tokio::test]
+    async fn export_jsonl_contains_every_match() {
+        let tmp = tempfile::tempdir().unwrap();
+        let response = search_fixture(tmp.path());
+
+        let body = body_string(export_response(
+            response,
+            ExportFormat::Jsonl,
+            MAX_EXPORT_ROWS,
+        ))
+        .await;
+        let rows: Vec<serde_json::Value> = body
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(rows.len(), 2);
+        assert!(rows.iter().all(|r| r["sessionId"] == "sess-export"));
+        assert_eq!(rows[0]["role"], "user");
+        assert_eq!(rows[1]["role"], "assistant");
+        assert!(rows[1]["snippet"]
+            .as_str()
+            .unwrap()
+            .contains("reconnect loop"));
+    }
+
+    #[tokio::test]
+    async fn export_csv_has_header_and_respects_row_cap() {
+        let tmp = tempfile::tempdir().unwrap();
+        let response = search_fixture(tmp.path());
+
+        let body = body_string(export_response(response, ExportFormat::Csv, 1)).await;
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "sessionId,turnNumber,role,snippet,timestamp");
+        assert!(lines[1].starts_with("sess-export,"));
+        // The snippet contains a comma, so it is quoted; markup is stripped.
+        assert!(lines[1].contains("websocket, reconnect\""));
+        assert!(!lines[1].contains("<mark>"));
+    }
+
+    #[test]
+    fn export_format_prefers_param_then_accept() {
+        assert_eq!(
+            export_format(Some("csv"), Some("application/x-ndjson")).unwrap(),
+            ExportFormat::Csv
+        );
+        assert_eq!(
+            export_format(None, Some("text/csv")).unwrap(),
+            ExportFormat::Csv
+        );
+        assert_eq!(export_format(None, None).unwrap(), ExportFormat::Jsonl);
+        assert!(export_format(Some("xml"), None).is_err());
+    }
+
+    #[tokio::test]
+    async fn cards_route_rejects_missing_query() {
+        let db = Database::new_in_memory().await.unwrap();
+        let app = crate::create_app(db);
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/search/cards?q=%20")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn display_options_parse_boosts() {
+        let display = display_options(Some(true), None, Some("user:2")).unwrap();
+        assert!(display.whole_word_highlight);
+        assert!(!display.dedupe_snippets);
+        assert_eq!(display.role_boosts.unwrap().user, 2.0);
+        assert!(display_options(None, None, None)
+            .unwrap()
+            .role_boosts
+            .is_none());
+        assert!(display_options(None, None, Some("admin:2")).is_err());
+    }
+
+    #[tokio::test]
+    async fn export_route_rejects_missing_query() {
+        let db = Database::new_in_memory().await.unwrap();
+        let app = crate::create_app(db);
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/search/export?format=csv")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
 }
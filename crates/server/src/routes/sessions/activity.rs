@@ -8,7 +8,9 @@ use axum::Json;
 use crate::error::ApiResult;
 use crate::state::AppState;
 
-use super::types::{RichActivityParams, SessionActivityResponse, SparklineActivityParams};
+use super::types::{
+    RichActivityParams, SessionActivityResponse, SparklineActivityParams, TokenHistogramParams,
+};
 
 /// GET /api/branches - Get distinct list of branch names across all sessions.
 ///
@@ -174,3 +176,50 @@ pub async fn session_activity_rich(
         .await?;
     Ok(Json(result))
 }
+
+/// GET /api/sessions/activity/token-histogram — Sessions bucketed by total tokens.
+///
+/// Every bucket is returned, empty ones with `sessions = 0`.
+#[utoipa::path(get, path = "/api/sessions/activity/token-histogram", tag = "sessions",
+    params(
+        ("time_after" = Option<i64>, Query, description = "Unix timestamp lower bound"),
+        ("time_before" = Option<i64>, Query, description = "Unix timestamp upper bound"),
+        ("project" = Option<String>, Query, description = "Filter by project id, git root or project path"),
+    ),
+    responses(
+        (status = 200, description = "Token histogram buckets", body = Vec<claude_view_db::TokenHistogramBucket>),
+    )
+)]
+pub async fn session_token_histogram(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<TokenHistogramParams>,
+) -> ApiResult<Json<Vec<claude_view_db::TokenHistogramBucket>>> {
+    let buckets = state
+        .db
+        .session_token_histogram(
+            params.project.as_deref(),
+            params.time_after,
+            params.time_before,
+        )
+        .await?;
+    Ok(Json(buckets))
+}
+
+/// GET /api/sessions/activity/streak — Current and longest runs of active days.
+///
+/// Days are bucketed in the server's local timezone.
+#[utoipa::path(get, path = "/api/sessions/activity/streak", tag = "sessions",
+    responses(
+        (status = 200, description = "Activity streak", body = claude_view_db::ActivityStreak),
+    )
+)]
+pub async fn session_activity_streak(
+    State(state): State<Arc<AppState>>,
+) -> ApiResult<Json<claude_view_db::ActivityStreak>> {
+    let now = chrono::Local::now();
+    let streak = state
+        .db
+        .activity_streak(now.timestamp(), now.offset().local_minus_utc() as i64)
+        .await?;
+    Ok(Json(streak))
+}
@@ -86,6 +86,10 @@ pub async fn get_session_detail(
     let commits: Vec<CommitWithTier> = commits_raw.into_iter().map(Into::into).collect();
 
     let derived_metrics = DerivedMetrics::from(&info);
+    let model_cost = state.db.session_model_cost(&session_id).await?;
+    let interruption_count = state.db.get_session_interruption_count(&session_id).await?;
+    let bash_categories = state.db.get_session_bash_categories(&session_id).await?;
+    let invocation_args = state.db.get_session_invocation_args(&session_id).await?;
 
     // 5. Task / plan sidecar files (+ inline JSONL todos below).
     let mut warnings: Vec<String> = Vec::new();
@@ -128,6 +132,10 @@ pub async fn get_session_detail(
         tasks,
         todos,
         has_plans,
+        model_cost,
+        interruption_count,
+        bash_categories,
+        invocation_args,
         warnings,
     }))
 }
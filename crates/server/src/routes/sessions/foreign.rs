@@ -250,6 +250,10 @@ pub async fn foreign_detail(
         tasks: Vec::new(),
         todos: Vec::new(),
         has_plans: false,
+        model_cost: None,
+        interruption_count: 0,
+        bash_categories: Default::default(),
+        invocation_args: Default::default(),
         warnings: Vec::new(),
     }))
 }
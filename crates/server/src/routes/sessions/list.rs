@@ -129,7 +129,10 @@ pub async fn list_sessions(
             &search_filters,
             usize::MAX,
             0,
-            true,
+            crate::search_service::SearchDisplay {
+                skip_snippets: true,
+                ..Default::default()
+            },
         )
         .await
         {
@@ -9,6 +9,10 @@ pub(crate) mod foreign;
 pub(crate) mod helpers;
 mod list;
 mod messages;
+mod models;
+mod refresh;
+mod related;
+mod transcript;
 mod types;
 
 // Test modules (split by concern to stay under 600 lines each)
@@ -30,9 +34,10 @@ mod tests_subagent;
 // Types
 pub use types::{
     ArchiveResponse, BulkArchiveRequest, BulkArchiveResponse, CommitWithTier, CostEstimate,
-    DerivedMetrics, EstimateRequest, PaginatedBlocks, RichActivityParams, SessionActivityResponse,
-    SessionDetail, SessionMessagesQuery, SessionsListQuery, SessionsListResponse,
-    SparklineActivityParams,
+    DerivedMetrics, EstimateRequest, PaginatedBlocks, RefreshResponse, RelatedSessionsQuery,
+    RichActivityParams, SessionActivityResponse, SessionDetail, SessionMessagesQuery,
+    SessionsListQuery, SessionsListResponse, SparklineActivityParams, TranscriptExport,
+    TranscriptExportQuery, TranscriptMetadata, TranscriptTurn,
 };
 
 // Helpers
@@ -41,6 +46,7 @@ pub(crate) use helpers::resolve_session_file_path;
 // Handlers
 pub use activity::{
     get_session_hook_events, list_branches, session_activity, session_activity_rich,
+    session_activity_streak, session_token_histogram,
 };
 pub use archive::{
     archive_session_handler, bulk_archive_handler, bulk_unarchive_handler,
@@ -52,6 +58,10 @@ pub use list::list_sessions;
 pub use messages::{
     get_session_messages_by_id, get_session_parsed, get_session_rich, get_subagent_messages,
 };
+pub use models::get_session_models;
+pub use refresh::refresh_session_handler;
+pub use related::get_related_sessions;
+pub use transcript::export_session_transcript;
 
 // utoipa __path_* re-exports (generated by #[utoipa::path] in submodules)
 #[allow(unused_imports)]
@@ -63,6 +73,10 @@ pub use activity::__path_session_activity;
 #[allow(unused_imports)]
 pub use activity::__path_session_activity_rich;
 #[allow(unused_imports)]
+pub use activity::__path_session_activity_streak;
+#[allow(unused_imports)]
+pub use activity::__path_session_token_histogram;
+#[allow(unused_imports)]
 pub use archive::__path_archive_session_handler;
 #[allow(unused_imports)]
 pub use archive::__path_bulk_archive_handler;
@@ -84,6 +98,14 @@ pub use messages::__path_get_session_parsed;
 pub use messages::__path_get_session_rich;
 #[allow(unused_imports)]
 pub use messages::__path_get_subagent_messages;
+#[allow(unused_imports)]
+pub use models::__path_get_session_models;
+#[allow(unused_imports)]
+pub use refresh::__path_refresh_session_handler;
+#[allow(unused_imports)]
+pub use related::__path_get_related_sessions;
+#[allow(unused_imports)]
+pub use transcript::__path_export_session_transcript;
 
 // Router
 use std::sync::Arc;
@@ -100,6 +122,11 @@ pub fn router() -> Router<Arc<AppState>> {
         .route("/estimate", post(estimate_cost))
         .route("/sessions/activity", get(session_activity))
         .route("/sessions/activity/rich", get(session_activity_rich))
+        .route("/sessions/activity/streak", get(session_activity_streak))
+        .route(
+            "/sessions/activity/token-histogram",
+            get(session_token_histogram),
+        )
         .route("/sessions/archive", post(bulk_archive_handler))
         .route("/sessions/unarchive", post(bulk_unarchive_handler))
         .route("/sessions/{id}", get(get_session_detail))
@@ -110,8 +137,12 @@ pub fn router() -> Router<Arc<AppState>> {
             get(get_subagent_messages),
         )
         .route("/sessions/{id}/rich", get(get_session_rich))
+        .route("/sessions/{id}/related", get(get_related_sessions))
+        .route("/sessions/{id}/models", get(get_session_models))
+        .route("/sessions/{id}/export", get(export_session_transcript))
         .route("/sessions/{id}/hook-events", get(get_session_hook_events))
         .route("/sessions/{id}/archive", post(archive_session_handler))
         .route("/sessions/{id}/unarchive", post(unarchive_session_handler))
+        .route("/sessions/{id}/refresh", post(refresh_session_handler))
         .route("/branches", get(list_branches))
 }
@@ -0,0 +1,26 @@
+//! GET /api/sessions/:id/models — per-session model usage.
+
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::Json;
+use claude_view_db::ModelTurnCount;
+
+use crate::error::ApiResult;
+use crate::state::AppState;
+
+/// Models used in a session with their assistant turn counts, most-used
+/// first. More than one entry means the session switched models mid-way.
+#[utoipa::path(get, path = "/api/sessions/{id}/models", tag = "sessions",
+    params(("id" = String, Path, description = "Session ID")),
+    responses(
+        (status = 200, description = "Distinct models with turn counts (empty if unknown)", body = Vec<claude_view_db::ModelTurnCount>),
+    )
+)]
+pub async fn get_session_models(
+    State(state): State<Arc<AppState>>,
+    Path(session_id): Path<String>,
+) -> ApiResult<Json<Vec<ModelTurnCount>>> {
+    let models = state.db.models_used_in_session(&session_id).await?;
+    Ok(Json(models))
+}
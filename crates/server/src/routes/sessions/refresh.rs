@@ -0,0 +1,38 @@
+//! Single-session re-index handler.
+
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::Json;
+
+use crate::error::{ApiError, ApiResult};
+use crate::state::AppState;
+
+use super::types::RefreshResponse;
+
+/// Re-parse one session's JSONL and rewrite its stored metrics, e.g. after a
+/// parser fix, without waiting for a full re-index.
+#[utoipa::path(post, path = "/api/sessions/{id}/refresh", tag = "sessions",
+    params(("id" = String, Path, description = "Session ID")),
+    responses(
+        (status = 200, description = "Session metrics recomputed", body = RefreshResponse),
+        (status = 404, description = "Session not found"),
+    )
+)]
+pub async fn refresh_session_handler(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<RefreshResponse>> {
+    let refreshed = state
+        .db
+        .recompute_session_aggregates(&id)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to refresh session {id}: {e}");
+            ApiError::Internal(format!("refresh failed: {e}"))
+        })?;
+    if !refreshed {
+        return Err(ApiError::NotFound(format!("Session {id} not found")));
+    }
+    Ok(Json(RefreshResponse { refreshed }))
+}
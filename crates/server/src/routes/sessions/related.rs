@@ -0,0 +1,67 @@
+//! Related sessions endpoint.
+
+use std::sync::Arc;
+
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use claude_view_search::types::SessionHit;
+use claude_view_search::{related_sessions, JsonlFile};
+
+use crate::error::{ApiError, ApiResult};
+use crate::routes::grep::collect_jsonl_files;
+use crate::state::AppState;
+
+use super::helpers::resolve_session_file_path;
+use super::types::RelatedSessionsQuery;
+
+/// Most recent sessions scored against the source.
+const RELATED_CANDIDATE_LIMIT: i64 = 500;
+
+/// GET /api/sessions/:id/related — Sessions that share content with this one.
+///
+/// Scores the 500 most recently active other sessions by IDF-weighted
+/// overlap with the source session's top terms (see
+/// `claude_view_search::related`). `bestScore` is the similarity score;
+/// `topMatch.snippet` lists the shared terms.
+#[utoipa::path(get, path = "/api/sessions/{id}/related", tag = "sessions",
+    params(
+        ("id" = String, Path, description = "Session ID"),
+        RelatedSessionsQuery,
+    ),
+    responses(
+        (status = 200, description = "Related sessions, most similar first", body = serde_json::Value),
+        (status = 404, description = "Session not found"),
+    )
+)]
+pub async fn get_related_sessions(
+    State(state): State<Arc<AppState>>,
+    Path(session_id): Path<String>,
+    Query(query): Query<RelatedSessionsQuery>,
+) -> ApiResult<Json<Vec<SessionHit>>> {
+    let path = resolve_session_file_path(&state, &session_id).await?;
+    let limit = query.limit.unwrap_or(10);
+    // Scoring reads every candidate's JSONL, so only the most recent
+    // indexed sessions (in the project, if given) are considered.
+    let candidate_ids = state
+        .db
+        .recent_session_ids(query.project.as_deref(), RELATED_CANDIDATE_LIMIT)
+        .await?;
+    let known = state.db.search_file_meta().await?;
+
+    let hits = tokio::task::spawn_blocking(move || {
+        let candidates = collect_jsonl_files(None, Some(&candidate_ids), &known)?;
+        let source = JsonlFile {
+            path,
+            session_id,
+            project: String::new(),
+            project_path: String::new(),
+            modified_at: 0,
+            is_sidechain: false,
+        };
+        Ok::<_, ApiError>(related_sessions(&source, &candidates, limit))
+    })
+    .await
+    .map_err(|e| ApiError::Internal(format!("Related sessions join: {e}")))??;
+
+    Ok(Json(hits))
+}
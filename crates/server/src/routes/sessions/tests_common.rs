@@ -347,6 +347,7 @@ fn test_parsed_session_serialization() {
             total_messages: 2,
             tool_call_count: 0,
         },
+        attachments: Vec::new(),
     };
 
     let json = serde_json::to_string(&session).unwrap();
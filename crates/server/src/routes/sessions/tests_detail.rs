@@ -1,4 +1,4 @@
-//! Tests for GET /api/sessions/:id (detail), GET /api/branches, GET /api/sessions/activity.
+//! Tests for GET /api/sessions/:id (detail), GET /api/branches, GET /api/sessions/activity/*.
 
 #![cfg(test)]
 
@@ -27,6 +27,7 @@ async fn test_get_session_detail() {
     // reedit_rate comes from DB enrichment (files_edited_count=5, reedited_files_count=2 → 0.4).
     assert!(json["derivedMetrics"]["reeditRate"].is_number());
     assert_eq!(json["derivedMetrics"]["reeditRate"], 0.4);
+    assert!(json["modelCost"]["cost"]["totalUsd"].is_number());
 }
 
 #[tokio::test]
@@ -118,6 +119,47 @@ async fn test_session_activity() {
     assert!(activity[0]["count"].is_number());
 }
 
+#[tokio::test]
+async fn test_session_activity_streak() {
+    let db = test_db().await;
+    let session = make_session("sess-streak", "project-a", 1700000000);
+    db.insert_session(&session, "project-a", "Project A")
+        .await
+        .unwrap();
+
+    let app = build_app(db);
+    let (status, body) = do_get(app, "/api/sessions/activity/streak").await;
+
+    assert_eq!(status, StatusCode::OK);
+    let streak: serde_json::Value = serde_json::from_str(&body).unwrap();
+    // A single session in 2023 is a one-day run that has long since ended.
+    assert_eq!(streak["longestDays"], 1);
+    assert_eq!(streak["currentDays"], 0);
+}
+
+#[tokio::test]
+async fn test_session_token_histogram() {
+    let db = test_db().await;
+    let session = make_session("sess-tokens", "project-a", 1700000000);
+    db.insert_session(&session, "project-a", "Project A")
+        .await
+        .unwrap();
+
+    let app = build_app(db);
+    let (status, body) = do_get(app, "/api/sessions/activity/token-histogram").await;
+
+    assert_eq!(status, StatusCode::OK);
+    let buckets: serde_json::Value = serde_json::from_str(&body).unwrap();
+    let buckets = buckets.as_array().unwrap();
+    assert_eq!(buckets[0]["minTokens"], 0);
+    assert!(buckets.last().unwrap()["maxTokens"].is_null());
+    let total: i64 = buckets
+        .iter()
+        .map(|b| b["sessions"].as_i64().unwrap())
+        .sum();
+    assert_eq!(total, 1);
+}
+
 // ========================================================================
 // GET /api/sessions/:id/parsed tests
 // ========================================================================
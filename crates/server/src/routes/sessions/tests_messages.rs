@@ -340,3 +340,60 @@ async fn test_db_path_takes_precedence_over_live_session() {
         panic!("Expected a user block from DB file");
     }
 }
+
+// ========================================================================
+// GET /api/sessions/:id/export tests
+// ========================================================================
+
+#[tokio::test]
+async fn test_export_transcript_not_found() {
+    let db = test_db().await;
+    let app = build_app(db);
+    let (status, _) = do_get(app, "/api/sessions/nonexistent/export?format=md").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_export_transcript_markdown_and_json() {
+    let db = test_db().await;
+    let tmp = tempfile::tempdir().unwrap();
+    let session_file = tmp.path().join("export-me.jsonl");
+    std::fs::write(
+        &session_file,
+        concat!(
+            r#"{"type":"user","uuid":"u1","message":{"content":"How do I export a session?"},"timestamp":"2026-01-01T00:00:00Z"}"#,
+            "\n",
+            r#"{"type":"assistant","uuid":"a1","message":{"content":[{"type":"text","text":"Use the export endpoint."}]},"timestamp":"2026-01-01T00:00:05Z"}"#,
+            "\n",
+        ),
+    )
+    .unwrap();
+
+    let mut session = make_session("export-me", "proj", 1700000000);
+    session.file_path = session_file.to_str().unwrap().to_string();
+    db.insert_session(&session, "proj", "Project")
+        .await
+        .unwrap();
+
+    let app = build_app(db);
+    let (status, md) = do_get(app.clone(), "/api/sessions/export-me/export?format=md").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(md.starts_with("# Session export-me"), "header: {md}");
+    assert!(md.contains("## User"), "user heading: {md}");
+    assert!(md.contains("How do I export a session?"));
+    assert!(md.contains("## Assistant"), "assistant heading: {md}");
+    assert!(md.contains("Use the export endpoint."));
+
+    let (status, body) = do_get(app.clone(), "/api/sessions/export-me/export").await;
+    assert_eq!(status, StatusCode::OK);
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["metadata"]["sessionId"], "export-me");
+    assert_eq!(json["metadata"]["startedAt"], "2026-01-01T00:00:00Z");
+    let turns = json["turns"].as_array().unwrap();
+    assert_eq!(turns.len(), 2);
+    assert_eq!(turns[0]["role"], "user");
+    assert!(turns[0].get("uuid").is_none(), "internal fields stripped");
+
+    let (status, _) = do_get(app, "/api/sessions/export-me/export?format=pdf").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,165 @@
+//! Full-transcript export: GET /api/sessions/:id/export?format=json|md.
+
+use std::sync::Arc;
+
+use axum::extract::{Path, Query, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use claude_view_core::{Message, ParsedSession, Role};
+
+use crate::error::{ApiError, ApiResult};
+use crate::state::AppState;
+
+use super::helpers::resolve_session_file_path;
+use super::types::{TranscriptExport, TranscriptExportQuery, TranscriptMetadata, TranscriptTurn};
+
+/// GET /api/sessions/:id/export — Export the full conversation transcript.
+///
+/// Query parameters:
+/// - format: "json" (default) for structured turns, or "md" for Markdown
+///
+/// System and progress events are dropped; only user/assistant/tool turns
+/// are exported.
+#[utoipa::path(get, path = "/api/sessions/{id}/export", tag = "sessions",
+    params(
+        ("id" = String, Path, description = "Session ID"),
+        TranscriptExportQuery,
+    ),
+    responses(
+        (status = 200, description = "Session transcript (JSON or Markdown)", body = TranscriptExport),
+        (status = 400, description = "Invalid format"),
+        (status = 404, description = "Session not found"),
+    )
+)]
+pub async fn export_session_transcript(
+    State(state): State<Arc<AppState>>,
+    Path(session_id): Path<String>,
+    Query(query): Query<TranscriptExportQuery>,
+) -> ApiResult<Response> {
+    let format = query.format.unwrap_or_else(|| "json".to_string());
+    if format != "json" && format != "md" {
+        return Err(ApiError::BadRequest(format!(
+            "Invalid format '{}'. Valid options: json, md",
+            format
+        )));
+    }
+
+    let path = resolve_session_file_path(&state, &session_id).await?;
+    let parsed = claude_view_core::parse_session(&path).await?;
+    let project = state
+        .session_catalog_adapter
+        .get(&session_id)
+        .await
+        .map(|row| row.project_id);
+    let export = build_transcript(&session_id, project, &parsed);
+
+    match format.as_str() {
+        "md" => {
+            let disposition = format!("attachment; filename=\"{}.md\"", session_id);
+            Ok((
+                [
+                    (
+                        header::CONTENT_TYPE,
+                        "text/markdown; charset=utf-8".to_string(),
+                    ),
+                    (header::CONTENT_DISPOSITION, disposition),
+                ],
+                render_markdown(&export),
+            )
+                .into_response())
+        }
+        _ => Ok(Json(export).into_response()),
+    }
+}
+
+/// Convert a parsed session into exportable turns plus a metadata header.
+pub(crate) fn build_transcript(
+    session_id: &str,
+    project: Option<String>,
+    parsed: &ParsedSession,
+) -> TranscriptExport {
+    let turns: Vec<TranscriptTurn> = parsed
+        .messages
+        .iter()
+        .filter(|m| !matches!(m.role, Role::System | Role::Progress))
+        .map(to_turn)
+        .collect();
+
+    let tool_call_count = turns.iter().map(|t| t.tools.len()).sum();
+    let started_at = turns.iter().find_map(|t| t.timestamp.clone());
+    let ended_at = turns.iter().rev().find_map(|t| t.timestamp.clone());
+
+    TranscriptExport {
+        metadata: TranscriptMetadata {
+            session_id: session_id.to_string(),
+            project,
+            started_at,
+            ended_at,
+            turn_count: turns.len(),
+            tool_call_count,
+        },
+        turns,
+    }
+}
+
+fn to_turn(message: &Message) -> TranscriptTurn {
+    let tools = message
+        .tool_calls
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .map(|t| t.name.clone())
+        .collect();
+    TranscriptTurn {
+        role: message.role,
+        content: message.content.clone(),
+        timestamp: message.timestamp.clone(),
+        thinking: message.thinking.clone(),
+        tools,
+    }
+}
+
+fn role_heading(role: Role) -> &'static str {
+    match role {
+        Role::User => "User",
+        Role::Assistant => "Assistant",
+        Role::ToolUse => "Tool Use",
+        Role::ToolResult => "Tool Result",
+        Role::System => "System",
+        Role::Progress => "Progress",
+    }
+}
+
+/// Render an exported transcript as Markdown: a metadata list followed by
+/// one `## <Role>` section per turn.
+pub(crate) fn render_markdown(export: &TranscriptExport) -> String {
+    let meta = &export.metadata;
+    let mut md = format!("# Session {}\n\n", meta.session_id);
+    if let Some(project) = &meta.project {
+        md.push_str(&format!("- **Project:** {}\n", project));
+    }
+    if let Some(started) = &meta.started_at {
+        md.push_str(&format!("- **Started:** {}\n", started));
+    }
+    if let Some(ended) = &meta.ended_at {
+        md.push_str(&format!("- **Ended:** {}\n", ended));
+    }
+    md.push_str(&format!("- **Turns:** {}\n", meta.turn_count));
+    md.push_str(&format!("- **Tool calls:** {}\n", meta.tool_call_count));
+
+    for turn in &export.turns {
+        md.push_str(&format!("\n## {}\n\n", role_heading(turn.role)));
+        if let Some(ts) = &turn.timestamp {
+            md.push_str(&format!("_{}_\n\n", ts));
+        }
+        if !turn.content.is_empty() {
+            md.push_str(turn.content.trim_end());
+            md.push('\n');
+        }
+        if !turn.tools.is_empty() {
+            md.push_str(&format!("\nTools: `{}`\n", turn.tools.join("`, `")));
+        }
+    }
+    md
+}
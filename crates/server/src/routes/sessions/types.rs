@@ -1,5 +1,7 @@
 //! Request/response types, query structs, and constants for session endpoints.
 
+use std::collections::BTreeMap;
+
 use claude_view_core::task_files::TaskItem;
 use claude_view_core::todo_files::AgentTodos;
 use claude_view_core::SessionInfo;
@@ -21,6 +23,11 @@ pub struct ArchiveResponse {
     pub archived: bool,
 }
 
+#[derive(Serialize, utoipa::ToSchema)]
+pub struct RefreshResponse {
+    pub refreshed: bool,
+}
+
 #[derive(Serialize, utoipa::ToSchema)]
 pub struct BulkArchiveResponse {
     pub archived_count: usize,
@@ -126,6 +133,15 @@ pub struct SessionDetail {
     pub todos: Vec<AgentTodos>,
     /// Whether plan files exist for this session's slug
     pub has_plans: bool,
+    /// Session cost priced per model used, itemized by token type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_cost: Option<claude_view_db::SessionModelCost>,
+    /// Times the user interrupted the model mid-turn (ESC / abort).
+    pub interruption_count: u32,
+    /// Bash calls by command category (`git`, `test`, `build`, `misc`).
+    pub bash_categories: BTreeMap<String, u32>,
+    /// Argument strings passed to each slash command or skill, counted.
+    pub invocation_args: BTreeMap<String, BTreeMap<String, u32>>,
     /// Warnings for non-fatal data read failures (e.g. task/plan file errors)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub warnings: Vec<String>,
@@ -225,6 +241,70 @@ pub struct PaginatedBlocks {
     pub entrypoint: Option<String>,
 }
 
+// ============================================================================
+// Related Sessions Query
+// ============================================================================
+
+/// Query parameters for GET /api/sessions/:id/related
+#[derive(Debug, Deserialize, Default, utoipa::IntoParams)]
+#[serde(default)]
+pub struct RelatedSessionsQuery {
+    /// Maximum related sessions to return (default 10)
+    pub limit: Option<usize>,
+    /// Only consider sessions in this project (display name or full path)
+    pub project: Option<String>,
+}
+
+// ============================================================================
+// Transcript Export types
+// ============================================================================
+
+/// Query parameters for GET /api/sessions/:id/export
+#[derive(Debug, Deserialize, Default, utoipa::IntoParams)]
+#[serde(default)]
+pub struct TranscriptExportQuery {
+    /// "json" (default) or "md"
+    pub format: Option<String>,
+}
+
+/// Metadata header for an exported transcript.
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptMetadata {
+    pub session_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<String>,
+    pub turn_count: usize,
+    pub tool_call_count: usize,
+}
+
+/// One conversation turn with parser-internal fields (uuids, raw JSON,
+/// categories) stripped.
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptTurn {
+    pub role: claude_view_core::Role,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thinking: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tools: Vec<String>,
+}
+
+/// JSON body for GET /api/sessions/:id/export?format=json
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptExport {
+    pub metadata: TranscriptMetadata,
+    pub turns: Vec<TranscriptTurn>,
+}
+
 // ============================================================================
 // Cost Estimation types
 // ============================================================================
@@ -269,3 +349,11 @@ pub struct RichActivityParams {
     pub project: Option<String>,
     pub branch: Option<String>,
 }
+
+/// Query parameters for the session token histogram.
+#[derive(Debug, serde::Deserialize)]
+pub struct TokenHistogramParams {
+    pub time_after: Option<i64>,
+    pub time_before: Option<i64>,
+    pub project: Option<String>,
+}
@@ -1,7 +1,6 @@
 //! GET /api/stats/ai-generation — AI generation statistics with time range filtering.
 
 use std::sync::Arc;
-use std::time::Instant;
 
 use axum::extract::{Query, State};
 use axum::Json;
@@ -10,7 +9,6 @@ use claude_view_core::AnalyticsScopeMeta;
 use claude_view_db::AggregateCostBreakdown;
 
 use crate::error::ApiResult;
-use crate::metrics::record_request;
 use crate::state::AppState;
 
 use super::helpers::fetch_session_breakdown;
@@ -38,8 +36,6 @@ pub async fn ai_generation_stats(
     State(state): State<Arc<AppState>>,
     Query(query): Query<DashboardQuery>,
 ) -> ApiResult<Json<AIGenerationStatsResponse>> {
-    let start = Instant::now();
-
     // Reject half-specified ranges
     if query.from.is_some() != query.to.is_some() {
         return Err(crate::error::ApiError::BadRequest(
@@ -68,7 +64,6 @@ pub async fn ai_generation_stats(
         Ok(s) => s,
         Err(e) => {
             tracing::error!(endpoint = "ai_generation_stats", error = %e, "Failed to fetch AI generation stats");
-            record_request("ai_generation_stats", "500", start.elapsed());
             return Err(e.into());
         }
     };
@@ -175,7 +170,6 @@ pub async fn ai_generation_stats(
     )
     .await?;
 
-    record_request("ai_generation_stats", "200", start.elapsed());
     Ok(Json(AIGenerationStatsResponse {
         base: stats,
         meta: AnalyticsScopeMeta::new(session_breakdown),
@@ -0,0 +1,58 @@
+//! GET /api/stats/cost-by-model — full per-model cost including cache pricing.
+
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use axum::Json;
+use claude_view_db::ModelCost;
+
+use crate::error::{ApiError, ApiResult};
+use crate::state::AppState;
+
+use super::types::DashboardQuery;
+
+/// GET /api/stats/cost-by-model - Cost per model over a time range.
+///
+/// Unlike `/api/stats/ai-generation`, every token type is priced: input,
+/// output, cache reads, and cache writes. Sorted most expensive first.
+#[utoipa::path(get, path = "/api/stats/cost-by-model", tag = "stats",
+    params(DashboardQuery),
+    responses(
+        (status = 200, description = "Per-model cost breakdown", body = Vec<ModelCost>),
+        (status = 400, description = "Invalid time range"),
+    )
+)]
+pub async fn cost_by_model(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<DashboardQuery>,
+) -> ApiResult<Json<Vec<ModelCost>>> {
+    if query.from.is_some() != query.to.is_some() {
+        return Err(ApiError::BadRequest(
+            "Both 'from' and 'to' must be provided together".to_string(),
+        ));
+    }
+    if let (Some(from), Some(to)) = (query.from, query.to) {
+        if from >= to {
+            return Err(ApiError::BadRequest(
+                "'from' must be less than 'to'".to_string(),
+            ));
+        }
+    }
+
+    match state
+        .db
+        .cost_by_model(
+            query.from,
+            query.to,
+            query.project.as_deref(),
+            query.branch.as_deref(),
+        )
+        .await
+    {
+        Ok(costs) => Ok(Json(costs)),
+        Err(e) => {
+            tracing::error!(endpoint = "cost_by_model", error = %e, "Failed to compute cost by model");
+            Err(e.into())
+        }
+    }
+}
@@ -1,7 +1,6 @@
 //! GET /api/stats/dashboard — Pre-computed dashboard statistics with time range filtering.
 
 use std::sync::Arc;
-use std::time::Instant;
 
 use axum::extract::{Query, State};
 use axum::Json;
@@ -9,9 +8,7 @@ use claude_view_core::{AnalyticsScopeMeta, EffectiveRangeMeta, EffectiveRangeSou
 use claude_view_stats_rollup::{sum_global_stats_in_range, Bucket};
 
 use crate::error::ApiResult;
-use crate::metrics::{
-    record_request, record_time_range_resolution, record_time_range_resolution_error,
-};
+use crate::metrics::{record_time_range_resolution, record_time_range_resolution_error};
 use crate::state::AppState;
 use crate::time_range::{resolve_from_to_or_all_time, ResolveFromToInput};
 
@@ -46,7 +43,6 @@ pub async fn dashboard_stats(
     State(state): State<Arc<AppState>>,
     Query(query): Query<DashboardQuery>,
 ) -> ApiResult<Json<ExtendedDashboardStats>> {
-    let start = Instant::now();
     let now = chrono::Utc::now().timestamp();
 
     // Reject half-specified ranges
@@ -173,7 +169,6 @@ pub async fn dashboard_stats(
                 error = %e,
                 "Failed to fetch dashboard stats"
             );
-            record_request("dashboard_stats", "500", start.elapsed());
             return Err(e.into());
         }
     };
@@ -215,7 +210,6 @@ pub async fn dashboard_stats(
                     error = %e,
                     "Failed to fetch period trends"
                 );
-                record_request("dashboard_stats", "500", start.elapsed());
                 return Err(e.into());
             }
         }
@@ -267,7 +261,6 @@ pub async fn dashboard_stats(
                     error = %e,
                     "Failed to fetch all-time metrics"
                 );
-                record_request("dashboard_stats", "500", start.elapsed());
                 return Err(e.into());
             }
         }
@@ -282,9 +275,6 @@ pub async fn dashboard_stats(
     )
     .await?;
 
-    // Record successful request metrics
-    record_request("dashboard_stats", "200", start.elapsed());
-
     Ok(Json(ExtendedDashboardStats {
         base,
         current_week,
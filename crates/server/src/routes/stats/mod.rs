@@ -1,6 +1,7 @@
 //! Dashboard statistics endpoints.
 
 mod ai_generation;
+mod cost_by_model;
 mod dashboard;
 mod helpers;
 mod types;
@@ -10,6 +11,7 @@ mod tests;
 
 // Re-export all public items that were previously accessible from `stats.rs`.
 pub use ai_generation::ai_generation_stats;
+pub use cost_by_model::cost_by_model;
 pub use dashboard::dashboard_stats;
 pub use types::{
     AIGenerationStatsResponse, CurrentPeriodMetrics, DashboardMeta, DashboardQuery,
@@ -20,6 +22,8 @@ pub use types::{
 #[allow(unused_imports)]
 pub use ai_generation::__path_ai_generation_stats;
 #[allow(unused_imports)]
+pub use cost_by_model::__path_cost_by_model;
+#[allow(unused_imports)]
 pub use dashboard::__path_dashboard_stats;
 
 use std::sync::Arc;
@@ -34,6 +38,7 @@ pub fn router() -> Router<Arc<AppState>> {
         .route("/stats/dashboard", get(dashboard_stats))
         .route("/stats/storage", get(storage::storage_stats))
         .route("/stats/ai-generation", get(ai_generation_stats))
+        .route("/stats/cost-by-model", get(cost_by_model))
 }
 
 // Keep the storage handler accessible via `storage::storage_stats` for the router,
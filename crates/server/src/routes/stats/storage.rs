@@ -1,14 +1,12 @@
 //! GET /api/stats/storage — Storage statistics for the settings page.
 
 use std::sync::Arc;
-use std::time::Instant;
 
 use axum::extract::State;
 use axum::Json;
 use claude_view_core::claude_projects_dir;
 
 use crate::error::ApiResult;
-use crate::metrics::record_request;
 use crate::state::AppState;
 
 use super::helpers::{calculate_directory_size, calculate_jsonl_size};
@@ -26,8 +24,6 @@ use super::types::StorageStats;
     )
 )]
 pub async fn storage_stats(State(state): State<Arc<AppState>>) -> ApiResult<Json<StorageStats>> {
-    let start = Instant::now();
-
     // Get index metadata for timing info
     let metadata = match state.db.get_index_metadata().await {
         Ok(m) => m,
@@ -37,7 +33,6 @@ pub async fn storage_stats(State(state): State<Arc<AppState>>) -> ApiResult<Json
                 error = %e,
                 "Failed to fetch index metadata"
             );
-            record_request("storage_stats", "500", start.elapsed());
             return Err(e.into());
         }
     };
@@ -51,7 +46,6 @@ pub async fn storage_stats(State(state): State<Arc<AppState>>) -> ApiResult<Json
         Ok(counts) => counts,
         Err(e) => {
             tracing::error!(endpoint = "storage_stats", error = %e, "Failed to get storage counts");
-            record_request("storage_stats", "500", start.elapsed());
             return Err(e.into());
         }
     };
@@ -64,7 +58,6 @@ pub async fn storage_stats(State(state): State<Arc<AppState>>) -> ApiResult<Json
         Ok(size) => size as u64,
         Err(e) => {
             tracing::error!(endpoint = "storage_stats", error = %e, "Failed to get database size");
-            record_request("storage_stats", "500", start.elapsed());
             return Err(e.into());
         }
     };
@@ -93,8 +86,6 @@ pub async fn storage_stats(State(state): State<Arc<AppState>>) -> ApiResult<Json
     let index_path = shorten(claude_view_core::paths::obsolete_session_search_index_dir());
     let app_data_path = shorten(Some(claude_view_core::paths::data_dir()));
 
-    record_request("storage_stats", "200", start.elapsed());
-
     Ok(Json(StorageStats {
         jsonl_bytes,
         sqlite_bytes,
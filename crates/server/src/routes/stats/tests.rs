@@ -661,6 +661,19 @@ mod tests {
         assert!(json["tokensByProject"].as_array().unwrap().is_empty());
     }
 
+    #[tokio::test]
+    async fn test_cost_by_model_empty_db_and_range_validation() {
+        let db = test_db().await;
+        let app = build_app(db);
+        let (status, body) = do_get(app.clone(), "/api/stats/cost-by-model").await;
+        assert_eq!(status, StatusCode::OK);
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert!(json.as_array().unwrap().is_empty());
+
+        let (status, _) = do_get(app, "/api/stats/cost-by-model?from=100").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
     #[tokio::test]
     async fn test_ai_generation_includes_data_scope_meta() {
         let db = test_db().await;
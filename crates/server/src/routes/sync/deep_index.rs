@@ -10,6 +10,9 @@ use axum::{
     Json,
 };
 
+use claude_view_db::Database;
+use tokio::sync::mpsc;
+
 use crate::error::{ApiError, ApiResult};
 use crate::metrics::record_sync;
 use crate::state::AppState;
@@ -58,20 +61,42 @@ pub async fn trigger_deep_index(State(state): State<Arc<AppState>>) -> ApiResult
 
                 tracing::info!("Deep index rebuild triggered via API");
 
-                // Step 1: Mark all sessions for re-indexing
-                match db.mark_all_sessions_for_reindex().await {
-                    Ok(count) => {
-                        tracing::info!(sessions_marked = count, "Marked sessions for re-indexing");
-                    }
-                    Err(e) => {
-                        tracing::error!(
-                            error = %e,
-                            "Failed to mark sessions for re-indexing"
+                // Step 1: Mark all sessions for re-indexing, unless resuming a
+                // rebuild interrupted by a restart: sessions it already
+                // re-indexed are up to date and only the rest are still marked.
+                let resumed = db.begin_search_index_rebuild().await.unwrap_or_else(|e| {
+                    tracing::warn!(error = %e, "Failed to load rebuild checkpoint");
+                    None
+                });
+                let processed_before = match &resumed {
+                    Some(checkpoint) => {
+                        tracing::info!(
+                            last_session_id = ?checkpoint.last_session_id,
+                            sessions_processed = checkpoint.sessions_processed,
+                            "Resuming interrupted deep index rebuild"
                         );
-                        indexing.set_error(format!("Failed to mark sessions: {e}"));
-                        return;
+                        checkpoint.sessions_processed
                     }
-                }
+                    None => match db.mark_all_sessions_for_reindex().await {
+                        Ok(count) => {
+                            tracing::info!(
+                                sessions_marked = count,
+                                "Marked sessions for re-indexing"
+                            );
+                            0
+                        }
+                        Err(e) => {
+                            tracing::error!(
+                                error = %e,
+                                "Failed to mark sessions for re-indexing"
+                            );
+                            indexing.set_error(format!("Failed to mark sessions: {e}"));
+                            return;
+                        }
+                    },
+                };
+                let (checkpoint_tx, checkpoint_task) =
+                    spawn_rebuild_checkpointer(db.clone(), processed_before);
 
                 // Transition to deep indexing phase
                 indexing.set_status(crate::indexing_state::IndexingStatus::DeepIndexing);
@@ -99,8 +124,9 @@ pub async fn trigger_deep_index(State(state): State<Arc<AppState>>) -> ApiResult
                     &db,
                     &hints,
                     registry_for_scan,
-                    move |_session_id| {
+                    move |session_id| {
                         indexing_cb.increment_indexed();
+                        let _ = checkpoint_tx.send(session_id.to_string());
                     },
                     move |file_count| {
                         indexing_total.set_total(file_count);
@@ -112,9 +138,14 @@ pub async fn trigger_deep_index(State(state): State<Arc<AppState>>) -> ApiResult
                     },
                 )
                 .await;
+                // The scan dropped the sender; wait for the final checkpoint.
+                let _ = checkpoint_task.await;
 
                 match result {
                     Ok((indexed_count, _skipped)) => {
+                        if let Err(e) = db.complete_search_index_rebuild().await {
+                            tracing::warn!(error = %e, "Failed to mark rebuild complete");
+                        }
                         let duration = start.elapsed();
                         tracing::info!(
                             sessions_indexed = indexed_count,
@@ -170,3 +201,43 @@ pub async fn trigger_deep_index(State(state): State<Arc<AppState>>) -> ApiResult
         )),
     }
 }
+
+/// Persist a rebuild checkpoint every this many sessions.
+const CHECKPOINT_EVERY: i64 = 50;
+
+/// Spawn the task persisting rebuild progress for session ids sent on the
+/// returned channel, counting on from `processed_before`. The task writes a
+/// final checkpoint and exits once every sender is dropped.
+fn spawn_rebuild_checkpointer(
+    db: Database,
+    processed_before: i64,
+) -> (mpsc::UnboundedSender<String>, tokio::task::JoinHandle<()>) {
+    let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+    let task = tokio::spawn(async move {
+        let mut processed = processed_before;
+        let mut pending = None;
+        while let Some(session_id) = rx.recv().await {
+            processed += 1;
+            if processed % CHECKPOINT_EVERY == 0 {
+                if let Err(e) = db
+                    .record_search_index_rebuild_progress(&session_id, processed)
+                    .await
+                {
+                    tracing::warn!(error = %e, "Failed to checkpoint rebuild progress");
+                }
+                pending = None;
+            } else {
+                pending = Some(session_id);
+            }
+        }
+        if let Some(session_id) = pending {
+            if let Err(e) = db
+                .record_search_index_rebuild_progress(&session_id, processed)
+                .await
+            {
+                tracing::warn!(error = %e, "Failed to checkpoint rebuild progress");
+            }
+        }
+    });
+    (tx, task)
+}
@@ -14,7 +14,7 @@ use crate::state::AppState;
 
 use super::types::{
     ActionResponse, CheckPathQuery, CheckPathResponse, ClearCacheResponse, IndexRunInfo,
-    IntegrityInfo, PerformanceInfo, ResetRequest, SystemResponse,
+    IntegrityInfo, PerformanceInfo, ResetProjectRequest, ResetRequest, SystemResponse,
 };
 
 /// GET /api/system - Get comprehensive system status.
@@ -37,6 +37,7 @@ pub async fn get_system_status(
         index_runs_result,
         classification_result,
         integrity_result,
+        rebuild_result,
     ) = tokio::join!(
         state.db.get_storage_stats(),
         state.db.get_health_stats(),
@@ -44,6 +45,7 @@ pub async fn get_system_status(
         state.db.get_recent_index_runs(),
         state.db.get_classification_status(),
         state.db.get_latest_integrity_counters(),
+        state.db.search_index_rebuild_progress(),
     );
 
     let storage_stats = storage_result?;
@@ -52,6 +54,7 @@ pub async fn get_system_status(
     let index_runs = index_runs_result?;
     let classification = classification_result?;
     let integrity_counters = integrity_result?;
+    let rebuild_progress = rebuild_result?;
 
     // Detect Claude CLI (runs shell commands - fast enough for API call)
     let claude_cli = tokio::task::spawn_blocking(ClaudeCliStatus::detect)
@@ -82,6 +85,7 @@ pub async fn get_system_status(
             counters: integrity_counters.into(),
         },
         index_history,
+        rebuild_progress,
         classification: classification.into(),
         claude_cli,
     };
@@ -251,6 +255,58 @@ pub async fn reset_all(
     }))
 }
 
+/// POST /api/system/reset-project - Delete one project's indexed data.
+///
+/// Requires `confirm` to repeat the project id. Removes the project's
+/// sessions, commit links, indexer state and contribution snapshots, plus
+/// its prompts in the search index. The JSONL files are untouched, so the
+/// next scan re-indexes whatever of the project is still on disk.
+#[utoipa::path(post, path = "/api/system/reset-project", tag = "system",
+    request_body = serde_json::Value,
+    responses(
+        (status = 200, description = "Project data reset", body = crate::routes::system::ActionResponse),
+        (status = 400, description = "Missing or mismatched confirmation"),
+    )
+)]
+pub async fn reset_project(
+    State(state): State<Arc<AppState>>,
+    Json(body): Json<ResetProjectRequest>,
+) -> ApiResult<Json<ActionResponse>> {
+    if body.project_id.is_empty() || body.confirm != body.project_id {
+        return Err(ApiError::BadRequest(
+            "Invalid confirmation. Send {\"projectId\": \"<id>\", \"confirm\": \"<id>\"} to confirm."
+                .to_string(),
+        ));
+    }
+
+    // Read before the reset: the paths come from the rows it deletes.
+    let paths = state.db.project_paths(&body.project_id).await?;
+    let removed = state
+        .db
+        .reset_project_data(&body.project_id)
+        .await
+        .map_err(|e| ApiError::Internal(format!("Failed to reset project: {}", e)))?;
+
+    let index = state.prompt_index.read().unwrap().clone();
+    if let Some(index) = index {
+        let cleared = paths
+            .iter()
+            .try_for_each(|path| index.delete_by_project(path))
+            .and_then(|()| index.commit());
+        if let Err(e) = cleared {
+            tracing::warn!("Failed to remove project prompts from search index: {}", e);
+        }
+    }
+
+    Ok(Json(ActionResponse {
+        status: "success".to_string(),
+        message: Some(format!(
+            "Removed {} sessions of {}",
+            removed, body.project_id
+        )),
+    }))
+}
+
 /// Calculate the size of a directory recursively.
 fn calculate_dir_size(dir: &std::path::Path) -> u64 {
     if !dir.exists() {
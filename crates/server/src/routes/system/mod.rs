@@ -5,6 +5,7 @@
 //! - POST /system/clear-cache — Clear obsolete search cache
 //! - POST /system/git-resync — Trigger full git re-sync (stub -- not yet implemented)
 //! - POST /system/reset     — Factory reset (requires confirmation)
+//! - POST /system/reset-project — Reset one project's data (requires confirmation)
 
 mod handlers;
 mod types;
@@ -26,19 +27,20 @@ use crate::state::AppState;
 // Re-export all public types so external code sees the same API as before.
 pub use types::{
     ActionResponse, CheckPathQuery, CheckPathResponse, ClassificationInfo, ClearCacheResponse,
-    HealthInfo, IndexRunInfo, IntegrityCounterInfo, IntegrityInfo, PerformanceInfo, ResetRequest,
-    StorageInfo, SystemResponse,
+    HealthInfo, IndexRunInfo, IntegrityCounterInfo, IntegrityInfo, PerformanceInfo,
+    ResetProjectRequest, ResetRequest, StorageInfo, SystemResponse,
 };
 
 // Re-export all handler functions for OpenAPI registration.
 pub use handlers::{
-    check_path, clear_cache, get_system_status, reset_all, trigger_git_resync, trigger_reindex,
+    check_path, clear_cache, get_system_status, reset_all, reset_project, trigger_git_resync,
+    trigger_reindex,
 };
 
 // Re-export utoipa __path_* types (generated by #[utoipa::path] on handlers)
 pub use handlers::{
     __path_check_path, __path_clear_cache, __path_get_system_status, __path_reset_all,
-    __path_trigger_git_resync, __path_trigger_reindex,
+    __path_reset_project, __path_trigger_git_resync, __path_trigger_reindex,
 };
 
 /// Create the system routes router.
@@ -49,5 +51,6 @@ pub fn router() -> Router<Arc<AppState>> {
         .route("/system/clear-cache", post(handlers::clear_cache))
         .route("/system/git-resync", post(handlers::trigger_git_resync))
         .route("/system/reset", post(handlers::reset_all))
+        .route("/system/reset-project", post(handlers::reset_project))
         .route("/check-path", get(handlers::check_path))
 }
@@ -338,6 +338,48 @@ async fn test_reset_with_correct_confirmation() {
     assert_eq!(health.commits_count, 0);
 }
 
+#[tokio::test]
+async fn test_reset_project_removes_only_that_project() {
+    let db = test_db().await;
+    for (id, project) in [("sess-a", "project-a"), ("sess-b", "project-b")] {
+        claude_view_db::test_support::SessionSeedBuilder::new(id)
+            .project_id(project)
+            .project_path(format!("/tmp/{project}"))
+            .file_path(format!("/tmp/{id}.jsonl"))
+            .modified_at(chrono::Utc::now().timestamp())
+            .seed(&db)
+            .await
+            .unwrap();
+    }
+
+    let app = build_app(db.clone());
+    let (status, _body) = do_post_json(
+        app.clone(),
+        "/api/system/reset-project",
+        r#"{"projectId": "project-a", "confirm": "project-b"}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, body) = do_post_json(
+        app,
+        "/api/system/reset-project",
+        r#"{"projectId": "project-a", "confirm": "project-a"}"#,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["status"], "success");
+
+    let health = db.get_health_stats().await.unwrap();
+    assert_eq!(health.sessions_count, 1);
+    assert!(db.project_paths("project-a").await.unwrap().is_empty());
+    assert_eq!(
+        db.project_paths("project-b").await.unwrap(),
+        vec!["/tmp/project-b".to_string()]
+    );
+}
+
 #[tokio::test]
 async fn test_reset_without_body_fails() {
     let db = test_db().await;
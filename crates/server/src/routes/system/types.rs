@@ -1,7 +1,8 @@
 //! Request and response types for system endpoints.
 
 use claude_view_db::{
-    ClassificationStatus, HealthStats, HealthStatus, IndexRunIntegrityCounters, SystemStorageStats,
+    ClassificationStatus, HealthStats, HealthStatus, IndexRunIntegrityCounters,
+    SearchIndexRebuildProgress, SystemStorageStats,
 };
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
@@ -20,6 +21,8 @@ pub struct SystemResponse {
     pub health: HealthInfo,
     pub integrity: IntegrityInfo,
     pub index_history: Vec<IndexRunInfo>,
+    /// Checkpoint of the running or last full deep index rebuild.
+    pub rebuild_progress: Option<SearchIndexRebuildProgress>,
     pub classification: ClassificationInfo,
     pub claude_cli: claude_view_core::ClaudeCliStatus,
 }
@@ -233,6 +236,14 @@ pub struct ResetRequest {
     pub confirm: String,
 }
 
+/// Reset-project request body.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResetProjectRequest {
+    pub project_id: String,
+    pub confirm: String,
+}
+
 /// Query parameters for the check-path endpoint.
 #[derive(Debug, Deserialize, utoipa::IntoParams)]
 pub struct CheckPathQuery {
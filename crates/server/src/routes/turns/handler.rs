@@ -11,8 +11,8 @@ use axum::{
 use crate::error::{ApiError, ApiResult};
 use crate::state::AppState;
 
-use super::scanner::scan_turns;
-use super::types::TurnInfo;
+use super::scanner::{scan_turns, session_token_timeline};
+use super::types::{TurnInfo, TurnTokens};
 
 /// GET /api/sessions/{id}/turns -- Per-turn breakdown for a historical session.
 #[utoipa::path(get, path = "/api/sessions/{id}/turns", tag = "turns",
@@ -26,6 +26,28 @@ pub async fn get_session_turns(
     State(state): State<Arc<AppState>>,
     Path(session_id): Path<String>,
 ) -> ApiResult<Json<Vec<TurnInfo>>> {
+    Ok(Json(load_turns(&state, session_id).await?))
+}
+
+/// GET /api/sessions/{id}/token-timeline -- Per-turn incremental and
+/// cumulative token usage, for spotting the expensive turns.
+#[utoipa::path(get, path = "/api/sessions/{id}/token-timeline", tag = "turns",
+    params(("id" = String, Path, description = "Session ID")),
+    responses(
+        (status = 200, description = "Tokens per turn with running totals, in turn order", body = Vec<crate::routes::turns::TurnTokens>),
+        (status = 404, description = "Session not found"),
+    )
+)]
+pub async fn get_session_token_timeline(
+    State(state): State<Arc<AppState>>,
+    Path(session_id): Path<String>,
+) -> ApiResult<Json<Vec<TurnTokens>>> {
+    let turns = load_turns(&state, session_id).await?;
+    Ok(Json(session_token_timeline(&turns)))
+}
+
+/// Resolve a session's JSONL via the DB and scan it into turns.
+async fn load_turns(state: &AppState, session_id: String) -> ApiResult<Vec<TurnInfo>> {
     // Resolve JSONL file path via DB
     let file_path = state
         .db
@@ -39,18 +61,21 @@ pub async fn get_session_turns(
     }
 
     // Read + parse in blocking thread (file I/O)
-    let turns = tokio::task::spawn_blocking(move || {
+    tokio::task::spawn_blocking(move || {
         let data = std::fs::read(&path)
             .map_err(|e| ApiError::Internal(format!("Failed to read session file: {}", e)))?;
         Ok::<Vec<TurnInfo>, ApiError>(scan_turns(&data))
     })
     .await
-    .map_err(|e| ApiError::Internal(format!("Task join error: {}", e)))??;
-
-    Ok(Json(turns))
+    .map_err(|e| ApiError::Internal(format!("Task join error: {}", e)))?
 }
 
 /// Create the turns routes router.
 pub fn router() -> Router<Arc<AppState>> {
-    Router::new().route("/sessions/{id}/turns", get(get_session_turns))
+    Router::new()
+        .route("/sessions/{id}/turns", get(get_session_turns))
+        .route(
+            "/sessions/{id}/token-timeline",
+            get(get_session_token_timeline),
+        )
 }
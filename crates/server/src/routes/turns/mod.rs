@@ -3,6 +3,8 @@
 //! `GET /api/sessions/{id}/turns` re-parses the JSONL file on demand to extract
 //! per-turn data (wall-clock duration, CC duration, prompt preview). This avoids
 //! storing per-turn data in the DB for rarely-accessed detail views.
+//! `GET /api/sessions/{id}/token-timeline` folds the same turns into a
+//! per-turn token series.
 
 mod handler;
 pub mod scanner;
@@ -11,8 +13,8 @@ mod tests;
 pub mod types;
 
 // Re-export public API
-pub use handler::{get_session_turns, router};
-pub use types::TurnInfo;
+pub use handler::{get_session_token_timeline, get_session_turns, router};
+pub use types::{TurnInfo, TurnTokens};
 
-// Re-export utoipa-generated hidden path type for OpenAPI registration
-pub use handler::__path_get_session_turns;
+// Re-export utoipa-generated hidden path types for OpenAPI registration
+pub use handler::{__path_get_session_token_timeline, __path_get_session_turns};
@@ -3,11 +3,13 @@
 //! Uses SIMD pre-filtering via `memchr` to avoid JSON parsing lines that
 //! aren't interesting (user, assistant, system).
 
+use std::collections::HashMap;
+
 use memchr::memmem;
 
 use claude_view_core::is_system_user_content;
 
-use super::types::TurnInfo;
+use super::types::{TurnInfo, TurnTokens};
 
 // ============================================================================
 // SIMD Finders
@@ -22,6 +24,7 @@ struct TurnFinders {
     turn_duration: memmem::Finder<'static>,
     timestamp_key: memmem::Finder<'static>,
     content_key: memmem::Finder<'static>,
+    usage_key: memmem::Finder<'static>,
 }
 
 impl TurnFinders {
@@ -34,6 +37,7 @@ impl TurnFinders {
             turn_duration: memmem::Finder::new(b"\"turn_duration\""),
             timestamp_key: memmem::Finder::new(b"\"timestamp\""),
             content_key: memmem::Finder::new(b"\"content\""),
+            usage_key: memmem::Finder::new(b"\"usage\""),
         }
     }
 }
@@ -82,6 +86,19 @@ fn extract_timestamp(parsed: &serde_json::Value) -> Option<i64> {
     }
 }
 
+/// Extract an assistant line's message id and total billed tokens
+/// (input + output + cache read + cache creation).
+fn extract_usage_tokens(parsed: &serde_json::Value) -> Option<(Option<&str>, u64)> {
+    let msg = parsed.get("message")?;
+    let usage = msg.get("usage")?;
+    let field = |key: &str| usage.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+    let tokens = field("input_tokens")
+        + field("output_tokens")
+        + field("cache_read_input_tokens")
+        + field("cache_creation_input_tokens");
+    Some((msg.get("id").and_then(|v| v.as_str()), tokens))
+}
+
 /// Truncate a string to at most `max_chars` characters, appending "..." if truncated.
 pub(crate) fn truncate_preview(s: &str, max_chars: usize) -> String {
     let trimmed = s.trim();
@@ -109,6 +126,24 @@ pub fn scan_turns(data: &[u8]) -> Vec<TurnInfo> {
         started_at: i64,
         prompt_preview: String,
         cc_duration_ms: Option<u64>,
+        // Streaming writes one line per content block, each repeating the
+        // message's usage, so tokens are keyed by message id (last wins).
+        message_tokens: HashMap<String, u64>,
+        unkeyed_tokens: u64,
+    }
+
+    impl PendingTurn {
+        fn finish(self, last_timestamp: Option<i64>) -> TurnInfo {
+            let end_ts = last_timestamp.unwrap_or(self.started_at);
+            TurnInfo {
+                index: self.index,
+                started_at: self.started_at,
+                wall_clock_seconds: (end_ts - self.started_at).max(0),
+                cc_duration_ms: self.cc_duration_ms,
+                prompt_preview: self.prompt_preview,
+                tokens: self.message_tokens.values().sum::<u64>() + self.unkeyed_tokens,
+            }
+        }
     }
 
     let mut turns: Vec<TurnInfo> = Vec::new();
@@ -192,14 +227,7 @@ pub fn scan_turns(data: &[u8]) -> Vec<TurnInfo> {
                         // This is a real user turn start.
                         // Close the previous turn if one was open.
                         if let Some(pending) = current_turn.take() {
-                            let end_ts = last_timestamp.unwrap_or(pending.started_at);
-                            turns.push(TurnInfo {
-                                index: pending.index,
-                                started_at: pending.started_at,
-                                wall_clock_seconds: (end_ts - pending.started_at).max(0),
-                                cc_duration_ms: pending.cc_duration_ms,
-                                prompt_preview: pending.prompt_preview,
-                            });
+                            turns.push(pending.finish(last_timestamp));
                         }
 
                         turn_counter += 1;
@@ -211,6 +239,8 @@ pub fn scan_turns(data: &[u8]) -> Vec<TurnInfo> {
                             started_at,
                             prompt_preview: preview,
                             cc_duration_ms: None,
+                            message_tokens: HashMap::new(),
+                            unkeyed_tokens: 0,
                         });
 
                         if let Some(t) = ts {
@@ -227,27 +257,49 @@ pub fn scan_turns(data: &[u8]) -> Vec<TurnInfo> {
             continue;
         }
 
-        // For assistant/system lines: just track timestamp for wall-clock end
-        if finders.timestamp_key.find(raw_line).is_some() {
+        // For assistant/system lines: track timestamp for wall-clock end, and
+        // charge assistant usage to the open turn
+        let has_usage = is_assistant && finders.usage_key.find(raw_line).is_some();
+        if has_usage || finders.timestamp_key.find(raw_line).is_some() {
             if let Ok(parsed) = serde_json::from_slice::<serde_json::Value>(raw_line) {
                 if let Some(ts) = extract_timestamp(&parsed) {
                     last_timestamp = Some(ts);
                 }
+                if let (Some(turn), Some((id, tokens))) =
+                    (current_turn.as_mut(), extract_usage_tokens(&parsed))
+                {
+                    match id {
+                        Some(id) => {
+                            turn.message_tokens.insert(id.to_string(), tokens);
+                        }
+                        None => turn.unkeyed_tokens += tokens,
+                    }
+                }
             }
         }
     }
 
     // Close the last turn
     if let Some(pending) = current_turn.take() {
-        let end_ts = last_timestamp.unwrap_or(pending.started_at);
-        turns.push(TurnInfo {
-            index: pending.index,
-            started_at: pending.started_at,
-            wall_clock_seconds: (end_ts - pending.started_at).max(0),
-            cc_duration_ms: pending.cc_duration_ms,
-            prompt_preview: pending.prompt_preview,
-        });
+        turns.push(pending.finish(last_timestamp));
     }
 
     turns
 }
+
+/// Per-turn incremental and running token totals, in turn order.
+pub fn session_token_timeline(turns: &[TurnInfo]) -> Vec<TurnTokens> {
+    let mut cumulative = 0;
+    turns
+        .iter()
+        .map(|turn| {
+            cumulative += turn.tokens;
+            TurnTokens {
+                index: turn.index,
+                started_at: turn.started_at,
+                tokens: turn.tokens,
+                cumulative_tokens: cumulative,
+            }
+        })
+        .collect()
+}
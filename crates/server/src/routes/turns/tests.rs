@@ -10,7 +10,7 @@ mod tests {
     use claude_view_db::Database;
     use tower::ServiceExt;
 
-    use crate::routes::turns::scanner::{scan_turns, truncate_preview};
+    use crate::routes::turns::scanner::{scan_turns, session_token_timeline, truncate_preview};
     async fn test_db() -> Database {
         Database::new_in_memory().await.expect("in-memory DB")
     }
@@ -207,6 +207,37 @@ mod tests {
         assert_eq!(wall2, 125, "Wall clock for last turn should extend to EOF");
     }
 
+    #[tokio::test]
+    async fn test_get_token_timeline() {
+        let db = test_db().await;
+        let tmp = tempfile::tempdir().unwrap();
+        let session_file = tmp.path().join("timeline.jsonl");
+
+        let jsonl = r#"{"type":"user","uuid":"u1","timestamp":1700000000,"message":{"role":"user","content":"first"}}
+{"type":"assistant","uuid":"a1","timestamp":1700000010,"message":{"id":"m1","role":"assistant","content":[],"usage":{"input_tokens":100,"output_tokens":20}}}
+{"type":"user","uuid":"u2","timestamp":1700000100,"message":{"role":"user","content":"second"}}
+{"type":"assistant","uuid":"a2","timestamp":1700000110,"message":{"id":"m2","role":"assistant","content":[],"usage":{"input_tokens":300,"output_tokens":80}}}
+"#;
+        std::fs::write(&session_file, jsonl).unwrap();
+
+        let session = make_session("turns-timeline", session_file.to_str().unwrap());
+        db.insert_session(&session, "test-project", "Test Project")
+            .await
+            .unwrap();
+
+        let app = build_app(db);
+        let (status, body) = do_get(app, "/api/sessions/turns-timeline/token-timeline").await;
+
+        assert_eq!(status, StatusCode::OK);
+        let points: Vec<serde_json::Value> = serde_json::from_str(&body).unwrap();
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0]["index"], 1);
+        assert_eq!(points[0]["tokens"], 120);
+        assert_eq!(points[0]["cumulativeTokens"], 120);
+        assert_eq!(points[1]["tokens"], 380);
+        assert_eq!(points[1]["cumulativeTokens"], 500);
+    }
+
     #[tokio::test]
     async fn test_get_turns_response_shape() {
         let db = test_db().await;
@@ -367,6 +398,33 @@ mod tests {
         assert_eq!(turns[0].prompt_preview, "Real prompt");
     }
 
+    #[test]
+    fn test_session_token_timeline_accumulates_turn_tokens() {
+        // m1 is streamed as two lines repeating its usage; it counts once.
+        let data = br#"{"type":"user","uuid":"u1","timestamp":1700000000,"message":{"role":"user","content":"one"}}
+{"type":"assistant","uuid":"a1","timestamp":1700000010,"message":{"id":"m1","role":"assistant","content":[{"type":"thinking","thinking":"..."}],"usage":{"input_tokens":1000,"output_tokens":10,"cache_read_input_tokens":200,"cache_creation_input_tokens":100}}}
+{"type":"assistant","uuid":"a2","timestamp":1700000011,"message":{"id":"m1","role":"assistant","content":[{"type":"text","text":"ok"}],"usage":{"input_tokens":1000,"output_tokens":50,"cache_read_input_tokens":200,"cache_creation_input_tokens":100}}}
+{"type":"user","uuid":"u2","timestamp":1700000100,"message":{"role":"user","content":"two"}}
+{"type":"user","uuid":"u3","timestamp":1700000200,"message":{"role":"user","content":"three"}}
+{"type":"assistant","uuid":"a3","timestamp":1700000210,"message":{"id":"m2","role":"assistant","content":[],"usage":{"input_tokens":5000,"output_tokens":700}}}
+{"type":"assistant","uuid":"a4","timestamp":1700000220,"message":{"id":"m3","role":"assistant","content":[],"usage":{"input_tokens":6000,"output_tokens":300}}}
+"#;
+        let turns = scan_turns(data);
+        assert_eq!(
+            turns.iter().map(|t| t.tokens).collect::<Vec<_>>(),
+            vec![1350, 0, 12000]
+        );
+
+        let timeline = session_token_timeline(&turns);
+        assert_eq!(
+            timeline
+                .iter()
+                .map(|p| (p.index, p.tokens, p.cumulative_tokens))
+                .collect::<Vec<_>>(),
+            vec![(1, 1350, 1350), (2, 0, 1350), (3, 12000, 13350)]
+        );
+    }
+
     #[test]
     fn test_truncate_preview_short() {
         assert_eq!(truncate_preview("short", 60), "short");
@@ -23,4 +23,26 @@ pub struct TurnInfo {
     pub cc_duration_ms: Option<u64>,
     /// First 60 characters of the user prompt text.
     pub prompt_preview: String,
+    /// Tokens billed for the turn's assistant messages (input + output +
+    /// cache read + cache creation), each message counted once.
+    #[ts(type = "number")]
+    pub tokens: u64,
+}
+
+/// One point of a session's per-turn token timeline.
+#[derive(Debug, Clone, Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct TurnTokens {
+    /// 1-based turn index.
+    pub index: u32,
+    /// Unix timestamp (seconds) when the turn started (user prompt).
+    #[ts(type = "number")]
+    pub started_at: i64,
+    /// Tokens used by this turn alone.
+    #[ts(type = "number")]
+    pub tokens: u64,
+    /// Tokens used by this turn and every turn before it.
+    #[ts(type = "number")]
+    pub cumulative_tokens: u64,
 }
@@ -3,13 +3,22 @@
 //! Both `/api/search` and `/api/sessions?q=` call `execute_search()` with
 //! the same `SearchFilters`. Search itself runs over raw JSONL files via
 //! ripgrep-core; SQLite is used only to pre-filter candidate session IDs.
+//! `execute_search_cards()` (behind `/api/search/cards`) additionally
+//! hydrates each hit with its `SessionInfo` so list views get snippets and
+//! metrics in one call.
 
 use std::collections::HashSet;
 use std::sync::Arc;
 
-use claude_view_db::SearchPrefilter;
-use claude_view_search::types::SearchResponse;
-use claude_view_search::{unified_search, UnifiedSearchOptions};
+use claude_view_core::SessionInfo;
+use claude_view_db::{Database, DbResult, SearchPrefilter};
+use claude_view_search::types::{SearchResponse, SessionHit};
+use claude_view_search::{
+    split_mcp_qualifiers, split_range_qualifiers, unified_search, RoleBoosts, SearchFields,
+    UnifiedSearchOptions,
+};
+use serde::Serialize;
+use ts_rs::TS;
 
 use crate::error::ApiError;
 use crate::routes::grep::collect_jsonl_files;
@@ -27,6 +36,20 @@ pub struct SearchFilters {
     pub before: Option<String>,
 }
 
+/// How hits are presented. `Default` is plain search: snippets built,
+/// substring highlighting, every match kept, most recent session first.
+#[derive(Debug, Default, Clone)]
+pub struct SearchDisplay {
+    pub skip_snippets: bool,
+    pub fields: Option<SearchFields>,
+    /// See `UnifiedSearchOptions::whole_word_highlight`.
+    pub whole_word_highlight: bool,
+    /// See `UnifiedSearchOptions::dedupe_snippets`.
+    pub dedupe_snippets: bool,
+    /// See `UnifiedSearchOptions::role_boosts`.
+    pub role_boosts: Option<RoleBoosts>,
+}
+
 /// Parse ISO date string ("YYYY-MM-DD") to Unix timestamp (midnight UTC).
 fn parse_iso_date(s: &str) -> Option<i64> {
     chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
@@ -41,15 +64,25 @@ pub async fn execute_search(
     filters: &SearchFilters,
     limit: usize,
     offset: usize,
-    skip_snippets: bool,
+    display: SearchDisplay,
 ) -> Result<SearchResponse, ApiError> {
-    // 1. Build SQLite pre-filter from shared filters.
+    // 1. Build SQLite pre-filter from shared filters plus any
+    //    `duration:` / `tokens:` range and `mcp:` server qualifiers in the query.
+    let (ranges, text_query) = split_range_qualifiers(query);
+    let (mcp_servers, text_query) = split_mcp_qualifiers(&text_query);
+    let duration = ranges.duration.unwrap_or_default();
+    let tokens = ranges.tokens.unwrap_or_default();
     let prefilter = SearchPrefilter {
         project: filters.project.clone(),
         branch: filters.branch.clone(),
         model: filters.model.clone(),
         after: filters.after.as_deref().and_then(parse_iso_date),
         before: filters.before.as_deref().and_then(parse_iso_date),
+        min_duration: duration.min,
+        max_duration: duration.max,
+        min_tokens: tokens.min,
+        max_tokens: tokens.max,
+        mcp_servers,
     };
 
     // 2. SQL pre-filter (only if any filters set).
@@ -66,10 +99,18 @@ pub async fn execute_search(
     };
 
     // 3. Collect JSONL files (narrowed by session IDs when filtered).
+    let known = state.db.search_file_meta().await.unwrap_or_else(|e| {
+        tracing::warn!("Failed to load search file metadata: {e}");
+        Default::default()
+    });
     let project_filter = prefilter.project.clone();
     let session_ids_clone = session_ids.clone();
     let jsonl_files = tokio::task::spawn_blocking(move || {
-        collect_jsonl_files(project_filter.as_deref(), session_ids_clone.as_ref())
+        collect_jsonl_files(
+            project_filter.as_deref(),
+            session_ids_clone.as_ref(),
+            &known,
+        )
     })
     .await
     .map_err(|e| ApiError::Internal(format!("File collection join: {e}")))?
@@ -79,7 +120,7 @@ pub async fn execute_search(
     });
 
     // 4. Run grep search in spawn_blocking.
-    let q_owned = query.to_string();
+    let q_owned = text_query;
     let start = std::time::Instant::now();
 
     let result = tokio::task::spawn_blocking(move || {
@@ -88,7 +129,11 @@ pub async fn execute_search(
             scope: None,
             limit,
             offset,
-            skip_snippets,
+            skip_snippets: display.skip_snippets,
+            whole_word_highlight: display.whole_word_highlight,
+            dedupe_snippets: display.dedupe_snippets,
+            fields: display.fields,
+            role_boosts: display.role_boosts,
         };
         unified_search(&jsonl_files, &opts)
     })
@@ -112,7 +157,161 @@ pub async fn execute_search(
     .map_err(|e| ApiError::Internal(format!("Search failed: {e}")))?;
 
     let mut response = result.response;
+    // Echo the query as typed, qualifiers included.
+    response.query = query.to_string();
     response.elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
 
     Ok(response)
 }
+
+/// A search hit merged with its session's indexed metadata.
+#[derive(Debug, Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct SearchSessionCard {
+    #[serde(flatten)]
+    pub hit: SessionHit,
+    /// `None` when the JSONL file matched but the session isn't indexed yet.
+    pub session: Option<SessionInfo>,
+}
+
+/// [`SearchResponse`] with each hit hydrated into a [`SearchSessionCard`].
+#[derive(Debug, Serialize, TS, utoipa::ToSchema)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct SearchCardsResponse {
+    pub query: String,
+    pub total_sessions: usize,
+    pub total_matches: usize,
+    pub elapsed_ms: f64,
+    pub cards: Vec<SearchSessionCard>,
+}
+
+/// Run [`execute_search`] and hydrate the page of hits from the DB.
+pub async fn execute_search_cards(
+    state: &Arc<AppState>,
+    query: &str,
+    filters: &SearchFilters,
+    limit: usize,
+    offset: usize,
+    display: SearchDisplay,
+) -> Result<SearchCardsResponse, ApiError> {
+    let response = execute_search(state, query, filters, limit, offset, display).await?;
+    Ok(hydrate_search_cards(&state.db, response).await?)
+}
+
+/// Attach `SessionInfo` to every hit with one batched lookup, keeping the
+/// hit order.
+pub async fn hydrate_search_cards(
+    db: &Database,
+    response: SearchResponse,
+) -> DbResult<SearchCardsResponse> {
+    let ids: Vec<String> = response
+        .sessions
+        .iter()
+        .map(|hit| hit.session_id.clone())
+        .collect();
+    let mut by_id: std::collections::HashMap<String, SessionInfo> = db
+        .get_sessions_by_ids(&ids)
+        .await?
+        .into_iter()
+        .map(|info| (info.id.clone(), info))
+        .collect();
+    let cards = response
+        .sessions
+        .into_iter()
+        .map(|hit| SearchSessionCard {
+            session: by_id.remove(&hit.session_id),
+            hit,
+        })
+        .collect();
+    Ok(SearchCardsResponse {
+        query: response.query,
+        total_sessions: response.total_sessions,
+        total_matches: response.total_matches,
+        elapsed_ms: response.elapsed_ms,
+        cards,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use claude_view_db::test_support::SessionSeedBuilder;
+    use claude_view_search::{unified_search, JsonlFile, UnifiedSearchOptions};
+
+    #[tokio::test]
+    async fn hydrated_card_has_snippet_and_db_metrics() {
+        let db = Database::new_in_memory().await.unwrap();
+        SessionSeedBuilder::new("sess-card")
+            .project_id("proj")
+            .file_path("/tmp/sess-card.jsonl")
+            .last_message_at(1_700_000_000)
+            .turn_count(7)
+            .primary_model("claude-opus-4-6")
+            .total_input_tokens(4200)
+            .seed(&db)
+            .await
+            .unwrap();
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mut files = Vec::new();
+        for id in ["sess-card", "sess-unindexed"] {
+            let path = tmp.path().join(format!("{id}.jsonl"));
+            std::fs::write(
+                &path,
+                r#"{"type":"user","message":{"role":"user","content":"fix the websocket reconnect"}}"#,
+            )
+            .unwrap();
+            files.push(JsonlFile {
+                path,
+                session_id: id.to_string(),
+                project: "proj".to_string(),
+                project_path: tmp.path().to_string_lossy().to_string(),
+                modified_at: 1_700_000_000,
+                is_sidechain: false,
+            });
+        }
+        let opts = UnifiedSearchOptions {
+            query: "websocket".to_string(),
+            scope: None,
+            limit: 10,
+            offset: 0,
+            skip_snippets: false,
+            whole_word_highlight: false,
+            dedupe_snippets: false,
+            fields: None,
+            role_boosts: None,
+        };
+        let response = unified_search(&files, &opts).unwrap().response;
+
+        let cards = hydrate_search_cards(&db, response).await.unwrap();
+        assert_eq!(cards.total_sessions, 2);
+        let card = cards
+            .cards
+            .iter()
+            .find(|c| c.hit.session_id == "sess-card")
+            .unwrap();
+        assert!(card
+            .hit
+            .top_match
+            .snippet
+            .contains("<mark>websocket</mark>"));
+        let session = card.session.as_ref().expect("indexed session hydrated");
+        assert_eq!(session.total_input_tokens, Some(4200));
+        assert_eq!(session.primary_model.as_deref(), Some("claude-opus-4-6"));
+
+        let unindexed = cards
+            .cards
+            .iter()
+            .find(|c| c.hit.session_id == "sess-unindexed")
+            .unwrap();
+        assert!(unindexed.session.is_none());
+
+        let json = serde_json::to_value(&cards).unwrap();
+        let first = &json["cards"][0];
+        assert!(first.get("sessionId").is_some());
+        assert!(first.get("topMatch").is_some());
+        assert!(first.get("session").is_some());
+    }
+}
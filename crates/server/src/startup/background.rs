@@ -1,4 +1,5 @@
-//! Periodic background tasks — git sync + contribution-snapshot refresh.
+//! Periodic background tasks — git sync, contribution-snapshot refresh,
+//! and preview backfill.
 //!
 //! Extracted from `main.rs` in CQRS Phase 7.c. The metrics shape
 //! (`record_sync`) and structured-log fields are unchanged so existing
@@ -76,6 +77,37 @@ pub async fn run_snapshot_generation(db: &Database, label: &str) {
     }
 }
 
+/// Fill empty session previews left by older parsers.
+/// Opt-in via `CLAUDE_VIEW_REGENERATE_PREVIEWS=1`; non-fatal, and a no-op
+/// once every session with user prompts has a preview.
+pub async fn run_preview_regeneration(db: &Database, label: &str) {
+    if std::env::var("CLAUDE_VIEW_REGENERATE_PREVIEWS").as_deref() != Ok("1") {
+        return;
+    }
+    match db.regenerate_previews().await {
+        Ok(0) => {}
+        Ok(count) => tracing::info!("{} preview regeneration: {} previews filled", label, count),
+        Err(e) => tracing::warn!("{} preview regeneration failed (non-fatal): {}", label, e),
+    }
+}
+
+/// Drop commit links left behind by sessions deleted outside the app.
+/// Opt-in via `CLAUDE_VIEW_PURGE_ORPHANS=1`; non-fatal.
+pub async fn run_orphan_purge(db: &Database, label: &str) {
+    if std::env::var("CLAUDE_VIEW_PURGE_ORPHANS").as_deref() != Ok("1") {
+        return;
+    }
+    match db.purge_orphan_session_commits().await {
+        Ok(0) => {}
+        Ok(count) => tracing::info!(
+            "{} orphan purge: {} session commit links removed",
+            label,
+            count
+        ),
+        Err(e) => tracing::warn!("{} orphan purge failed (non-fatal): {}", label, e),
+    }
+}
+
 /// Format a byte count as a human-readable string (e.g. "23.4 GB", "512 MB").
 pub fn format_bytes(bytes: u64) -> String {
     const GB: u64 = 1_000_000_000;
@@ -10,9 +10,13 @@ use std::time::{Duration, Instant};
 
 use claude_view_db::indexer_parallel::{build_index_hints, scan_and_index_all};
 use claude_view_db::Database;
+use claude_view_search::{DayPartClock, SearchIndexConfig};
 
+use crate::live::registry_watcher::{seed_invocables, spawn_registry_watcher};
 use crate::record_sync;
-use crate::startup::background::{run_git_sync_logged, run_snapshot_generation};
+use crate::startup::background::{
+    run_git_sync_logged, run_orphan_purge, run_preview_regeneration, run_snapshot_generation,
+};
 use crate::telemetry::TelemetryClient;
 use crate::{
     IndexingState, IndexingStatus, PromptIndexHolder, PromptStatsHolder, PromptTemplatesHolder,
@@ -63,23 +67,7 @@ pub fn spawn_indexer_task(deps: IndexerDeps) {
         let registry = claude_view_core::build_registry(&claude_dir).await;
 
         // 2b. Seed invocables into DB so invocations can reference them (FK constraint)
-        let invocable_tuples: Vec<(String, Option<String>, String, String, String)> = registry
-            .all_invocables()
-            .map(|info| {
-                (
-                    info.id.clone(),
-                    info.plugin_name.clone(),
-                    info.name.clone(),
-                    info.kind.to_string(),
-                    info.description.clone(),
-                )
-            })
-            .collect();
-        if !invocable_tuples.is_empty() {
-            if let Err(e) = idx_db.batch_upsert_invocables(&invocable_tuples).await {
-                tracing::warn!(error = %e, "Failed to seed invocables");
-            }
-        }
+        seed_invocables(&idx_db, &registry).await;
 
         // 2c. Auto-reindex: compare combined fingerprint with stored hash.
         //
@@ -124,6 +112,16 @@ pub fn spawn_indexer_task(deps: IndexerDeps) {
         let registry_arc = Arc::new(registry);
         *idx_registry.write().unwrap() = Some((*registry_arc).clone());
 
+        // 2d. Hot-reload: rebuild the registry when a skill/plugin is
+        // installed or removed, instead of waiting for a restart.
+        if let Err(e) = spawn_registry_watcher(
+            claude_dir.clone(),
+            idx_registry.clone(),
+            Some(idx_db.clone()),
+        ) {
+            tracing::warn!(error = %e, "Registry watcher disabled");
+        }
+
         // 3. Single-pass scan: parse + upsert for each changed file
         idx_state.set_status(IndexingStatus::DeepIndexing);
         let state_for_progress = idx_state.clone();
@@ -171,6 +169,23 @@ pub fn spawn_indexer_task(deps: IndexerDeps) {
                     }
                 }
 
+                // A deep rebuild interrupted by a restart left its unfinished
+                // sessions marked stale; this scan re-indexed them.
+                match idx_db.search_index_rebuild_progress().await {
+                    Ok(Some(progress)) if progress.is_in_progress() => {
+                        tracing::info!(
+                            last_session_id = ?progress.last_session_id,
+                            sessions_processed = progress.sessions_processed,
+                            "Startup scan resumed an interrupted deep index rebuild"
+                        );
+                        if let Err(e) = idx_db.complete_search_index_rebuild().await {
+                            tracing::warn!(error = %e, "Failed to mark rebuild complete");
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!(error = %e, "Failed to load rebuild checkpoint"),
+                }
+
                 // Signal Done immediately — session data is ready.
                 // Post-scan cleanup below is housekeeping, not indexing.
                 idx_state.set_status(IndexingStatus::Done);
@@ -245,10 +260,16 @@ pub fn spawn_indexer_task(deps: IndexerDeps) {
                 // 5. Post-index tasks
                 run_git_sync_logged(&idx_db, "initial").await;
                 run_snapshot_generation(&idx_db, "initial").await;
+                run_preview_regeneration(&idx_db, "initial").await;
 
                 // 6. Prompt History Indexing
-                index_prompt_history(&idx_prompt_index, &idx_prompt_stats, &idx_prompt_templates)
-                    .await;
+                index_prompt_history(
+                    &idx_db,
+                    &idx_prompt_index,
+                    &idx_prompt_stats,
+                    &idx_prompt_templates,
+                )
+                .await;
 
                 // 7. Periodic sync loop: re-scan changed sessions, git-sync, snapshots.
                 // No more two-pass polling — the watcher handles incremental updates.
@@ -259,14 +280,21 @@ pub fn spawn_indexer_task(deps: IndexerDeps) {
                     let sync_interval = Duration::from_secs(interval_secs);
                     tokio::time::sleep(sync_interval).await;
 
-                    // Lightweight re-scan: picks up any files the watcher missed
+                    // Lightweight re-scan: picks up any files the watcher missed.
+                    // Uses the current registry so hot-reloaded invocables resolve.
+                    let registry_now = idx_registry
+                        .read()
+                        .unwrap()
+                        .clone()
+                        .map(Arc::new)
+                        .unwrap_or_else(|| registry_arc.clone());
                     let hints = build_index_hints(&claude_dir);
                     let rescan_start = Instant::now();
-                    match scan_and_index_all(
+                    let rescanned = match scan_and_index_all(
                         &claude_dir,
                         &idx_db,
                         &hints,
-                        Some(registry_arc.clone()),
+                        Some(registry_now),
                         |_| {},
                         |_| {},
                         || {},
@@ -282,12 +310,22 @@ pub fn spawn_indexer_task(deps: IndexerDeps) {
                                     Some(indexed as u64),
                                 );
                             }
+                            indexed
                         }
-                        Err(e) => tracing::warn!(error = %e, "Periodic re-scan failed (non-fatal)"),
-                    }
+                        Err(e) => {
+                            tracing::warn!(error = %e, "Periodic re-scan failed (non-fatal)");
+                            0
+                        }
+                    };
 
                     run_git_sync_logged(&idx_db, "periodic").await;
                     run_snapshot_generation(&idx_db, "periodic").await;
+                    // Only re-indexed sessions can have picked up a blank preview.
+                    if rescanned > 0 {
+                        run_preview_regeneration(&idx_db, "periodic").await;
+                    }
+                    run_orphan_purge(&idx_db, "periodic").await;
+                    optimize_prompt_index_if_idle(&idx_prompt_index).await;
                 }
             }
             Err(e) => {
@@ -299,7 +337,13 @@ pub fn spawn_indexer_task(deps: IndexerDeps) {
 
 /// Build the Tantivy prompt-history index from `~/.claude/history.jsonl`.
 /// No-op when the history file does not exist.
+///
+/// When the index was just wiped (schema bump or corruption), it is first
+/// seeded from session previews in the DB and published, so prompt search
+/// works while history is re-parsed. Those seed documents are deleted in the
+/// same commit that adds the history.
 async fn index_prompt_history(
+    db: &Database,
     prompt_index_holder: &PromptIndexHolder,
     prompt_stats_holder: &PromptStatsHolder,
     prompt_templates_holder: &PromptTemplatesHolder,
@@ -315,6 +359,31 @@ async fn index_prompt_history(
         return;
     }
 
+    // Open (or create) the prompt-history index.
+    let index_path = claude_view_core::paths::prompt_index_dir();
+    let index = match claude_view_search::prompt_index::PromptSearchIndex::open_with_config(
+        &index_path,
+        prompt_index_config_from_env(),
+    ) {
+        Ok(index) => index,
+        Err(e) => {
+            tracing::error!(error = %e, "failed to open prompt index");
+            return;
+        }
+    };
+
+    let index = Arc::new(index);
+    let seeded = index.needs_full_reindex;
+    if seeded {
+        match db.rebuild_prompt_index(&index).await {
+            Ok(count) => {
+                *prompt_index_holder.write().unwrap() = Some(index.clone());
+                tracing::info!(count, "prompt index seeded from session previews");
+            }
+            Err(e) => tracing::warn!(error = %e, "failed to seed prompt index from DB"),
+        }
+    }
+
     let entries = match claude_view_core::prompt_history::parse_history(&history_path).await {
         Ok(entries) => entries,
         Err(e) => {
@@ -334,15 +403,11 @@ async fn index_prompt_history(
     let templates = claude_view_core::prompt_templates::detect_templates(&prompt_strs, 3);
     *prompt_templates_holder.write().unwrap() = Some(templates);
 
-    // Build prompt-history index.
-    let index_path = claude_view_core::paths::prompt_index_dir();
-    let index = match claude_view_search::prompt_index::PromptSearchIndex::open(&index_path) {
-        Ok(index) => index,
-        Err(e) => {
-            tracing::error!(error = %e, "failed to open prompt index");
-            return;
-        }
-    };
+    // Prompts carry no model of their own; tag them with their session's.
+    let session_models = db.session_primary_models().await.unwrap_or_else(|e| {
+        tracing::warn!(error = %e, "failed to load session models for prompt index");
+        Default::default()
+    });
 
     let documents: Vec<claude_view_search::prompt_index::PromptDocument> = entries
         .iter()
@@ -352,9 +417,18 @@ async fn index_prompt_history(
             display: e.display.clone(),
             paste_text: e.paste_text(),
             project: e.project_display_name().to_string(),
+            project_display: e.project_display_name().to_string(),
             session_id: e.session_id.clone(),
             branch: String::new(),
-            model: String::new(),
+            model: e
+                .session_id
+                .as_ref()
+                .and_then(|id| session_models.get(id))
+                .cloned()
+                .unwrap_or_default(),
+            skills: claude_view_core::prompt_history::slash_command_name(&e.display)
+                .unwrap_or_default()
+                .to_string(),
             git_root: e.project.clone(),
             intent: claude_view_core::prompt_history::classify_intent(&e.display).to_string(),
             complexity: claude_view_core::prompt_history::complexity_bucket(&e.display).to_string(),
@@ -367,16 +441,87 @@ async fn index_prompt_history(
         tracing::error!(error = %e, "failed to index prompts");
         return;
     }
+    if seeded {
+        if let Err(e) = index.delete_by_prompt_id_prefix(claude_view_db::DB_PROMPT_ID_PREFIX) {
+            tracing::error!(error = %e, "failed to drop seeded prompts");
+            return;
+        }
+    }
     if let Err(e) = index.commit() {
         tracing::error!(error = %e, "failed to commit prompt index");
         return;
     }
     index.mark_schema_synced();
     index.release_writer().ok();
-    *prompt_index_holder.write().unwrap() = Some(Arc::new(index));
+    *prompt_index_holder.write().unwrap() = Some(index);
     tracing::info!(
         count = documents.len(),
         elapsed_ms = ph_start.elapsed().as_millis() as u64,
         "prompt history indexed"
     );
 }
+
+/// Prompt-index config with the idle-optimize thresholds overridable via
+/// `CLAUDE_VIEW_SEARCH_OPTIMIZE_IDLE_SECS` and
+/// `CLAUDE_VIEW_SEARCH_OPTIMIZE_MAX_SEGMENTS`, the per-search result cap via
+/// `CLAUDE_VIEW_SEARCH_MAX_RESULTS`, and the per-prompt indexed text cap via
+/// `CLAUDE_VIEW_SEARCH_MAX_CONTENT_BYTES`. Day-parts (`when:`) follow the
+/// machine's local timezone, DST included, unless
+/// `CLAUDE_VIEW_SEARCH_DAYPART_UTC_OFFSET_SECS` pins a fixed offset. Stop
+/// words come from comma-separated `CLAUDE_VIEW_SEARCH_STOP_WORDS`, minus any
+/// in `CLAUDE_VIEW_SEARCH_KEEP_WORDS`.
+/// Changing the day-part offset, text cap or stop words only affects prompts
+/// indexed afterwards, so delete the prompt index to rebuild it. Unparseable
+/// values keep the default.
+fn prompt_index_config_from_env() -> SearchIndexConfig {
+    let mut config = SearchIndexConfig {
+        daypart_clock: DayPartClock::Local,
+        ..SearchIndexConfig::default()
+    };
+    if let Some(offset) = env_parse::<i32>("CLAUDE_VIEW_SEARCH_DAYPART_UTC_OFFSET_SECS") {
+        config.daypart_clock = DayPartClock::FixedOffset(offset);
+    }
+    if let Some(secs) = env_parse::<u64>("CLAUDE_VIEW_SEARCH_OPTIMIZE_IDLE_SECS") {
+        config.idle_optimize.idle_after = Duration::from_secs(secs);
+    }
+    if let Some(max) = env_parse::<usize>("CLAUDE_VIEW_SEARCH_OPTIMIZE_MAX_SEGMENTS") {
+        config.idle_optimize.max_segments = max;
+    }
+    if let Some(max) = env_parse::<usize>("CLAUDE_VIEW_SEARCH_MAX_RESULTS") {
+        config.max_results = max;
+    }
+    if let Some(max) = env_parse::<usize>("CLAUDE_VIEW_SEARCH_MAX_CONTENT_BYTES") {
+        config.max_content_bytes = Some(max);
+    }
+    config.stop_words.words = env_words("CLAUDE_VIEW_SEARCH_STOP_WORDS");
+    config.stop_words.keep = env_words("CLAUDE_VIEW_SEARCH_KEEP_WORDS");
+    config
+}
+
+/// Comma-separated words from `key`, trimmed, empty entries dropped.
+fn env_words(key: &str) -> Vec<String> {
+    std::env::var(key)
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|w| !w.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn env_parse<T: std::str::FromStr>(key: &str) -> Option<T> {
+    std::env::var(key).ok()?.trim().parse().ok()
+}
+
+/// Merge the prompt index's segments once it has been idle long enough.
+/// Runs on the blocking pool: a merge rewrites the whole index.
+async fn optimize_prompt_index_if_idle(prompt_index_holder: &PromptIndexHolder) {
+    let Some(index) = prompt_index_holder.read().unwrap().clone() else {
+        return;
+    };
+    match tokio::task::spawn_blocking(move || index.optimize_if_idle(Instant::now())).await {
+        Ok(Ok(_)) => {}
+        Ok(Err(e)) => tracing::warn!(error = %e, "Prompt index optimize failed (non-fatal)"),
+        Err(e) => tracing::warn!(error = %e, "Prompt index optimize task panicked"),
+    }
+}
@@ -1,8 +1,10 @@
-//! Server port detection + reclamation helpers.
+//! Server host/port detection + reclamation helpers.
 //!
 //! Extracted from `main.rs` in CQRS Phase 7.c so the runtime entry point
 //! stays focused on orchestration. Behaviour is unchanged.
 
+use std::net::{IpAddr, Ipv4Addr};
+
 /// Default port for the server.
 pub const DEFAULT_PORT: u16 = 47892;
 
@@ -17,6 +19,49 @@ pub fn get_port() -> u16 {
         .unwrap_or(DEFAULT_PORT)
 }
 
+/// Resolve the bind address from the environment.
+///
+/// Precedence: `CLAUDE_VIEW_HOST` → `CLAUDE_VIEW_BIND_ADDR` (older name) →
+/// `127.0.0.1`. An unparseable value falls back to loopback with a warning.
+/// Binding anything other than loopback exposes every session transcript to
+/// the network, so that case prints a loud warning; CORS stays
+/// localhost-only (plus `CLAUDE_VIEW_CORS_ORIGINS`) either way.
+pub fn get_bind_addr() -> IpAddr {
+    let raw = std::env::var("CLAUDE_VIEW_HOST")
+        .ok()
+        .or_else(|| std::env::var("CLAUDE_VIEW_BIND_ADDR").ok());
+    let addr = match parse_bind_addr(raw.as_deref()) {
+        Ok(addr) => addr,
+        Err(bad) => {
+            tracing::warn!("Invalid CLAUDE_VIEW_HOST '{bad}', falling back to 127.0.0.1");
+            IpAddr::V4(Ipv4Addr::LOCALHOST)
+        }
+    };
+    if !addr.is_loopback() {
+        eprintln!(
+            "\n  WARNING: binding to {addr} (non-loopback). Anyone who can reach this \
+             address can read your Claude Code sessions.\n  Unset CLAUDE_VIEW_HOST to \
+             listen on 127.0.0.1 only.\n"
+        );
+        tracing::warn!(%addr, "server bound to a non-loopback address");
+    }
+    addr
+}
+
+/// Parse a host value into an IP address. `None`/empty and `localhost` mean
+/// loopback. Returns the offending input on failure.
+pub fn parse_bind_addr(raw: Option<&str>) -> Result<IpAddr, String> {
+    match raw.map(str::trim) {
+        None | Some("") => Ok(IpAddr::V4(Ipv4Addr::LOCALHOST)),
+        Some(host) if host.eq_ignore_ascii_case("localhost") => Ok(IpAddr::V4(Ipv4Addr::LOCALHOST)),
+        Some(host) => host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse()
+            .map_err(|_| host.to_string()),
+    }
+}
+
 /// Check if a process holding a port is a stale claude-view instance.
 ///
 /// Returns true if the process name contains "claude-view" or
@@ -69,3 +114,41 @@ pub fn try_reclaim_port(port: u16) -> bool {
     }
     killed_any
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv6Addr;
+
+    #[test]
+    fn test_parse_bind_addr_defaults_to_loopback() {
+        let loopback = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        assert_eq!(parse_bind_addr(None), Ok(loopback));
+        assert_eq!(parse_bind_addr(Some("")), Ok(loopback));
+        assert_eq!(parse_bind_addr(Some("localhost")), Ok(loopback));
+    }
+
+    #[test]
+    fn test_parse_bind_addr_accepts_ipv4_and_ipv6() {
+        assert_eq!(
+            parse_bind_addr(Some("0.0.0.0")),
+            Ok(IpAddr::V4(Ipv4Addr::UNSPECIFIED))
+        );
+        assert_eq!(
+            parse_bind_addr(Some(" 172.17.0.2 ")),
+            Ok(IpAddr::V4(Ipv4Addr::new(172, 17, 0, 2)))
+        );
+        assert_eq!(
+            parse_bind_addr(Some("[::1]")),
+            Ok(IpAddr::V6(Ipv6Addr::LOCALHOST))
+        );
+    }
+
+    #[test]
+    fn test_parse_bind_addr_rejects_hostnames() {
+        assert_eq!(
+            parse_bind_addr(Some("my-container")),
+            Err("my-container".to_string())
+        );
+    }
+}
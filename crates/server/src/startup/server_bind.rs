@@ -14,7 +14,7 @@ use tokio::net::TcpListener;
 use std::path::Path;
 
 use crate::startup::install::{detect_install_source, ping_install_beacon};
-use crate::startup::port::{get_port, try_reclaim_port};
+use crate::startup::port::{get_bind_addr, get_port, try_reclaim_port};
 use crate::startup::startup_telemetry::{plan_startup_telemetry, print_privacy_notice};
 use crate::telemetry::TelemetryClient;
 use claude_view_core::telemetry_config::{read_telemetry_config, write_telemetry_config};
@@ -24,7 +24,8 @@ use claude_view_core::telemetry_events::{EVENT_APP_ACTIVE, EVENT_SERVER_STARTED}
 /// return the bound listener together with the port actually used.
 ///
 /// Strategy:
-/// 1. Try the requested port (`CLAUDE_VIEW_PORT` / `PORT` / default).
+/// 1. Try the requested port (`CLAUDE_VIEW_PORT` / `PORT` / default) on
+///    `CLAUDE_VIEW_HOST` (default `127.0.0.1`).
 /// 2. On `EADDRINUSE`, if the holder looks like a stale claude-view → kill
 ///    it and retry the same port.
 /// 3. If the holder is another app → auto-increment (up to +10) unless
@@ -33,18 +34,7 @@ use claude_view_core::telemetry_events::{EVENT_APP_ACTIVE, EVENT_SERVER_STARTED}
 ///    port that hooks wouldn't route to).
 pub async fn bind_listener() -> Result<(TcpListener, u16)> {
     let port = get_port();
-    let bind_addr: std::net::IpAddr = std::env::var("CLAUDE_VIEW_BIND_ADDR")
-        .ok()
-        .and_then(|s| {
-            s.parse()
-                .map_err(|e| {
-                    tracing::warn!(
-                        "Invalid CLAUDE_VIEW_BIND_ADDR '{s}': {e}, falling back to localhost"
-                    );
-                })
-                .ok()
-        })
-        .unwrap_or(std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST));
+    let bind_addr = get_bind_addr();
 
     let skip_hooks = std::env::var("CLAUDE_VIEW_SKIP_HOOKS").as_deref() == Ok("1");
     let mut try_port = port;
@@ -60,6 +60,18 @@ async fn test_health_endpoint_response_structure() {
     assert!(json["uptime_secs"].is_number());
 }
 
+#[tokio::test]
+async fn test_health_endpoint_reports_missing_search_index() {
+    // The default test app never opens a search index.
+    let app = create_app(test_db().await);
+    let (status, body) = get(app, "/api/health").await;
+
+    assert_eq!(status, StatusCode::OK);
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["search_available"], false);
+    assert_eq!(json["search_doc_count"], 0);
+}
+
 // ========================================================================
 // Projects Endpoint Tests
 // ========================================================================
@@ -151,6 +163,62 @@ async fn test_cors_rejects_external_origin() {
     );
 }
 
+/// Minimal router behind a CORS layer with `extra` allowed origins; returns
+/// the `access-control-allow-origin` header for a request from `origin`.
+async fn cors_allow_origin(extra: &str, origin: &str) -> Option<axum::http::HeaderValue> {
+    let app = axum::Router::new()
+        .route("/ping", axum::routing::get(|| async { "ok" }))
+        .layer(app_factory::cors_layer_with_origins(
+            app_factory::parse_cors_origins(Some(extra)),
+        ));
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/ping")
+                .header("Origin", origin)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    response
+        .headers()
+        .get("access-control-allow-origin")
+        .cloned()
+}
+
+#[tokio::test]
+async fn test_cors_allows_configured_origin() {
+    let extra = " http://claude.local/ , https://dev-proxy.example:8443";
+    assert_eq!(
+        cors_allow_origin(extra, "http://claude.local")
+            .await
+            .unwrap(),
+        "http://claude.local"
+    );
+    assert_eq!(
+        cors_allow_origin(extra, "https://dev-proxy.example:8443")
+            .await
+            .unwrap(),
+        "https://dev-proxy.example:8443"
+    );
+    // Localhost stays allowed alongside the configured list.
+    assert!(cors_allow_origin(extra, "http://localhost:5173")
+        .await
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_cors_configured_origins_reject_others() {
+    let extra = "http://claude.local";
+    assert!(cors_allow_origin(extra, "https://evil.com").await.is_none());
+    // Exact match only: no prefix or subdomain tricks.
+    assert!(cors_allow_origin(extra, "http://claude.local.evil.com")
+        .await
+        .is_none());
+    assert!(cors_allow_origin("", "http://claude.local").await.is_none());
+}
+
 // ========================================================================
 // 404 Tests
 // ========================================================================
@@ -324,3 +392,48 @@ async fn test_static_serving_serves_actual_files() {
     assert_eq!(status, StatusCode::OK);
     assert!(body.contains("console.log"));
 }
+
+#[tokio::test]
+async fn test_static_serving_cache_headers() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("index.html"),
+        "<!DOCTYPE html><html></html>",
+    )
+    .unwrap();
+    std::fs::create_dir(temp_dir.path().join("assets")).unwrap();
+    std::fs::write(temp_dir.path().join("assets/index-3f9a1c.js"), "1;").unwrap();
+
+    let app = create_app_with_static(test_db().await, Some(temp_dir.path().to_path_buf()));
+    let cache_control = |uri: &'static str| {
+        let app = app.clone();
+        async move {
+            let response = app
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK, "{uri}");
+            response
+                .headers()
+                .get(axum::http::header::CACHE_CONTROL)
+                .map(|v| v.to_str().unwrap().to_string())
+        }
+    };
+
+    let asset = cache_control("/assets/index-3f9a1c.js").await.unwrap();
+    assert!(asset.contains("immutable"));
+    assert!(asset.contains("max-age=31536000"));
+    assert_eq!(cache_control("/").await.as_deref(), Some("no-store"));
+    assert_eq!(
+        cache_control("/sessions/abc").await.as_deref(),
+        Some("no-store"),
+        "SPA fallback"
+    );
+    assert_eq!(
+        cache_control("/assets/index-stale.js").await.as_deref(),
+        Some("no-store"),
+        "missing asset falls back to index.html"
+    );
+}
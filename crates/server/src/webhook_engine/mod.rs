@@ -115,6 +115,8 @@ async fn handle_event(
             error_tracker.remove(&session.id);
             (vec![WebhookEventType::SessionEnded], session)
         }
+        // `session.ended` webhooks already fire from SessionRemove above.
+        SessionEvent::SessionEnded { .. } => return,
     };
 
     // Load config (file-backed, re-read each time for hot reload).
@@ -0,0 +1,55 @@
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use claude_view_db::Database;
+use tower::ServiceExt;
+
+/// Value of the `dashboard_requests_total` sample for `endpoint`/`status`.
+fn request_count(rendered: &str, endpoint: &str, status: &str) -> Option<u64> {
+    rendered
+        .lines()
+        .filter(|l| l.starts_with("dashboard_requests_total{"))
+        .find(|l| {
+            l.contains(&format!("endpoint=\"{endpoint}\""))
+                && l.contains(&format!("status=\"{status}\""))
+        })
+        .and_then(|l| l.rsplit(' ').next())
+        .and_then(|v| v.parse().ok())
+}
+
+#[tokio::test]
+async fn each_request_is_timed_once_under_its_route_template() {
+    claude_view_server::init_metrics();
+    let db = Database::new_in_memory().await.unwrap();
+    let app = claude_view_server::create_app(db);
+
+    for uri in [
+        "/api/stats/cost-by-model",
+        "/api/stats/cost-by-model",
+        "/api/no-such-route",
+    ] {
+        let resp = app
+            .clone()
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert!(resp.headers().contains_key("x-request-id"));
+        if uri == "/api/no-such-route" {
+            assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        } else {
+            assert_eq!(resp.status(), StatusCode::OK);
+        }
+    }
+
+    let rendered = claude_view_server::metrics::render_metrics().expect("metrics initialized");
+    // Timed by the access-log middleware only: the handler no longer
+    // records its own `cost_by_model` sample on top.
+    assert_eq!(
+        request_count(&rendered, "GET /api/stats/cost-by-model", "200"),
+        Some(2),
+        "{rendered}"
+    );
+    assert_eq!(request_count(&rendered, "cost_by_model", "200"), None);
+    // Unmatched paths share one label instead of leaking raw paths.
+    assert_eq!(request_count(&rendered, "unmatched", "404"), Some(1));
+    assert!(!rendered.contains("no-such-route"));
+}
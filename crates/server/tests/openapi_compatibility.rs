@@ -159,6 +159,14 @@ fn shared_component_schemas_are_strict_supersets() {
 /// declares. "Superset" = baseline's properties must exist in current
 /// with the same JSON type; current is free to add extras.
 fn assert_superset(baseline: &Value, current: &Value, context: &str) {
+    if let Some(violation) = superset_violation(baseline, current, context) {
+        panic!("{violation}");
+    }
+}
+
+/// Non-panicking core of [`assert_superset`]: the first breaking change
+/// found, if any.
+fn superset_violation(baseline: &Value, current: &Value, context: &str) -> Option<String> {
     match (baseline, current) {
         (Value::Object(b), Value::Object(c)) => {
             // If this is a JSON schema object, check `properties` + `type`
@@ -168,59 +176,81 @@ fn assert_superset(baseline: &Value, current: &Value, context: &str) {
                 c.get("properties").filter(|v| v.is_object()),
             ) {
                 for (field, base_field_schema) in bp {
-                    let cur_field_schema = cp.get(field).unwrap_or_else(|| {
-                        panic!(
+                    let Some(cur_field_schema) = cp.get(field) else {
+                        return Some(format!(
                             "{context}: field `{field}` removed from current spec \
                              — breaks legacy frontend. If intentional, update baseline \
                              fixture + coordinate with frontend in the same PR."
-                        )
-                    });
-                    assert_superset(
+                        ));
+                    };
+                    if let Some(violation) = superset_violation(
                         base_field_schema,
                         cur_field_schema,
                         &format!("{context}.properties.{field}"),
-                    );
+                    ) {
+                        return Some(violation);
+                    }
                 }
 
                 // `type` (if declared in baseline) must match exactly —
                 // changing a field from `string` to `integer` is always
                 // breaking.
                 if let Some(base_type) = b.get("type") {
-                    let cur_type = c
-                        .get("type")
-                        .unwrap_or_else(|| panic!("{context}: `type` removed (was {base_type})"));
-                    assert_eq!(
-                        base_type, cur_type,
-                        "{context}: `type` changed — breaking change"
-                    );
+                    let Some(cur_type) = c.get("type") else {
+                        return Some(format!("{context}: `type` removed (was {base_type})"));
+                    };
+                    if base_type != cur_type {
+                        return Some(format!(
+                            "{context}: `type` changed — breaking change \
+                             ({base_type} -> {cur_type})"
+                        ));
+                    }
                 }
-            } else {
-                // Not a JSON schema, recurse through fields that exist in
-                // both. Don't fail on missing keys — this lets non-schema
-                // JSON (descriptions, examples) evolve freely.
-                for (k, bv) in b {
-                    if let Some(cv) = c.get(k) {
-                        assert_superset(bv, cv, &format!("{context}.{k}"));
+            }
+
+            // Recurse through the remaining fields that exist in both.
+            // Don't fail on missing keys — this lets non-schema JSON
+            // (descriptions, examples) evolve freely.
+            for (k, bv) in b {
+                if k == "properties" {
+                    continue;
+                }
+                if let Some(cv) = c.get(k) {
+                    if let Some(violation) = superset_violation(bv, cv, &format!("{context}.{k}")) {
+                        return Some(violation);
                     }
                 }
             }
+            None
         }
         (Value::Array(b), Value::Array(c)) => {
             // Arrays in the OpenAPI spec (like `enum`, `required`,
             // `tags`) are treated as sets where baseline ⊆ current.
-            let cur_items: Vec<&Value> = c.iter().collect();
+            // Schema entries (`allOf` / `oneOf` members) match a current
+            // entry with the same `$ref` and `type` that is itself a
+            // superset, so composed schemas may gain fields.
             for item in b {
-                assert!(
-                    cur_items.iter().any(|ci| *ci == item),
-                    "{context}: array missing required entry {item} — \
-                     enum value / required field was removed"
-                );
+                let found = c.iter().any(|ci| {
+                    ci == item
+                        || (item.is_object()
+                            && ci.is_object()
+                            && item.get("$ref") == ci.get("$ref")
+                            && item.get("type") == ci.get("type")
+                            && superset_violation(item, ci, context).is_none())
+                });
+                if !found {
+                    return Some(format!(
+                        "{context}: array missing required entry {item} — \
+                         enum value / required field was removed"
+                    ));
+                }
             }
+            None
         }
         // Scalar mismatches are caught above as type mismatches. At leaf
         // level the test doesn't enforce value equality — descriptions
         // and examples may reasonably change.
-        _ => {}
+        _ => None,
     }
 }
 
@@ -0,0 +1,73 @@
+//! `/api/search/cards` end to end: a match in a transcript under
+//! `$HOME/.claude/projects` comes back as a card carrying both the hit and
+//! the session's indexed metadata.
+
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use claude_view_db::test_support::SessionSeedBuilder;
+use claude_view_db::Database;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn cards_route_returns_hits_with_session_info() {
+    let home = tempfile::tempdir().unwrap();
+    let project = home.path().join(".claude/projects/-tmp-proj");
+    std::fs::create_dir_all(&project).unwrap();
+    let file_path = project.join("sess-card.jsonl");
+    std::fs::write(
+        &file_path,
+        concat!(
+            r#"{"type":"user","message":{"role":"user","content":"fix the flaky websocket"},"timestamp":"2024-03-09T16:00:00Z"}"#,
+            "\n",
+            r#"{"type":"assistant","message":{"role":"assistant","content":[{"type":"text","text":"websocket fixed"}]},"timestamp":"2024-03-09T16:00:30Z"}"#,
+            "\n",
+        ),
+    )
+    .unwrap();
+    // This binary runs alone, so pointing HOME at the fixture is safe.
+    std::env::set_var("HOME", home.path());
+
+    let db = Database::new_in_memory().await.unwrap();
+    SessionSeedBuilder::new("sess-card")
+        .project_id("-tmp-proj")
+        .file_path(file_path.to_str().unwrap())
+        .last_message_at(1_710_000_030)
+        .turn_count(2)
+        .primary_model("claude-opus-4-6")
+        .seed(&db)
+        .await
+        .unwrap();
+    let app = claude_view_server::create_app(db);
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/search/cards?q=websocket")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["query"], "websocket");
+    assert_eq!(body["totalSessions"], 1);
+    assert_eq!(body["totalMatches"], 2);
+
+    let card = &body["cards"][0];
+    // Hit fields are flattened onto the card...
+    assert_eq!(card["sessionId"], "sess-card");
+    assert_eq!(card["matchCount"], 2);
+    assert_eq!(card["matchTurns"], serde_json::json!([1]));
+    assert!(card["topMatch"]["snippet"]
+        .as_str()
+        .unwrap()
+        .contains("<mark>websocket</mark>"));
+    // ...next to the session's indexed metadata.
+    assert_eq!(card["session"]["id"], "sess-card");
+    assert_eq!(card["session"]["turnCount"], 2);
+    assert_eq!(card["session"]["primaryModel"], "claude-opus-4-6");
+}
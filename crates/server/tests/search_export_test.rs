@@ -0,0 +1,61 @@
+//! `/api/search/export` end to end: transcripts under `$HOME/.claude/projects`
+//! are searched and every match comes back as one export row.
+
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use claude_view_db::Database;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn export_route_streams_plain_rows_with_role_and_message_time() {
+    let home = tempfile::tempdir().unwrap();
+    let project = home.path().join(".claude/projects/-tmp-proj");
+    std::fs::create_dir_all(&project).unwrap();
+    std::fs::write(
+        project.join("sess-export.jsonl"),
+        concat!(
+            r#"{"type":"user","message":{"role":"user","content":"fix the flaky websocket"},"timestamp":"2024-03-09T16:00:00Z"}"#,
+            "\n",
+            r#"{"type":"assistant","message":{"role":"assistant","content":[{"type":"text","text":"websocket fixed"}]},"timestamp":"2024-03-09T16:00:30Z"}"#,
+            "\n",
+        ),
+    )
+    .unwrap();
+    // This binary runs alone, so pointing HOME at the fixture is safe.
+    std::env::set_var("HOME", home.path());
+
+    let db = Database::new_in_memory().await.unwrap();
+    let app = claude_view_server::create_app(db);
+    let resp = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/search/export?q=websocket&format=jsonl")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.headers().get(header::CONTENT_TYPE).unwrap(),
+        "application/x-ndjson"
+    );
+
+    let bytes = axum::body::to_bytes(resp.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let rows: Vec<serde_json::Value> = String::from_utf8(bytes.to_vec())
+        .unwrap()
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0]["sessionId"], "sess-export");
+    assert_eq!(rows[0]["role"], "user");
+    assert_eq!(rows[0]["timestamp"], 1_710_000_000);
+    assert_eq!(rows[1]["role"], "assistant");
+    assert_eq!(rows[1]["timestamp"], 1_710_000_030);
+    assert!(rows
+        .iter()
+        .all(|r| !r["snippet"].as_str().unwrap().contains("<mark>")));
+}
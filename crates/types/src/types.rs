@@ -176,12 +176,26 @@ pub struct SessionMetadata {
     pub tool_call_count: usize,
 }
 
+/// A file or image attached to a user message (image/document content block).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[cfg_attr(feature = "codegen", ts(export))]
+#[serde(rename_all = "camelCase")]
+pub struct Attachment {
+    /// File name from the block's title or source URL, else a placeholder
+    /// derived from the media type (e.g. `image.png`).
+    pub name: String,
+    pub media_type: String,
+}
+
 /// A parsed session with messages and metadata
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS)]
 #[cfg_attr(feature = "codegen", ts(export))]
 pub struct ParsedSession {
     pub messages: Vec<Message>,
     pub metadata: SessionMetadata,
+    /// Distinct attachments across the session, in first-seen order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
 }
 
 impl ParsedSession {
@@ -192,9 +206,15 @@ impl ParsedSession {
                 tool_call_count,
             },
             messages,
+            attachments: Vec::new(),
         }
     }
 
+    pub fn with_attachments(mut self, attachments: Vec<Attachment>) -> Self {
+        self.attachments = attachments;
+        self
+    }
+
     pub fn is_empty(&self) -> bool {
         self.messages.is_empty()
     }
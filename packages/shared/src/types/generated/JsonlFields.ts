@@ -56,6 +56,11 @@ export type JsonlFields = {
    * Computed cost breakdown in USD.
    */
   cost: CostBreakdown
+  /**
+   * Current token burn rate in tokens per minute, over a rolling window.
+   * 0 when the session is idle or has too few samples.
+   */
+  tokenBurnRate: number
   /**
    * Whether the Anthropic prompt cache is likely warm or cold.
    */